@@ -0,0 +1,248 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::state::write_atomic;
+
+/// Where the host's last-used login is kept, next to its login directories.
+/// The leading dot can't come out of `path_segment`, so no login clashes.
+const LAST_LOGIN_FILE: &str = ".last-login";
+
+// ── Account namespace ──────────────────────────────────────────────
+
+/// Whose state and caches are read and written. Each account gets its own
+/// `~/.atlas/state/<host>/<login>/` and `~/.atlas/cache/<host>/<login>/`,
+/// so switching tokens never shows one account's repos to another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Account {
+    pub host: String,
+    pub login: String,
+}
+
+impl Account {
+    pub fn new(api_url: &str, login: &str) -> Self {
+        Self {
+            host: api_host(api_url),
+            login: login.to_string(),
+        }
+    }
+
+    fn relative_dir(&self) -> PathBuf {
+        PathBuf::from(path_segment(&self.host)).join(path_segment(&self.login))
+    }
+
+    /// `~/.atlas/state/<host>/<login>`, holding `state.json`
+    pub fn state_dir(&self, atlas_dir: &Path) -> PathBuf {
+        atlas_dir.join("state").join(self.relative_dir())
+    }
+
+    /// `~/.atlas/cache/<host>/<login>`
+    pub fn cache_dir(&self, atlas_dir: &Path) -> PathBuf {
+        atlas_dir.join("cache").join(self.relative_dir())
+    }
+
+    /// Record this as the host's current account, for commands that can't
+    /// ask GitHub who the token belongs to (shell completion)
+    pub fn remember(&self, atlas_dir: &Path) -> Result<()> {
+        let dir = atlas_dir.join("state").join(path_segment(&self.host));
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+        write_atomic(&dir.join(LAST_LOGIN_FILE), self.login.as_bytes())
+    }
+
+    /// The account last used against `api_url`, if any
+    pub fn last_used(atlas_dir: &Path, api_url: &str) -> Option<Self> {
+        let host = api_host(api_url);
+        let path = atlas_dir
+            .join("state")
+            .join(path_segment(&host))
+            .join(LAST_LOGIN_FILE);
+        let login = std::fs::read_to_string(path).ok()?;
+        let login = login.trim();
+        (!login.is_empty()).then(|| Self {
+            host,
+            login: login.to_string(),
+        })
+    }
+
+    /// Move the state file and duration cache from before accounts had
+    /// their own directories into this one. Whatever the account already
+    /// has is left alone. Returns where each moved file went.
+    pub fn migrate_flat_files(&self, atlas_dir: &Path) -> Result<Vec<PathBuf>> {
+        let moves = [
+            (
+                atlas_dir.join("state.json"),
+                self.state_dir(atlas_dir).join("state.json"),
+            ),
+            (
+                atlas_dir.join("cache").join("durations"),
+                self.cache_dir(atlas_dir).join("durations"),
+            ),
+        ];
+        let mut moved = Vec::new();
+        for (from, to) in moves {
+            if !from.exists() || to.exists() {
+                continue;
+            }
+            if let Some(parent) = to.parent() {
+                std::fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create {}", parent.display()))?;
+            }
+            std::fs::rename(&from, &to).with_context(|| {
+                format!("Failed to move {} to {}", from.display(), to.display())
+            })?;
+            moved.push(to);
+        }
+        Ok(moved)
+    }
+
+    /// Whether anything is stored for this account
+    pub fn has_data(&self, atlas_dir: &Path) -> bool {
+        self.state_dir(atlas_dir).exists() || self.cache_dir(atlas_dir).exists()
+    }
+
+    /// Delete this account's state and caches
+    pub fn purge(&self, atlas_dir: &Path) -> Result<()> {
+        for dir in [self.state_dir(atlas_dir), self.cache_dir(atlas_dir)] {
+            if dir.exists() {
+                std::fs::remove_dir_all(&dir)
+                    .with_context(|| format!("Failed to delete {}", dir.display()))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The host an API URL belongs to: `github.com` for `https://api.github.com`,
+/// `ghe.example.com:8443` for `https://ghe.example.com:8443/api/v3`
+pub fn api_host(api_url: &str) -> String {
+    let Ok(url) = reqwest::Url::parse(api_url) else {
+        return api_url.to_string();
+    };
+    let host = url.host_str().unwrap_or_default();
+    let host = if host == "api.github.com" {
+        "github.com"
+    } else {
+        host
+    };
+    match url.port() {
+        Some(port) => format!("{}:{}", host, port),
+        None => host.to_string(),
+    }
+}
+
+/// A login or host as one directory name: lowercased (logins are
+/// case-insensitive), with anything but letters, digits, `-`, `_` and
+/// inner dots percent-encoded so two names never share a directory
+pub fn path_segment(text: &str) -> String {
+    if text.is_empty() {
+        return "%00".to_string();
+    }
+    let mut segment = String::new();
+    for (i, byte) in text.to_lowercase().bytes().enumerate() {
+        match byte {
+            b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' => segment.push(byte as char),
+            b'.' if i > 0 => segment.push('.'),
+            _ => segment.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    segment
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_atlas(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("atlas-account-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_account_paths() {
+        let root = Path::new("/home/me/.atlas");
+        let account = Account::new("https://api.github.com", "Ilias");
+        assert_eq!(
+            account.cache_dir(root),
+            Path::new("/home/me/.atlas/cache/github.com/ilias")
+        );
+        assert_eq!(
+            account.state_dir(root),
+            Path::new("/home/me/.atlas/state/github.com/ilias")
+        );
+
+        let ghe = Account::new("https://ghe.corp:8443/api/v3", "ilias");
+        assert_eq!(ghe.host, "ghe.corp:8443");
+        assert_eq!(
+            ghe.cache_dir(root),
+            Path::new("/home/me/.atlas/cache/ghe.corp%3A8443/ilias")
+        );
+    }
+
+    #[test]
+    fn test_path_segment_sanitizes_logins() {
+        assert_eq!(path_segment("octo-cat_1"), "octo-cat_1");
+        assert_eq!(path_segment("first.last"), "first.last");
+        assert_eq!(path_segment("../etc"), "%2E.%2Fetc");
+        assert_eq!(path_segment(".."), "%2E.");
+        assert_eq!(path_segment("a/b"), "a%2Fb");
+        assert_eq!(path_segment("a\\b"), "a%5Cb");
+        assert_eq!(path_segment("dom\\user@corp"), "dom%5Cuser%40corp");
+        assert_eq!(path_segment("jürgen"), "j%C3%BCrgen");
+        assert_eq!(path_segment(""), "%00");
+        // Encoded names can't collide with a plain one
+        assert_ne!(path_segment("a b"), path_segment("a_b"));
+    }
+
+    #[test]
+    fn test_migrate_flat_files() {
+        let root = temp_atlas("migrate");
+        std::fs::create_dir_all(root.join("cache/durations/octo")).unwrap();
+        std::fs::write(root.join("cache/durations/octo/api.json"), "{}").unwrap();
+        std::fs::write(root.join("state.json"), "{\"recent\": {\"octo/api\": 100}}").unwrap();
+
+        let account = Account::new("https://api.github.com", "ilias");
+        let moved = account.migrate_flat_files(&root).unwrap();
+        assert_eq!(moved.len(), 2);
+        assert!(!root.join("state.json").exists());
+        assert!(account.state_dir(&root).join("state.json").exists());
+        assert!(account
+            .cache_dir(&root)
+            .join("durations/octo/api.json")
+            .exists());
+
+        // A second account finds nothing left to take over
+        let work = Account::new("https://api.github.com", "ilias-work");
+        assert!(work.migrate_flat_files(&root).unwrap().is_empty());
+        assert!(!work.has_data(&root));
+
+        // Never overwrites what the account already has
+        std::fs::write(root.join("state.json"), "{}").unwrap();
+        assert!(account.migrate_flat_files(&root).unwrap().is_empty());
+        assert!(root.join("state.json").exists());
+
+        account.purge(&root).unwrap();
+        assert!(!account.has_data(&root));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_last_used_is_per_host() {
+        let root = temp_atlas("last-used");
+        let account = Account::new("https://api.github.com", "ilias");
+        account.remember(&root).unwrap();
+        assert_eq!(
+            Account::last_used(&root, "https://api.github.com"),
+            Some(account.clone())
+        );
+        assert_eq!(Account::last_used(&root, "https://ghe.corp/api/v3"), None);
+
+        // The marker file survives purging the account itself
+        account.purge(&root).unwrap();
+        assert!(Account::last_used(&root, "https://api.github.com").is_some());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,261 @@
+use ratatui::style::{Color, Modifier, Style};
+use std::ops::Range;
+
+// ── Escape sequences ───────────────────────────────────────────────
+
+const ESC: u8 = 0x1b;
+/// Ends an OSC sequence (`ESC ]` … `BEL`), e.g. terminal hyperlinks
+const BEL: u8 = 0x07;
+
+/// Byte ranges of a log line's visible text, each with the style the
+/// escape codes before it set up
+pub type AnsiSegments = Vec<(Range<usize>, Style)>;
+
+// ── Parser ─────────────────────────────────────────────────────────
+
+/// Split an ANSI-colored line into styled text, leaving the escape
+/// sequences out. `None` when the line has no escapes at all.
+///
+/// Only SGR (`ESC [ … m`) changes the style; other sequences are dropped,
+/// and one cut off by the end of the line is dropped with it.
+pub fn segments(line: &str) -> Option<AnsiSegments> {
+    let b = line.as_bytes();
+    if !b.contains(&ESC) {
+        return None;
+    }
+
+    let mut out = Vec::new();
+    let mut style = Style::default();
+    let mut text_start = 0;
+    let mut i = 0;
+    while i < b.len() {
+        if b[i] != ESC {
+            i += 1;
+            continue;
+        }
+        if text_start < i {
+            out.push((text_start..i, style));
+        }
+        i = match b.get(i + 1) {
+            Some(b'[') => {
+                let (end, sgr) = csi(b, i + 2);
+                if let Some(params) = sgr {
+                    style = apply_sgr(style, &line[params]);
+                }
+                end
+            }
+            Some(b']') => osc_end(b, i + 2),
+            // Two-byte escapes (`ESC =`, `ESC M`, …)
+            Some(c) if c.is_ascii() => i + 2,
+            _ => i + 1,
+        };
+        text_start = i;
+    }
+    if text_start < b.len() {
+        out.push((text_start..b.len(), style));
+    }
+    Some(out)
+}
+
+/// Skip a CSI sequence whose parameters start at `start`. Returns where
+/// the sequence ends and, for SGR, the parameter bytes.
+fn csi(b: &[u8], start: usize) -> (usize, Option<Range<usize>>) {
+    let mut i = start;
+    // Parameter and intermediate bytes
+    while i < b.len() && (0x20..=0x3f).contains(&b[i]) {
+        i += 1;
+    }
+    match b.get(i) {
+        Some(b'm') => (i + 1, Some(start..i)),
+        Some(c) if (0x40..=0x7e).contains(c) => (i + 1, None),
+        // Malformed: drop what was read, keep whatever follows
+        _ => (i, None),
+    }
+}
+
+/// Skip an OSC sequence, ended by `BEL` or `ESC \`
+fn osc_end(b: &[u8], start: usize) -> usize {
+    let mut i = start;
+    while i < b.len() {
+        match b[i] {
+            BEL => return i + 1,
+            ESC if b.get(i + 1) == Some(&b'\\') => return i + 2,
+            _ => i += 1,
+        }
+    }
+    i
+}
+
+/// Apply `1;31`-style SGR parameters; unknown codes change nothing
+fn apply_sgr(mut style: Style, params: &str) -> Style {
+    if params.is_empty() {
+        return Style::default();
+    }
+    let codes: Vec<Option<u16>> = params.split([';', ':']).map(|p| p.parse().ok()).collect();
+    let mut i = 0;
+    while i < codes.len() {
+        let Some(code) = codes[i] else {
+            i += 1;
+            continue;
+        };
+        match code {
+            0 => style = Style::default(),
+            1 => style = style.add_modifier(Modifier::BOLD),
+            2 => style = style.add_modifier(Modifier::DIM),
+            3 => style = style.add_modifier(Modifier::ITALIC),
+            4 => style = style.add_modifier(Modifier::UNDERLINED),
+            7 => style = style.add_modifier(Modifier::REVERSED),
+            9 => style = style.add_modifier(Modifier::CROSSED_OUT),
+            22 => style = style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+            23 => style = style.remove_modifier(Modifier::ITALIC),
+            24 => style = style.remove_modifier(Modifier::UNDERLINED),
+            27 => style = style.remove_modifier(Modifier::REVERSED),
+            29 => style = style.remove_modifier(Modifier::CROSSED_OUT),
+            30..=37 => style.fg = Some(basic_color(code - 30, false)),
+            39 => style.fg = None,
+            40..=47 => style.bg = Some(basic_color(code - 40, false)),
+            49 => style.bg = None,
+            90..=97 => style.fg = Some(basic_color(code - 90, true)),
+            100..=107 => style.bg = Some(basic_color(code - 100, true)),
+            38 | 48 => {
+                let (color, used) = extended_color(&codes[i + 1..]);
+                if let Some(color) = color {
+                    if code == 38 {
+                        style.fg = Some(color);
+                    } else {
+                        style.bg = Some(color);
+                    }
+                }
+                i += used;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    style
+}
+
+/// `5;n` (256-color) or `2;r;g;b` after a 38/48, and how many
+/// parameters it took
+fn extended_color(rest: &[Option<u16>]) -> (Option<Color>, usize) {
+    let byte = |i: usize| {
+        rest.get(i)
+            .copied()
+            .flatten()
+            .and_then(|v| u8::try_from(v).ok())
+    };
+    match rest.first().copied().flatten() {
+        Some(5) => (byte(1).map(Color::Indexed), 2.min(rest.len())),
+        Some(2) => match (byte(1), byte(2), byte(3)) {
+            (Some(r), Some(g), Some(b)) => (Some(Color::Rgb(r, g, b)), 4),
+            _ => (None, rest.len()),
+        },
+        _ => (None, 0),
+    }
+}
+
+fn basic_color(n: u16, bright: bool) -> Color {
+    match (n, bright) {
+        (0, false) => Color::Black,
+        (1, false) => Color::Red,
+        (2, false) => Color::Green,
+        (3, false) => Color::Yellow,
+        (4, false) => Color::Blue,
+        (5, false) => Color::Magenta,
+        (6, false) => Color::Cyan,
+        (7, false) => Color::Gray,
+        (0, true) => Color::DarkGray,
+        (1, true) => Color::LightRed,
+        (2, true) => Color::LightGreen,
+        (3, true) => Color::LightYellow,
+        (4, true) => Color::LightBlue,
+        (5, true) => Color::LightMagenta,
+        (6, true) => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The visible text of `line`, with the style of each piece
+    fn pieces(line: &str) -> Vec<(&str, Style)> {
+        segments(line)
+            .unwrap()
+            .into_iter()
+            .map(|(range, style)| (&line[range], style))
+            .collect()
+    }
+
+    fn text(line: &str) -> String {
+        pieces(line).into_iter().map(|(t, _)| t).collect()
+    }
+
+    #[test]
+    fn test_plain_lines_have_no_segments() {
+        assert_eq!(segments("test result: ok. 3 passed"), None);
+        assert_eq!(segments(""), None);
+    }
+
+    #[test]
+    fn test_cargo_output() {
+        let line = "\x1b[1m\x1b[92m   Compiling\x1b[0m atlas v1.0.1 (/home/runner/work/atlas)";
+        let got = pieces(line);
+        assert_eq!(got[0].0, "   Compiling");
+        assert_eq!(
+            got[0].1,
+            Style::default()
+                .fg(Color::LightGreen)
+                .add_modifier(Modifier::BOLD)
+        );
+        assert_eq!(
+            got[1],
+            (" atlas v1.0.1 (/home/runner/work/atlas)", Style::default())
+        );
+
+        let line =
+            "\x1b[0m\x1b[1m\x1b[38;5;9merror[E0308]\x1b[0m\x1b[0m\x1b[1m: mismatched types\x1b[0m";
+        let got = pieces(line);
+        assert_eq!(got[0].0, "error[E0308]");
+        assert_eq!(got[0].1.fg, Some(Color::Indexed(9)));
+        assert_eq!(got[1].0, ": mismatched types");
+        assert_eq!(got[1].1, Style::default().add_modifier(Modifier::BOLD));
+    }
+
+    #[test]
+    fn test_npm_and_pytest_output() {
+        let line = "\x1b[37;40mnpm\x1b[0m \x1b[0m\x1b[31;40mERR!\x1b[0m \x1b[0m\x1b[35mcode\x1b[0m ELIFECYCLE";
+        assert_eq!(text(line), "npm ERR! code ELIFECYCLE");
+        let got = pieces(line);
+        assert_eq!(got[0].1, Style::default().fg(Color::Gray).bg(Color::Black));
+        assert_eq!(got[2].0, "ERR!");
+        assert_eq!(got[2].1.fg, Some(Color::Red));
+
+        let line = "\x1b[31mFAILED\x1b[0m tests/test_api.py::test_login - \x1b[38;2;255;85;85mAssertionError\x1b[39m";
+        let got = pieces(line);
+        assert_eq!(got[0], ("FAILED", Style::default().fg(Color::Red)));
+        assert_eq!(got[2].1.fg, Some(Color::Rgb(255, 85, 85)));
+    }
+
+    #[test]
+    fn test_partial_and_unsupported_sequences() {
+        // Cut off at the end of the line
+        assert_eq!(text("done\x1b[3"), "done");
+        assert_eq!(text("done\x1b"), "done");
+        // Extended colors missing their values
+        assert_eq!(pieces("\x1b[38;5mx")[0], ("x", Style::default()));
+        assert_eq!(pieces("\x1b[38;2;1mx")[0], ("x", Style::default()));
+        // Cursor movement and erase are dropped
+        assert_eq!(text("\x1b[2K\x1b[1Gprogress 50%"), "progress 50%");
+        // Hyperlinks keep their text
+        assert_eq!(
+            text("\x1b]8;;https://example.com\x07docs\x1b]8;;\x1b\\ here"),
+            "docs here"
+        );
+        // An escape before multi-byte text doesn't split it
+        assert_eq!(text("\x1bé\x1b[é"), "éé");
+    }
+}
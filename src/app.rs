@@ -1,41 +1,700 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use crossterm::event::KeyCode;
+use ratatui::layout::{Position, Rect};
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
-use tracing::{debug, error};
+use tokio::task::AbortHandle;
+use tracing::{debug, error, info, warn};
 
+use crate::account::Account;
+use crate::audit::{self, AuditAction, AuditEntry, AuditLog};
+use crate::bookmarks::BookmarkFile;
+use crate::cache::{self, CacheReport};
+use crate::config::{Config, QuitBehavior};
+use crate::dispatch::{self, DispatchForm, DispatchInput};
+use crate::durations::{self, DurationHistory, Stuck};
+use crate::event::help_len;
+use crate::exports::{ExportLog, ExportMeta, ExportRecord, ExportsOverlay};
 use crate::github::GitHubClient;
-use crate::models::{Job, JobsResponse, Repository, WorkflowRun, WorkflowRunsResponse};
+use crate::groups::{self, LogLine};
+use crate::highlight::{LogHighlight, LogHighlighter};
+use crate::lint::{self, WorkflowProblem};
+use crate::models::{
+    format_bytes, format_count, format_duration, sort_failed_first, ActionsCache, Annotation,
+    Artifact, ArtifactsResponse, Commit, Deployment, Environment, Job, JobsResponse, Repository,
+    Runner, Step, Workflow, WorkflowRun, WorkflowRunsResponse,
+};
+use crate::protected::ProtectedWorkflows;
+use crate::scopes::{self, TokenScope};
+use crate::state::{self, StateStore};
 
 // ── App views ──────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum View {
     RepoList,
+    WorkflowList,
     RunsList,
     RunDetail,
     Logs,
+    Artifacts,
+    Environments,
+    Runners,
+    Caches,
+    Statistics,
+    /// A run's jobs and the selected job's steps as bars on a time axis
+    RunTimeline,
+}
+
+/// Tables a mouse click can land on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ViewArea {
+    Repos,
+    Workflows,
+    Runs,
+    /// Run details' jobs
+    Jobs,
+    /// Run details' steps of the selected job
+    Steps,
+    /// The split runs view's jobs pane
+    PreviewJobs,
+    /// Run details' annotations panel
+    Annotations,
+    Artifacts,
+    Environments,
+    Runners,
+    Caches,
+}
+
+/// Where the last frame drew each table's rows
+pub type RenderedAreas = RefCell<HashMap<ViewArea, Rect>>;
+
+/// Which pane of the split runs view takes navigation keys
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PaneFocus {
+    Runs,
+    Jobs,
+}
+
+/// Which list in run details takes navigation keys
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DetailFocus {
+    Jobs,
+    /// The selected job's steps; Enter opens the log at the step
+    Steps,
+    Annotations,
+}
+
+/// The part of a job log one step wrote, when the log was opened from
+/// the step
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepSlice {
+    /// Index into the job's steps
+    pub index: usize,
+    pub name: String,
+    /// `log_content` lines of the step, once they have arrived
+    pub lines: Option<Range<usize>>,
+    /// Only the step's lines show; `x` shows the whole job log
+    pub only: bool,
+}
+
+/// Terminals at least this wide show the runs list and the selected run's
+/// jobs side by side
+const SPLIT_MIN_WIDTH: u16 = 180;
+/// Quiet period after the selection moves before its jobs are fetched
+const PREVIEW_DEBOUNCE: Duration = Duration::from_millis(300);
+/// How long jobs of an unfinished run are reused in the split view
+const PREVIEW_TTL: Duration = Duration::from_secs(15);
+/// How often `F` re-fetches the log of a running job
+const LOG_FOLLOW_INTERVAL: Duration = Duration::from_secs(3);
+/// Line batches a log download may get ahead of the UI by
+const LOG_STREAM_BUFFER: usize = 16;
+/// How often `W` polls the run it re-ran
+const WATCH_INTERVAL: Duration = Duration::from_secs(10);
+/// How long a toast stays up
+const TOAST_TTL: Duration = Duration::from_secs(8);
+/// How often, and how many times, to look for the run a dispatch started
+const DISPATCH_POLL_INTERVAL: Duration = Duration::from_secs(3);
+const DISPATCH_POLLS: usize = 10;
+/// Newest runs of the workflow compared before and after a dispatch
+const DISPATCH_LOOKBACK: u8 = 10;
+/// Frames of the loading spinner, one per tick
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+struct PreviewJobs {
+    fetched_at: Instant,
+    jobs: Vec<Job>,
 }
 
 // ── Background task results ────────────────────────────────────────
 
 pub enum BackgroundResult {
     ReposFetched(Result<Vec<Repository>>),
+    /// How many repos the user's list pages through
+    ReposCounted(Result<u64>),
+    WorkflowsFetched(Result<Vec<Workflow>>),
     RunsFetched(Result<WorkflowRunsResponse>),
     JobsFetched {
         run_number: u64,
         result: Result<JobsResponse>,
     },
+    JobHydrated {
+        job_id: u64,
+        result: Result<Job>,
+    },
     LogsFetched {
+        job_id: u64,
         job_name: String,
         result: Result<String>,
     },
+    /// A poll of the run `W` is watching
+    WatchedRunFetched {
+        run_id: u64,
+        result: Result<WorkflowRun>,
+    },
+    /// Lines of a log still downloading
+    LogChunk {
+        job_id: u64,
+        lines: Vec<String>,
+    },
+    /// The end of a streamed log; its lines came as `LogChunk`s
+    LogsStreamed {
+        job_id: u64,
+        job_name: String,
+        result: Result<()>,
+    },
     RerunComplete {
+        repo: String,
+        run_id: u64,
+        run_number: u64,
+        debug: bool,
+        result: Result<()>,
+    },
+    RerunFailedComplete {
+        repo: String,
+        run_id: u64,
+        run_number: u64,
+        debug: bool,
+        result: Result<()>,
+    },
+    JobRerunComplete {
+        repo: String,
+        run_id: u64,
         run_number: u64,
+        job_name: String,
+        debug: bool,
         result: Result<()>,
     },
     CancelComplete {
+        repo: String,
+        run_id: u64,
+        run_number: u64,
+        result: Result<()>,
+    },
+    /// A cancel or re-run of the marked runs: one `(run_id, run_number,
+    /// result)` per run
+    BulkActionComplete {
+        action: AuditAction,
+        repo: String,
+        results: Vec<(u64, u64, Result<()>)>,
+    },
+    /// `Ok(None)` when there is no recent failed run
+    LatestFailureFetched(Result<Option<Box<LatestFailure>>>),
+    PreviewJobsFetched {
+        run_id: u64,
+        result: Result<JobsResponse>,
+    },
+    WorkflowDiagnosed {
+        run_id: u64,
+        result: Result<WorkflowProblem>,
+    },
+    /// `Ok(None)` when the workflow has no `workflow_dispatch` trigger
+    DispatchInputsFetched {
+        workflow_id: u64,
+        /// The repo's default branch, when the form had no ref to start with
+        default_ref: Option<String>,
+        result: Result<Option<Vec<DispatchInput>>>,
+    },
+    DispatchComplete {
+        repo: String,
+        workflow_name: String,
+        git_ref: String,
+        result: Result<()>,
+    },
+    /// The run a dispatch started; `None` if it didn't show up in time
+    DispatchedRunFound {
+        workflow_name: String,
+        git_ref: String,
+        run: Option<Box<WorkflowRun>>,
+    },
+    ExportSaved(Result<ExportRecord>),
+    /// The authenticated user's login, for the audit log
+    LoginFetched(Result<String>),
+    /// Whether the token can re-run and cancel in `repo`
+    TokenScopeChecked {
+        repo: String,
+        result: Result<TokenScope>,
+    },
+    ArtifactsFetched {
+        run_id: u64,
+        result: Result<ArtifactsResponse>,
+    },
+    /// The head commit of the run in view
+    CommitFetched {
+        sha: String,
+        result: Result<Commit>,
+    },
+    /// The repo's environments and the deployments the run made to them
+    EnvironmentsFetched {
+        run_id: u64,
+        result: Result<(Vec<Environment>, Vec<Deployment>)>,
+    },
+    /// The repo's Actions caches, largest first
+    CachesFetched {
+        repo: String,
+        result: Result<Vec<ActionsCache>>,
+    },
+    CacheDeleted {
+        repo: String,
+        cache_id: u64,
+        key: String,
+        result: Result<()>,
+    },
+    /// One run of a deletion gone through, `done` of `total`, `failed` of
+    /// them refused so far
+    RunDeleted {
+        repo: String,
+        run_id: u64,
         run_number: u64,
         result: Result<()>,
+        done: usize,
+        total: usize,
+        failed: usize,
+    },
+    /// A workflow turned on (`enable`) or off
+    WorkflowToggled {
+        repo: String,
+        workflow_id: u64,
+        name: String,
+        enable: bool,
+        result: Result<()>,
+    },
+    /// Self-hosted runners of the repo and its org
+    RunnersFetched {
+        run_id: u64,
+        result: Result<Vec<Runner>>,
+    },
+    RunCountsFetched {
+        run_id: u64,
+        counts: RunCounts,
+    },
+    /// Check-run annotations of a failed run
+    AnnotationsFetched {
+        run_id: u64,
+        result: Result<Vec<Annotation>>,
+    },
+    RetentionFetched {
+        /// `owner/repo`
+        repo: String,
+        result: Result<u32>,
+    },
+    /// Sent each time a download's whole-percent progress changes
+    ArtifactProgress {
+        artifact_id: u64,
+        percent: u8,
+    },
+    ArtifactDownloaded {
+        artifact_id: u64,
+        result: Result<PathBuf>,
+    },
+}
+
+/// Everything needed to open the TUI straight on a failed job's logs
+pub struct LatestFailure {
+    pub run: WorkflowRun,
+    pub jobs: Vec<Job>,
+    pub job_index: usize,
+    pub logs: String,
+}
+
+/// Log retention when the repo's setting can't be read: GitHub's default,
+/// and the most a public repo may keep
+pub const DEFAULT_RETENTION_DAYS: u32 = 90;
+
+/// What `f` cycles the runs list through, after "all"
+pub const STATUS_FILTERS: [&str; 5] = ["failure", "in_progress", "queued", "success", "cancelled"];
+
+/// What `e` cycles the runs list through, after "all"
+pub const EVENT_FILTERS: [&str; 5] = [
+    "push",
+    "pull_request",
+    "workflow_dispatch",
+    "schedule",
+    "release",
+];
+
+/// Byte ranges of `query` in `line`, ignoring case. Empty when lowercasing
+/// the line changes its length; such lines are only marked as a whole.
+pub fn search_hits(line: &str, query: &str) -> Vec<Range<usize>> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let lower = line.to_lowercase();
+    if lower.len() != line.len() {
+        return Vec::new();
+    }
+    lower
+        .match_indices(&query.to_lowercase())
+        .map(|(i, hit)| i..i + hit.len())
+        .filter(|r| line.is_char_boundary(r.start) && line.is_char_boundary(r.end))
+        .collect()
+}
+
+/// Lines `e` jumps between: the `##[error]` annotations, or lines
+/// mentioning "Error" / "FAILED" when a log has none
+pub fn error_lines(lines: &[String]) -> Vec<usize> {
+    let matching = |pred: &dyn Fn(&str) -> bool| -> Vec<usize> {
+        lines
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| pred(l))
+            .map(|(i, _)| i)
+            .collect()
+    };
+    let annotated = matching(&|l| l.contains("##[error]"));
+    if !annotated.is_empty() {
+        return annotated;
+    }
+    matching(&|l| l.contains("Error") || l.contains("FAILED"))
+}
+
+// ── Downloads ──────────────────────────────────────────────────────
+
+/// A large transfer (job logs, an artifact) that quitting would throw away
+#[derive(Debug, Clone)]
+pub struct Download {
+    /// Job id for logs, artifact id for artifacts
+    pub id: u64,
+    pub label: String,
+    pub started: Instant,
+}
+
+// ── Run counts ─────────────────────────────────────────────────────
+
+/// At-a-glance counts under the run summary; `None` until fetched, or
+/// when the fetch failed
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunCounts {
+    pub artifacts: Option<u64>,
+    pub annotations: Option<u64>,
+    pub billable_ms: Option<u64>,
+}
+
+// ── Re-run and watch ───────────────────────────────────────────────
+
+/// `W`: a run whose failed jobs were re-run, polled until it finishes
+pub struct RunWatch {
+    pub run_id: u64,
+    pub run_number: u64,
+    pub workflow: String,
+    /// The attempt that failed; a later one completing ends the watch
+    attempt: Option<u64>,
+    /// The run's repo, wherever browsing goes in the meantime
+    client: GitHubClient,
+    /// The re-run request hasn't been answered yet
+    pub triggering: bool,
+    /// Status of the last poll, `queued` until one lands
+    pub status: String,
+    /// The re-run showed up as queued or in progress at least once
+    seen_active: bool,
+    pub started: Instant,
+    polled_at: Instant,
+    poll_in_flight: bool,
+}
+
+/// A short notice in the corner that goes away on its own
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub success: bool,
+    pub shown_at: Instant,
+}
+
+// ── Startup ────────────────────────────────────────────────────────
+
+/// The first fetch after launch; the TUI shows placeholder rows until it
+/// lands
+#[derive(Debug)]
+pub struct Connecting {
+    pub since: Instant,
+    tasks: Vec<AbortHandle>,
+}
+
+// ── Confirmation ───────────────────────────────────────────────────
+
+/// A change that can't be taken back, waiting for `y` (or, for a
+/// protected workflow, the run number typed out)
+#[derive(Debug, Clone, PartialEq)]
+pub enum PendingAction {
+    Cancel {
+        run_id: u64,
+        run_number: u64,
+        /// ` 'Deploy prod' on main`, after the run number
+        title: String,
+    },
+    /// `debug` turns on step debug logging (Ctrl+R)
+    RerunAll {
+        run_id: u64,
+        run_number: u64,
+        title: String,
+        debug: bool,
+    },
+    /// Only asked for protected workflows; `F` is otherwise immediate
+    RerunFailed {
+        run_id: u64,
+        run_number: u64,
+        title: String,
+        debug: bool,
+    },
+    /// `R` in run details: just the selected job
+    RerunJob {
+        run_id: u64,
+        run_number: u64,
+        title: String,
+        job_id: u64,
+        job_name: String,
+        debug: bool,
     },
+    /// The dispatch form's submission, for a protected workflow
+    Dispatch { workflow_name: String },
+    /// `C` or `X` with runs marked with Space
+    CancelSelected { count: usize },
+    /// `R` with runs marked with Space
+    RerunSelected { count: usize },
+    /// `d` in the caches view
+    DeleteCache {
+        cache_id: u64,
+        key: String,
+        size_in_bytes: u64,
+    },
+    /// `D`: the selected runs, or the one under the cursor, as `(id, number)`.
+    /// Always asked, even with `confirm_actions` off.
+    DeleteRuns {
+        runs: Vec<(u64, u64)>,
+        /// The run's title when there's just one
+        title: String,
+    },
+    /// `t` in the workflow list
+    ToggleWorkflow {
+        workflow_id: u64,
+        name: String,
+        enable: bool,
+    },
+}
+
+impl PendingAction {
+    pub fn prompt(&self) -> String {
+        match self {
+            Self::Cancel {
+                run_number, title, ..
+            } => format!("Cancel run #{}{}?", run_number, title),
+            Self::RerunAll {
+                run_number,
+                title,
+                debug,
+                ..
+            } => format!(
+                "Re-run all jobs of #{}{}{}?",
+                run_number,
+                title,
+                with_debug(*debug)
+            ),
+            Self::RerunFailed {
+                run_number,
+                title,
+                debug,
+                ..
+            } => format!(
+                "Re-run the failed jobs of #{}{}{}?",
+                run_number,
+                title,
+                with_debug(*debug)
+            ),
+            Self::RerunJob {
+                run_number,
+                title,
+                job_name,
+                debug,
+                ..
+            } => format!(
+                "Re-run job '{}' of #{}{}{}?",
+                job_name,
+                run_number,
+                title,
+                with_debug(*debug)
+            ),
+            Self::Dispatch { workflow_name } => format!("Dispatch {}?", workflow_name),
+            Self::CancelSelected { count } => format!("Cancel {} selected runs?", count),
+            Self::RerunSelected { count } => {
+                format!("Re-run all jobs of {} selected runs?", count)
+            }
+            Self::DeleteCache {
+                key, size_in_bytes, ..
+            } => format!("Delete cache '{}' ({})?", key, format_bytes(*size_in_bytes)),
+            Self::DeleteRuns { runs, title } => match runs.as_slice() {
+                [(_, run_number)] => {
+                    format!("Delete run #{}{}? This can't be undone", run_number, title)
+                }
+                _ => format!("Delete {} selected runs? This can't be undone", runs.len()),
+            },
+            Self::ToggleWorkflow { name, enable, .. } => format!(
+                "{} workflow '{}'?",
+                if *enable { "Enable" } else { "Disable" },
+                name
+            ),
+        }
+    }
+
+    /// What to type when a single key won't do
+    pub fn confirm_word(&self) -> String {
+        match self {
+            Self::Cancel { run_number, .. }
+            | Self::RerunAll { run_number, .. }
+            | Self::RerunFailed { run_number, .. }
+            | Self::RerunJob { run_number, .. } => run_number.to_string(),
+            Self::DeleteRuns { runs, .. } if runs.len() == 1 => runs[0].1.to_string(),
+            Self::DeleteRuns { .. } => "yes".to_string(),
+            Self::Dispatch { .. }
+            | Self::CancelSelected { .. }
+            | Self::RerunSelected { .. }
+            | Self::DeleteCache { .. }
+            | Self::ToggleWorkflow { .. } => "yes".to_string(),
+        }
+    }
+
+    /// The run number, or `yes` for anything
+    fn confirmed_by(&self, typed: &str) -> bool {
+        let typed = typed.trim().trim_start_matches('#');
+        typed.eq_ignore_ascii_case("yes") || typed == self.confirm_word()
+    }
+}
+
+/// ` 'Deploy prod' on main`: how a confirmation names a run after its
+/// number, so a fat-fingered key shows what it would hit
+fn run_title(run: &WorkflowRun) -> String {
+    let mut title = String::new();
+    if let Some(name) = &run.name {
+        title.push_str(&format!(" '{}'", name));
+    }
+    if let Some(branch) = &run.head_branch {
+        title.push_str(&format!(" on {}", branch));
+    }
+    title
+}
+
+/// How a re-run's messages mention debug logging
+fn with_debug(debug: bool) -> &'static str {
+    if debug {
+        " with debug logging"
+    } else {
+        ""
+    }
+}
+
+// ── Selection anchor ───────────────────────────────────────────────
+
+/// The run that was selected when a refresh went out, so the selection can
+/// stay on it even if newer runs push it to another page
+#[derive(Debug, Clone)]
+struct RunAnchor {
+    id: u64,
+    created_at: DateTime<Utc>,
+    /// Page step already taken while following (−1, 0, or 1); following
+    /// never reverses direction, so a deleted run can't bounce between pages
+    hop: i64,
+}
+
+impl From<&WorkflowRun> for RunAnchor {
+    fn from(run: &WorkflowRun) -> Self {
+        Self {
+            id: run.id,
+            created_at: run.created_at,
+            hop: 0,
+        }
+    }
+}
+
+/// Index of the run created closest to `created_at` (0 when empty)
+fn nearest_run(runs: &[WorkflowRun], created_at: DateTime<Utc>) -> usize {
+    runs.iter()
+        .enumerate()
+        .min_by_key(|(_, r)| (r.created_at - created_at).num_seconds().abs())
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+/// Scroll a table showing `visible` of `len` rows from `offset` just far
+/// enough that row `selected` is on screen
+pub fn keep_in_view(selected: usize, offset: usize, visible: usize, len: usize) -> usize {
+    let offset = offset.min(len.saturating_sub(visible));
+    if selected < offset {
+        selected
+    } else if visible > 0 && selected >= offset + visible {
+        selected + 1 - visible
+    } else {
+        offset
+    }
+}
+
+/// `420ms`, `1.3s`
+fn format_fetch_time(elapsed: Duration) -> String {
+    if elapsed < Duration::from_secs(1) {
+        format!("{}ms", elapsed.as_millis())
+    } else {
+        format!("{:.1}s", elapsed.as_secs_f64())
+    }
+}
+
+/// Where the item with id `previous` is after a refresh; if it's gone,
+/// the old `index`, clamped to the new list
+fn reselect<T>(items: &[T], id: impl Fn(&T) -> u64, previous: Option<u64>, index: usize) -> usize {
+    previous
+        .and_then(|previous| items.iter().position(|item| id(item) == previous))
+        .unwrap_or_else(|| index.min(items.len().saturating_sub(1)))
+}
+
+/// Poll the workflow's newest runs for a `workflow_dispatch` run that isn't
+/// in `known`: GitHub answers a dispatch without saying which run it made
+async fn find_dispatched_run(
+    client: &GitHubClient,
+    workflow_id: u64,
+    known: &HashSet<u64>,
+    interval: Duration,
+) -> Option<WorkflowRun> {
+    for _ in 0..DISPATCH_POLLS {
+        tokio::time::sleep(interval).await;
+        match client
+            .get_workflow_runs(DISPATCH_LOOKBACK, 1, None, None, None, Some(workflow_id))
+            .await
+        {
+            Ok(response) => {
+                let found = response
+                    .workflow_runs
+                    .into_iter()
+                    .find(|run| run.event == "workflow_dispatch" && !known.contains(&run.id));
+                if found.is_some() {
+                    return found;
+                }
+            }
+            Err(e) => warn!(error = %e, workflow_id, "Failed to look for the dispatched run"),
+        }
+    }
+    None
 }
 
 // ── App state ──────────────────────────────────────────────────────
@@ -44,35 +703,240 @@ pub struct App {
     pub client: GitHubClient,
     pub view: View,
     pub should_quit: bool,
+    /// Quit was pressed mid-download and is waiting for a y/n answer
+    pub confirm_quit: bool,
+    /// `C` or `R` was pressed and is waiting for a y/n answer
+    pub confirmation_pending: Option<PendingAction>,
+    /// What has been typed to confirm `confirmation_pending`, when it
+    /// targets a protected workflow and a single key won't do
+    pub confirmation_input: Option<String>,
+    protected: ProtectedWorkflows,
+    /// The `?` popup is open and takes every key
+    pub show_help: bool,
+    pub help_scroll: usize,
+    /// The key before this one, for two-key sequences like `gg`
+    pub last_key: Option<KeyCode>,
+    pub quit_behavior: QuitBehavior,
 
     // Background task channel
     bg_tx: mpsc::UnboundedSender<BackgroundResult>,
 
+    /// `~/.atlas`; the account's state and caches go under it once the
+    /// login is known. `None` keeps the app off the disk.
+    pub atlas_dir: Option<PathBuf>,
+    /// The account's `state.json`; `None` until the login is known
+    pub state_store: Option<StateStore>,
+    /// The account's cache directory; `None` keeps duration history in
+    /// memory only
+    pub cache_dir: Option<PathBuf>,
+
+    /// Successful run durations of `durations_repo`, per workflow
+    durations: DurationHistory,
+    durations_repo: Option<String>,
+    /// Say so in the status bar when a run goes past its usual duration
+    pub stuck_alerts: bool,
+    /// Ask y/N before re-runs and cancels (`--yes` turns it off)
+    pub confirm_actions: bool,
+    /// Runs already announced as stuck
+    stuck_notified: HashSet<u64>,
+
     // Repository list
     pub repos: Vec<Repository>,
     pub repos_selected: usize,
+    /// First row the table shows; the UI moves it to keep the selection
+    /// on screen
+    pub repos_offset: Cell<usize>,
+    pub repos_page: u64,
+    pub repos_per_page: u8,
+    /// Repos over all pages; 0 until counted
+    pub repos_total: u64,
     pub repo_filter: String,
     pub searching: bool,
+    /// `owner/repo` of the bookmarked repos, listed first
+    pub bookmarks: HashSet<String>,
+    /// `~/.atlas/bookmarks.json`; `None` keeps bookmarks in memory only
+    pub bookmark_file: Option<BookmarkFile>,
+    /// The list holds the repos named on the command line rather than
+    /// everything the user can see
+    repo_choices: bool,
+
+    // Workflow list (row 0 is "All workflows")
+    pub workflows: Vec<Workflow>,
+    pub workflows_selected: usize,
+    /// Only show runs of this workflow
+    pub workflow_filter: Option<Workflow>,
+    /// The `w` popup over the runs list is open; it shares
+    /// `workflows_selected` with the workflow list
+    pub workflow_picker: bool,
 
     // Runs list
     pub runs: Vec<WorkflowRun>,
     pub runs_selected: usize,
+    /// Like `repos_offset`
+    pub runs_offset: Cell<usize>,
     pub runs_total: u64,
+    /// Runs marked with Space for `X`; only runs on the loaded page stay
+    /// marked
+    pub selected_run_ids: HashSet<u64>,
     pub page: u64,
     pub per_page: u8,
+    /// Fetch whichever page the selected run moved to instead of settling
+    /// on its nearest neighbour
+    pub follow_selection: bool,
+    runs_anchor: Option<RunAnchor>,
+    /// Only show runs on this branch
+    pub branch_filter: Option<String>,
+    /// Branch being typed after `b`
+    pub branch_input: Option<String>,
+    /// Only show runs with this status or conclusion (one of `STATUS_FILTERS`)
+    pub status_filter: Option<&'static str>,
+    /// Only show runs triggered by this event (`push`, `pull_request`, ...)
+    pub event_filter: Option<String>,
+
+    // Split runs view (runs left, selected run's jobs right)
+    /// `None` follows the terminal width
+    pub split_override: Option<bool>,
+    pub terminal_width: u16,
+    pub terminal_height: u16,
+    /// Where the last frame drew each table's rows (below its header), so
+    /// a click can tell which row it hit
+    pub last_rendered_areas: RenderedAreas,
+    pub pane_focus: PaneFocus,
+    pub preview_selected: usize,
+    pub preview_loading: Option<u64>,
+    preview_cache: HashMap<u64, PreviewJobs>,
+    preview_due: Option<(u64, Instant)>,
 
     // Run detail (jobs + steps)
     pub current_run: Option<WorkflowRun>,
     pub jobs: Vec<Job>,
     pub jobs_selected: usize,
+    /// `f` in run details: failed jobs on top
+    pub jobs_failed_first: bool,
+    /// Steps by job id — the jobs list arrives without steps and each job
+    /// is hydrated on demand when selected
+    steps_cache: HashMap<u64, Vec<Step>>,
+    /// What each job's cache steps restored, by job id, read from its log
+    /// once loaded
+    pub cache_reports: HashMap<u64, CacheReport>,
+    pub steps_loading: Option<u64>,
+    /// Why a `startup_failure` run has no jobs
+    pub workflow_problem: Option<WorkflowProblem>,
+
+    // Artifacts of the current run
+    pub artifacts: Vec<Artifact>,
+    pub artifacts_selected: usize,
+    /// Downloads go to `<artifacts_dir>/<run_id>/<name>.zip`
+    pub artifacts_dir: PathBuf,
+
+    // Environments, and the current run's deployments to them
+    pub environments: Vec<Environment>,
+    pub deployments: Vec<Deployment>,
+    pub environments_selected: usize,
+
+    /// Self-hosted runners that could take the selected job
+    pub runners: Vec<Runner>,
+    pub runners_selected: usize,
+    /// The repo's Actions caches, largest first
+    pub actions_caches: Vec<ActionsCache>,
+    pub actions_caches_selected: usize,
+
+    /// Counts for the run summary, with the run they belong to
+    pub run_counts: Option<(u64, RunCounts)>,
+    /// The head commit of the last run opened, for the run summary
+    pub current_commit: Option<Commit>,
+    pub commit_loading: bool,
+    /// Annotations of a failed run, most severe first, with the run they
+    /// belong to
+    pub run_annotations: Option<(u64, Vec<Annotation>)>,
+    pub annotations_selected: usize,
+    /// Tab in run details: the list the cursor is on
+    pub detail_focus: DetailFocus,
+    pub steps_selected: usize,
+    /// Like `runs_offset`, for the steps panel: it keeps its scroll while
+    /// the jobs have focus
+    pub steps_offset: Cell<usize>,
+
+    /// Log retention days per `owner/repo`, looked up once each
+    retention: HashMap<String, u32>,
 
     // Logs (usize avoids u16 overflow on large logs)
     pub log_content: Vec<String>,
     pub log_scroll: usize,
+    pub log_highlighter: LogHighlighter,
+    /// User-pattern matches per log line, found once when the log loads
+    pub log_highlights: Vec<LogHighlight>,
+    /// `##[group]` sections of `log_content`, and which are folded
+    pub log_groups: Vec<LogLine>,
+    /// Set instead of fetching when the run's logs are past retention
+    pub log_expired: Option<u32>,
+    /// Job whose log is loaded, so fetching it again only appends new lines
+    log_job_id: Option<u64>,
+    /// `F`: keep re-fetching a running job's log, pinned to the bottom
+    pub log_follow: bool,
+    /// Download of the log being shown, while it streams in
+    log_stream: Option<AbortHandle>,
+    /// `t`: draw the timestamp GitHub prefixes each log line with
+    pub log_timestamps: bool,
+    log_polled_at: Instant,
+    log_poll_in_flight: bool,
+    /// `/` query in the log view; matching lines are highlighted
+    pub log_search: String,
+    /// Indices of the lines matching `log_search`
+    pub log_matches: Vec<usize>,
+    /// Which of `log_matches` `n`/`N` last jumped to
+    pub log_match_cursor: usize,
+    /// Error line `e` last centered, marked in the log view
+    pub log_error_target: Option<usize>,
+    /// The step the log was opened from, if it was
+    pub log_step: Option<StepSlice>,
+
+    /// Downloads in flight, tracked apart from ordinary fetches
+    pub downloads: Vec<Download>,
+
+    /// Open `workflow_dispatch` popup
+    pub dispatch_form: Option<DispatchForm>,
+
+    // Log exports
+    /// `~/.atlas/exports.json`; `None` keeps exports off the disk
+    pub export_log: Option<ExportLog>,
+    /// Annotation being typed after `s`
+    pub export_prompt: Option<String>,
+    /// Open `E` popup
+    pub exports_overlay: Option<ExportsOverlay>,
+
+    // Audit
+    /// `~/.atlas/audit.log`; `None` records nothing
+    pub audit_log: Option<AuditLog>,
+    /// Who the token belongs to, once `/user` has answered
+    pub login: Option<String>,
+    /// What the token may do in the current repo; `None` until checked
+    pub token_scope: Option<TokenScope>,
+    /// File for main to page through outside the TUI
+    pub pager_request: Option<PathBuf>,
+    /// Text for main to put on the clipboard
+    pub clipboard_request: Option<String>,
+    /// Desktop notification for main to ask the terminal for
+    pub notification_request: Option<String>,
+
+    /// `W` waiting on a re-run to finish
+    pub watch: Option<RunWatch>,
+    pub toast: Option<Toast>,
+
+    // Auto-refresh (`--refresh`)
+    /// `None` leaves refreshing to `r`
+    pub refresh_interval: Option<Duration>,
+    last_refresh: Instant,
 
     // Status bar messages
     pub status_message: String,
     pub loading: bool,
+    /// When the fetch behind `loading` was sent; the status bar adds how
+    /// long it took once it's back
+    loading_since: Option<Instant>,
+    spinner_frame: usize,
+    /// From launch until the first list arrives; Esc cancels it
+    pub connecting: Option<Connecting>,
 }
 
 impl App {
@@ -80,50 +944,173 @@ impl App {
     pub fn new_browser(
         client: GitHubClient,
         bg_tx: mpsc::UnboundedSender<BackgroundResult>,
+        config: &Config,
     ) -> Self {
+        let client = client.with_response_cache(
+            Duration::from_secs(config.api_cache.ttl_secs),
+            config.api_cache.max_entries,
+        );
         Self {
             client,
             view: View::RepoList,
             should_quit: false,
+            confirm_quit: false,
+            show_help: false,
+            help_scroll: 0,
+            last_key: None,
+            confirmation_pending: None,
+            confirmation_input: None,
+            protected: ProtectedWorkflows::new(&config.protected_workflows),
+            quit_behavior: config.downloads.on_quit,
             bg_tx,
+            atlas_dir: None,
+            state_store: None,
+            cache_dir: None,
+
+            durations: DurationHistory::default(),
+            durations_repo: None,
+            stuck_alerts: config.alerts.stuck_runs,
+            confirm_actions: config.confirm_actions,
+            stuck_notified: HashSet::new(),
 
             repos: Vec::new(),
             repos_selected: 0,
+            repos_offset: Cell::new(0),
+            repos_page: 1,
+            repos_per_page: 100,
+            repos_total: 0,
             repo_filter: String::new(),
             searching: false,
+            bookmarks: HashSet::new(),
+            bookmark_file: None,
+            repo_choices: false,
+
+            workflows: Vec::new(),
+            workflows_selected: 0,
+            workflow_filter: None,
+            workflow_picker: false,
 
             runs: Vec::new(),
             runs_selected: 0,
+            runs_offset: Cell::new(0),
             runs_total: 0,
+            selected_run_ids: HashSet::new(),
             page: 1,
-            per_page: 20,
+            per_page: config.per_page,
+            follow_selection: false,
+            runs_anchor: None,
+            branch_filter: None,
+            branch_input: None,
+            status_filter: None,
+            event_filter: None,
+
+            split_override: None,
+            terminal_width: 0,
+            terminal_height: 0,
+            last_rendered_areas: RefCell::new(HashMap::new()),
+            pane_focus: PaneFocus::Runs,
+            preview_selected: 0,
+            preview_loading: None,
+            preview_cache: HashMap::new(),
+            preview_due: None,
 
             current_run: None,
             jobs: Vec::new(),
             jobs_selected: 0,
+            jobs_failed_first: false,
+            steps_cache: HashMap::new(),
+            cache_reports: HashMap::new(),
+            steps_loading: None,
+            workflow_problem: None,
+
+            artifacts: Vec::new(),
+            artifacts_selected: 0,
+            artifacts_dir: state::atlas_dir().join("artifacts"),
+
+            environments: Vec::new(),
+            deployments: Vec::new(),
+            environments_selected: 0,
+
+            runners: Vec::new(),
+            runners_selected: 0,
+            actions_caches: Vec::new(),
+            actions_caches_selected: 0,
+
+            run_counts: None,
+            current_commit: None,
+            commit_loading: false,
+            run_annotations: None,
+            annotations_selected: 0,
+            detail_focus: DetailFocus::Jobs,
+            steps_selected: 0,
+            steps_offset: Cell::new(0),
+            retention: HashMap::new(),
 
             log_content: Vec::new(),
             log_scroll: 0,
+            log_highlighter: LogHighlighter::default(),
+            log_highlights: Vec::new(),
+            log_groups: Vec::new(),
+            log_expired: None,
+            log_job_id: None,
+            log_follow: false,
+            log_stream: None,
+            log_timestamps: false,
+            log_polled_at: Instant::now(),
+            log_poll_in_flight: false,
+            log_search: String::new(),
+            log_matches: Vec::new(),
+            log_match_cursor: 0,
+            log_error_target: None,
+            log_step: None,
+
+            downloads: Vec::new(),
+
+            dispatch_form: None,
+
+            export_log: None,
+            export_prompt: None,
+            exports_overlay: None,
+            audit_log: None,
+            login: None,
+            token_scope: None,
+            pager_request: None,
+            clipboard_request: None,
+            notification_request: None,
+            watch: None,
+            toast: None,
+
+            refresh_interval: (config.refresh_secs > 0)
+                .then(|| Duration::from_secs(config.refresh_secs)),
+            last_refresh: Instant::now(),
 
             status_message: String::from("Loading repositories..."),
             loading: true,
+            loading_since: None,
+            spinner_frame: 0,
+            connecting: None,
         }
     }
 
     /// Create app in single-repo mode (starts at RunsList)
-    pub fn new(client: GitHubClient, bg_tx: mpsc::UnboundedSender<BackgroundResult>) -> Self {
+    pub fn new(
+        client: GitHubClient,
+        bg_tx: mpsc::UnboundedSender<BackgroundResult>,
+        config: &Config,
+    ) -> Self {
         Self {
             view: View::RunsList,
             status_message: String::from("Loading..."),
-            ..Self::new_browser(client, bg_tx)
+            ..Self::new_browser(client, bg_tx, config)
         }
     }
 
     // ── Filtered repos helper ──────────────────────────────────────
 
     /// Returns repos filtered by the current search string
+    /// Repos matching the filter, the bookmarked ones first
     pub fn filtered_repos(&self) -> Vec<&Repository> {
-        if self.repo_filter.is_empty() {
+        let mut repos: Vec<&Repository> = if self.repo_filter.is_empty() {
             self.repos.iter().collect()
         } else {
             let q = self.repo_filter.to_lowercase();
@@ -143,14 +1130,86 @@ impl App {
                             .contains(&q)
                 })
                 .collect()
+        };
+        repos.sort_by_key(|r| !self.bookmarks.contains(&r.full_name));
+        repos
+    }
+
+    // ── Bookmarks ──────────────────────────────────────────────────
+
+    pub fn bookmark_repo(&mut self, full_name: &str) {
+        if self.bookmarks.insert(full_name.to_string()) {
+            let full_name = full_name.to_string();
+            self.save_bookmarks(|bookmarks| {
+                bookmarks.insert(full_name);
+            });
+        }
+    }
+
+    pub fn unbookmark_repo(&mut self, full_name: &str) {
+        if self.bookmarks.remove(full_name) {
+            self.save_bookmarks(|bookmarks| {
+                bookmarks.remove(full_name);
+            });
+        }
+    }
+
+    /// `m` in the repo list: bookmark the selected repo, or drop its
+    /// bookmark, keeping it selected as it moves
+    pub fn toggle_bookmark(&mut self) {
+        if self.view != View::RepoList {
+            return;
+        }
+        let Some(full_name) = self
+            .filtered_repos()
+            .get(self.repos_selected)
+            .map(|r| r.full_name.clone())
+        else {
+            return;
+        };
+        // A failed save says so over these
+        if self.bookmarks.contains(&full_name) {
+            self.status_message = format!("Removed the bookmark on {}", full_name);
+            self.unbookmark_repo(&full_name);
+        } else {
+            self.status_message = format!("★ Bookmarked {}", full_name);
+            self.bookmark_repo(&full_name);
+        }
+        if let Some(at) = self
+            .filtered_repos()
+            .iter()
+            .position(|r| r.full_name == full_name)
+        {
+            self.repos_selected = at;
+        }
+    }
+
+    /// Apply `change` to the bookmarks file too, picking up any bookmarks
+    /// another instance made since
+    fn save_bookmarks(&mut self, change: impl FnOnce(&mut HashSet<String>)) {
+        let Some(file) = &self.bookmark_file else {
+            return;
+        };
+        match file.update(change) {
+            Ok(bookmarks) => self.bookmarks = bookmarks,
+            Err(e) => {
+                error!(error = %e, "Failed to save bookmarks");
+                self.status_message = format!("Error saving bookmarks: {:#}", e);
+            }
         }
     }
 
     // ── Search mode ────────────────────────────────────────────────
 
     pub fn start_search(&mut self) {
-        if self.view == View::RepoList {
-            self.searching = true;
+        match self.view {
+            View::RepoList => self.searching = true,
+            View::Logs => {
+                self.searching = true;
+                self.log_search.clear();
+                self.find_log_matches();
+            }
+            _ => {}
         }
     }
 
@@ -158,13 +1217,25 @@ impl App {
         self.searching = false;
     }
 
+    /// Type into the active search: the log query in the log view, the
+    /// repo filter otherwise
     pub fn search_push(&mut self, c: char) {
+        if self.view == View::Logs {
+            self.log_search.push(c);
+            self.find_log_matches();
+            return;
+        }
         self.repo_filter.push(c);
         self.repos_selected = 0;
         self.update_repo_status();
     }
 
     pub fn search_backspace(&mut self) {
+        if self.view == View::Logs {
+            self.log_search.pop();
+            self.find_log_matches();
+            return;
+        }
         self.repo_filter.pop();
         self.repos_selected = 0;
         self.update_repo_status();
@@ -180,108 +1251,556 @@ impl App {
         }
     }
 
-    fn update_repo_status(&mut self) {
-        let filtered = self.filtered_repos();
-        let total = self.repos.len();
-        let shown = filtered.len();
-        if self.repo_filter.is_empty() {
-            self.status_message = format!("{} repositories", total);
-        } else {
-            self.status_message = format!(
-                "{} / {} repos matching \"{}\"",
-                shown, total, self.repo_filter
-            );
-        }
+    // ── Log search ─────────────────────────────────────────────────
+
+    /// Esc: drop the query and its highlighting
+    pub fn clear_log_search(&mut self) {
+        self.searching = false;
+        self.log_search.clear();
+        self.log_matches.clear();
+        self.status_message.clear();
     }
 
-    // ── Background task spawning (non-blocking) ────────────────────
+    /// Recompute matching lines (case-insensitive) and jump to the first
+    /// one at or below the current scroll position
+    fn find_log_matches(&mut self) {
+        self.log_matches = self.matching_log_lines();
+        let top = self.top_log_line();
+        self.log_match_cursor = self
+            .log_matches
+            .iter()
+            .position(|&line| line >= top)
+            .unwrap_or(0);
+        self.show_log_match();
+    }
 
-    pub fn spawn_fetch_repos(&mut self) {
-        self.loading = true;
-        self.status_message = "Fetching repositories...".to_string();
+    fn matching_log_lines(&self) -> Vec<usize> {
+        if self.log_search.is_empty() {
+            return Vec::new();
+        }
+        let query = self.log_search.to_lowercase();
+        let step = self.log_step_lines();
+        self.log_content
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| step.as_ref().is_none_or(|lines| lines.contains(i)))
+            .filter(|(_, line)| line.to_lowercase().contains(&query))
+            .map(|(i, _)| i)
+            .collect()
+    }
 
-        let client = self.client.clone();
-        let tx = self.bg_tx.clone();
+    /// New log content: recount matches without moving the view
+    fn refresh_log_matches(&mut self) {
+        let current = self.log_matches.get(self.log_match_cursor).copied();
+        self.log_matches = self.matching_log_lines();
+        self.log_match_cursor = current
+            .and_then(|line| self.log_matches.iter().position(|&m| m == line))
+            .unwrap_or(0);
+    }
 
-        tokio::spawn(async move {
-            debug!("Fetching user repositories");
-            let result = client.get_user_repos(100, 1).await;
-            let _ = tx.send(BackgroundResult::ReposFetched(result));
-        });
+    /// `n` / `N`: the next or previous match, wrapping around
+    pub fn next_log_match(&mut self, forward: bool) {
+        let count = self.log_matches.len();
+        if count == 0 {
+            return;
+        }
+        self.log_match_cursor = if forward {
+            (self.log_match_cursor + 1) % count
+        } else {
+            (self.log_match_cursor + count - 1) % count
+        };
+        self.show_log_match();
     }
 
-    pub fn spawn_fetch_runs(&mut self) {
-        self.loading = true;
-        self.status_message = "Fetching workflow runs...".to_string();
+    /// Scroll to the current match, a few lines of context above it
+    fn show_log_match(&mut self) {
+        if self.log_search.is_empty() {
+            return;
+        }
+        let Some(&line) = self.log_matches.get(self.log_match_cursor) else {
+            self.status_message = format!("No matches for \"{}\"", self.log_search);
+            return;
+        };
+        self.log_follow = false;
+        self.scroll_to_log_line(line, 3);
+        self.status_message = format!(
+            "match {}/{}",
+            self.log_match_cursor + 1,
+            self.log_matches.len()
+        );
+    }
 
-        let client = self.client.clone();
-        let per_page = self.per_page;
-        let page = self.page;
-        let tx = self.bg_tx.clone();
+    // ── Error navigation ───────────────────────────────────────────
 
-        tokio::spawn(async move {
-            debug!(page, per_page, "Fetching workflow runs");
-            let result = client.get_workflow_runs(per_page, page, None, None).await;
-            let _ = tx.send(BackgroundResult::RunsFetched(result));
-        });
+    /// `e`: center the next error line, wrapping back to the first
+    pub fn next_log_error(&mut self) {
+        let errors = self.log_error_lines();
+        let next = errors
+            .iter()
+            .position(|&line| self.log_error_target.is_none_or(|t| line > t))
+            .unwrap_or(0);
+        let Some(&line) = errors.get(next) else {
+            self.status_message = "No errors in this log".to_string();
+            return;
+        };
+        self.center_log_error(line);
+        self.status_message = format!("error {}/{}", next + 1, errors.len());
     }
 
-    pub fn spawn_fetch_jobs(&mut self) {
-        if let Some(run) = &self.current_run {
-            self.loading = true;
-            self.status_message = format!("Fetching jobs for run #{}...", run.run_number);
+    /// Mark `line` as the targeted error and scroll it to mid-screen
+    fn center_log_error(&mut self, line: usize) {
+        self.log_error_target = Some(line);
+        self.log_follow = false;
+        self.scroll_to_log_line(line, self.log_viewport_height() / 2);
+    }
 
-            let client = self.client.clone();
-            let run_id = run.id;
-            let run_number = run.run_number;
-            let tx = self.bg_tx.clone();
+    // ── Log groups ─────────────────────────────────────────────────
 
-            tokio::spawn(async move {
-                debug!(run_id, run_number, "Fetching jobs");
-                let result = client.get_jobs(run_id).await;
-                let _ = tx.send(BackgroundResult::JobsFetched { run_number, result });
-            });
+    /// Indices into `log_content` of the lines not folded away, nor
+    /// outside the step the log was opened at; the log view scrolls
+    /// through these
+    pub fn visible_log_lines(&self) -> Vec<usize> {
+        let visible = groups::visible(&self.log_groups, self.log_content.len());
+        match self.log_step_lines() {
+            Some(lines) => visible.into_iter().filter(|i| lines.contains(i)).collect(),
+            None => visible,
         }
     }
 
-    pub fn spawn_fetch_logs(&mut self) {
-        if let Some(job) = self.jobs.get(self.jobs_selected) {
-            self.loading = true;
-            self.status_message = format!("Fetching logs for {}...", job.name);
+    // ── Step logs ──────────────────────────────────────────────────
 
-            let client = self.client.clone();
-            let job_id = job.id;
-            let job_name = job.name.clone();
-            let tx = self.bg_tx.clone();
+    /// The step's lines, while the view is limited to them
+    fn log_step_lines(&self) -> Option<Range<usize>> {
+        self.log_step
+            .as_ref()
+            .filter(|step| step.only)
+            .and_then(|step| step.lines.clone())
+    }
 
-            tokio::spawn(async move {
-                debug!(job_id, %job_name, "Fetching logs");
-                let result = client.get_job_logs(job_id).await;
-                let _ = tx.send(BackgroundResult::LogsFetched { job_name, result });
+    /// Error lines of the log, or of the step shown
+    fn log_error_lines(&self) -> Vec<usize> {
+        let step = self.log_step_lines();
+        error_lines(&self.log_content)
+            .into_iter()
+            .filter(|i| step.as_ref().is_none_or(|lines| lines.contains(i)))
+            .collect()
+    }
+
+    /// Steps of the selected job, once fetched
+    fn selected_steps(&self) -> Option<&[Step]> {
+        self.jobs.get(self.jobs_selected)?.steps.as_deref()
+    }
+
+    /// Find the lines of the step the log was opened at. The first time
+    /// they turn up, open the step's section and scroll to it.
+    fn slice_log_step(&mut self) {
+        let Some(index) = self.log_step.as_ref().map(|step| step.index) else {
+            return;
+        };
+        let Some(starts) = self
+            .selected_steps()
+            .map(|steps| steps.iter().map(|s| s.started_at).collect::<Vec<_>>())
+        else {
+            return;
+        };
+        let lines = groups::step_ranges(&self.log_content, &starts)
+            .into_iter()
+            .nth(index)
+            .flatten();
+        let Some(step) = &mut self.log_step else {
+            return;
+        };
+        let found = step.lines.is_none() && lines.is_some();
+        step.lines = lines.clone();
+        if let Some(start) = lines.map(|lines| lines.start).filter(|_| found) {
+            if let Some(group) = self.log_groups.get_mut(start).filter(|g| g.header) {
+                group.collapsed = false;
+            }
+            self.scroll_to_log_line(start, 0);
+        }
+    }
+
+    /// `x` in a log opened at a step: show the whole job log, or only the
+    /// step again, keeping the step's first line in view
+    pub fn toggle_log_step(&mut self) {
+        if self.view != View::Logs {
+            return;
+        }
+        let Some(step) = &mut self.log_step else {
+            self.status_message =
+                "Open a log at a step to see it alone: Tab to the steps in run details".to_string();
+            return;
+        };
+        step.only = !step.only;
+        self.status_message = if step.only {
+            format!("Showing {} only", step.name)
+        } else {
+            "Showing the whole job log".to_string()
+        };
+        if let Some(start) = step.lines.as_ref().map(|lines| lines.start) {
+            self.scroll_to_log_line(start, 0);
+        }
+        self.refresh_log_matches();
+    }
+
+    /// The `log_content` line at the top of the view
+    fn top_log_line(&self) -> usize {
+        self.visible_log_lines()
+            .get(self.log_scroll)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Unfold the sections around `line` and scroll it into view with
+    /// `above` lines of context
+    fn scroll_to_log_line(&mut self, line: usize, above: usize) {
+        groups::expand_to(&mut self.log_groups, line);
+        let row = self
+            .visible_log_lines()
+            .binary_search(&line)
+            .unwrap_or_else(|row| row);
+        self.log_scroll = row.saturating_sub(above).min(self.max_log_scroll());
+    }
+
+    /// Enter / Space: fold or unfold the section at the top of the view.
+    /// Inside an open section, folds it and scrolls back to its header.
+    pub fn toggle_log_group(&mut self) {
+        let top = self.top_log_line();
+        let header = match self.log_groups.get(top) {
+            Some(line) if line.header => top,
+            _ => match groups::enclosing_header(&self.log_groups, top) {
+                Some(header) => header,
+                None => {
+                    self.status_message = "No ##[group] section here".to_string();
+                    return;
+                }
+            },
+        };
+        let group = &mut self.log_groups[header];
+        group.collapsed = !group.collapsed;
+        if let Ok(row) = self.visible_log_lines().binary_search(&header) {
+            self.log_scroll = row.min(self.max_log_scroll());
+        }
+    }
+
+    /// Log rows on screen: the terminal minus header, status bar,
+    /// keybindings, and the log's borders
+    fn log_viewport_height(&self) -> usize {
+        self.terminal_height.saturating_sub(9) as usize
+    }
+
+    // ── Branch filter ──────────────────────────────────────────────
+
+    /// `b`: start typing a branch name (Enter on an empty name clears the filter)
+    pub fn start_branch_input(&mut self) {
+        if self.view == View::RunsList {
+            self.branch_input = Some(String::new());
+        }
+    }
+
+    pub fn branch_input_push(&mut self, c: char) {
+        if let Some(input) = &mut self.branch_input {
+            input.push(c);
+        }
+    }
+
+    pub fn branch_input_backspace(&mut self) {
+        if let Some(input) = &mut self.branch_input {
+            input.pop();
+        }
+    }
+
+    pub fn cancel_branch_input(&mut self) {
+        self.branch_input = None;
+    }
+
+    /// Apply the typed branch and refetch from the first page
+    pub fn apply_branch_input(&mut self) {
+        let Some(input) = self.branch_input.take() else {
+            return;
+        };
+        let branch = input.trim();
+        self.branch_filter = (!branch.is_empty()).then(|| branch.to_string());
+        self.page = 1;
+        self.runs_selected = 0;
+        self.runs_anchor = None;
+        self.spawn_fetch_runs();
+    }
+
+    // ── Status filter ──────────────────────────────────────────────
+
+    /// `f` / `S`: all → failure → in_progress → queued → success →
+    /// cancelled → all
+    pub fn cycle_status_filter(&mut self) {
+        if self.view != View::RunsList {
+            return;
+        }
+        self.status_filter = match self.status_filter {
+            None => Some(STATUS_FILTERS[0]),
+            Some(current) => STATUS_FILTERS
+                .iter()
+                .position(|s| *s == current)
+                .and_then(|i| STATUS_FILTERS.get(i + 1))
+                .copied(),
+        };
+        self.page = 1;
+        self.runs_selected = 0;
+        self.runs_anchor = None;
+        self.spawn_fetch_runs();
+    }
+
+    /// `e`: all → push → pull_request → workflow_dispatch → schedule →
+    /// release → all. An event from `--event` that isn't among them goes
+    /// back to all
+    pub fn cycle_event_filter(&mut self) {
+        if self.view != View::RunsList {
+            return;
+        }
+        self.event_filter = match &self.event_filter {
+            None => Some(EVENT_FILTERS[0].to_string()),
+            Some(current) => EVENT_FILTERS
+                .iter()
+                .position(|e| e == current)
+                .and_then(|i| EVENT_FILTERS.get(i + 1))
+                .map(|e| e.to_string()),
+        };
+        self.page = 1;
+        self.runs_selected = 0;
+        self.runs_anchor = None;
+        self.spawn_fetch_runs();
+    }
+
+    fn update_repo_status(&mut self) {
+        let filtered = self.filtered_repos();
+        let total = self.repos.len();
+        let shown = filtered.len();
+        if self.repo_filter.is_empty() {
+            self.status_message = format!("{} repositories", format_count(total as u64));
+        } else {
+            self.status_message = format!(
+                "{} / {} repos matching \"{}\"",
+                format_count(shown as u64),
+                format_count(total as u64),
+                self.repo_filter
+            );
+        }
+    }
+
+    // ── Background task spawning (non-blocking) ────────────────────
+
+    /// Start on a chooser of just these repos (`atlas repoA repoB`); their
+    /// details arrive with the first fetch
+    pub fn set_repo_choices(&mut self, repos: &[(String, String)]) {
+        self.repos = repos
+            .iter()
+            .map(|(owner, name)| Repository::stub(owner, name))
+            .collect();
+        self.repo_choices = true;
+    }
+
+    pub fn spawn_fetch_repos(&mut self) {
+        self.loading = true;
+        self.loading_since = Some(Instant::now());
+        self.status_message = "Fetching repositories...".to_string();
+
+        let client = self.client.clone();
+        let tx = self.bg_tx.clone();
+
+        if self.repo_choices {
+            let repos = self.repos.clone();
+            let task = tokio::spawn(async move {
+                debug!(count = repos.len(), "Fetching chosen repositories");
+                let fetches = repos
+                    .iter()
+                    .map(|repo| client.get_repo(&repo.owner.login, &repo.name));
+                let fetched = futures::future::join_all(fetches).await;
+                // A repo that can't be read stays a stub; opening it shows why
+                let hydrated = repos
+                    .into_iter()
+                    .zip(fetched)
+                    .map(|(stub, result)| {
+                        result.unwrap_or_else(|e| {
+                            warn!(error = %e, repo = %stub.full_name, "Failed to fetch repository");
+                            stub
+                        })
+                    })
+                    .collect();
+                let _ = tx.send(BackgroundResult::ReposFetched(Ok(hydrated)));
             });
+            self.track_connecting(task.abort_handle());
+            return;
         }
+
+        let (per_page, page) = (self.repos_per_page, self.repos_page);
+        let task = tokio::spawn(async move {
+            debug!(page, "Fetching user repositories");
+            // The count comes along with the first page; later pages reuse it
+            if page == 1 {
+                let (repos, count) = tokio::join!(
+                    client.get_user_repos(per_page, page),
+                    client.get_user_repos_count()
+                );
+                let _ = tx.send(BackgroundResult::ReposCounted(count));
+                let _ = tx.send(BackgroundResult::ReposFetched(repos));
+            } else {
+                let result = client.get_user_repos(per_page, page).await;
+                let _ = tx.send(BackgroundResult::ReposFetched(result));
+            }
+        });
+        self.track_connecting(task.abort_handle());
     }
 
-    pub fn spawn_rerun(&mut self) {
-        if let Some(run) = self.get_selected_run() {
-            self.status_message = format!("Re-running workflow #{}...", run.run_number);
+    /// Fetch the workflow files, plus the latest runs to tell when each
+    /// workflow last ran
+    pub fn spawn_fetch_workflows(&mut self) {
+        self.loading = true;
+        self.loading_since = Some(Instant::now());
+        self.status_message = "Fetching workflows...".to_string();
 
-            let client = self.client.clone();
-            let run_id = run.id;
-            let run_number = run.run_number;
-            let tx = self.bg_tx.clone();
+        let client = self.client.clone();
+        let tx = self.bg_tx.clone();
 
-            tokio::spawn(async move {
-                debug!(run_id, run_number, "Re-running workflow");
-                let result = client.rerun_workflow(run_id).await;
-                let _ = tx.send(BackgroundResult::RerunComplete { run_number, result });
+        tokio::spawn(async move {
+            debug!("Fetching workflows");
+            let (workflows, recent) = tokio::join!(
+                client.get_workflows(),
+                client.get_workflow_runs(100, 1, None, None, None, None)
+            );
+            let result = workflows.map(|mut workflows| {
+                // Workflows with no run among the latest 100 show no date
+                let recent = recent.map(|r| r.workflow_runs).unwrap_or_default();
+                for workflow in &mut workflows {
+                    workflow.last_run_at = recent
+                        .iter()
+                        .filter(|run| run.workflow_id == Some(workflow.id))
+                        .map(|run| run.created_at)
+                        .max();
+                }
+                workflows
             });
+            let _ = tx.send(BackgroundResult::WorkflowsFetched(result));
+        });
+    }
+
+    /// The workflow row under the cursor; `None` for "All workflows"
+    pub fn selected_workflow(&self) -> Option<&Workflow> {
+        self.workflows_selected
+            .checked_sub(1)
+            .and_then(|i| self.workflows.get(i))
+    }
+
+    /// `w`: pick a workflow to filter the runs list by
+    pub fn open_workflow_picker(&mut self) {
+        if self.view != View::RunsList {
+            return;
+        }
+        self.workflow_picker = true;
+        if self.workflows.is_empty() {
+            self.workflows_selected = 0;
+            self.spawn_fetch_workflows();
+        } else {
+            self.select_filtered_workflow();
         }
     }
 
-    pub fn spawn_cancel(&mut self) {
-        if let Some(run) = self.get_selected_run() {
-            self.status_message = format!("Cancelling workflow #{}...", run.run_number);
+    /// `w` or `q` in the picker: close it, filter unchanged
+    pub fn close_workflow_picker(&mut self) {
+        self.workflow_picker = false;
+    }
+
+    pub fn move_workflow_picker(&mut self, down: bool) {
+        // One extra row for "All workflows"
+        self.workflows_selected = if down {
+            (self.workflows_selected + 1).min(self.workflows.len())
+        } else {
+            self.workflows_selected.saturating_sub(1)
+        };
+    }
+
+    /// `Enter` in the picker: show only the chosen workflow's runs, or all
+    /// of them
+    pub fn choose_workflow(&mut self) {
+        self.workflow_picker = false;
+        let workflow = self.selected_workflow().cloned();
+        if workflow.as_ref().map(|w| w.id) != self.workflow_filter.as_ref().map(|w| w.id) {
+            self.filter_runs_by(workflow);
+        }
+    }
+
+    /// `Esc` in the picker: back to every workflow's runs
+    pub fn clear_workflow_filter(&mut self) {
+        self.workflow_picker = false;
+        if self.workflow_filter.is_some() {
+            self.filter_runs_by(None);
+        }
+    }
+
+    /// Start the runs list over on page 1 of `workflow`'s runs
+    fn filter_runs_by(&mut self, workflow: Option<Workflow>) {
+        self.workflow_filter = workflow;
+        self.view = View::RunsList;
+        self.runs.clear();
+        self.runs_selected = 0;
+        self.runs_total = 0;
+        self.page = 1;
+        self.runs_anchor = None;
+        self.spawn_fetch_runs();
+    }
+
+    /// Put the cursor on the workflow the runs list is filtered by
+    fn select_filtered_workflow(&mut self) {
+        let id = self.workflow_filter.as_ref().map(|w| w.id);
+        self.workflows_selected = id
+            .and_then(|id| self.workflows.iter().position(|w| w.id == id))
+            .map_or(0, |i| i + 1);
+    }
+
+    pub fn spawn_fetch_runs(&mut self) {
+        self.loading = true;
+        self.loading_since = Some(Instant::now());
+        self.status_message = "Fetching workflow runs...".to_string();
+
+        let client = self.client.clone();
+        let per_page = self.per_page;
+        let page = self.page;
+        let branch = self.branch_filter.clone();
+        let status = self.status_filter;
+        let event = self.event_filter.clone();
+        let workflow_id = self.workflow_filter.as_ref().map(|w| w.id);
+        let tx = self.bg_tx.clone();
+
+        self.ensure_retention();
+
+        let task = tokio::spawn(async move {
+            debug!(
+                page,
+                per_page,
+                ?branch,
+                ?status,
+                ?event,
+                ?workflow_id,
+                "Fetching workflow runs"
+            );
+            let result = client
+                .get_workflow_runs(
+                    per_page,
+                    page,
+                    branch.as_deref(),
+                    status,
+                    event.as_deref(),
+                    workflow_id,
+                )
+                .await;
+            let _ = tx.send(BackgroundResult::RunsFetched(result));
+        });
+        self.track_connecting(task.abort_handle());
+    }
+
+    pub fn spawn_fetch_jobs(&mut self) {
+        if let Some(run) = &self.current_run {
+            self.loading = true;
+            self.loading_since = Some(Instant::now());
+            self.status_message = format!("Fetching jobs for run #{}...", run.run_number);
 
             let client = self.client.clone();
             let run_id = run.id;
@@ -289,422 +1808,6445 @@ impl App {
             let tx = self.bg_tx.clone();
 
             tokio::spawn(async move {
-                debug!(run_id, run_number, "Cancelling workflow");
-                let result = client.cancel_workflow(run_id).await;
-                let _ = tx.send(BackgroundResult::CancelComplete { run_number, result });
+                debug!(run_id, run_number, "Fetching jobs");
+                let result = client.get_jobs(run_id).await;
+                let _ = tx.send(BackgroundResult::JobsFetched { run_number, result });
             });
         }
     }
 
-    fn get_selected_run(&self) -> Option<WorkflowRun> {
-        match self.view {
-            View::RunsList => self.runs.get(self.runs_selected).cloned(),
-            View::RunDetail | View::Logs => self.current_run.clone(),
-            View::RepoList => None,
+    /// Artifact, annotation, and billable-time counts for the summary box.
+    /// Runs still going are re-counted on every refresh.
+    fn spawn_fetch_run_counts(&mut self) {
+        let Some(run) = &self.current_run else {
+            return;
+        };
+        let finished = run.status.as_deref() == Some("completed");
+        if finished
+            && self
+                .run_counts
+                .as_ref()
+                .is_some_and(|(id, _)| *id == run.id)
+        {
+            return;
+        }
+        let run_id = run.id;
+        let check_suite_id = run.check_suite_id;
+        if self.run_counts.as_ref().is_none_or(|(id, _)| *id != run_id) {
+            self.run_counts = Some((run_id, RunCounts::default()));
         }
-    }
-
-    // ── Handle background results ──────────────────────────────────
 
-    pub fn handle_background(&mut self, result: BackgroundResult) {
-        match result {
-            BackgroundResult::ReposFetched(result) => match result {
-                Ok(repos) => {
-                    let count = repos.len();
-                    self.repos = repos;
-                    self.loading = false;
-                    self.repos_selected = 0;
-                    self.status_message =
-                        format!("{} repositories · sorted by last push · / to search", count,);
-                    debug!(count, "Repositories fetched");
-                }
-                Err(e) => {
-                    self.loading = false;
-                    self.status_message = format!("Error: {}", e);
-                    error!(error = %e, "Failed to fetch repositories");
+        let client = self.client.clone();
+        let tx = self.bg_tx.clone();
+        tokio::spawn(async move {
+            debug!(run_id, "Fetching run counts");
+            let annotations = async {
+                match check_suite_id {
+                    Some(id) => client.get_annotation_count(id).await.ok(),
+                    None => None,
                 }
-            },
-
-            BackgroundResult::RunsFetched(result) => match result {
-                Ok(response) => {
-                    self.runs = response.workflow_runs;
-                    self.runs_total = response.total_count;
-                    self.loading = false;
+            };
+            let (artifacts, annotations, timing) = tokio::join!(
+                client.get_artifacts(run_id),
+                annotations,
+                client.get_run_timing(run_id)
+            );
+            let counts = RunCounts {
+                artifacts: artifacts.ok().map(|a| a.total_count),
+                annotations,
+                billable_ms: timing.ok().map(|t| t.billable_ms()),
+            };
+            let _ = tx.send(BackgroundResult::RunCountsFetched { run_id, counts });
+        });
+    }
 
-                    let total_pages = self.runs_total.div_ceil(self.per_page as u64);
-                    self.status_message = format!(
-                        "{} runs total · Page {}/{} · {} {}",
-                        self.runs_total,
-                        self.page,
-                        total_pages,
-                        self.client.owner,
-                        self.client.repo,
-                    );
-                    debug!(total = self.runs_total, page = self.page, "Runs fetched");
-                }
-                Err(e) => {
-                    self.loading = false;
-                    self.status_message = format!("Error: {}", e);
-                    error!(error = %e, "Failed to fetch runs");
-                }
-            },
+    /// The run's head commit for the summary, fetched when the run is
+    /// opened unless it's the one already shown
+    fn spawn_fetch_commit(&mut self) {
+        let Some(run) = &self.current_run else {
+            return;
+        };
+        if self.run_commit().is_some() {
+            return;
+        }
+        let sha = run.head_sha.clone();
+        self.commit_loading = true;
 
-            BackgroundResult::JobsFetched { run_number, result } => match result {
-                Ok(response) => {
-                    self.jobs = response.jobs;
-                    self.jobs_selected = 0;
-                    self.loading = false;
+        let client = self.client.clone();
+        let tx = self.bg_tx.clone();
+        tokio::spawn(async move {
+            debug!(%sha, "Fetching commit");
+            let result = client.get_commit(&sha).await;
+            let _ = tx.send(BackgroundResult::CommitFetched { sha, result });
+        });
+    }
 
-                    let run_name = self
-                        .current_run
-                        .as_ref()
-                        .and_then(|r| r.display_title.as_deref().or(r.name.as_deref()))
-                        .unwrap_or("Unknown");
-                    self.status_message = format!(
-                        "Run #{} · {} · {} jobs",
-                        run_number,
-                        run_name,
-                        self.jobs.len()
-                    );
-                    debug!(run_number, jobs = self.jobs.len(), "Jobs fetched");
-                }
-                Err(e) => {
-                    self.loading = false;
-                    self.status_message = format!("Error: {}", e);
-                    error!(error = %e, run_number, "Failed to fetch jobs");
-                }
-            },
+    /// The head commit of the run in view, once fetched
+    pub fn run_commit(&self) -> Option<&Commit> {
+        match (&self.current_commit, &self.current_run) {
+            (Some(commit), Some(run)) if commit.sha == run.head_sha => Some(commit),
+            _ => None,
+        }
+    }
 
-            BackgroundResult::LogsFetched { job_name, result } => match result {
-                Ok(logs) => {
-                    self.log_content = logs.lines().map(|l| l.to_string()).collect();
-                    self.log_scroll = 0;
-                    self.loading = false;
-                    self.status_message =
-                        format!("Logs: {} · {} lines", job_name, self.log_content.len());
-                    debug!(%job_name, lines = self.log_content.len(), "Logs fetched");
-                }
-                Err(e) => {
-                    self.log_content = vec![format!("Error fetching logs: {}", e)];
-                    self.loading = false;
-                    self.status_message = format!("Failed to load logs for {}", job_name);
-                    error!(error = %e, %job_name, "Failed to fetch logs");
-                }
-            },
+    /// Annotations for the panel under the jobs, fetched once per run and
+    /// only for runs that finished with a failure: the rest rarely have any
+    /// worth the calls
+    fn spawn_fetch_annotations(&mut self) {
+        let Some(run) = &self.current_run else {
+            return;
+        };
+        let failed = run.status.as_deref() == Some("completed")
+            && matches!(run.conclusion.as_deref(), Some("failure" | "timed_out"));
+        let Some(check_suite_id) = run.check_suite_id.filter(|_| failed) else {
+            return;
+        };
+        let run_id = run.id;
+        if self
+            .run_annotations
+            .as_ref()
+            .is_some_and(|(id, _)| *id == run_id)
+        {
+            return;
+        }
+        self.run_annotations = Some((run_id, Vec::new()));
+        self.annotations_selected = 0;
 
-            BackgroundResult::RerunComplete { run_number, result } => match result {
-                Ok(()) => {
-                    self.status_message = format!("✓ Re-run triggered for #{}", run_number);
-                    debug!(run_number, "Re-run triggered");
-                }
-                Err(e) => {
-                    self.status_message = format!("Error: {}", e);
-                    error!(error = %e, run_number, "Failed to re-run");
-                }
-            },
+        let client = self.client.clone();
+        let tx = self.bg_tx.clone();
+        tokio::spawn(async move {
+            debug!(run_id, check_suite_id, "Fetching annotations");
+            let result = client.get_check_suite_annotations(check_suite_id).await;
+            let _ = tx.send(BackgroundResult::AnnotationsFetched { run_id, result });
+        });
+    }
 
-            BackgroundResult::CancelComplete { run_number, result } => match result {
-                Ok(()) => {
-                    self.status_message = format!("✓ Cancelled #{}", run_number);
-                    debug!(run_number, "Workflow cancelled");
-                }
-                Err(e) => {
-                    self.status_message = format!("Error: {}", e);
-                    error!(error = %e, run_number, "Failed to cancel");
-                }
-            },
+    /// Annotations of the run in view
+    pub fn annotations(&self) -> &[Annotation] {
+        match (&self.run_annotations, &self.current_run) {
+            (Some((id, annotations)), Some(run)) if *id == run.id => annotations,
+            _ => &[],
         }
     }
 
-    // ── Navigation ─────────────────────────────────────────────────
+    /// Enter on an annotation: put its `path:line` on the clipboard
+    fn copy_annotation_location(&mut self) {
+        let Some(location) = self
+            .annotations()
+            .get(self.annotations_selected)
+            .map(Annotation::location)
+        else {
+            return;
+        };
+        self.status_message = format!("Copied {}", location);
+        self.clipboard_request = Some(location);
+    }
 
-    pub fn move_up(&mut self) {
-        match self.view {
-            View::RepoList => {
-                if self.repos_selected > 0 {
-                    self.repos_selected -= 1;
-                }
+    fn spawn_fetch_preview_jobs(&mut self, run_id: u64) {
+        let client = self.client.clone();
+        let tx = self.bg_tx.clone();
+        self.preview_loading = Some(run_id);
+
+        tokio::spawn(async move {
+            debug!(run_id, "Fetching jobs for split view");
+            let result = client.get_jobs(run_id).await;
+            let _ = tx.send(BackgroundResult::PreviewJobsFetched { run_id, result });
+        });
+    }
+
+    /// `f` in run details: float failed jobs to the top, or put the jobs
+    /// back in run order
+    pub fn toggle_failed_first(&mut self) {
+        if self.view != View::RunDetail {
+            return;
+        }
+        self.jobs_failed_first = !self.jobs_failed_first;
+        self.order_jobs();
+        self.status_message = if self.jobs_failed_first {
+            "Failed jobs first".to_string()
+        } else {
+            "Jobs in run order".to_string()
+        };
+    }
+
+    /// Sort `jobs` the way `jobs_failed_first` asks, keeping the selected
+    /// job selected
+    fn order_jobs(&mut self) {
+        let selected = self.jobs.get(self.jobs_selected).map(|j| j.id);
+        if self.jobs_failed_first {
+            sort_failed_first(&mut self.jobs);
+        } else {
+            // GitHub lists jobs in the order they were created
+            self.jobs.sort_by_key(|j| j.id);
+        }
+        self.jobs_selected = reselect(&self.jobs, |j| j.id, selected, self.jobs_selected);
+    }
+
+    /// Fetch steps for the selected job unless they're already cached
+    pub fn spawn_hydrate_selected_job(&mut self) {
+        let Some(job) = self.jobs.get(self.jobs_selected) else {
+            return;
+        };
+        if job.steps.is_some() || self.steps_loading == Some(job.id) {
+            return;
+        }
+
+        let client = self.client.clone();
+        let job_id = job.id;
+        let tx = self.bg_tx.clone();
+        self.steps_loading = Some(job_id);
+
+        tokio::spawn(async move {
+            debug!(job_id, "Hydrating job steps");
+            let result = client.get_job(job_id).await;
+            let _ = tx.send(BackgroundResult::JobHydrated { job_id, result });
+        });
+    }
+
+    pub fn spawn_fetch_logs(&mut self) {
+        if let Some(run) = self.current_run.as_ref().filter(|r| self.logs_expired(r)) {
+            // GitHub would answer 410 Gone; say why instead
+            self.log_expired = Some(self.retention_days());
+            self.log_content.clear();
+            self.log_groups.clear();
+            self.status_message = format!("Logs for run #{} have expired", run.run_number);
+            return;
+        }
+        if self.log_stream.is_some() {
+            // Still arriving; a second copy would only be appended to it
+            return;
+        }
+        if let Some(job) = self.jobs.get(self.jobs_selected) {
+            self.loading = true;
+            self.loading_since = Some(Instant::now());
+            self.status_message = format!("Fetching logs for {}...", job.name);
+
+            let client = self.client.clone();
+            let job_id = job.id;
+            let job_name = job.name.clone();
+            let tx = self.bg_tx.clone();
+            self.downloads.push(Download {
+                id: job_id,
+                label: format!("logs for {}", job_name),
+                started: Instant::now(),
+            });
+
+            if self.log_job_id == Some(job_id) {
+                // A refresh: fetch it whole and append what's new
+                tokio::spawn(async move {
+                    debug!(job_id, %job_name, "Fetching logs");
+                    let result = client.get_job_logs(job_id).await;
+                    let _ = tx.send(BackgroundResult::LogsFetched {
+                        job_id,
+                        job_name,
+                        result,
+                    });
+                });
+                return;
             }
-            View::RunsList => {
-                if self.runs_selected > 0 {
-                    self.runs_selected -= 1;
-                }
+
+            // A new log: show it as it downloads
+            self.set_log_content("");
+            self.log_job_id = Some(job_id);
+            let task = tokio::spawn(async move {
+                debug!(job_id, %job_name, "Streaming logs");
+                let (chunk_tx, mut chunk_rx) = mpsc::channel::<String>(LOG_STREAM_BUFFER);
+                let forward = async {
+                    while let Some(batch) = chunk_rx.recv().await {
+                        let lines = batch.lines().map(str::to_string).collect();
+                        let _ = tx.send(BackgroundResult::LogChunk { job_id, lines });
+                    }
+                };
+                let (result, ()) =
+                    tokio::join!(client.get_job_logs_streaming(job_id, chunk_tx), forward);
+                let _ = tx.send(BackgroundResult::LogsStreamed {
+                    job_id,
+                    job_name,
+                    result,
+                });
+            });
+            self.log_stream = Some(task.abort_handle());
+        }
+    }
+
+    /// A log is still downloading into the view
+    pub fn log_streaming(&self) -> bool {
+        self.log_stream.is_some()
+    }
+
+    /// Stop downloading the log being left
+    fn cancel_log_stream(&mut self) {
+        if let Some(task) = self.log_stream.take() {
+            task.abort();
+            if let Some(job_id) = self.log_job_id {
+                self.finish_download(job_id);
             }
-            View::RunDetail => {
-                if self.jobs_selected > 0 {
-                    self.jobs_selected -= 1;
+            self.loading = false;
+        }
+    }
+
+    /// `t` in the log view: show or hide line timestamps. Only drawing
+    /// changes; `log_content` keeps them.
+    pub fn toggle_log_timestamps(&mut self) {
+        if self.view != View::Logs {
+            return;
+        }
+        self.log_timestamps = !self.log_timestamps;
+        self.status_message = if self.log_timestamps {
+            "Showing timestamps".to_string()
+        } else {
+            "Hiding timestamps".to_string()
+        };
+    }
+
+    /// `F`/`f` in the log view: follow a running job's log, or stop
+    /// following. A log still streaming in can be followed either way.
+    pub fn toggle_log_follow(&mut self) {
+        if self.view != View::Logs {
+            return;
+        }
+        if self.log_follow {
+            self.log_follow = false;
+            self.status_message = "Stopped following".to_string();
+            return;
+        }
+        let Some(job) = self.jobs.get(self.jobs_selected) else {
+            return;
+        };
+        if job.status.as_deref() == Some("completed") && self.log_stream.is_none() {
+            self.status_message = format!("{} has finished — nothing to follow", job.name);
+            return;
+        }
+        self.status_message = format!("Following {}...", job.name);
+        self.log_follow = true;
+        self.log_scroll = self.max_log_scroll();
+        if self.log_stream.is_none() {
+            self.spawn_poll_logs();
+        }
+    }
+
+    /// Re-fetch the followed job and its log; the job's status says when
+    /// to stop
+    fn spawn_poll_logs(&mut self) {
+        let Some(job) = self.jobs.get(self.jobs_selected) else {
+            return;
+        };
+        self.log_poll_in_flight = true;
+        self.log_polled_at = Instant::now();
+
+        let client = self.client.clone();
+        let job_id = job.id;
+        let job_name = job.name.clone();
+        let tx = self.bg_tx.clone();
+        tokio::spawn(async move {
+            debug!(job_id, "Polling followed log");
+            let job = client.get_job(job_id).await;
+            let _ = tx.send(BackgroundResult::JobHydrated {
+                job_id,
+                result: job,
+            });
+            let result = client.get_job_logs(job_id).await;
+            let _ = tx.send(BackgroundResult::LogsFetched {
+                job_id,
+                job_name,
+                result,
+            });
+        });
+    }
+
+    /// `R` (Ctrl+R with debug logging): ask before re-running every job of
+    /// the selected run
+    pub fn request_rerun(&mut self, debug: bool) {
+        if self.view == View::RunsList && !self.selected_run_ids.is_empty() && !debug {
+            return self.request_bulk(AuditAction::Rerun);
+        }
+        if let Some(run) = self.get_selected_run() {
+            let pending = PendingAction::RerunAll {
+                run_id: run.id,
+                run_number: run.run_number,
+                title: run_title(&run),
+                debug,
+            };
+            self.ask(pending, self.run_protected(&run));
+        }
+    }
+
+    /// `R` in run details (Ctrl+R with debug logging): ask before re-running
+    /// the selected job alone
+    pub fn request_rerun_job(&mut self, debug: bool) {
+        let (Some(run), Some(job)) = (&self.current_run, self.jobs.get(self.jobs_selected)) else {
+            return;
+        };
+        let pending = PendingAction::RerunJob {
+            run_id: run.id,
+            run_number: run.run_number,
+            title: run_title(run),
+            job_id: job.id,
+            job_name: job.name.clone(),
+            debug,
+        };
+        self.ask(pending, self.run_protected(run));
+    }
+
+    /// `C`: ask before cancelling the selected run, or every marked one
+    pub fn request_cancel(&mut self) {
+        if self.view == View::RunsList && !self.selected_run_ids.is_empty() {
+            return self.request_bulk(AuditAction::Cancel);
+        }
+        if let Some(run) = self.get_selected_run() {
+            let pending = PendingAction::Cancel {
+                run_id: run.id,
+                run_number: run.run_number,
+                title: run_title(&run),
+            };
+            self.ask(pending, self.run_protected(&run));
+        }
+    }
+
+    fn run_protected(&self, run: &WorkflowRun) -> bool {
+        self.protected
+            .covers(run.name.as_deref(), run.path.as_deref())
+    }
+
+    /// Put up the confirmation for `pending`; a protected workflow's needs
+    /// typing. With `confirm_actions` off the rest go ahead at once.
+    fn ask(&mut self, pending: PendingAction, protected: bool) {
+        self.confirmation_pending = Some(pending);
+        self.confirmation_input = protected.then(String::new);
+        if !protected && !self.confirm_actions {
+            self.answer_confirmation(true);
+        }
+    }
+
+    /// `y` carries out the pending action; `n` or `Esc` drops it
+    pub fn answer_confirmation(&mut self, confirmed: bool) {
+        self.confirmation_input = None;
+        let Some(pending) = self.confirmation_pending.take() else {
+            return;
+        };
+        if !confirmed {
+            if self.watch.as_ref().is_some_and(|w| w.triggering) {
+                self.watch = None;
+            }
+            self.status_message = "Nothing changed".to_string();
+            return;
+        }
+        match pending {
+            PendingAction::Cancel {
+                run_id, run_number, ..
+            } => self.spawn_cancel(run_id, run_number),
+            PendingAction::RerunAll {
+                run_id,
+                run_number,
+                debug,
+                ..
+            } => self.spawn_rerun(run_id, run_number, debug),
+            PendingAction::RerunFailed {
+                run_id,
+                run_number,
+                debug,
+                ..
+            } => self.spawn_rerun_failed_jobs(run_id, run_number, debug),
+            PendingAction::RerunJob {
+                run_id,
+                run_number,
+                job_id,
+                job_name,
+                debug,
+                ..
+            } => self.spawn_rerun_job(run_id, run_number, job_id, job_name, debug),
+            PendingAction::Dispatch { .. } => self.send_dispatch(),
+            PendingAction::CancelSelected { .. } => self.spawn_bulk(AuditAction::Cancel),
+            PendingAction::RerunSelected { .. } => self.spawn_bulk(AuditAction::Rerun),
+            PendingAction::DeleteCache { cache_id, key, .. } => {
+                self.spawn_delete_cache(cache_id, key)
+            }
+            PendingAction::DeleteRuns { runs, .. } => self.spawn_delete_runs(runs),
+            PendingAction::ToggleWorkflow {
+                workflow_id,
+                name,
+                enable,
+            } => self.spawn_toggle_workflow(workflow_id, name, enable),
+        }
+    }
+
+    /// Enter in a typed confirmation: go ahead if it spells the run number
+    /// (or `yes`), otherwise keep asking
+    pub fn submit_confirmation_input(&mut self) {
+        let (Some(pending), Some(typed)) = (&self.confirmation_pending, &self.confirmation_input)
+        else {
+            return;
+        };
+        if pending.confirmed_by(typed) {
+            self.answer_confirmation(true);
+        } else {
+            self.status_message = format!(
+                "Type {} to confirm, Esc to back out",
+                pending.confirm_word()
+            );
+            self.confirmation_input = Some(String::new());
+        }
+    }
+
+    fn spawn_rerun(&mut self, run_id: u64, run_number: u64, debug: bool) {
+        self.status_message = format!(
+            "Re-running workflow #{}{}...",
+            run_number,
+            with_debug(debug)
+        );
+
+        let client = self.client.clone();
+        let repo = self.repo_key();
+        let tx = self.bg_tx.clone();
+
+        tokio::spawn(async move {
+            debug!(run_id, run_number, "Re-running workflow");
+            let result = client.rerun_workflow(run_id, debug).await;
+            let _ = tx.send(BackgroundResult::RerunComplete {
+                repo,
+                run_id,
+                run_number,
+                debug,
+                result,
+            });
+        });
+    }
+
+    fn spawn_rerun_job(
+        &mut self,
+        run_id: u64,
+        run_number: u64,
+        job_id: u64,
+        job_name: String,
+        debug: bool,
+    ) {
+        self.status_message = format!(
+            "Re-running job '{}' of #{}{}...",
+            job_name,
+            run_number,
+            with_debug(debug)
+        );
+
+        let client = self.client.clone();
+        let repo = self.repo_key();
+        let tx = self.bg_tx.clone();
+
+        tokio::spawn(async move {
+            debug!(run_id, job_id, "Re-running job");
+            let result = client.rerun_job(job_id, debug).await;
+            let _ = tx.send(BackgroundResult::JobRerunComplete {
+                repo,
+                run_id,
+                run_number,
+                job_name,
+                debug,
+                result,
+            });
+        });
+    }
+
+    /// `F`: ask before re-running just the failed jobs of the selected run
+    /// (Ctrl+F with debug logging); a run that didn't fail gets the full
+    /// re-run instead
+    pub fn request_rerun_failed(&mut self, debug: bool) {
+        let Some(run) = self.get_selected_run() else {
+            return;
+        };
+        if run.conclusion.as_deref() != Some("failure") {
+            self.status_message = format!("#{} has no failed jobs", run.run_number);
+            self.request_rerun(debug);
+            return;
+        }
+        let pending = PendingAction::RerunFailed {
+            run_id: run.id,
+            run_number: run.run_number,
+            title: run_title(&run),
+            debug,
+        };
+        self.ask(pending, self.run_protected(&run));
+    }
+
+    fn spawn_rerun_failed_jobs(&mut self, run_id: u64, run_number: u64, debug: bool) {
+        self.status_message = format!(
+            "Re-running failed jobs of #{}{}...",
+            run_number,
+            with_debug(debug)
+        );
+
+        let client = self.client.clone();
+        let repo = self.repo_key();
+        let tx = self.bg_tx.clone();
+
+        tokio::spawn(async move {
+            debug!(run_id, run_number, "Re-running failed jobs");
+            let result = client.rerun_failed_jobs(run_id, debug).await;
+            let _ = tx.send(BackgroundResult::RerunFailedComplete {
+                repo,
+                run_id,
+                run_number,
+                debug,
+                result,
+            });
+        });
+    }
+
+    fn spawn_cancel(&mut self, run_id: u64, run_number: u64) {
+        self.status_message = format!("Cancelling workflow #{}...", run_number);
+
+        let client = self.client.clone();
+        let repo = self.repo_key();
+        let tx = self.bg_tx.clone();
+
+        tokio::spawn(async move {
+            debug!(run_id, run_number, "Cancelling workflow");
+            let result = client.cancel_workflow(run_id).await;
+            let _ = tx.send(BackgroundResult::CancelComplete {
+                repo,
+                run_id,
+                run_number,
+                result,
+            });
+        });
+    }
+
+    // ── Re-run and watch ───────────────────────────────────────────
+
+    /// `W`: re-run the selected run's failed jobs, then poll it until it
+    /// finishes and announce how it went
+    pub fn rerun_failed_and_watch(&mut self) {
+        let Some(run) = self.get_selected_run() else {
+            return;
+        };
+        if run.conclusion.as_deref() != Some("failure") {
+            self.status_message = format!("#{} has no failed jobs to re-run", run.run_number);
+            return;
+        }
+        let now = Instant::now();
+        self.watch = Some(RunWatch {
+            run_id: run.id,
+            run_number: run.run_number,
+            workflow: run.name.clone().unwrap_or_else(|| "Workflow".to_string()),
+            attempt: run.run_attempt,
+            client: self.client.clone(),
+            triggering: true,
+            status: "queued".to_string(),
+            seen_active: false,
+            started: now,
+            polled_at: now,
+            poll_in_flight: false,
+        });
+        // Asks first like `F`; backing out drops the watch
+        self.request_rerun_failed(false);
+    }
+
+    /// Esc while watching: stop polling; the re-run itself goes on
+    pub fn stop_watch(&mut self) {
+        if let Some(watch) = self.watch.take() {
+            self.status_message = format!(
+                "Stopped watching #{}; its re-run carries on",
+                watch.run_number
+            );
+        }
+    }
+
+    /// The status bar while watching: `⟳ #42 in progress · 1m 5s · Esc stops`
+    pub fn watch_readout(&self) -> Option<String> {
+        let watch = self.watch.as_ref()?;
+        let status = if watch.triggering {
+            "re-running failed jobs"
+        } else {
+            &watch.status.replace('_', " ")
+        };
+        Some(format!(
+            "⟳ {} #{} {} · {} · Esc stops watching",
+            watch.workflow,
+            watch.run_number,
+            status,
+            format_duration(watch.started.elapsed().as_secs() as i64)
+        ))
+    }
+
+    fn poll_watch(&mut self) {
+        let Some(watch) = self.watch.as_mut() else {
+            return;
+        };
+        if watch.triggering || watch.poll_in_flight || watch.polled_at.elapsed() < WATCH_INTERVAL {
+            return;
+        }
+        watch.poll_in_flight = true;
+        watch.polled_at = Instant::now();
+
+        let client = watch.client.clone();
+        let run_id = watch.run_id;
+        let tx = self.bg_tx.clone();
+        tokio::spawn(async move {
+            debug!(run_id, "Polling watched run");
+            let result = client.get_workflow_run(run_id).await;
+            let _ = tx.send(BackgroundResult::WatchedRunFetched { run_id, result });
+        });
+    }
+
+    /// A poll landed: keep the lists current, and announce the end
+    fn watched_run_fetched(&mut self, run: WorkflowRun) {
+        let Some(watch) = self.watch.as_mut() else {
+            return;
+        };
+        watch.poll_in_flight = false;
+        let status = run.status.clone().unwrap_or_default();
+        let completed = status == "completed";
+        if !completed {
+            watch.seen_active = true;
+        }
+        let new_attempt = matches!(
+            (watch.attempt, run.run_attempt),
+            (Some(before), Some(now)) if now > before
+        );
+        watch.status = status;
+        let finished = completed && (new_attempt || watch.seen_active);
+
+        if let Some(listed) = self.runs.iter_mut().find(|r| r.id == run.id) {
+            *listed = run.clone();
+        }
+        if self.current_run.as_ref().is_some_and(|r| r.id == run.id) {
+            self.current_run = Some(run.clone());
+        }
+        if !finished {
+            return;
+        }
+
+        let Some(watch) = self.watch.take() else {
+            return;
+        };
+        let conclusion = run.conclusion.as_deref().unwrap_or("unknown");
+        let success = conclusion == "success";
+        let message = format!(
+            "{} {} #{} {} after re-running its failed jobs",
+            if success { "✓" } else { "✗" },
+            watch.workflow,
+            watch.run_number,
+            conclusion.replace('_', " ")
+        );
+        info!(run_id = run.id, conclusion, "Watched run finished");
+        self.status_message = message.clone();
+        self.notification_request = Some(format!(
+            "{} #{}: {}",
+            watch.workflow, watch.run_number, conclusion
+        ));
+        self.toast = Some(Toast {
+            message,
+            success,
+            shown_at: Instant::now(),
+        });
+    }
+
+    /// Space in the runs list: mark the run under the cursor for `C`, `R`
+    /// or `D` (or unmark it) and move on to the next
+    pub fn toggle_run_selected(&mut self) {
+        if self.view != View::RunsList || self.pane_focus == PaneFocus::Jobs {
+            return;
+        }
+        let Some(run) = self.runs.get(self.runs_selected) else {
+            return;
+        };
+        if !self.selected_run_ids.remove(&run.id) {
+            self.selected_run_ids.insert(run.id);
+        }
+        self.status_message = match self.selected_run_ids.len() {
+            0 => "No runs selected".to_string(),
+            n => format!(
+                "{} selected · C cancels, R re-runs, D deletes them",
+                format_count(n as u64)
+            ),
+        };
+        self.move_down(None);
+    }
+
+    /// The selected runs, in list order
+    fn selected_runs(&self) -> Vec<&WorkflowRun> {
+        self.runs
+            .iter()
+            .filter(|run| self.selected_run_ids.contains(&run.id))
+            .collect()
+    }
+
+    /// `X`: ask before cancelling every selected run
+    pub fn request_cancel_selected(&mut self) {
+        self.request_bulk(AuditAction::Cancel);
+    }
+
+    /// Ask before cancelling or re-running every selected run
+    fn request_bulk(&mut self, action: AuditAction) {
+        let runs = self.selected_runs();
+        if runs.is_empty() {
+            self.status_message = "Select runs with Space first".to_string();
+            return;
+        }
+        let protected = runs.iter().any(|run| self.run_protected(run));
+        let count = runs.len();
+        let pending = match action {
+            AuditAction::Rerun => PendingAction::RerunSelected { count },
+            _ => PendingAction::CancelSelected { count },
+        };
+        self.ask(pending, protected);
+    }
+
+    /// Cancel or re-run the selected runs, a task each, and report them
+    /// together once all are answered
+    fn spawn_bulk(&mut self, action: AuditAction) {
+        let targets: Vec<(u64, u64)> = self
+            .selected_runs()
+            .iter()
+            .map(|run| (run.id, run.run_number))
+            .collect();
+        if targets.is_empty() {
+            return;
+        }
+        self.status_message = match action {
+            AuditAction::Rerun => format!("Re-running {} runs...", targets.len()),
+            _ => format!("Cancelling {} runs...", targets.len()),
+        };
+
+        let client = self.client.clone();
+        let repo = self.repo_key();
+        let tx = self.bg_tx.clone();
+
+        tokio::spawn(async move {
+            debug!(count = targets.len(), ?action, "Acting on selected runs");
+            let tasks = targets.into_iter().map(|(run_id, run_number)| {
+                let client = client.clone();
+                let task = tokio::spawn(async move {
+                    match action {
+                        AuditAction::Rerun => client.rerun_workflow(run_id, false).await,
+                        _ => client.cancel_workflow(run_id).await,
+                    }
+                });
+                async move {
+                    let result = task
+                        .await
+                        .unwrap_or_else(|e| Err(anyhow::anyhow!("Task failed: {}", e)));
+                    (run_id, run_number, result)
+                }
+            });
+            let results = futures::future::join_all(tasks).await;
+            let _ = tx.send(BackgroundResult::BulkActionComplete {
+                action,
+                repo,
+                results,
+            });
+        });
+    }
+
+    /// `D`: ask before deleting the selected runs, or the one under the
+    /// cursor when none are selected. Deleting can't be taken back, so this
+    /// asks even with `confirm_actions` off.
+    pub fn request_delete_runs(&mut self) {
+        if self.view != View::RunsList {
+            return;
+        }
+        let mut runs = self.selected_runs();
+        if runs.is_empty() {
+            runs.extend(self.runs.get(self.runs_selected));
+        }
+        let [first, ..] = runs.as_slice() else {
+            return;
+        };
+        let protected = runs.iter().any(|run| self.run_protected(run));
+        let pending = PendingAction::DeleteRuns {
+            title: run_title(first),
+            runs: runs.iter().map(|run| (run.id, run.run_number)).collect(),
+        };
+        self.confirmation_pending = Some(pending);
+        self.confirmation_input = protected.then(String::new);
+    }
+
+    /// Delete `runs` one after another, reporting each as it goes
+    fn spawn_delete_runs(&mut self, runs: Vec<(u64, u64)>) {
+        let total = runs.len();
+        self.status_message = match runs.as_slice() {
+            [(_, run_number)] => format!("Deleting run #{}...", run_number),
+            _ => format!("Deleting runs... 0/{}", total),
+        };
+
+        let client = self.client.clone();
+        let repo = self.repo_key();
+        let tx = self.bg_tx.clone();
+        tokio::spawn(async move {
+            debug!(total, "Deleting runs");
+            let mut failed = 0;
+            for (i, (run_id, run_number)) in runs.into_iter().enumerate() {
+                let result = client.delete_workflow_run(run_id).await;
+                if result.is_err() {
+                    failed += 1;
+                }
+                let sent = tx.send(BackgroundResult::RunDeleted {
+                    repo: repo.clone(),
+                    run_id,
+                    run_number,
+                    result,
+                    done: i + 1,
+                    total,
+                    failed,
+                });
+                if sent.is_err() {
+                    break;
                 }
             }
-            View::Logs => {
-                self.log_scroll = self.log_scroll.saturating_sub(3);
+        });
+    }
+
+    /// Open the dispatch popup and fetch the workflow file to learn its
+    /// inputs: for the workflow picked in the workflow list or picker, on
+    /// the repo's default branch, else for the selected run's workflow on
+    /// the run's branch
+    pub fn open_dispatch_form(&mut self) {
+        let (workflow_id, workflow_name, path, git_ref) =
+            if self.view == View::WorkflowList || self.workflow_picker {
+                let Some(workflow) = self.selected_workflow() else {
+                    self.status_message = "Pick a workflow to dispatch".to_string();
+                    return;
+                };
+                let path = Some(workflow.path.clone());
+                (workflow.id, workflow.name.clone(), path, None)
+            } else if self.view == View::RunsList {
+                let Some(run) = self.get_selected_run() else {
+                    return;
+                };
+                let Some(workflow_id) = run.workflow_id else {
+                    self.status_message = "This run has no workflow to dispatch".to_string();
+                    return;
+                };
+                let name = run.name.clone().unwrap_or_else(|| "workflow".to_string());
+                let git_ref = run.head_branch.clone().unwrap_or_default();
+                (workflow_id, name, run.path.clone(), Some(git_ref))
+            } else {
+                return;
+            };
+        self.workflow_picker = false;
+        self.dispatch_form = Some(DispatchForm::new(
+            workflow_id,
+            workflow_name,
+            git_ref.clone().unwrap_or_default(),
+        ));
+
+        let Some(path) = path else {
+            if let Some(form) = &mut self.dispatch_form {
+                form.set_inputs(Vec::new());
             }
+            return;
+        };
+        let client = self.client.clone();
+        let tx = self.bg_tx.clone();
+
+        tokio::spawn(async move {
+            let default_ref = match git_ref {
+                Some(_) => None,
+                None => match client.get_repo(&client.owner, &client.repo).await {
+                    Ok(repo) => repo.default_branch,
+                    Err(e) => {
+                        warn!(error = %e, "Couldn't look up the default branch");
+                        None
+                    }
+                },
+            };
+            let at = git_ref.or_else(|| default_ref.clone()).unwrap_or_default();
+            debug!(workflow_id, %path, %at, "Fetching workflow dispatch inputs");
+            let result = client
+                .get_file_content(&path, &at)
+                .await
+                .map(|content| dispatch::dispatch_inputs(&content));
+            let _ = tx.send(BackgroundResult::DispatchInputsFetched {
+                workflow_id,
+                default_ref,
+                result,
+            });
+        });
+    }
+
+    pub fn close_dispatch_form(&mut self) {
+        self.dispatch_form = None;
+    }
+
+    /// Fire the `workflow_dispatch` event from the popup
+    pub fn spawn_dispatch(&mut self) {
+        let Some(form) = &mut self.dispatch_form else {
+            return;
+        };
+        if let Err(e) = form.submission() {
+            form.error = Some(e);
+            return;
         }
+        if self.protected.covers(Some(&form.workflow_name), None) {
+            let pending = PendingAction::Dispatch {
+                workflow_name: form.workflow_name.clone(),
+            };
+            self.ask(pending, true);
+            return;
+        }
+        self.send_dispatch();
+    }
+
+    /// Submit the dispatch form, which has been checked already
+    fn send_dispatch(&mut self) {
+        let Some(form) = self.dispatch_form.take() else {
+            return;
+        };
+        let Ok((git_ref, inputs)) = form.submission() else {
+            return;
+        };
+        self.status_message = format!("Dispatching {} on {}...", form.workflow_name, git_ref);
+
+        let client = self.client.clone();
+        let tx = self.bg_tx.clone();
+        let repo = self.repo_key();
+        let workflow_id = form.workflow_id;
+        let workflow_name = form.workflow_name;
+
+        tokio::spawn(async move {
+            // The runs already there, to tell the dispatched one apart
+            let known = client
+                .get_workflow_runs(DISPATCH_LOOKBACK, 1, None, None, None, Some(workflow_id))
+                .await
+                .map(|response| response.workflow_runs.iter().map(|run| run.id).collect());
+            debug!(workflow_id, %git_ref, "Dispatching workflow");
+            let result = client
+                .dispatch_workflow(workflow_id, &git_ref, inputs)
+                .await;
+            let dispatched = result.is_ok();
+            let _ = tx.send(BackgroundResult::DispatchComplete {
+                repo,
+                workflow_name: workflow_name.clone(),
+                git_ref: git_ref.clone(),
+                result,
+            });
+            if !dispatched {
+                return;
+            }
+            let run = match known {
+                Ok(known) => {
+                    find_dispatched_run(&client, workflow_id, &known, DISPATCH_POLL_INTERVAL).await
+                }
+                Err(e) => {
+                    warn!(error = %e, "Couldn't list runs to find the dispatched one");
+                    None
+                }
+            };
+            let _ = tx.send(BackgroundResult::DispatchedRunFound {
+                workflow_name,
+                git_ref,
+                run: run.map(Box::new),
+            });
+        });
+    }
+
+    /// Work out why a `startup_failure` run never started: lint the
+    /// workflow file at the run's commit, or fall back to GitHub's
+    /// annotations when the YAML itself is fine
+    pub fn spawn_diagnose_startup_failure(&mut self) {
+        let Some(run) = &self.current_run else {
+            return;
+        };
+        self.loading = true;
+        self.loading_since = Some(Instant::now());
+        self.status_message = format!("Checking workflow file for run #{}...", run.run_number);
+
+        let client = self.client.clone();
+        let run_id = run.id;
+        let path = run.path.clone();
+        let sha = run.head_sha.clone();
+        let check_suite_id = run.check_suite_id;
+        let tx = self.bg_tx.clone();
+
+        tokio::spawn(async move {
+            debug!(run_id, ?path, "Diagnosing startup failure");
+            let result = async {
+                let path = path.context("Run has no workflow file path")?;
+                let content = client.get_file_content(&path, &sha).await?;
+                if let Some(error) = lint::lint_workflow(&content) {
+                    let context = lint::context_lines(&content, error.line);
+                    return Ok(WorkflowProblem::Syntax {
+                        path,
+                        error,
+                        context,
+                    });
+                }
+                let annotations = match check_suite_id {
+                    Some(id) => client.get_check_suite_annotations(id).await?,
+                    None => Vec::new(),
+                };
+                Ok(WorkflowProblem::Annotations(annotations))
+            }
+            .await;
+            let _ = tx.send(BackgroundResult::WorkflowDiagnosed { run_id, result });
+        });
+    }
+
+    /// Record the current repo as recently opened
+    pub fn remember_repo(&self) {
+        let Some(store) = self.state_store.clone() else {
+            return;
+        };
+        let full_name = format!("{}/{}", self.client.owner, self.client.repo);
+        tokio::task::spawn_blocking(move || {
+            let now = chrono::Utc::now().timestamp();
+            if let Err(e) = store.update(|s| s.touch_recent(&full_name, now)) {
+                error!(error = %e, "Failed to update state file");
+            }
+        });
+    }
+
+    /// Find the most recent failed run (optionally on `branch`), its first
+    /// failed job, and that job's logs, then open them in the log view
+    pub fn spawn_open_latest_failure(&mut self, branch: Option<String>) {
+        self.loading = true;
+        self.loading_since = Some(Instant::now());
+        self.status_message = "Looking up the latest failed run...".to_string();
+
+        let client = self.client.clone();
+        let tx = self.bg_tx.clone();
+
+        let task = tokio::spawn(async move {
+            debug!(?branch, "Fetching latest failed run");
+            let result = async {
+                let runs = client
+                    .get_workflow_runs(1, 1, branch.as_deref(), Some("failure"), None, None)
+                    .await?;
+                let Some(run) = runs.workflow_runs.into_iter().next() else {
+                    return Ok(None);
+                };
+                let jobs = client.get_jobs(run.id).await?.jobs;
+                let job_index = jobs
+                    .iter()
+                    .position(|j| j.conclusion.as_deref() == Some("failure"))
+                    .unwrap_or(0);
+                let logs = match jobs.get(job_index) {
+                    Some(job) => client.get_job_logs(job.id).await?,
+                    None => String::new(),
+                };
+                Ok(Some(Box::new(LatestFailure {
+                    run,
+                    jobs,
+                    job_index,
+                    logs,
+                })))
+            }
+            .await;
+            let _ = tx.send(BackgroundResult::LatestFailureFetched(result));
+        });
+        self.track_connecting(task.abort_handle());
+    }
+
+    // ── Startup ────────────────────────────────────────────────────
+
+    /// Launch: fetch the first list while the TUI shows placeholders
+    pub fn begin_connecting(&mut self) {
+        self.connecting = Some(Connecting {
+            since: Instant::now(),
+            tasks: Vec::new(),
+        });
+        match self.view {
+            View::RepoList => self.spawn_fetch_repos(),
+            _ => self.spawn_fetch_runs(),
+        }
+    }
+
+    /// Tie a task spawned during startup to `connecting` so Esc aborts it
+    fn track_connecting(&mut self, task: AbortHandle) {
+        if let Some(connecting) = &mut self.connecting {
+            connecting.tasks.push(task);
+        }
+    }
+
+    /// Esc while connecting: give up on the first fetch and leave an empty
+    /// view where `r` retries
+    pub fn cancel_connecting(&mut self) {
+        let Some(connecting) = self.connecting.take() else {
+            return;
+        };
+        for task in connecting.tasks {
+            task.abort();
+        }
+        self.loading = false;
+        self.status_message = "Cancelled · r to retry".to_string();
+        debug!(
+            waited_ms = connecting.since.elapsed().as_millis() as u64,
+            "Initial fetch cancelled"
+        );
+    }
+
+    fn get_selected_run(&self) -> Option<WorkflowRun> {
+        match self.view {
+            View::RunsList => self.runs.get(self.runs_selected).cloned(),
+            View::RunDetail
+            | View::RunTimeline
+            | View::Logs
+            | View::Artifacts
+            | View::Environments
+            | View::Runners => self.current_run.clone(),
+            View::RepoList | View::WorkflowList | View::Caches | View::Statistics => None,
+        }
+    }
+
+    // ── Log retention ──────────────────────────────────────────────
+
+    /// Add the loaded page's successful runs to the repo's duration
+    /// history, switching histories when the repo changed
+    fn record_durations(&mut self) {
+        let repo = self.repo_key();
+        if self.durations_repo.as_deref() != Some(repo.as_str()) {
+            self.durations = match &self.cache_dir {
+                Some(dir) => durations::load(&durations::history_path(dir, &repo)),
+                None => DurationHistory::default(),
+            };
+            self.durations_repo = Some(repo.clone());
+            self.stuck_notified.clear();
+        }
+        if !self.durations.record(&self.runs) {
+            return;
+        }
+        let Some(dir) = &self.cache_dir else {
+            return;
+        };
+        let path = durations::history_path(dir, &repo);
+        let history = self.durations.clone();
+        tokio::task::spawn_blocking(move || {
+            if let Err(e) = durations::save(&path, &history) {
+                error!(error = %e, "Failed to save duration history");
+            }
+        });
+    }
+
+    /// Cache hits and misses across the current run's jobs whose logs have
+    /// been loaded
+    pub fn run_cache_summary(&self) -> Option<String> {
+        cache::run_summary(
+            self.jobs
+                .iter()
+                .filter_map(|job| self.cache_reports.get(&job.id)),
+        )
+    }
+
+    /// Read the cache restores out of the log just loaded for `job_id`
+    fn record_cache_use(&mut self, job_id: u64) {
+        self.cache_reports
+            .insert(job_id, CacheReport::parse(&self.log_content));
+    }
+
+    /// An in-progress run that has outlasted its workflow's p95
+    pub fn stuck_run(&self, run: &WorkflowRun) -> Option<Stuck> {
+        self.durations.stuck(run, Utc::now())
+    }
+
+    /// Announce each run the first time it goes past its usual duration
+    fn notify_stuck_runs(&mut self) {
+        if !self.stuck_alerts {
+            return;
+        }
+        let newly_stuck = self.runs.iter().find_map(|run| {
+            let stuck = self.stuck_run(run)?;
+            (!self.stuck_notified.contains(&run.id)).then_some((run, stuck))
+        });
+        if let Some((run, stuck)) = newly_stuck {
+            self.status_message = format!(
+                "⚠ {} #{} is {}",
+                run.name.as_deref().unwrap_or("Run"),
+                run.run_number,
+                stuck.label()
+            );
+            self.stuck_notified.insert(run.id);
+        }
+    }
+
+    /// Who the token belongs to, so audit entries can name them and the
+    /// state and caches can be kept per account
+    pub fn spawn_fetch_login(&self) {
+        let client = self.client.clone();
+        let tx = self.bg_tx.clone();
+
+        tokio::spawn(async move {
+            let result = client.get_login().await;
+            let _ = tx.send(BackgroundResult::LoginFetched(result));
+        });
+    }
+
+    /// Check the token's scopes and its write access to the current repo,
+    /// for the header's badge. Runs again whenever the repo changes, since
+    /// a fine-grained token can differ from one repo to the next.
+    pub fn spawn_check_token_scope(&mut self) {
+        self.token_scope = None;
+        let client = self.client.clone();
+        let repo = self.repo_key();
+        let tx = self.bg_tx.clone();
+
+        tokio::spawn(async move {
+            let result = scopes::detect(&client).await;
+            let _ = tx.send(BackgroundResult::TokenScopeChecked { repo, result });
+        });
+    }
+
+    /// Point the state file and caches at `login`'s own directories,
+    /// moving any files from before accounts were kept apart there first
+    fn use_account(&mut self, login: &str) {
+        let Some(atlas_dir) = self.atlas_dir.clone() else {
+            return;
+        };
+        let account = Account::new(self.client.base_url(), login);
+        match account.migrate_flat_files(&atlas_dir) {
+            Ok(moved) => {
+                for path in moved {
+                    info!(path = %path.display(), %login, "Moved into the account's directory");
+                }
+            }
+            Err(e) => warn!(error = %e, "Failed to move old state into the account's directory"),
+        }
+        if let Err(e) = account.remember(&atlas_dir) {
+            warn!(error = %e, "Failed to record the current account");
+        }
+        self.state_store = Some(StateStore::new(account.state_dir(&atlas_dir)));
+        self.cache_dir = Some(account.cache_dir(&atlas_dir));
+        // Reload the duration history from the account's cache next fetch
+        self.durations_repo = None;
+        if !self.client.repo.is_empty() {
+            self.remember_repo();
+        }
+    }
+
+    /// Append a finished rerun, cancel or dispatch to the audit log. Best
+    /// effort: a failed write is logged and the result passed on as is.
+    fn record_audit(
+        &self,
+        action: AuditAction,
+        repo: String,
+        run: Option<(u64, u64)>,
+        detail: Option<String>,
+        result: Result<()>,
+    ) -> Result<()> {
+        let Some(audit_log) = self.audit_log.clone() else {
+            return result;
+        };
+        let entry = AuditEntry {
+            timestamp: Utc::now(),
+            login: self.login.clone(),
+            repo,
+            run_id: run.map(|(id, _)| id),
+            run_number: run.map(|(_, number)| number),
+            action,
+            outcome: audit::outcome(&result),
+            detail,
+        };
+        tokio::task::spawn_blocking(move || {
+            if let Err(e) = audit_log.append(&entry) {
+                warn!(error = %e, "Failed to write the audit log");
+            }
+        });
+        result
+    }
+
+    fn repo_key(&self) -> String {
+        format!("{}/{}", self.client.owner, self.client.repo)
+    }
+
+    /// Look the repo's retention up once; GitHub's default stands in
+    /// until it arrives, or if the token can't read it
+    fn ensure_retention(&mut self) {
+        let repo = self.repo_key();
+        if self.retention.contains_key(&repo) {
+            return;
+        }
+        self.retention.insert(repo.clone(), DEFAULT_RETENTION_DAYS);
+
+        let client = self.client.clone();
+        let tx = self.bg_tx.clone();
+        tokio::spawn(async move {
+            debug!(%repo, "Fetching log retention");
+            let result = client.get_retention_days().await;
+            let _ = tx.send(BackgroundResult::RetentionFetched { repo, result });
+        });
+    }
+
+    fn retention_days(&self) -> u32 {
+        self.retention
+            .get(&self.repo_key())
+            .copied()
+            .unwrap_or(DEFAULT_RETENTION_DAYS)
+    }
+
+    /// The run finished longer ago than the repo keeps logs
+    pub fn logs_expired(&self, run: &WorkflowRun) -> bool {
+        let kept = chrono::Duration::days(self.retention_days() as i64);
+        run.updated_at + kept < Utc::now()
+    }
+
+    // ── Artifacts ──────────────────────────────────────────────────
+
+    /// `a` in run details: list the run's artifacts
+    pub fn open_artifacts(&mut self) {
+        if self.view != View::RunDetail || self.current_run.is_none() {
+            return;
+        }
+        self.view = View::Artifacts;
+        self.artifacts.clear();
+        self.artifacts_selected = 0;
+        self.spawn_fetch_artifacts();
+    }
+
+    pub fn spawn_fetch_artifacts(&mut self) {
+        let Some(run) = &self.current_run else {
+            return;
+        };
+        self.loading = true;
+        self.loading_since = Some(Instant::now());
+        self.status_message = format!("Fetching artifacts for #{}...", run.run_number);
+
+        let client = self.client.clone();
+        let run_id = run.id;
+        let tx = self.bg_tx.clone();
+        tokio::spawn(async move {
+            debug!(run_id, "Fetching artifacts");
+            let result = client.get_artifacts(run_id).await;
+            let _ = tx.send(BackgroundResult::ArtifactsFetched { run_id, result });
+        });
+    }
+
+    // ── Environments ───────────────────────────────────────────────
+
+    /// `e` in run details: where the run deploys, and what holds it up
+    pub fn open_environments(&mut self) {
+        if self.view != View::RunDetail || self.current_run.is_none() {
+            return;
+        }
+        self.view = View::Environments;
+        self.environments.clear();
+        self.deployments.clear();
+        self.environments_selected = 0;
+        self.spawn_fetch_environments();
+    }
+
+    pub fn spawn_fetch_environments(&mut self) {
+        let Some(run) = &self.current_run else {
+            return;
+        };
+        self.loading = true;
+        self.loading_since = Some(Instant::now());
+        self.status_message = format!("Fetching environments for #{}...", run.run_number);
+
+        let client = self.client.clone();
+        let run_id = run.id;
+        let head_sha = run.head_sha.clone();
+        let tx = self.bg_tx.clone();
+        tokio::spawn(async move {
+            debug!(run_id, "Fetching environments");
+            let result = tokio::try_join!(
+                client.get_environments(),
+                client.get_deployments(run_id, &head_sha)
+            );
+            let _ = tx.send(BackgroundResult::EnvironmentsFetched { run_id, result });
+        });
+    }
+
+    /// The run's latest deployment to `environment`
+    pub fn deployment_to(&self, environment: &str) -> Option<&Deployment> {
+        self.deployments
+            .iter()
+            .find(|d| d.environment == environment)
+    }
+
+    // ── Runners ────────────────────────────────────────────────────
+
+    /// `u` in run details: the self-hosted runners, for a job that stays
+    /// queued
+    pub fn open_runners(&mut self) {
+        if self.view != View::RunDetail || self.current_run.is_none() {
+            return;
+        }
+        self.view = View::Runners;
+        self.runners.clear();
+        self.runners_selected = 0;
+        self.spawn_fetch_runners();
+    }
+
+    /// The repo's runners, plus the org's when the token may list them
+    pub fn spawn_fetch_runners(&mut self) {
+        let Some(run) = &self.current_run else {
+            return;
+        };
+        self.loading = true;
+        self.loading_since = Some(Instant::now());
+        self.status_message = "Fetching runners...".to_string();
+
+        let client = self.client.clone();
+        let run_id = run.id;
+        let tx = self.bg_tx.clone();
+        tokio::spawn(async move {
+            debug!(run_id, "Fetching runners");
+            let (repo, org) = tokio::join!(
+                client.get_repo_runners(),
+                client.get_org_runners(&client.owner)
+            );
+            let result = repo.map(|mut runners| {
+                match org {
+                    Ok(org_runners) => runners.extend(org_runners),
+                    // A user account, or a token without org admin rights
+                    Err(e) => debug!(error = %e, "No org runners"),
+                }
+                runners
+            });
+            let _ = tx.send(BackgroundResult::RunnersFetched { run_id, result });
+        });
+    }
+
+    // ── Timeline ───────────────────────────────────────────────────
+
+    /// `t` in run details: the jobs as bars on the run's time axis, to see
+    /// what ran side by side and what waited
+    pub fn open_timeline(&mut self) {
+        if self.view != View::RunDetail || self.jobs.is_empty() {
+            return;
+        }
+        self.view = View::RunTimeline;
+        self.status_message = "↑↓ picks the job whose steps show".to_string();
+        self.spawn_hydrate_selected_job();
+    }
+
+    // ── Statistics ─────────────────────────────────────────────────
+
+    /// `T` in the runs list: build health of the runs loaded
+    pub fn open_statistics(&mut self) {
+        if self.view != View::RunsList {
+            return;
+        }
+        self.view = View::Statistics;
+        self.status_message = format!(
+            "Statistics over the {} runs loaded",
+            format_count(self.runs.len() as u64)
+        );
+    }
+
+    // ── Actions caches ─────────────────────────────────────────────
+
+    /// `K` in the runs list: the repo's Actions caches
+    pub fn open_caches(&mut self) {
+        if self.view != View::RunsList {
+            return;
+        }
+        self.view = View::Caches;
+        self.actions_caches.clear();
+        self.actions_caches_selected = 0;
+        self.spawn_fetch_caches();
+    }
+
+    pub fn spawn_fetch_caches(&mut self) {
+        self.loading = true;
+        self.loading_since = Some(Instant::now());
+        self.status_message = "Fetching caches...".to_string();
+
+        let client = self.client.clone();
+        let repo = self.repo_key();
+        let tx = self.bg_tx.clone();
+        tokio::spawn(async move {
+            debug!(%repo, "Fetching caches");
+            let result = client.get_caches().await;
+            let _ = tx.send(BackgroundResult::CachesFetched { repo, result });
+        });
+    }
+
+    /// `d` or `Delete` in the caches view: ask before deleting the selected
+    /// cache
+    pub fn request_delete_cache(&mut self) {
+        if self.view != View::Caches {
+            return;
+        }
+        let Some(cache) = self.actions_caches.get(self.actions_caches_selected) else {
+            return;
+        };
+        let pending = PendingAction::DeleteCache {
+            cache_id: cache.id,
+            key: cache.key.clone(),
+            size_in_bytes: cache.size_in_bytes,
+        };
+        self.ask(pending, false);
+    }
+
+    fn spawn_delete_cache(&mut self, cache_id: u64, key: String) {
+        self.status_message = format!("Deleting cache {}...", key);
+
+        let client = self.client.clone();
+        let repo = self.repo_key();
+        let tx = self.bg_tx.clone();
+        tokio::spawn(async move {
+            debug!(cache_id, %key, "Deleting cache");
+            let result = client.delete_cache(cache_id).await;
+            let _ = tx.send(BackgroundResult::CacheDeleted {
+                repo,
+                cache_id,
+                key,
+                result,
+            });
+        });
+    }
+
+    /// `t` in the workflow list: ask before disabling the selected workflow,
+    /// or enabling it again
+    pub fn request_toggle_workflow(&mut self) {
+        if self.view != View::WorkflowList {
+            return;
+        }
+        let Some(workflow) = self.selected_workflow() else {
+            return;
+        };
+        if workflow.state == "deleted" {
+            self.status_message = format!("{} was deleted", workflow.name);
+            return;
+        }
+        let protected = self
+            .protected
+            .covers(Some(&workflow.name), Some(&workflow.path));
+        let pending = PendingAction::ToggleWorkflow {
+            workflow_id: workflow.id,
+            name: workflow.name.clone(),
+            enable: workflow.state != "active",
+        };
+        self.ask(pending, protected);
+    }
+
+    fn spawn_toggle_workflow(&mut self, workflow_id: u64, name: String, enable: bool) {
+        self.status_message = format!(
+            "{} {}...",
+            if enable { "Enabling" } else { "Disabling" },
+            name
+        );
+
+        let client = self.client.clone();
+        let repo = self.repo_key();
+        let tx = self.bg_tx.clone();
+        tokio::spawn(async move {
+            debug!(workflow_id, enable, "Toggling workflow");
+            let result = if enable {
+                client.enable_workflow(workflow_id).await
+            } else {
+                client.disable_workflow(workflow_id).await
+            };
+            let _ = tx.send(BackgroundResult::WorkflowToggled {
+                repo,
+                workflow_id,
+                name,
+                enable,
+                result,
+            });
+        });
+    }
+
+    /// The `runs-on` labels of the job selected in run details
+    pub fn runner_labels_wanted(&self) -> &[String] {
+        self.jobs
+            .get(self.jobs_selected)
+            .map_or(&[], |job| job.labels.as_slice())
+    }
+
+    /// Download the selected artifact into `artifacts_dir`
+    pub fn spawn_download_artifact(&mut self) {
+        let (Some(run), Some(artifact)) = (
+            &self.current_run,
+            self.artifacts.get(self.artifacts_selected),
+        ) else {
+            return;
+        };
+        if artifact.expired {
+            self.status_message = format!("{} has expired", artifact.name);
+            return;
+        }
+        if self.downloads.iter().any(|d| d.id == artifact.id) {
+            return;
+        }
+
+        let client = self.client.clone();
+        let artifact_id = artifact.id;
+        let size = artifact.size_in_bytes;
+        // Artifact names can't contain path separators, but don't trust that
+        let file_name = format!("{}.zip", artifact.name.replace(['/', '\\'], "_"));
+        let dest = self.artifacts_dir.join(run.id.to_string()).join(file_name);
+        let tx = self.bg_tx.clone();
+        self.status_message = format!("Downloading {}...", artifact.name);
+        self.downloads.push(Download {
+            id: artifact_id,
+            label: artifact.name.clone(),
+            started: Instant::now(),
+        });
+
+        tokio::spawn(async move {
+            debug!(artifact_id, dest = %dest.display(), "Downloading artifact");
+            let mut last_percent = None;
+            let progress = |written: u64| {
+                let percent = (written.saturating_mul(100) / size.max(1)).min(100) as u8;
+                if last_percent != Some(percent) {
+                    last_percent = Some(percent);
+                    let _ = tx.send(BackgroundResult::ArtifactProgress {
+                        artifact_id,
+                        percent,
+                    });
+                }
+            };
+            let result = async {
+                let dir = dest.parent().context("Artifact path has no directory")?;
+                tokio::fs::create_dir_all(dir)
+                    .await
+                    .with_context(|| format!("Failed to create {}", dir.display()))?;
+                client
+                    .download_artifact(artifact_id, &dest, progress)
+                    .await?;
+                Ok(dest)
+            }
+            .await;
+            let _ = tx.send(BackgroundResult::ArtifactDownloaded {
+                artifact_id,
+                result,
+            });
+        });
+    }
+
+    // ── Log exports ────────────────────────────────────────────────
+
+    /// `s` in the log view: ask for an optional annotation, then save
+    pub fn start_export(&mut self) {
+        if self.view != View::Logs || self.log_content.is_empty() {
+            return;
+        }
+        if self.export_log.is_none() {
+            self.status_message = "Exports are disabled".to_string();
+            return;
+        }
+        self.export_prompt = Some(String::new());
+    }
+
+    pub fn export_prompt_push(&mut self, c: char) {
+        if let Some(prompt) = &mut self.export_prompt {
+            prompt.push(c);
+        }
+    }
+
+    pub fn export_prompt_backspace(&mut self) {
+        if let Some(prompt) = &mut self.export_prompt {
+            prompt.pop();
+        }
+    }
+
+    pub fn cancel_export(&mut self) {
+        self.export_prompt = None;
+    }
+
+    /// Write the open log to `~/.atlas/exports/` and record it
+    pub fn save_export(&mut self) {
+        let Some(annotation) = self.export_prompt.take() else {
+            return;
+        };
+        let Some(export_log) = self.export_log.clone() else {
+            return;
+        };
+        let annotation = annotation.trim();
+        let meta = ExportMeta {
+            repo: format!("{}/{}", self.client.owner, self.client.repo),
+            run_number: self.current_run.as_ref().map(|r| r.run_number),
+            job: self.jobs.get(self.jobs_selected).map(|j| j.name.clone()),
+            annotation: (!annotation.is_empty()).then(|| annotation.to_string()),
+        };
+        let content = self.log_text();
+        let tx = self.bg_tx.clone();
+        tokio::task::spawn_blocking(move || {
+            let _ = tx.send(BackgroundResult::ExportSaved(
+                export_log.save(&content, meta),
+            ));
+        });
+    }
+
+    /// `E`: show or hide the recent exports
+    pub fn toggle_exports_overlay(&mut self) {
+        if self.exports_overlay.take().is_some() {
+            return;
+        }
+        let Some(export_log) = &self.export_log else {
+            self.status_message = "Exports are disabled".to_string();
+            return;
+        };
+        match export_log.load() {
+            Ok(records) => self.exports_overlay = Some(ExportsOverlay::new(records)),
+            Err(e) => {
+                self.status_message = format!("Error: {:#}", e);
+                error!(error = %e, "Failed to read exports");
+            }
+        }
+    }
+
+    /// Page through the selected export with `$PAGER`
+    pub fn open_selected_export(&mut self) {
+        let Some((record, exists)) = self.exports_overlay.as_ref().and_then(|o| o.selected())
+        else {
+            return;
+        };
+        if *exists {
+            self.pager_request = Some(record.path.clone());
+        } else {
+            self.status_message = format!("{} was deleted", record.path.display());
+        }
+    }
+
+    /// Put the selected export's path on the clipboard
+    pub fn copy_selected_export_path(&mut self) {
+        let Some((record, _)) = self.exports_overlay.as_ref().and_then(|o| o.selected()) else {
+            return;
+        };
+        let path = record.path.display().to_string();
+        self.status_message = format!("Copied {}", path);
+        self.clipboard_request = Some(path);
+    }
+
+    /// Forget exports whose files are gone and refresh the popup
+    pub fn prune_exports(&mut self) {
+        let Some(export_log) = &self.export_log else {
+            return;
+        };
+        match export_log
+            .prune_missing()
+            .and_then(|n| Ok((n, export_log.load()?)))
+        {
+            Ok((pruned, records)) => {
+                self.status_message = format!("Pruned {} missing export(s)", pruned);
+                self.exports_overlay = Some(ExportsOverlay::new(records));
+            }
+            Err(e) => {
+                self.status_message = format!("Error: {:#}", e);
+                error!(error = %e, "Failed to prune exports");
+            }
+        }
+    }
+
+    // ── Handle background results ──────────────────────────────────
+
+    pub fn handle_background(&mut self, result: BackgroundResult) {
+        self.apply_background(result);
+        if self.loading {
+            return;
+        }
+        if let Some(since) = self.loading_since.take() {
+            if !self.status_message.starts_with("Error") {
+                self.status_message.push_str(&format!(
+                    " · fetched in {}",
+                    format_fetch_time(since.elapsed())
+                ));
+            }
+        }
+    }
+
+    /// The spinner frame to draw while loading
+    pub fn spinner(&self) -> &'static str {
+        SPINNER[self.spinner_frame % SPINNER.len()]
+    }
+
+    fn apply_background(&mut self, result: BackgroundResult) {
+        if matches!(
+            result,
+            BackgroundResult::ReposFetched(_) | BackgroundResult::RunsFetched(_)
+        ) {
+            // The first list has landed (or failed); startup is over
+            self.connecting = None;
+        }
+        match result {
+            BackgroundResult::ReposFetched(result) => match result {
+                Ok(repos) => {
+                    let count = repos.len();
+                    let previous = self.filtered_repos().get(self.repos_selected).map(|r| r.id);
+                    self.repos = repos;
+                    self.loading = false;
+                    self.repos_selected = reselect(
+                        &self.filtered_repos(),
+                        |r| r.id,
+                        previous,
+                        self.repos_selected,
+                    );
+                    self.status_message = match self.repos_pages() {
+                        Some(pages) => format!(
+                            "{} repositories · page {}/{} · sorted by last push · / to search",
+                            format_count(self.repos_total),
+                            self.repos_page,
+                            pages
+                        ),
+                        None => format!(
+                            "{} repositories · sorted by last push · / to search",
+                            format_count(count as u64)
+                        ),
+                    };
+                    debug!(count, page = self.repos_page, "Repositories fetched");
+                }
+                Err(e) => {
+                    self.loading = false;
+                    self.status_message = format!("Error: {:#}", e);
+                    error!(error = %e, "Failed to fetch repositories");
+                }
+            },
+
+            BackgroundResult::ReposCounted(result) => match result {
+                Ok(total) => self.repos_total = total,
+                // The list still pages on without a count
+                Err(e) => warn!(error = %e, "Failed to count repositories"),
+            },
+
+            BackgroundResult::WorkflowsFetched(result) => match result {
+                Ok(workflows) => {
+                    self.workflows = workflows;
+                    self.loading = false;
+                    self.select_filtered_workflow();
+                    self.status_message = format!(
+                        "{} workflows · {}/{}",
+                        format_count(self.workflows.len() as u64),
+                        self.client.owner,
+                        self.client.repo
+                    );
+                    debug!(count = self.workflows.len(), "Workflows fetched");
+                }
+                Err(e) => {
+                    self.loading = false;
+                    self.status_message = format!("Error: {:#}", e);
+                    error!(error = %e, "Failed to fetch workflows");
+                }
+            },
+
+            BackgroundResult::RunsFetched(result) => match result {
+                Ok(response) => {
+                    let previous = self.runs.get(self.runs_selected).map(|r| r.id);
+                    self.runs = response.workflow_runs;
+                    let runs = &self.runs;
+                    self.selected_run_ids
+                        .retain(|id| runs.iter().any(|run| run.id == *id));
+                    self.runs_total = response.total_count;
+                    self.loading = false;
+
+                    self.status_message = format!(
+                        "{} runs total · Page {}/{} · {} {}",
+                        format_count(self.runs_total),
+                        self.page,
+                        self.total_pages(),
+                        self.client.owner,
+                        self.client.repo,
+                    );
+                    if let Some(branch) = &self.branch_filter {
+                        self.status_message
+                            .push_str(&format!(" · branch {}", branch));
+                    }
+                    if let Some(status) = self.status_filter {
+                        self.status_message.push_str(&format!(" · {}", status));
+                    }
+                    if let Some(event) = &self.event_filter {
+                        self.status_message.push_str(&format!(" · event {}", event));
+                    }
+                    debug!(total = self.runs_total, page = self.page, "Runs fetched");
+                    self.record_durations();
+
+                    match self.runs_anchor.take() {
+                        Some(anchor) => self.reselect_run(anchor),
+                        None => {
+                            self.runs_selected =
+                                reselect(&self.runs, |r| r.id, previous, self.runs_selected)
+                        }
+                    }
+                    self.schedule_preview();
+                }
+                Err(e) => {
+                    self.loading = false;
+                    self.status_message = format!("Error: {:#}", e);
+                    error!(error = %e, "Failed to fetch runs");
+                }
+            },
+
+            BackgroundResult::JobsFetched { run_number, result } => match result {
+                Ok(response) => {
+                    let previous = self.jobs.get(self.jobs_selected).map(|j| j.id);
+                    self.jobs = response.jobs;
+                    if self.jobs_failed_first {
+                        sort_failed_first(&mut self.jobs);
+                    }
+                    self.jobs_selected =
+                        reselect(&self.jobs, |j| j.id, previous, self.jobs_selected);
+                    self.loading = false;
+                    self.steps_loading = None;
+
+                    // Steps of unfinished jobs keep changing, so only reuse
+                    // cached steps for completed jobs
+                    for job in &mut self.jobs {
+                        if job.status.as_deref() == Some("completed") {
+                            job.steps = self.steps_cache.get(&job.id).cloned();
+                        } else {
+                            self.steps_cache.remove(&job.id);
+                        }
+                    }
+                    self.spawn_hydrate_selected_job();
+                    self.spawn_fetch_run_counts();
+                    self.spawn_fetch_annotations();
+
+                    let startup_failure = self
+                        .current_run
+                        .as_ref()
+                        .is_some_and(|r| r.conclusion.as_deref() == Some("startup_failure"));
+                    if self.jobs.is_empty() && startup_failure {
+                        self.spawn_diagnose_startup_failure();
+                        return;
+                    }
+
+                    let run_name = self
+                        .current_run
+                        .as_ref()
+                        .and_then(|r| r.display_title.as_deref().or(r.name.as_deref()))
+                        .unwrap_or("Unknown");
+                    self.status_message = format!(
+                        "Run #{} · {} · {} jobs",
+                        run_number,
+                        run_name,
+                        format_count(self.jobs.len() as u64)
+                    );
+                    debug!(run_number, jobs = self.jobs.len(), "Jobs fetched");
+                }
+                Err(e) => {
+                    self.loading = false;
+                    self.status_message = format!("Error: {:#}", e);
+                    error!(error = %e, run_number, "Failed to fetch jobs");
+                }
+            },
+
+            BackgroundResult::JobHydrated { job_id, result } => {
+                if self.steps_loading == Some(job_id) {
+                    self.steps_loading = None;
+                }
+                match result {
+                    Ok(mut job) => {
+                        let steps = job.steps.take().unwrap_or_default();
+                        debug!(job_id, steps = steps.len(), "Job steps hydrated");
+                        // Also picks up a status change (log follow watches it)
+                        if let Some(listed) = self.jobs.iter_mut().find(|j| j.id == job_id) {
+                            *listed = Job {
+                                steps: Some(steps.clone()),
+                                ..job
+                            };
+                        }
+                        self.steps_cache.insert(job_id, steps);
+                    }
+                    Err(e) => {
+                        self.status_message = format!("Error: {:#}", e);
+                        error!(error = %e, job_id, "Failed to fetch job steps");
+                    }
+                }
+                // The selection may have moved on while this was in flight
+                self.spawn_hydrate_selected_job();
+            }
+
+            BackgroundResult::LogsFetched {
+                job_id,
+                job_name,
+                result,
+            } => match result {
+                Ok(logs) => {
+                    self.finish_download(job_id);
+                    self.loading = false;
+                    if self.view == View::Logs && self.log_job_id == Some(job_id) {
+                        // Same log again (follow, refresh): keep the scroll
+                        self.log_poll_in_flight = false;
+                        let added = self.append_log_delta(&logs);
+                        let lines = format_count(self.log_content.len() as u64);
+                        self.status_message = format!("Logs: {} · {} lines", job_name, lines);
+                        if self.log_follow {
+                            self.log_scroll = self.max_log_scroll();
+                            let finished = self
+                                .jobs
+                                .get(self.jobs_selected)
+                                .is_some_and(|j| j.status.as_deref() == Some("completed"));
+                            self.status_message = if finished {
+                                self.log_follow = false;
+                                format!("✓ {} finished · {} lines", job_name, lines)
+                            } else {
+                                format!("Following {} · {} lines (+{})", job_name, lines, added)
+                            };
+                        }
+                    } else {
+                        self.set_log_content(&logs);
+                        self.log_job_id = Some(job_id);
+                        self.center_first_error_if_failed(job_id);
+                        self.status_message = format!(
+                            "Logs: {} · {} lines",
+                            job_name,
+                            format_count(self.log_content.len() as u64)
+                        );
+                    }
+                    if !self.log_search.is_empty() {
+                        self.refresh_log_matches();
+                    }
+                    self.record_cache_use(job_id);
+                    debug!(%job_name, lines = self.log_content.len(), "Logs fetched");
+                }
+                Err(e) => {
+                    self.finish_download(job_id);
+                    self.log_poll_in_flight = false;
+                    self.log_follow = false;
+                    self.log_content = vec![format!("Error fetching logs: {:#}", e)];
+                    self.log_highlights.clear();
+                    self.log_groups.clear();
+                    self.loading = false;
+                    self.status_message = format!("Failed to load logs for {}", job_name);
+                    error!(error = %e, %job_name, "Failed to fetch logs");
+                }
+            },
+
+            BackgroundResult::LogChunk { job_id, lines } => {
+                if self.log_stream.is_none()
+                    || self.view != View::Logs
+                    || self.log_job_id != Some(job_id)
+                {
+                    return;
+                }
+                self.push_log_lines(lines);
+                if self.log_follow {
+                    self.log_scroll = self.max_log_scroll();
+                }
+                if let Some(job) = self.jobs.get(self.jobs_selected) {
+                    self.status_message = format!(
+                        "Streaming logs for {} · {} lines...",
+                        job.name,
+                        format_count(self.log_content.len() as u64)
+                    );
+                }
+            }
+
+            BackgroundResult::LogsStreamed {
+                job_id,
+                job_name,
+                result,
+            } => {
+                self.finish_download(job_id);
+                if self.log_stream.is_none()
+                    || self.view != View::Logs
+                    || self.log_job_id != Some(job_id)
+                {
+                    return;
+                }
+                self.log_stream = None;
+                self.loading = false;
+                match result {
+                    Ok(()) => {
+                        // Fold it the way a log fetched whole opens
+                        self.fold_log_groups();
+                        if self.log_follow {
+                            self.log_scroll = self.max_log_scroll();
+                            let finished = self
+                                .jobs
+                                .get(self.jobs_selected)
+                                .is_some_and(|j| j.status.as_deref() == Some("completed"));
+                            if finished {
+                                self.log_follow = false;
+                            }
+                        } else {
+                            self.log_scroll = self.log_scroll.min(self.max_log_scroll());
+                            self.center_first_error_if_failed(job_id);
+                        }
+                        if !self.log_search.is_empty() {
+                            self.refresh_log_matches();
+                        }
+                        self.record_cache_use(job_id);
+                        self.status_message = format!(
+                            "Logs: {} · {} lines",
+                            job_name,
+                            format_count(self.log_content.len() as u64)
+                        );
+                        debug!(%job_name, lines = self.log_content.len(), "Logs streamed");
+                    }
+                    Err(e) => {
+                        self.log_follow = false;
+                        self.log_content = vec![format!("Error fetching logs: {:#}", e)];
+                        self.log_highlights.clear();
+                        self.log_groups.clear();
+                        self.status_message = format!("Failed to load logs for {}", job_name);
+                        error!(error = %e, %job_name, "Failed to stream logs");
+                    }
+                }
+            }
+
+            BackgroundResult::RerunComplete {
+                repo,
+                run_id,
+                run_number,
+                debug,
+                result,
+            } => match self.record_audit(
+                AuditAction::Rerun,
+                repo,
+                Some((run_id, run_number)),
+                debug.then(|| "debug logging".to_string()),
+                result,
+            ) {
+                Ok(()) => {
+                    self.status_message = format!(
+                        "✓ Re-run triggered{} for #{}",
+                        with_debug(debug),
+                        run_number
+                    );
+                    debug!(run_number, "Re-run triggered");
+                }
+                Err(e) => {
+                    self.status_message = format!("Error: {:#}", e);
+                    error!(error = %e, run_number, "Failed to re-run");
+                }
+            },
+
+            BackgroundResult::JobRerunComplete {
+                repo,
+                run_id,
+                run_number,
+                job_name,
+                debug,
+                result,
+            } => {
+                let detail = format!("job {}{}", job_name, with_debug(debug));
+                match self.record_audit(
+                    AuditAction::RerunJob,
+                    repo,
+                    Some((run_id, run_number)),
+                    Some(detail),
+                    result,
+                ) {
+                    Ok(()) => {
+                        // Show the new attempt queued
+                        if self.view == View::RunDetail
+                            && self.current_run.as_ref().map(|r| r.id) == Some(run_id)
+                        {
+                            self.spawn_fetch_jobs();
+                        }
+                        self.status_message = format!(
+                            "✓ Re-run of job '{}' triggered{} for #{}",
+                            job_name,
+                            with_debug(debug),
+                            run_number
+                        );
+                        debug!(run_number, %job_name, "Job re-run triggered");
+                    }
+                    Err(e) => {
+                        self.status_message = format!("Error: {:#}", e);
+                        error!(error = %e, run_number, %job_name, "Failed to re-run job");
+                    }
+                }
+            }
+
+            BackgroundResult::DispatchInputsFetched {
+                workflow_id,
+                default_ref,
+                result,
+            } => {
+                let Some(form) = self
+                    .dispatch_form
+                    .as_mut()
+                    .filter(|f| f.workflow_id == workflow_id)
+                else {
+                    return;
+                };
+                // Unless a ref was typed in the meantime
+                if let Some(branch) = default_ref.filter(|_| form.fields[0].value.is_empty()) {
+                    form.fields[0].value = branch;
+                }
+                match result {
+                    Ok(Some(inputs)) => form.set_inputs(inputs),
+                    Ok(None) => {
+                        form.set_inputs(Vec::new());
+                        form.error =
+                            Some("This workflow has no workflow_dispatch trigger".to_string());
+                    }
+                    Err(e) => {
+                        // Inputs can still be typed out as key=value
+                        form.set_free_inputs();
+                        form.error = Some(format!("Couldn't read inputs: {:#}", e));
+                        error!(error = %e, workflow_id, "Failed to fetch dispatch inputs");
+                    }
+                }
+            }
+
+            BackgroundResult::DispatchComplete {
+                repo,
+                workflow_name,
+                git_ref,
+                result,
+            } => match self.record_audit(
+                AuditAction::Dispatch,
+                repo,
+                None,
+                Some(format!("{} on {}", workflow_name, git_ref)),
+                result,
+            ) {
+                Ok(()) => {
+                    self.status_message = format!(
+                        "✓ Dispatched {} on {} — waiting for its run...",
+                        workflow_name, git_ref
+                    );
+                    debug!(%workflow_name, %git_ref, "Workflow dispatched");
+                }
+                Err(e) => {
+                    self.status_message = format!("Error: {:#}", e);
+                    error!(error = %e, %workflow_name, "Failed to dispatch");
+                }
+            },
+
+            BackgroundResult::DispatchedRunFound {
+                workflow_name,
+                git_ref,
+                run,
+            } => match run {
+                Some(run) => {
+                    let message = format!(
+                        "▶ {} #{} started on {}",
+                        workflow_name, run.run_number, git_ref
+                    );
+                    debug!(run_id = run.id, "Dispatched run found");
+                    if self.view == View::RunsList {
+                        // Reload the list with the new run selected
+                        self.runs_anchor = Some(RunAnchor::from(run.as_ref()));
+                        self.spawn_fetch_runs();
+                    } else {
+                        self.status_message = message.clone();
+                    }
+                    self.toast = Some(Toast {
+                        message,
+                        success: true,
+                        shown_at: Instant::now(),
+                    });
+                }
+                None => {
+                    self.status_message = format!(
+                        "Dispatched {} on {}, but its run hasn't shown up yet — press r to look again",
+                        workflow_name, git_ref
+                    );
+                }
+            },
+
+            BackgroundResult::LoginFetched(result) => match result {
+                Ok(login) => {
+                    self.use_account(&login);
+                    self.login = Some(login);
+                }
+                Err(e) => warn!(
+                    error = %e,
+                    "Couldn't look up the login; state and caches stay in memory"
+                ),
+            },
+
+            BackgroundResult::TokenScopeChecked { repo, result } => match result {
+                Ok(scope) if repo == self.repo_key() => {
+                    if scope.is_limited() {
+                        warn!(%repo, missing = ?scope.missing, "Token has limited scope");
+                        self.status_message = format!("⚠ {}", scope.summary());
+                    }
+                    self.token_scope = Some(scope);
+                }
+                Ok(_) => {}
+                // Nothing to warn about if GitHub won't say
+                Err(e) => debug!(%repo, error = %e, "Couldn't check the token's scope"),
+            },
+
+            BackgroundResult::ExportSaved(result) => match result {
+                Ok(record) => {
+                    self.status_message = format!("✓ Saved logs to {}", record.path.display());
+                    debug!(path = %record.path.display(), "Logs exported");
+                }
+                Err(e) => {
+                    self.status_message = format!("Error: {:#}", e);
+                    error!(error = %e, "Failed to export logs");
+                }
+            },
+
+            BackgroundResult::RunCountsFetched { run_id, counts } => {
+                // Failed fetches stay `None` and show as a dash
+                if let Some((id, current)) = &mut self.run_counts {
+                    if *id == run_id {
+                        *current = counts;
+                    }
+                }
+            }
+
+            BackgroundResult::CommitFetched { sha, result } => {
+                let current = self
+                    .current_run
+                    .as_ref()
+                    .is_some_and(|run| run.head_sha == sha);
+                if current {
+                    self.commit_loading = false;
+                }
+                match result {
+                    Ok(commit) if current => self.current_commit = Some(commit),
+                    Ok(_) => {}
+                    // The summary shows a dash; the SHA is still there
+                    Err(e) => warn!(%sha, error = %e, "Failed to fetch commit"),
+                }
+            }
+
+            BackgroundResult::AnnotationsFetched { run_id, result } => match result {
+                Ok(mut annotations) => {
+                    debug!(run_id, count = annotations.len(), "Annotations fetched");
+                    if let Some((id, current)) = &mut self.run_annotations {
+                        if *id == run_id {
+                            annotations.sort_by_key(Annotation::severity_rank);
+                            *current = annotations;
+                        }
+                    }
+                }
+                // The panel just stays away; the count in the summary and
+                // the run's page still have them
+                Err(e) => warn!(run_id, error = %e, "Failed to fetch annotations"),
+            },
+
+            BackgroundResult::RetentionFetched { repo, result } => match result {
+                Ok(days) => {
+                    debug!(%repo, days, "Log retention fetched");
+                    self.retention.insert(repo, days);
+                }
+                Err(e) => {
+                    // Only admins may read it; keep the default
+                    debug!(error = %e, %repo, "Log retention unavailable");
+                }
+            },
+
+            BackgroundResult::ArtifactsFetched { run_id, result } => {
+                // Stale if the user has left the run since
+                if self.current_run.as_ref().map(|r| r.id) != Some(run_id) {
+                    return;
+                }
+                self.loading = false;
+                match result {
+                    Ok(resp) => {
+                        self.status_message = match resp.artifacts.len() {
+                            0 => "No artifacts for this run".to_string(),
+                            n => format!("{} artifact(s)", n),
+                        };
+                        let previous = self.artifacts.get(self.artifacts_selected).map(|a| a.id);
+                        self.artifacts = resp.artifacts;
+                        self.artifacts_selected =
+                            reselect(&self.artifacts, |a| a.id, previous, self.artifacts_selected);
+                        debug!(run_id, count = self.artifacts.len(), "Artifacts fetched");
+                    }
+                    Err(e) => {
+                        self.status_message = format!("Error: {:#}", e);
+                        error!(error = %e, run_id, "Failed to fetch artifacts");
+                    }
+                }
+            }
+
+            BackgroundResult::EnvironmentsFetched { run_id, result } => {
+                if self.current_run.as_ref().map(|r| r.id) != Some(run_id) {
+                    return;
+                }
+                self.loading = false;
+                match result {
+                    Ok((environments, deployments)) => {
+                        let waiting = deployments.iter().filter(|d| d.awaiting_approval()).count();
+                        self.status_message = match (environments.len(), waiting) {
+                            (0, _) => "This repository has no environments".to_string(),
+                            (n, 0) => format!("{} environment(s)", n),
+                            (n, w) => format!("{} environment(s) · {} waiting for approval", n, w),
+                        };
+                        let previous = self
+                            .environments
+                            .get(self.environments_selected)
+                            .map(|e| e.id);
+                        self.environments = environments;
+                        self.deployments = deployments;
+                        self.environments_selected = reselect(
+                            &self.environments,
+                            |e| e.id,
+                            previous,
+                            self.environments_selected,
+                        );
+                        debug!(
+                            run_id,
+                            count = self.environments.len(),
+                            waiting,
+                            "Environments fetched"
+                        );
+                    }
+                    Err(e) => {
+                        self.status_message = format!("Error: {:#}", e);
+                        error!(error = %e, run_id, "Failed to fetch environments");
+                    }
+                }
+            }
+
+            BackgroundResult::CachesFetched { repo, result } => {
+                if repo != self.repo_key() {
+                    return;
+                }
+                self.loading = false;
+                match result {
+                    Ok(caches) => {
+                        let total: u64 = caches.iter().map(|c| c.size_in_bytes).sum();
+                        self.status_message = format!(
+                            "{} caches · {} in total",
+                            format_count(caches.len() as u64),
+                            format_bytes(total)
+                        );
+                        let previous = self
+                            .actions_caches
+                            .get(self.actions_caches_selected)
+                            .map(|c| c.id);
+                        self.actions_caches = caches;
+                        self.actions_caches_selected = reselect(
+                            &self.actions_caches,
+                            |c| c.id,
+                            previous,
+                            self.actions_caches_selected,
+                        );
+                        debug!(count = self.actions_caches.len(), total, "Caches fetched");
+                    }
+                    Err(e) => {
+                        self.status_message = format!("Error: {:#}", e);
+                        error!(error = %e, "Failed to fetch caches");
+                    }
+                }
+            }
+
+            BackgroundResult::CacheDeleted {
+                repo,
+                cache_id,
+                key,
+                result,
+            } => match self.record_audit(
+                AuditAction::DeleteCache,
+                repo.clone(),
+                None,
+                Some(key.clone()),
+                result,
+            ) {
+                Ok(()) => {
+                    self.status_message = format!("✓ Deleted cache {}", key);
+                    debug!(cache_id, %key, "Cache deleted");
+                    if repo == self.repo_key() {
+                        self.actions_caches.retain(|c| c.id != cache_id);
+                        self.actions_caches_selected = self
+                            .actions_caches_selected
+                            .min(self.actions_caches.len().saturating_sub(1));
+                    }
+                }
+                Err(e) => {
+                    self.status_message = format!("Error: {:#}", e);
+                    error!(error = %e, cache_id, "Failed to delete cache");
+                }
+            },
+
+            BackgroundResult::RunDeleted {
+                repo,
+                run_id,
+                run_number,
+                result,
+                done,
+                total,
+                failed,
+            } => {
+                let result = self.record_audit(
+                    AuditAction::DeleteRun,
+                    repo.clone(),
+                    Some((run_id, run_number)),
+                    None,
+                    result,
+                );
+                match &result {
+                    Ok(()) if repo == self.repo_key() => {
+                        // Dropped in place rather than refetching the page
+                        if let Some(at) = self.runs.iter().position(|r| r.id == run_id) {
+                            self.runs.remove(at);
+                            self.runs_total = self.runs_total.saturating_sub(1);
+                            self.runs_selected =
+                                self.runs_selected.min(self.runs.len().saturating_sub(1));
+                        }
+                        self.selected_run_ids.remove(&run_id);
+                        self.preview_cache.remove(&run_id);
+                    }
+                    Ok(()) => {}
+                    Err(e) => error!(error = %e, run_number, "Failed to delete run"),
+                }
+                self.status_message = match (total, &result) {
+                    (1, Ok(())) => format!("✓ Deleted run #{}", run_number),
+                    (1, Err(e)) => format!("Error: {:#}", e),
+                    _ if done < total => format!("Deleting runs... {}/{}", done, total),
+                    _ if failed == 0 => format!("✓ Deleted {}/{}", total, total),
+                    _ => format!(
+                        "Deleted {}/{} ({} failed — see atlas audit list)",
+                        total - failed,
+                        total,
+                        failed
+                    ),
+                };
+            }
+
+            BackgroundResult::WorkflowToggled {
+                repo,
+                workflow_id,
+                name,
+                enable,
+                result,
+            } => match self.record_audit(
+                if enable {
+                    AuditAction::EnableWorkflow
+                } else {
+                    AuditAction::DisableWorkflow
+                },
+                repo.clone(),
+                None,
+                Some(name.clone()),
+                result,
+            ) {
+                Ok(()) => {
+                    self.status_message = if enable {
+                        format!("✓ Enabled {}", name)
+                    } else {
+                        format!("✓ Disabled {} — its past runs stay viewable", name)
+                    };
+                    if repo == self.repo_key() {
+                        if let Some(workflow) =
+                            self.workflows.iter_mut().find(|w| w.id == workflow_id)
+                        {
+                            workflow.state = if enable {
+                                "active"
+                            } else {
+                                "disabled_manually"
+                            }
+                            .to_string();
+                        }
+                    }
+                }
+                Err(e) => {
+                    self.status_message = format!("Error: {:#}", e);
+                    error!(error = %e, workflow_id, "Failed to toggle workflow");
+                }
+            },
+
+            BackgroundResult::RunnersFetched { run_id, result } => {
+                if self.current_run.as_ref().map(|r| r.id) != Some(run_id) {
+                    return;
+                }
+                self.loading = false;
+                match result {
+                    Ok(runners) => {
+                        let online = runners.iter().filter(|r| r.status == "online").count();
+                        let busy = runners.iter().filter(|r| r.state() == "busy").count();
+                        let wanted = self.runner_labels_wanted();
+                        let fitting = runners
+                            .iter()
+                            .filter(|r| r.status == "online" && r.fits(wanted))
+                            .count();
+                        self.status_message = if runners.is_empty() {
+                            "No self-hosted runners — GitHub-hosted ones aren't listed".to_string()
+                        } else {
+                            let mut message = format!(
+                                "{} runner(s) · {} online · {} busy",
+                                runners.len(),
+                                online,
+                                busy
+                            );
+                            if !wanted.is_empty() {
+                                message.push_str(&format!(
+                                    " · {} online with {}",
+                                    fitting,
+                                    wanted.join(", ")
+                                ));
+                            }
+                            message
+                        };
+                        let previous = self.runners.get(self.runners_selected).map(|r| r.id);
+                        self.runners = runners;
+                        self.runners_selected =
+                            reselect(&self.runners, |r| r.id, previous, self.runners_selected);
+                        debug!(
+                            run_id,
+                            count = self.runners.len(),
+                            online,
+                            "Runners fetched"
+                        );
+                    }
+                    Err(e) => {
+                        self.status_message = format!("Error: {:#}", e);
+                        error!(error = %e, run_id, "Failed to fetch runners");
+                    }
+                }
+            }
+
+            BackgroundResult::ArtifactProgress {
+                artifact_id,
+                percent,
+            } => {
+                if let Some(download) = self.downloads.iter().find(|d| d.id == artifact_id) {
+                    self.status_message = format!("Downloading {}... {}%", download.label, percent);
+                }
+            }
+
+            BackgroundResult::ArtifactDownloaded {
+                artifact_id,
+                result,
+            } => {
+                self.finish_download(artifact_id);
+                match result {
+                    Ok(path) => {
+                        self.status_message = format!("✓ Saved {}", path.display());
+                        debug!(artifact_id, path = %path.display(), "Artifact downloaded");
+                    }
+                    Err(e) => {
+                        self.status_message = format!("Error: {:#}", e);
+                        error!(error = %e, artifact_id, "Failed to download artifact");
+                    }
+                }
+            }
+
+            BackgroundResult::RerunFailedComplete {
+                repo,
+                run_id,
+                run_number,
+                debug,
+                result,
+            } => match self.record_audit(
+                AuditAction::RerunFailed,
+                repo,
+                Some((run_id, run_number)),
+                debug.then(|| "debug logging".to_string()),
+                result,
+            ) {
+                Ok(()) => {
+                    self.status_message = format!(
+                        "✓ Re-run of failed jobs triggered{} for #{}",
+                        with_debug(debug),
+                        run_number
+                    );
+                    if let Some(watch) = self.watch.as_mut().filter(|w| w.run_id == run_id) {
+                        // The first poll waits a round for GitHub to queue it
+                        watch.triggering = false;
+                        watch.polled_at = Instant::now();
+                    }
+                    debug!(run_number, "Failed jobs re-run triggered");
+                }
+                Err(e) => {
+                    if self.watch.as_ref().is_some_and(|w| w.run_id == run_id) {
+                        self.watch = None;
+                    }
+                    self.status_message = format!("Error: {:#}", e);
+                    error!(error = %e, run_number, "Failed to re-run failed jobs");
+                }
+            },
+
+            BackgroundResult::WatchedRunFetched { run_id, result } => {
+                if self.watch.as_ref().is_none_or(|w| w.run_id != run_id) {
+                    return;
+                }
+                match result {
+                    Ok(run) => self.watched_run_fetched(run),
+                    Err(e) => {
+                        // Try again next round; Esc gives up
+                        if let Some(watch) = self.watch.as_mut() {
+                            watch.poll_in_flight = false;
+                        }
+                        self.status_message = format!("Error: {:#}", e);
+                        warn!(error = %e, run_id, "Failed to poll watched run");
+                    }
+                }
+            }
+
+            BackgroundResult::CancelComplete {
+                repo,
+                run_id,
+                run_number,
+                result,
+            } => match self.record_audit(
+                AuditAction::Cancel,
+                repo,
+                Some((run_id, run_number)),
+                None,
+                result,
+            ) {
+                Ok(()) => {
+                    self.status_message = format!("✓ Cancelled #{}", run_number);
+                    debug!(run_number, "Workflow cancelled");
+                }
+                Err(e) => {
+                    self.status_message = format!("Error: {:#}", e);
+                    error!(error = %e, run_number, "Failed to cancel");
+                }
+            },
+
+            BackgroundResult::BulkActionComplete {
+                action,
+                repo,
+                results,
+            } => {
+                let total = results.len();
+                let mut failed = 0;
+                for (run_id, run_number, result) in results {
+                    let result = self.record_audit(
+                        action,
+                        repo.clone(),
+                        Some((run_id, run_number)),
+                        None,
+                        result,
+                    );
+                    match result {
+                        // Failures stay selected for another try
+                        Ok(()) => {
+                            self.selected_run_ids.remove(&run_id);
+                        }
+                        Err(e) => {
+                            failed += 1;
+                            error!(error = %e, run_number, ?action, "Failed on a selected run");
+                        }
+                    }
+                }
+                let done = match action {
+                    AuditAction::Rerun => "Re-ran",
+                    _ => "Cancelled",
+                };
+                self.status_message = if failed == 0 {
+                    format!("✓ {} {}", done, total)
+                } else {
+                    format!("{} {}, failed {}", done, total - failed, failed)
+                };
+            }
+
+            BackgroundResult::PreviewJobsFetched { run_id, result } => {
+                if self.preview_loading == Some(run_id) {
+                    self.preview_loading = None;
+                }
+                match result {
+                    Ok(response) => {
+                        debug!(
+                            run_id,
+                            jobs = response.jobs.len(),
+                            "Split view jobs fetched"
+                        );
+                        self.preview_cache.insert(
+                            run_id,
+                            PreviewJobs {
+                                fetched_at: Instant::now(),
+                                jobs: response.jobs,
+                            },
+                        );
+                    }
+                    Err(e) => {
+                        self.status_message = format!("Error: {:#}", e);
+                        error!(error = %e, run_id, "Failed to fetch jobs for split view");
+                    }
+                }
+            }
+
+            BackgroundResult::WorkflowDiagnosed { run_id, result } => {
+                if self.current_run.as_ref().map(|r| r.id) != Some(run_id) {
+                    return;
+                }
+                self.loading = false;
+                match result {
+                    Ok(problem) => {
+                        self.status_message = match &problem {
+                            WorkflowProblem::Syntax { path, error, .. } => format!(
+                                "Startup failure · {} has a YAML error at line {}",
+                                path, error.line
+                            ),
+                            WorkflowProblem::Annotations(annotations) => format!(
+                                "Startup failure · {} annotations from GitHub",
+                                annotations.len()
+                            ),
+                        };
+                        self.workflow_problem = Some(problem);
+                    }
+                    Err(e) => {
+                        self.status_message = format!("Error: {:#}", e);
+                        error!(error = %e, run_id, "Failed to diagnose startup failure");
+                    }
+                }
+            }
+
+            BackgroundResult::LatestFailureFetched(result) => {
+                self.loading = false;
+                match result {
+                    Ok(Some(failure)) => {
+                        let failure = *failure;
+                        let run_number = failure.run.run_number;
+                        self.current_run = Some(failure.run);
+                        self.spawn_fetch_commit();
+                        self.jobs = failure.jobs;
+                        self.jobs_selected = failure.job_index;
+                        self.order_jobs();
+                        self.steps_cache.clear();
+                        self.steps_loading = None;
+                        self.spawn_hydrate_selected_job();
+
+                        self.log_step = None;
+                        self.set_log_content(&failure.logs);
+                        self.log_job_id = self.jobs.get(self.jobs_selected).map(|j| j.id);
+                        if let Some(&line) = error_lines(&self.log_content).first() {
+                            self.center_log_error(line);
+                        }
+                        self.view = View::Logs;
+
+                        let job_name = self
+                            .jobs
+                            .get(self.jobs_selected)
+                            .map(|j| j.name.as_str())
+                            .unwrap_or("unknown job");
+                        self.status_message = format!(
+                            "Latest failure: run #{} · {} · {} lines",
+                            run_number,
+                            job_name,
+                            self.log_content.len()
+                        );
+                        debug!(run_number, "Opened latest failure");
+                    }
+                    Ok(None) => {
+                        self.status_message = "No recent failed runs".to_string();
+                        debug!("No failed runs found");
+                    }
+                    Err(e) => {
+                        self.status_message = format!("Error: {:#}", e);
+                        error!(error = %e, "Failed to open latest failure");
+                    }
+                }
+            }
+        }
+    }
+
+    /// Put the selection back on the anchored run after a refresh. If it
+    /// left this page, either follow it to the neighbouring page or settle
+    /// on the run created closest to it.
+    fn reselect_run(&mut self, anchor: RunAnchor) {
+        if let Some(i) = self.runs.iter().position(|r| r.id == anchor.id) {
+            self.runs_selected = i;
+            return;
+        }
+
+        if self.follow_selection {
+            // Runs are newest first
+            let step = match (self.runs.first(), self.runs.last()) {
+                (Some(first), _) if anchor.created_at > first.created_at => -1,
+                (_, Some(last)) if anchor.created_at < last.created_at => 1,
+                _ => 0,
+            };
+            let target = self.page as i64 + step;
+            if step != 0
+                && (anchor.hop == 0 || anchor.hop == step)
+                && target >= 1
+                && target as u64 <= self.total_pages()
+            {
+                debug!(run_id = anchor.id, page = target, "Following selected run");
+                self.page = target as u64;
+                self.runs_anchor = Some(RunAnchor {
+                    hop: step,
+                    ..anchor
+                });
+                self.spawn_fetch_runs();
+                return;
+            }
+        }
+
+        self.runs_selected = nearest_run(&self.runs, anchor.created_at);
+    }
+
+    pub fn toggle_follow_selection(&mut self) {
+        if self.view == View::RunsList {
+            self.follow_selection = !self.follow_selection;
+            self.status_message = format!(
+                "Follow selection {}",
+                if self.follow_selection { "on" } else { "off" }
+            );
+        }
+    }
+
+    // ── Split runs view ────────────────────────────────────────────
+
+    /// Whether the runs list is shown next to the selected run's jobs
+    pub fn split_view(&self) -> bool {
+        self.view == View::RunsList
+            && self
+                .split_override
+                .unwrap_or(self.terminal_width >= SPLIT_MIN_WIDTH)
+    }
+
+    pub fn toggle_split_view(&mut self) {
+        if self.view != View::RunsList {
+            return;
+        }
+        self.split_override = Some(!self.split_view());
+        self.pane_focus = PaneFocus::Runs;
+        self.schedule_preview();
+    }
+
+    /// The terminal changed size: re-fit the split view and keep a
+    /// followed log on its last line
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.set_terminal_width(width);
+        if height != self.terminal_height {
+            self.terminal_height = height;
+            if self.log_follow {
+                self.log_scroll = self.max_log_scroll();
+            }
+        }
+    }
+
+    fn set_terminal_width(&mut self, width: u16) {
+        if width == self.terminal_width {
+            return;
+        }
+        let was_split = self.split_view();
+        self.terminal_width = width;
+        if !self.split_view() {
+            self.pane_focus = PaneFocus::Runs;
+        } else if !was_split {
+            self.schedule_preview();
+        }
+    }
+
+    /// Tab: move focus between the runs and jobs panes, or between the
+    /// jobs and annotations in run details
+    pub fn switch_pane(&mut self) {
+        if self.view == View::RunDetail {
+            let steps = self.selected_steps().is_some_and(|steps| !steps.is_empty());
+            let annotations = !self.annotations().is_empty();
+            self.detail_focus = match self.detail_focus {
+                DetailFocus::Jobs if steps => DetailFocus::Steps,
+                DetailFocus::Jobs | DetailFocus::Steps if annotations => DetailFocus::Annotations,
+                _ => DetailFocus::Jobs,
+            };
+            return;
+        }
+        if self.split_view() {
+            self.pane_focus = match self.pane_focus {
+                PaneFocus::Runs => PaneFocus::Jobs,
+                PaneFocus::Jobs => PaneFocus::Runs,
+            };
+        }
+    }
+
+    // ── Mouse ──────────────────────────────────────────────────────
+
+    /// A popup or prompt is up; the mouse leaves it alone
+    pub fn popup_open(&self) -> bool {
+        self.show_help
+            || self.workflow_picker
+            || self.confirm_quit
+            || self.confirmation_pending.is_some()
+            || self.connecting.is_some()
+            || self.dispatch_form.is_some()
+            || self.export_prompt.is_some()
+            || self.exports_overlay.is_some()
+            || self.branch_input.is_some()
+    }
+
+    /// Left click: select the table row under the pointer
+    pub fn click(&mut self, column: u16, row: u16) {
+        let Some((area, index)) = self.row_at(column, row) else {
+            return;
+        };
+        self.focus_pane_of(area);
+        self.select(index);
+    }
+
+    /// Mouse wheel: scroll like `j` / `k`, in the split pane under the
+    /// pointer
+    pub fn scroll_at(&mut self, column: u16, row: u16, down: bool) {
+        let hovered = self
+            .last_rendered_areas
+            .borrow()
+            .iter()
+            .find(|(_, rect)| rect.contains(Position::new(column, row)))
+            .map(|(area, _)| *area);
+        if let Some(area) = hovered {
+            self.focus_pane_of(area);
+        }
+        if down {
+            self.move_down(None);
+        } else {
+            self.move_up(None);
+        }
+    }
+
+    fn focus_pane_of(&mut self, area: ViewArea) {
+        match area {
+            ViewArea::Runs => self.pane_focus = PaneFocus::Runs,
+            ViewArea::PreviewJobs => self.pane_focus = PaneFocus::Jobs,
+            ViewArea::Jobs => self.detail_focus = DetailFocus::Jobs,
+            ViewArea::Steps => self.detail_focus = DetailFocus::Steps,
+            ViewArea::Annotations => self.detail_focus = DetailFocus::Annotations,
+            _ => {}
+        }
+    }
+
+    /// The table and row index drawn at `column`, `row`
+    fn row_at(&self, column: u16, row: u16) -> Option<(ViewArea, usize)> {
+        let areas = self.last_rendered_areas.borrow();
+        let (&area, rect) = areas
+            .iter()
+            .find(|(_, rect)| rect.contains(Position::new(column, row)))?;
+        let visible = rect.height as usize;
+        // Repos, runs and steps scroll from where they were; the other tables
+        // scroll just far enough to show the selection
+        let (offset, len) = match area {
+            ViewArea::Repos => (self.repos_offset.get(), self.filtered_repos().len()),
+            ViewArea::Runs => (self.runs_offset.get(), self.runs.len()),
+            ViewArea::Steps => (
+                self.steps_offset.get(),
+                self.selected_steps().map_or(0, <[Step]>::len),
+            ),
+            ViewArea::Workflows => {
+                let len = self.workflows.len() + 1;
+                (keep_in_view(self.workflows_selected, 0, visible, len), len)
+            }
+            ViewArea::Jobs => {
+                let len = self.jobs.len();
+                (keep_in_view(self.jobs_selected, 0, visible, len), len)
+            }
+            ViewArea::PreviewJobs => {
+                let len = self.preview_jobs().map_or(0, |jobs| jobs.len());
+                (keep_in_view(self.preview_selected, 0, visible, len), len)
+            }
+            ViewArea::Annotations => {
+                let len = self.annotations().len();
+                (
+                    keep_in_view(self.annotations_selected, 0, visible, len),
+                    len,
+                )
+            }
+            ViewArea::Artifacts => {
+                let len = self.artifacts.len();
+                (keep_in_view(self.artifacts_selected, 0, visible, len), len)
+            }
+            ViewArea::Environments => {
+                let len = self.environments.len();
+                (
+                    keep_in_view(self.environments_selected, 0, visible, len),
+                    len,
+                )
+            }
+            ViewArea::Runners => {
+                let len = self.runners.len();
+                (keep_in_view(self.runners_selected, 0, visible, len), len)
+            }
+            ViewArea::Caches => {
+                let len = self.actions_caches.len();
+                (
+                    keep_in_view(self.actions_caches_selected, 0, visible, len),
+                    len,
+                )
+            }
+        };
+        let index = offset + (row - rect.y) as usize;
+        (index < len).then_some((area, index))
+    }
+
+    /// Cached jobs of the selected run, if any
+    pub fn preview_jobs(&self) -> Option<&[Job]> {
+        let run = self.runs.get(self.runs_selected)?;
+        self.preview_cache.get(&run.id).map(|p| p.jobs.as_slice())
+    }
+
+    /// Queue a jobs fetch for the selected run; `on_tick` sends it once
+    /// the selection has settled
+    fn schedule_preview(&mut self) {
+        self.preview_selected = 0;
+        self.preview_due = match self.runs.get(self.runs_selected) {
+            Some(run) if self.split_view() => Some((run.id, Instant::now())),
+            _ => None,
+        };
+    }
+
+    /// Periodic housekeeping from the event loop tick
+    pub fn on_tick(&mut self) {
+        if self.loading {
+            self.spinner_frame = self.spinner_frame.wrapping_add(1);
+        }
+        let notices = self.client.take_api_notices();
+        if !notices.is_empty() {
+            self.status_message = format!("⚠ {}", notices.join(" · "));
+        }
+        if self.next_auto_refresh() == Some(Duration::ZERO) && !self.loading {
+            self.refresh();
+        }
+        self.notify_stuck_runs();
+        self.poll_watch();
+        if self
+            .toast
+            .as_ref()
+            .is_some_and(|t| t.shown_at.elapsed() >= TOAST_TTL)
+        {
+            self.toast = None;
+        }
+        if self.log_follow
+            && self.view == View::Logs
+            && self.log_stream.is_none()
+            && !self.log_poll_in_flight
+            && self.log_polled_at.elapsed() >= LOG_FOLLOW_INTERVAL
+        {
+            self.spawn_poll_logs();
+        }
+
+        let Some((run_id, since)) = self.preview_due else {
+            return;
+        };
+        if since.elapsed() < PREVIEW_DEBOUNCE {
+            return;
+        }
+        self.preview_due = None;
+
+        let Some(run) = self.runs.iter().find(|r| r.id == run_id) else {
+            return;
+        };
+        let finished = run.status.as_deref() == Some("completed");
+        let fresh = self
+            .preview_cache
+            .get(&run_id)
+            .is_some_and(|p| finished || p.fetched_at.elapsed() < PREVIEW_TTL);
+        if !fresh && self.preview_loading != Some(run_id) {
+            self.spawn_fetch_preview_jobs(run_id);
+        }
+    }
+
+    fn total_pages(&self) -> u64 {
+        self.runs_total.div_ceil(self.per_page as u64)
+    }
+
+    /// Pages of the repo list, once counted and if there's more than one
+    pub fn repos_pages(&self) -> Option<u64> {
+        let pages = self.repos_total.div_ceil(self.repos_per_page as u64);
+        (pages > 1).then_some(pages)
+    }
+
+    fn set_log_content(&mut self, logs: &str) {
+        self.log_content = logs.lines().map(|l| l.to_string()).collect();
+        self.log_highlights = self.find_log_highlights();
+        self.fold_log_groups();
+        self.log_scroll = 0;
+        self.log_error_target = None;
+        self.log_expired = None;
+    }
+
+    /// Sections start folded, except the one holding the first error and
+    /// the step the log was opened at
+    fn fold_log_groups(&mut self) {
+        self.log_groups = groups::parse(&self.log_content);
+        if let Some(&line) = error_lines(&self.log_content).first() {
+            groups::expand_to(&mut self.log_groups, line);
+        }
+        if let Some(step) = &mut self.log_step {
+            step.lines = None;
+        }
+        self.slice_log_step();
+    }
+
+    /// Open a failed job's log at its first error
+    fn center_first_error_if_failed(&mut self, job_id: u64) {
+        let failed = self
+            .jobs
+            .iter()
+            .any(|j| j.id == job_id && j.conclusion.as_deref() == Some("failure"));
+        if failed {
+            if let Some(&line) = self.log_error_lines().first() {
+                self.center_log_error(line);
+            }
+        }
+    }
+
+    /// Append lines to the loaded log; sections they open start unfolded
+    fn push_log_lines<S: AsRef<str>>(&mut self, lines: impl IntoIterator<Item = S>) {
+        for line in lines {
+            let line = line.as_ref();
+            if !self.log_highlighter.is_empty() {
+                self.log_highlights.push(self.log_highlighter.matches(line));
+            }
+            self.log_content.push(line.to_string());
+        }
+        self.log_groups = groups::reparse(&self.log_content, &self.log_groups);
+        self.slice_log_step();
+    }
+
+    /// The loaded log as text, as saved by `s`
+    pub fn log_text(&self) -> String {
+        self.log_content.join("\n")
+    }
+
+    /// Take a fresh copy of the loaded log, appending only the lines that
+    /// are new; returns how many were added
+    fn append_log_delta(&mut self, logs: &str) -> usize {
+        let lines: Vec<&str> = logs.lines().collect();
+        let old_len = self.log_content.len();
+        // The last line may have been cut off mid-write; the rest must match
+        let keep = old_len.saturating_sub(1);
+        let is_extension = lines.len() >= keep
+            && self.log_content[..keep]
+                .iter()
+                .zip(&lines)
+                .all(|(old, new)| old == new);
+        if !is_extension {
+            let scroll = self.log_scroll;
+            self.set_log_content(logs);
+            self.log_scroll = scroll.min(self.max_log_scroll());
+            return self.log_content.len();
+        }
+
+        self.log_content.truncate(keep);
+        self.log_highlights.truncate(keep);
+        self.push_log_lines(&lines[keep..]);
+        self.log_content.len().saturating_sub(old_len)
+    }
+
+    fn max_log_scroll(&self) -> usize {
+        self.visible_log_lines().len().saturating_sub(10)
+    }
+
+    fn find_log_highlights(&self) -> Vec<LogHighlight> {
+        if self.log_highlighter.is_empty() {
+            return Vec::new();
+        }
+        self.log_content
+            .iter()
+            .map(|line| self.log_highlighter.matches(line))
+            .collect()
+    }
+
+    // ── Navigation ─────────────────────────────────────────────────
+
+    /// Up a row (three log lines), or `count` of them
+    pub fn move_up(&mut self, count: Option<usize>) {
+        if self.view == View::Logs {
+            self.log_scroll = self.log_scroll.saturating_sub(count.unwrap_or(3));
+            self.pause_log_follow();
+            return;
+        }
+        let (current, _) = self.selection();
+        self.select(current.saturating_sub(count.unwrap_or(1)));
+    }
+
+    /// Scrolling up leaves the bottom of a followed log
+    fn pause_log_follow(&mut self) {
+        if self.log_follow {
+            self.log_follow = false;
+            self.status_message = "Paused following — F to resume".to_string();
+        }
+    }
+
+    /// `gg` / `G`: the first or last item, or the top or bottom of the log
+    pub fn jump(&mut self, to_end: bool) {
+        if self.view == View::Logs {
+            if to_end {
+                self.log_scroll = self.max_log_scroll();
+            } else {
+                self.log_scroll = 0;
+                self.pause_log_follow();
+            }
+            return;
+        }
+        let (_, len) = self.selection();
+        self.select(if to_end { len.saturating_sub(1) } else { 0 });
+    }
+
+    /// PageUp / PageDown: a screenful at a time
+    pub fn page(&mut self, down: bool) {
+        let step = self.log_viewport_height().max(1);
+        if down {
+            self.move_down(Some(step));
+        } else {
+            self.move_up(Some(step));
+        }
+    }
+
+    /// Ctrl+D / Ctrl+U: half a screenful
+    pub fn half_page(&mut self, down: bool) {
+        let step = (self.log_viewport_height() / 2).max(1);
+        if down {
+            self.move_down(Some(step));
+        } else {
+            self.move_up(Some(step));
+        }
+    }
+
+    /// Selected index and item count of the list in view
+    fn selection(&self) -> (usize, usize) {
+        match self.view {
+            View::RepoList => (self.repos_selected, self.filtered_repos().len()),
+            // One extra row for "All workflows"
+            View::WorkflowList => (self.workflows_selected, self.workflows.len() + 1),
+            View::RunsList if self.pane_focus == PaneFocus::Jobs => (
+                self.preview_selected,
+                self.preview_jobs().map_or(0, |jobs| jobs.len()),
+            ),
+            View::RunsList => (self.runs_selected, self.runs.len()),
+            View::RunDetail if self.detail_focus == DetailFocus::Steps => (
+                self.steps_selected,
+                self.selected_steps().map_or(0, <[Step]>::len),
+            ),
+            View::RunDetail if self.detail_focus == DetailFocus::Annotations => {
+                (self.annotations_selected, self.annotations().len())
+            }
+            View::RunDetail | View::RunTimeline => (self.jobs_selected, self.jobs.len()),
+            View::Artifacts => (self.artifacts_selected, self.artifacts.len()),
+            View::Environments => (self.environments_selected, self.environments.len()),
+            View::Runners => (self.runners_selected, self.runners.len()),
+            View::Caches => (self.actions_caches_selected, self.actions_caches.len()),
+            View::Logs | View::Statistics => (0, 0),
+        }
+    }
+
+    /// Move the list selection to `index`, doing what moving one row would
+    fn select(&mut self, index: usize) {
+        let (current, len) = self.selection();
+        if len == 0 || index == current {
+            return;
+        }
+        match self.view {
+            View::RepoList => self.repos_selected = index,
+            View::WorkflowList => self.workflows_selected = index,
+            View::RunsList if self.pane_focus == PaneFocus::Jobs => self.preview_selected = index,
+            View::RunsList => {
+                self.runs_selected = index;
+                self.schedule_preview();
+            }
+            View::RunDetail if self.detail_focus == DetailFocus::Steps => {
+                self.steps_selected = index
+            }
+            View::RunDetail if self.detail_focus == DetailFocus::Annotations => {
+                self.annotations_selected = index
+            }
+            View::RunDetail | View::RunTimeline => {
+                self.jobs_selected = index;
+                self.steps_selected = 0;
+                self.steps_offset.set(0);
+                self.spawn_hydrate_selected_job();
+            }
+            View::Artifacts => self.artifacts_selected = index,
+            View::Environments => self.environments_selected = index,
+            View::Runners => self.runners_selected = index,
+            View::Caches => self.actions_caches_selected = index,
+            View::Logs | View::Statistics => {}
+        }
+    }
+
+    /// Down a row (three log lines), or `count` of them
+    pub fn move_down(&mut self, count: Option<usize>) {
+        if self.view == View::Logs {
+            self.log_scroll = (self.log_scroll + count.unwrap_or(3)).min(self.max_log_scroll());
+            return;
+        }
+        let (current, len) = self.selection();
+        self.select(
+            current
+                .saturating_add(count.unwrap_or(1))
+                .min(len.saturating_sub(1)),
+        );
+    }
+
+    pub fn enter(&mut self) {
+        match self.view {
+            View::RepoList => {
+                let filtered = self.filtered_repos();
+                if let Some(repo) = filtered.get(self.repos_selected).cloned() {
+                    let owner = repo.owner.login.clone();
+                    let repo_name = repo.name.clone();
+                    self.client.set_repo(owner, repo_name);
+                    self.view = View::WorkflowList;
+                    self.workflows.clear();
+                    self.workflows_selected = 0;
+                    self.workflow_filter = None;
+                    self.branch_filter = None;
+                    self.status_filter = None;
+                    self.event_filter = None;
+                    self.preview_cache.clear();
+                    self.repo_filter.clear();
+                    self.searching = false;
+                    self.remember_repo();
+                    self.spawn_fetch_workflows();
+                    self.spawn_check_token_scope();
+                }
+            }
+            View::WorkflowList => {
+                let workflow = self.selected_workflow().cloned();
+                self.filter_runs_by(workflow);
+            }
+            View::RunsList => {
+                if let Some(run) = self.runs.get(self.runs_selected).cloned() {
+                    // Show the split view's jobs right away, keeping the job
+                    // selected in the jobs pane
+                    let preview = self.preview_jobs().filter(|_| self.split_view());
+                    if let Some(jobs) = preview.map(<[Job]>::to_vec) {
+                        self.jobs_selected = match self.pane_focus {
+                            PaneFocus::Jobs => {
+                                self.preview_selected.min(jobs.len().saturating_sub(1))
+                            }
+                            PaneFocus::Runs => 0,
+                        };
+                        self.jobs = jobs;
+                        self.order_jobs();
+                    }
+                    self.current_run = Some(run);
+                    self.workflow_problem = None;
+                    self.detail_focus = DetailFocus::Jobs;
+                    self.steps_selected = 0;
+                    self.steps_offset.set(0);
+                    self.view = View::RunDetail;
+                    self.spawn_fetch_jobs();
+                    self.spawn_fetch_commit();
+                }
+            }
+            View::RunDetail if self.detail_focus == DetailFocus::Annotations => {
+                self.copy_annotation_location()
+            }
+            View::RunDetail => {
+                self.log_step = self
+                    .selected_steps()
+                    .and_then(|steps| steps.get(self.steps_selected))
+                    .filter(|_| self.detail_focus == DetailFocus::Steps)
+                    .map(|step| StepSlice {
+                        index: self.steps_selected,
+                        name: step.name.clone(),
+                        lines: None,
+                        only: true,
+                    });
+                self.view = View::Logs;
+                self.spawn_fetch_logs();
+                // A log already loaded can be sliced now
+                self.slice_log_step();
+            }
+            View::Artifacts => self.spawn_download_artifact(),
+            View::Environments
+            | View::Runners
+            | View::Caches
+            | View::Statistics
+            | View::RunTimeline => {}
+            View::Logs => self.toggle_log_group(),
+        }
+    }
+
+    /// `q`: quit, unless that would lose a download in progress
+    pub fn request_quit(&mut self) {
+        if self.downloads.is_empty() {
+            self.should_quit = true;
+            return;
+        }
+        match self.quit_behavior {
+            QuitBehavior::Confirm => {
+                self.confirm_quit = true;
+                self.status_message = "Download in progress — quit anyway? (y/n)".to_string();
+            }
+            // main finishes the downloads once the terminal is restored
+            QuitBehavior::Finish => self.should_quit = true,
+        }
+    }
+
+    pub fn answer_quit(&mut self, quit: bool) {
+        self.confirm_quit = false;
+        if quit {
+            self.should_quit = true;
+        } else {
+            self.status_message = "Quit cancelled".to_string();
+        }
+    }
+
+    // ── Help ───────────────────────────────────────────────────────
+
+    /// `?`: open or close the keybindings popup
+    pub fn toggle_help(&mut self) {
+        self.show_help = !self.show_help;
+        self.help_scroll = 0;
+    }
+
+    /// Scroll the help by `delta` lines, as far as it overflows the screen
+    pub fn scroll_help(&mut self, delta: isize) {
+        let visible = self.terminal_height.saturating_sub(2) as usize;
+        let max = help_len().saturating_sub(visible);
+        self.help_scroll = self.help_scroll.saturating_add_signed(delta).min(max);
+    }
+
+    /// Stop tracking a finished download
+    pub fn finish_download(&mut self, id: u64) {
+        self.downloads.retain(|d| d.id != id);
+    }
+
+    pub fn back(&mut self) {
+        match self.view {
+            View::RepoList => {
+                self.request_quit();
+            }
+            View::WorkflowList => {
+                // Go back to repo list (or quit if in single-repo mode)
+                if self.repos.is_empty() {
+                    self.request_quit();
+                } else {
+                    self.view = View::RepoList;
+                    self.workflows.clear();
+                    self.update_repo_status();
+                }
+            }
+            View::RunsList => {
+                if !self.selected_run_ids.is_empty() {
+                    self.selected_run_ids.clear();
+                    self.status_message = "Selection cleared".to_string();
+                    return;
+                }
+                if self.branch_filter.is_some() {
+                    // Esc drops the branch filter before it leaves the list
+                    self.branch_filter = None;
+                    self.page = 1;
+                    self.runs_selected = 0;
+                    self.runs_anchor = None;
+                    self.spawn_fetch_runs();
+                    return;
+                }
+                // Single-repo mode starts here, so there's nothing to go
+                // back to unless `w` loaded the workflows
+                if self.repos.is_empty() && self.workflows.is_empty() {
+                    self.request_quit();
+                } else {
+                    self.view = View::WorkflowList;
+                    self.runs.clear();
+                    self.runs_selected = 0;
+                    self.pane_focus = PaneFocus::Runs;
+                    self.preview_due = None;
+                    self.select_filtered_workflow();
+                    if self.workflows.is_empty() {
+                        self.spawn_fetch_workflows();
+                    }
+                }
+            }
+            View::RunDetail => {
+                self.view = View::RunsList;
+                self.detail_focus = DetailFocus::Jobs;
+                self.current_run = None;
+                self.jobs.clear();
+                self.jobs_selected = 0;
+                self.steps_cache.clear();
+                self.steps_loading = None;
+                self.workflow_problem = None;
+                self.schedule_preview();
+            }
+            View::Logs => {
+                if !self.log_search.is_empty() {
+                    // Esc clears a search before it leaves the log
+                    self.clear_log_search();
+                    return;
+                }
+                self.cancel_log_stream();
+                self.view = View::RunDetail;
+                self.log_content.clear();
+                self.log_highlights.clear();
+                self.log_groups.clear();
+                self.log_expired = None;
+                self.log_scroll = 0;
+                self.log_job_id = None;
+                self.log_follow = false;
+            }
+            View::Artifacts => {
+                self.view = View::RunDetail;
+                self.artifacts.clear();
+            }
+            View::Environments => {
+                self.view = View::RunDetail;
+                self.environments.clear();
+                self.deployments.clear();
+            }
+            View::Runners => {
+                self.view = View::RunDetail;
+                self.runners.clear();
+            }
+            View::Caches => {
+                self.view = View::RunsList;
+                self.actions_caches.clear();
+            }
+            View::Statistics => self.view = View::RunsList,
+            View::RunTimeline => self.view = View::RunDetail,
+        }
+    }
+
+    pub fn next_page(&mut self) {
+        if self.view == View::RepoList && !self.repo_choices {
+            // Uncounted, a full page hints at another one
+            let more = match self.repos_pages() {
+                Some(pages) => self.repos_page < pages,
+                None => self.repos_total == 0 && self.repos.len() == self.repos_per_page as usize,
+            };
+            if more {
+                self.repos_page += 1;
+                self.repos_selected = 0;
+                self.repos_offset.set(0);
+                self.spawn_fetch_repos();
+            }
+            return;
+        }
+        if self.view == View::RunsList && self.page < self.total_pages() {
+            self.page += 1;
+            self.runs_selected = 0;
+            self.runs_anchor = None;
+            self.spawn_fetch_runs();
+        }
+    }
+
+    pub fn prev_page(&mut self) {
+        if self.view == View::RepoList && !self.repo_choices && self.repos_page > 1 {
+            self.repos_page -= 1;
+            self.repos_selected = 0;
+            self.repos_offset.set(0);
+            self.spawn_fetch_repos();
+            return;
+        }
+        if self.view == View::RunsList && self.page > 1 {
+            self.page -= 1;
+            self.runs_selected = 0;
+            self.runs_anchor = None;
+            self.spawn_fetch_runs();
+        }
+    }
+
+    /// Time left before `--refresh` reloads the current view, if it applies
+    /// here
+    pub fn next_auto_refresh(&self) -> Option<Duration> {
+        let interval = self.refresh_interval?;
+        matches!(
+            self.view,
+            View::RunsList | View::RunDetail | View::RunTimeline
+        )
+        .then(|| interval.saturating_sub(self.last_refresh.elapsed()))
+    }
+
+    pub fn refresh(&mut self) {
+        self.last_refresh = Instant::now();
+        self.invalidate_cache();
+        match self.view {
+            View::RepoList => self.spawn_fetch_repos(),
+            View::WorkflowList => self.spawn_fetch_workflows(),
+            View::RunsList => {
+                self.runs_anchor = self.runs.get(self.runs_selected).map(RunAnchor::from);
+                self.spawn_fetch_runs();
+            }
+            View::RunDetail | View::RunTimeline => self.spawn_fetch_jobs(),
+            View::Logs => self.spawn_fetch_logs(),
+            View::Artifacts => self.spawn_fetch_artifacts(),
+            View::Environments => self.spawn_fetch_environments(),
+            View::Runners => self.spawn_fetch_runners(),
+            View::Caches => self.spawn_fetch_caches(),
+            // Computed from the runs, so reload those
+            View::Statistics => self.spawn_fetch_runs(),
+        }
+    }
+
+    /// Drop cached API responses, so what's fetched next is current
+    pub fn invalidate_cache(&self) {
+        self.client.invalidate_cache();
+    }
+
+    pub fn open_in_browser(&self) {
+        let url = match self.view {
+            View::RepoList => {
+                let filtered = self.filtered_repos();
+                filtered
+                    .get(self.repos_selected)
+                    .map(|r| r.html_url.clone())
+            }
+            View::WorkflowList => self.selected_workflow().map(|w| w.html_url.clone()),
+            View::RunsList => self
+                .runs
+                .get(self.runs_selected)
+                .map(|r| r.html_url.clone()),
+            View::Artifacts => self.current_run.as_ref().map(|r| r.html_url.clone()),
+            View::Environments => self
+                .environments
+                .get(self.environments_selected)
+                .map(|e| e.html_url.clone()),
+            // The repo's runner settings
+            View::Runners => self.current_run.as_ref().and_then(|r| {
+                let (repo, _) = r.html_url.split_once("/actions/runs/")?;
+                Some(format!("{}/settings/actions/runners", repo))
+            }),
+            // The repo's caches page, found from any of its runs
+            View::Caches => self.runs.first().and_then(|r| {
+                let (repo, _) = r.html_url.split_once("/actions/runs/")?;
+                Some(format!("{}/actions/caches", repo))
+            }),
+            View::Statistics => self.runs.first().and_then(|r| {
+                let (actions, _) = r.html_url.split_once("/runs/")?;
+                Some(actions.to_string())
+            }),
+            View::RunDetail | View::RunTimeline | View::Logs => {
+                if let Some(job) = self.jobs.get(self.jobs_selected) {
+                    job.html_url.clone()
+                } else {
+                    self.current_run.as_ref().map(|r| r.html_url.clone())
+                }
+            }
+        };
+
+        if let Some(url) = url {
+            let _ = open::that(&url);
+        }
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::GitHubClient;
+
+    fn test_app() -> (App, mpsc::UnboundedReceiver<BackgroundResult>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let client = GitHubClient::new("owner".into(), "repo".into(), "token".into());
+        (App::new(client, tx, &Config::default()), rx)
+    }
+
+    fn make_job(id: u64, status: &str) -> Job {
+        Job {
+            id,
+            run_id: 1,
+            name: format!("job-{}", id),
+            status: Some(status.to_string()),
+            conclusion: None,
+            started_at: None,
+            completed_at: None,
+            steps: None,
+            html_url: None,
+            labels: Vec::new(),
+        }
+    }
+
+    /// A run created `age` minutes before a fixed point, newest first
+    fn make_run(id: u64, age: i64) -> WorkflowRun {
+        let created_at = chrono::TimeZone::with_ymd_and_hms(&Utc, 2024, 1, 1, 12, 0, 0).unwrap()
+            - chrono::Duration::minutes(age);
+        WorkflowRun {
+            id,
+            name: Some("CI".into()),
+            display_title: None,
+            head_branch: Some("main".into()),
+            head_sha: "abc123".into(),
+            status: Some("completed".into()),
+            conclusion: Some("success".into()),
+            run_number: id,
+            event: "push".into(),
+            created_at,
+            updated_at: created_at,
+            run_started_at: None,
+            html_url: String::new(),
+            actor: None,
+            run_attempt: None,
+            workflow_id: None,
+            path: None,
+            check_suite_id: None,
+        }
+    }
+
+    /// Page `page` (of size 3) of a repo whose newest run has id `newest`
+    fn runs_page(newest: u64, page: u64) -> BackgroundResult {
+        let start = newest - (page - 1) * 3;
+        let runs = (0..3)
+            .map(|i| start - i)
+            .map(|id| make_run(id, (100 - id) as i64))
+            .collect();
+        BackgroundResult::RunsFetched(Ok(WorkflowRunsResponse {
+            total_count: newest,
+            workflow_runs: runs,
+        }))
+    }
+
+    fn test_browser_app() -> (App, mpsc::UnboundedReceiver<BackgroundResult>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let client = GitHubClient::new_with_token("token".into());
+        (App::new_browser(client, tx, &Config::default()), rx)
+    }
+
+    #[tokio::test]
+    async fn test_cancel_connecting_aborts_first_fetch() {
+        let (mut app, mut rx) = test_app();
+        app.begin_connecting();
+        assert!(app.connecting.is_some());
+        assert!(app.loading);
+
+        app.cancel_connecting();
+        assert!(app.connecting.is_none());
+        assert!(!app.loading);
+        assert!(app.status_message.contains("r to retry"));
+        // The aborted fetch never reports back
+        tokio::task::yield_now().await;
+        assert!(rx.try_recv().is_err());
+
+        // The first list landing ends startup
+        app.begin_connecting();
+        app.handle_background(runs_page(3, 1));
+        assert!(app.connecting.is_none());
+        assert_eq!(app.runs.len(), 3);
+    }
+
+    #[test]
+    fn test_initial_state() {
+        let (app, _rx) = test_app();
+        assert_eq!(app.view, View::RunsList);
+        assert!(!app.should_quit);
+        assert_eq!(app.page, 1);
+        assert_eq!(app.runs_selected, 0);
+    }
+
+    #[test]
+    fn test_browser_initial_state() {
+        let (app, _rx) = test_browser_app();
+        assert_eq!(app.view, View::RepoList);
+        assert!(!app.should_quit);
+    }
+
+    #[test]
+    fn test_keep_in_view() {
+        // Already on screen: no scrolling
+        assert_eq!(keep_in_view(3, 0, 5, 20), 0);
+        assert_eq!(keep_in_view(6, 4, 5, 20), 4);
+        // Past the bottom: the selection becomes the last visible row
+        assert_eq!(keep_in_view(5, 0, 5, 20), 1);
+        assert_eq!(keep_in_view(19, 0, 5, 20), 15);
+        // Above the top: it becomes the first
+        assert_eq!(keep_in_view(2, 10, 5, 20), 2);
+        // The list shrank under the offset
+        assert_eq!(keep_in_view(1, 15, 5, 3), 0);
+        // A 10-row terminal leaves a single table row
+        assert_eq!(keep_in_view(7, 0, 1, 20), 7);
+        assert_eq!(keep_in_view(0, 0, 0, 0), 0);
+    }
+
+    #[test]
+    fn test_move_up_at_zero_stays() {
+        let (mut app, _rx) = test_app();
+        app.runs_selected = 0;
+        app.move_up(None);
+        assert_eq!(app.runs_selected, 0);
+    }
+
+    #[test]
+    fn test_move_down_empty_list() {
+        let (mut app, _rx) = test_app();
+        app.move_down(None);
+        assert_eq!(app.runs_selected, 0);
+    }
+
+    #[test]
+    fn test_jump_and_page_stay_in_bounds() {
+        let (mut app, _rx) = test_app();
+        for view in [View::RunsList, View::RunDetail, View::Artifacts, View::Logs] {
+            app.view = view;
+            app.jump(true);
+            app.page(true);
+            app.page(false);
+            app.jump(false);
+        }
+        assert_eq!(app.runs_selected, 0);
+        assert_eq!(app.jobs_selected, 0);
+        assert_eq!(app.artifacts_selected, 0);
+        assert_eq!(app.log_scroll, 0);
+
+        app.view = View::RunsList;
+        app.terminal_height = 19;
+        app.runs = (1..=25).map(|id| make_run(id, 1)).collect();
+        app.jump(true);
+        assert_eq!(app.runs_selected, 24);
+        app.page(false);
+        assert_eq!(app.runs_selected, 14);
+        app.page(true);
+        app.page(true);
+        assert_eq!(app.runs_selected, 24);
+        app.jump(false);
+        assert_eq!(app.runs_selected, 0);
+        app.half_page(true);
+        assert_eq!(app.runs_selected, 5);
+        app.move_down(Some(4));
+        assert_eq!(app.runs_selected, 9);
+        app.half_page(false);
+        app.move_up(Some(100));
+        assert_eq!(app.runs_selected, 0);
+
+        app.view = View::Logs;
+        app.set_log_content(&numbered_log(100));
+        app.page(true);
+        assert_eq!(app.log_scroll, 10);
+        app.half_page(true);
+        assert_eq!(app.log_scroll, 15);
+        app.move_up(None);
+        assert_eq!(app.log_scroll, 12);
+        app.jump(true);
+        assert_eq!(app.log_scroll, app.max_log_scroll());
+        app.page(true);
+        assert_eq!(app.log_scroll, app.max_log_scroll());
+        app.jump(false);
+        assert_eq!(app.log_scroll, 0);
+    }
+
+    #[test]
+    fn test_back_from_runs_single_repo_quits() {
+        let (mut app, _rx) = test_app();
+        app.view = View::RunsList;
+        app.back();
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_back_from_detail_goes_to_list() {
+        let (mut app, _rx) = test_app();
+        app.view = View::RunDetail;
+        app.back();
+        assert_eq!(app.view, View::RunsList);
+        assert!(app.current_run.is_none());
+    }
+
+    #[test]
+    fn test_back_from_logs_goes_to_detail() {
+        let (mut app, _rx) = test_app();
+        app.view = View::Logs;
+        app.log_content = vec!["line1".into()];
+        app.log_scroll = 5;
+        app.back();
+        assert_eq!(app.view, View::RunDetail);
+        assert!(app.log_content.is_empty());
+        assert_eq!(app.log_scroll, 0);
+    }
+
+    #[test]
+    fn test_log_scroll_large_values() {
+        let (mut app, _rx) = test_app();
+        app.view = View::Logs;
+        app.log_content = (0..100_000).map(|i| format!("line {}", i)).collect();
+        app.log_scroll = 99_980;
+        app.move_down(None);
+        assert!(app.log_scroll <= app.log_content.len());
+    }
+
+    #[test]
+    fn test_log_scroll_saturating_sub() {
+        let (mut app, _rx) = test_app();
+        app.view = View::Logs;
+        app.log_content = vec!["a".into(); 20];
+        app.log_scroll = 1;
+        app.move_up(None);
+        assert_eq!(app.log_scroll, 0);
+    }
+
+    #[test]
+    fn test_log_search_cycles_matches_and_esc_clears() {
+        let (mut app, _rx) = test_app();
+        app.view = View::Logs;
+        app.log_content = (0..100)
+            .map(|i| {
+                if i % 30 == 5 {
+                    format!("{} ERROR: boom", i)
+                } else {
+                    format!("{} ok", i)
+                }
+            })
+            .collect();
+
+        app.start_search();
+        assert!(app.searching);
+        for c in "error".chars() {
+            app.search_push(c);
+        }
+        assert_eq!(app.log_matches, vec![5, 35, 65, 95]);
+        assert_eq!(app.log_scroll, 2);
+        assert_eq!(app.status_message, "match 1/4");
+
+        app.stop_search();
+        app.next_log_match(true);
+        assert_eq!(app.log_scroll, 32);
+        assert_eq!(app.status_message, "match 2/4");
+        app.next_log_match(false);
+        app.next_log_match(false);
+        // Wraps to the last match, clamped to the scroll limit
+        assert_eq!(app.log_match_cursor, 3);
+        assert_eq!(app.log_scroll, 90);
+
+        app.search_push('x');
+        assert!(app.log_matches.is_empty());
+        assert_eq!(app.status_message, "No matches for \"errorx\"");
+
+        // Esc clears the search before leaving the log
+        app.back();
+        assert_eq!(app.view, View::Logs);
+        assert!(app.log_search.is_empty());
+        assert!(app.log_matches.is_empty());
+        app.back();
+        assert_eq!(app.view, View::RunDetail);
+    }
+
+    #[test]
+    fn test_next_error_centers_and_cycles() {
+        let (mut app, _rx) = test_app();
+        app.view = View::Logs;
+        app.terminal_height = 29; // 20 log rows
+        app.log_content = (0..100).map(|i| format!("line {}", i)).collect();
+        app.log_content[40] = "##[error]first".into();
+        app.log_content[70] = "##[error]second".into();
+        app.log_content[75] = "Error: not an annotation".into();
+
+        app.next_log_error();
+        assert_eq!(app.log_error_target, Some(40));
+        assert_eq!(app.log_scroll, 30);
+        assert_eq!(app.status_message, "error 1/2");
+        app.next_log_error();
+        assert_eq!(app.log_error_target, Some(70));
+        app.next_log_error();
+        assert_eq!(app.log_error_target, Some(40));
+
+        // No annotations: fall back to "Error" / "FAILED"
+        app.log_content = vec!["ok".into(), "test foo ... FAILED".into()];
+        assert_eq!(error_lines(&app.log_content), vec![1]);
+        app.log_content = vec!["all good".into()];
+        assert!(error_lines(&app.log_content).is_empty());
+    }
+
+    #[test]
+    fn test_search_hits_ignore_case() {
+        assert_eq!(
+            search_hits("Error: boom ERROR", "error"),
+            vec![0..5, 12..17]
+        );
+        assert_eq!(search_hits("✓ done", "DONE"), vec![4..8]);
+        assert!(search_hits("all good", "error").is_empty());
+        assert!(search_hits("anything", "").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failed_job_logs_open_at_first_error() {
+        let (mut app, _rx) = test_app();
+        let mut failed = make_job(3, "completed");
+        failed.conclusion = Some("failure".into());
+        app.jobs = vec![failed];
+        app.view = View::Logs;
+        app.terminal_height = 29;
+        let mut logs: Vec<String> = (0..60).map(|i| format!("line {}", i)).collect();
+        logs[35] = "##[error]boom".into();
+        app.handle_background(BackgroundResult::LogsFetched {
+            job_id: 3,
+            job_name: "job-3".into(),
+            result: Ok(logs.join("\n")),
+        });
+        assert_eq!(app.log_error_target, Some(35));
+        assert_eq!(app.log_scroll, 25);
+    }
+
+    #[test]
+    fn test_log_groups_fold_except_first_error() {
+        let (mut app, _rx) = test_app();
+        app.view = View::Logs;
+        app.set_log_content(
+            "##[group]Run actions/checkout@v4\nfetching\n##[endgroup]\n\
+             ##[group]Run cargo test\ntest a ... ok\n##[error]exit code 101\n##[endgroup]",
+        );
+        assert_eq!(app.visible_log_lines(), vec![0, 3, 4, 5, 6]);
+
+        // Enter at the top unfolds the checkout section
+        app.enter();
+        assert_eq!(app.visible_log_lines().len(), 7);
+
+        // Inside a section, Enter folds it back up to its header
+        app.log_scroll = 4;
+        app.enter();
+        assert_eq!(app.visible_log_lines(), vec![0, 1, 2, 3]);
+        assert_eq!(app.log_scroll, 0);
+
+        // Jumping to an error unfolds its section again
+        app.next_log_error();
+        assert_eq!(app.log_error_target, Some(5));
+        assert!(app.visible_log_lines().contains(&5));
+    }
+
+    #[tokio::test]
+    async fn test_expired_logs_are_not_fetched() {
+        let (mut app, _rx) = test_app();
+        // Finished in January 2024, well past the default 90 days
+        let run = make_run(4, 0);
+        app.current_run = Some(run.clone());
+        app.jobs = vec![make_job(1, "completed")];
+        app.view = View::RunDetail;
+        app.enter();
+        assert_eq!(app.view, View::Logs);
+        assert_eq!(app.log_expired, Some(DEFAULT_RETENTION_DAYS));
+        assert!(app.downloads.is_empty());
+
+        // The repo's own setting replaces the default once known
+        app.handle_background(BackgroundResult::RetentionFetched {
+            repo: "owner/repo".into(),
+            result: Ok(3650),
+        });
+        assert!(!app.logs_expired(&run));
+        app.back();
+        assert_eq!(app.log_expired, None);
+    }
+
+    #[test]
+    fn test_search_mode() {
+        let (mut app, _rx) = test_browser_app();
+        assert!(!app.searching);
+        app.start_search();
+        assert!(app.searching);
+        app.search_push('t');
+        app.search_push('e');
+        assert_eq!(app.repo_filter, "te");
+        app.search_backspace();
+        assert_eq!(app.repo_filter, "t");
+        app.search_clear();
+        assert_eq!(app.repo_filter, "");
+        assert!(app.searching);
+        app.search_clear();
+        assert!(!app.searching);
+    }
+
+    #[test]
+    fn test_back_from_repo_list_quits() {
+        let (mut app, _rx) = test_browser_app();
+        app.view = View::RepoList;
+        app.back();
+        assert!(app.should_quit);
+    }
+
+    #[tokio::test]
+    async fn test_job_hydration_fills_steps_and_survives_refetch() {
+        let (mut app, _rx) = test_app();
+        app.view = View::RunDetail;
+        app.handle_background(BackgroundResult::JobsFetched {
+            run_number: 1,
+            result: Ok(JobsResponse {
+                total_count: 2,
+                jobs: vec![make_job(1, "completed"), make_job(2, "in_progress")],
+            }),
+        });
+        assert_eq!(app.steps_loading, Some(1));
+
+        let mut hydrated = make_job(1, "completed");
+        hydrated.steps = Some(Vec::new());
+        app.handle_background(BackgroundResult::JobHydrated {
+            job_id: 1,
+            result: Ok(hydrated),
+        });
+        assert!(app.jobs[0].steps.is_some());
+        assert_eq!(app.steps_loading, None);
+
+        // A refresh keeps cached steps for completed jobs only
+        app.handle_background(BackgroundResult::JobsFetched {
+            run_number: 1,
+            result: Ok(JobsResponse {
+                total_count: 2,
+                jobs: vec![make_job(1, "completed"), make_job(2, "in_progress")],
+            }),
+        });
+        assert!(app.jobs[0].steps.is_some());
+        assert!(app.jobs[1].steps.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_run_counts_start_unknown_and_ignore_other_runs() {
+        let (mut app, _rx) = test_app();
+        app.view = View::RunDetail;
+        app.current_run = Some(make_run(7, 0));
+        app.handle_background(BackgroundResult::JobsFetched {
+            run_number: 7,
+            result: Ok(JobsResponse {
+                total_count: 1,
+                jobs: vec![make_job(1, "completed")],
+            }),
+        });
+        assert_eq!(app.run_counts, Some((7, RunCounts::default())));
+
+        let counts = RunCounts {
+            artifacts: Some(3),
+            annotations: None,
+            billable_ms: Some(840_000),
+        };
+        app.handle_background(BackgroundResult::RunCountsFetched {
+            run_id: 6,
+            counts: counts.clone(),
+        });
+        assert_eq!(app.run_counts, Some((7, RunCounts::default())));
+        app.handle_background(BackgroundResult::RunCountsFetched {
+            run_id: 7,
+            counts: counts.clone(),
+        });
+        assert_eq!(app.run_counts, Some((7, counts)));
+    }
+
+    #[tokio::test]
+    async fn test_annotations_of_a_failed_run() {
+        let (mut app, _rx) = test_app();
+        let jobs_fetched = || BackgroundResult::JobsFetched {
+            run_number: 7,
+            result: Ok(JobsResponse {
+                total_count: 1,
+                jobs: vec![make_job(1, "completed")],
+            }),
+        };
+        app.view = View::RunDetail;
+        app.current_run = Some(make_run(7, 0));
+        app.current_run.as_mut().unwrap().check_suite_id = Some(70);
+        app.handle_background(jobs_fetched());
+        // A run that passed isn't asked for them
+        assert!(app.run_annotations.is_none());
+
+        app.current_run.as_mut().unwrap().conclusion = Some("failure".into());
+        app.handle_background(jobs_fetched());
+        assert_eq!(app.run_annotations.as_ref().map(|(id, _)| *id), Some(7));
+
+        let annotation = |path: &str, line: Option<u64>, level: &str| Annotation {
+            path: path.into(),
+            start_line: line,
+            annotation_level: Some(level.into()),
+            title: None,
+            message: "boom".into(),
+        };
+        app.handle_background(BackgroundResult::AnnotationsFetched {
+            run_id: 7,
+            result: Ok(vec![
+                annotation(".github", None, "warning"),
+                annotation("src/lib.rs", Some(12), "failure"),
+            ]),
+        });
+        let paths: Vec<&str> = app.annotations().iter().map(|a| a.path.as_str()).collect();
+        assert_eq!(paths, ["src/lib.rs", ".github"]);
+
+        app.switch_pane();
+        assert_eq!(app.detail_focus, DetailFocus::Annotations);
+        app.enter();
+        assert_eq!(app.clipboard_request.as_deref(), Some("src/lib.rs:12"));
+        assert_eq!(app.view, View::RunDetail);
+        app.move_down(None);
+        app.enter();
+        assert_eq!(app.clipboard_request.as_deref(), Some(".github"));
+
+        app.switch_pane();
+        assert_eq!(app.detail_focus, DetailFocus::Jobs);
+    }
+
+    #[tokio::test]
+    async fn test_stuck_run_is_announced_once() {
+        let (mut app, _rx) = test_app();
+        let mut runs: Vec<WorkflowRun> = (1..=6)
+            .map(|id| {
+                let mut run = make_run(id, 60);
+                run.workflow_id = Some(7);
+                run.run_started_at = Some(run.created_at);
+                run.updated_at = run.created_at + chrono::Duration::minutes(6);
+                run
+            })
+            .collect();
+        let mut running = make_run(7, 0);
+        running.workflow_id = Some(7);
+        running.status = Some("in_progress".into());
+        running.conclusion = None;
+        runs.push(running);
+        app.handle_background(BackgroundResult::RunsFetched(Ok(WorkflowRunsResponse {
+            total_count: 7,
+            workflow_runs: runs,
+        })));
+
+        assert!(app.stuck_run(&app.runs[6]).is_some());
+        assert!(app.stuck_run(&app.runs[0]).is_none());
+        app.on_tick();
+        assert!(app.status_message.starts_with("⚠ CI #7 is running"));
+        assert!(app.status_message.contains("(usually ≤ 6m 0s)"));
+        app.status_message.clear();
+        app.on_tick();
+        assert!(app.status_message.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_keeps_selected_run_when_runs_prepended() {
+        let (mut app, _rx) = test_app();
+        app.per_page = 3;
+        app.handle_background(runs_page(10, 1)); // 10, 9, 8
+        app.runs_selected = 1; // run 9
+        app.refresh();
+        app.handle_background(runs_page(11, 1)); // 11, 10, 9
+        assert_eq!(app.runs[app.runs_selected].id, 9);
+    }
+
+    #[tokio::test]
+    async fn test_run_pushed_off_page_selects_nearest_neighbor() {
+        let (mut app, _rx) = test_app();
+        app.per_page = 3;
+        app.handle_background(runs_page(10, 1)); // 10, 9, 8
+        app.runs_selected = 2; // run 8
+        app.refresh();
+        app.handle_background(runs_page(12, 1)); // 12, 11, 10
+        assert_eq!(app.page, 1);
+        assert_eq!(app.runs[app.runs_selected].id, 10);
+    }
+
+    #[tokio::test]
+    async fn test_follow_fetches_page_containing_selected_run() {
+        let (mut app, _rx) = test_app();
+        app.per_page = 3;
+        app.follow_selection = true;
+        app.handle_background(runs_page(10, 1)); // 10, 9, 8
+        app.runs_selected = 2; // run 8
+        app.refresh();
+        app.handle_background(runs_page(12, 1)); // 12, 11, 10
+        assert_eq!(app.page, 2);
+        assert!(app.loading);
+
+        app.handle_background(runs_page(12, 2)); // 9, 8, 7
+        assert_eq!(app.page, 2);
+        assert_eq!(app.runs[app.runs_selected].id, 8);
+    }
+
+    #[tokio::test]
+    async fn test_follow_gives_up_when_run_disappears() {
+        let (mut app, _rx) = test_app();
+        app.per_page = 3;
+        app.follow_selection = true;
+        app.handle_background(runs_page(10, 1));
+        app.runs_selected = 2; // run 8
+        app.refresh();
+        app.handle_background(runs_page(12, 1));
+        assert_eq!(app.page, 2);
+
+        // Run 8 was deleted in the meantime: settle instead of paging again
+        let mut page = runs_page(12, 2);
+        if let BackgroundResult::RunsFetched(Ok(resp)) = &mut page {
+            resp.workflow_runs.retain(|r| r.id != 8);
+        }
+        app.handle_background(page);
+        assert_eq!(app.page, 2);
+        assert!(!app.loading);
+        assert!(matches!(app.runs[app.runs_selected].id, 7 | 9));
+    }
+
+    fn runs_fetched(ids: &[u64]) -> BackgroundResult {
+        BackgroundResult::RunsFetched(Ok(WorkflowRunsResponse {
+            total_count: ids.len() as u64,
+            workflow_runs: ids.iter().map(|&id| make_run(id, 0)).collect(),
+        }))
+    }
+
+    fn jobs_fetched(ids: &[u64]) -> BackgroundResult {
+        BackgroundResult::JobsFetched {
+            run_number: 1,
+            result: Ok(JobsResponse {
+                total_count: ids.len() as u64,
+                jobs: ids.iter().map(|&id| make_job(id, "completed")).collect(),
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refetched_runs_keep_the_selected_run() {
+        let (mut app, _rx) = test_app();
+        app.handle_background(runs_fetched(&[5, 4, 3]));
+        app.runs_selected = 1;
+
+        // A new run pushed #4 down
+        app.handle_background(runs_fetched(&[6, 5, 4, 3]));
+        assert_eq!(app.runs[app.runs_selected].id, 4);
+        // #4 is gone: stay at the same row
+        app.handle_background(runs_fetched(&[6, 5, 3]));
+        assert_eq!(app.runs_selected, 2);
+        // Fewer rows than the old index
+        app.handle_background(runs_fetched(&[6]));
+        assert_eq!(app.runs_selected, 0);
+        app.handle_background(runs_fetched(&[]));
+        assert_eq!(app.runs_selected, 0);
+    }
+
+    #[tokio::test]
+    async fn test_refetched_jobs_keep_the_selected_job() {
+        let (mut app, _rx) = test_app();
+        app.view = View::RunDetail;
+        app.handle_background(jobs_fetched(&[1, 2, 3]));
+        app.jobs_selected = 2;
+
+        app.handle_background(jobs_fetched(&[3, 1, 2]));
+        assert_eq!(app.jobs_selected, 0);
+        app.jobs_selected = 2;
+        app.handle_background(jobs_fetched(&[1, 3]));
+        assert_eq!(app.jobs[app.jobs_selected].id, 3);
+        app.handle_background(jobs_fetched(&[1, 2]));
+        assert_eq!(app.jobs_selected, 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_first_keeps_the_selected_job() {
+        let (mut app, _rx) = test_app();
+        app.view = View::RunDetail;
+        let fail = |mut job: Job| {
+            job.conclusion = Some("failure".into());
+            job
+        };
+        app.handle_background(jobs_fetched(&[1, 2, 3, 4]));
+        app.jobs[2] = fail(app.jobs[2].clone());
+        app.jobs_selected = 1;
+
+        app.toggle_failed_first();
+        let ids: Vec<u64> = app.jobs.iter().map(|j| j.id).collect();
+        assert_eq!(ids, [3, 1, 2, 4]);
+        assert_eq!(app.jobs[app.jobs_selected].id, 2);
+
+        // Refreshed jobs come sorted too
+        let BackgroundResult::JobsFetched {
+            result: Ok(mut resp),
+            ..
+        } = jobs_fetched(&[1, 2, 3, 4])
+        else {
+            unreachable!()
+        };
+        resp.jobs[3] = fail(resp.jobs[3].clone());
+        app.handle_background(BackgroundResult::JobsFetched {
+            run_number: 1,
+            result: Ok(resp),
+        });
+        assert_eq!(app.jobs[0].id, 4);
+        assert_eq!(app.jobs[app.jobs_selected].id, 2);
+
+        app.toggle_failed_first();
+        let ids: Vec<u64> = app.jobs.iter().map(|j| j.id).collect();
+        assert_eq!(ids, [1, 2, 3, 4]);
+        assert_eq!(app.jobs_selected, 1);
+    }
+
+    #[tokio::test]
+    async fn test_page_change_drops_anchor() {
+        let (mut app, _rx) = test_app();
+        app.per_page = 3;
+        app.handle_background(runs_page(10, 1));
+        app.runs_selected = 2;
+        app.refresh();
+        app.next_page();
+        app.handle_background(runs_page(10, 2));
+        assert_eq!(app.page, 2);
+        assert_eq!(app.runs_selected, 0);
+    }
+
+    #[tokio::test]
+    async fn test_latest_failure_opens_logs_at_first_error() {
+        let (mut app, _rx) = test_app();
+        let mut failed = make_job(2, "completed");
+        failed.conclusion = Some("failure".into());
+        let logs = (0..20)
+            .map(|i| {
+                if i == 12 {
+                    "##[error]Process completed with exit code 1.".to_string()
+                } else {
+                    format!("line {}", i)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let failure = LatestFailure {
+            run: make_run(7, 0),
+            jobs: vec![make_job(1, "completed"), failed],
+            job_index: 1,
+            logs,
+        };
+        app.handle_background(BackgroundResult::LatestFailureFetched(Ok(Some(Box::new(
+            failure,
+        )))));
+
+        assert_eq!(app.view, View::Logs);
+        assert_eq!(app.jobs_selected, 1);
+        assert_eq!(app.current_run.as_ref().map(|r| r.id), Some(7));
+        assert_eq!(app.log_error_target, Some(12));
+        assert_eq!(app.log_scroll, 10);
+    }
+
+    #[test]
+    fn test_no_latest_failure_stays_on_runs_list() {
+        let (mut app, _rx) = test_app();
+        app.handle_background(BackgroundResult::LatestFailureFetched(Ok(None)));
+        assert_eq!(app.view, View::RunsList);
+        assert_eq!(app.status_message, "No recent failed runs");
+    }
+
+    #[tokio::test]
+    async fn test_startup_failure_without_jobs_is_diagnosed() {
+        let (mut app, _rx) = test_app();
+        let mut run = make_run(7, 0);
+        run.conclusion = Some("startup_failure".into());
+        run.path = Some(".github/workflows/ci.yml".into());
+        app.current_run = Some(run);
+        app.view = View::RunDetail;
+
+        app.handle_background(BackgroundResult::JobsFetched {
+            run_number: 7,
+            result: Ok(JobsResponse {
+                total_count: 0,
+                jobs: Vec::new(),
+            }),
+        });
+        assert!(app.loading);
+        assert!(app.status_message.starts_with("Checking workflow file"));
+
+        // A result for some other run is ignored
+        app.handle_background(BackgroundResult::WorkflowDiagnosed {
+            run_id: 99,
+            result: Ok(WorkflowProblem::Annotations(Vec::new())),
+        });
+        assert!(app.workflow_problem.is_none());
+
+        let content = "on: push\njobs: [oops\n";
+        let error = lint::lint_workflow(content).unwrap();
+        app.handle_background(BackgroundResult::WorkflowDiagnosed {
+            run_id: 7,
+            result: Ok(WorkflowProblem::Syntax {
+                path: ".github/workflows/ci.yml".into(),
+                context: lint::context_lines(content, error.line),
+                error,
+            }),
+        });
+        assert!(!app.loading);
+        assert!(matches!(
+            app.workflow_problem,
+            Some(WorkflowProblem::Syntax { .. })
+        ));
+
+        app.back();
+        assert!(app.workflow_problem.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_split_view_debounces_and_caches_job_fetches() {
+        let (mut app, _rx) = test_app();
+        app.per_page = 3;
+        app.set_terminal_width(200);
+        assert!(app.split_view());
+        app.handle_background(runs_page(10, 1)); // 10, 9, 8
+
+        // Moving quickly only fetches once the selection settles
+        app.move_down(None);
+        app.move_down(None);
+        app.on_tick();
+        assert_eq!(app.preview_loading, None);
+        app.preview_due = app.preview_due.map(|(id, at)| (id, at - PREVIEW_DEBOUNCE));
+        app.on_tick();
+        assert_eq!(app.preview_loading, Some(8));
+
+        app.handle_background(BackgroundResult::PreviewJobsFetched {
+            run_id: 8,
+            result: Ok(JobsResponse {
+                total_count: 1,
+                jobs: vec![make_job(80, "completed")],
+            }),
+        });
+        assert_eq!(app.preview_jobs().map(|j| j.len()), Some(1));
+
+        // Coming back to a finished run reuses the cached jobs
+        app.move_up(None);
+        app.move_down(None);
+        app.preview_due = app.preview_due.map(|(id, at)| (id, at - PREVIEW_DEBOUNCE));
+        app.on_tick();
+        assert_eq!(app.preview_loading, None);
+    }
+
+    #[tokio::test]
+    async fn test_enter_from_jobs_pane_keeps_job_selected() {
+        let (mut app, _rx) = test_app();
+        app.split_override = Some(true);
+        app.handle_background(runs_page(10, 1));
+        app.handle_background(BackgroundResult::PreviewJobsFetched {
+            run_id: 10,
+            result: Ok(JobsResponse {
+                total_count: 2,
+                jobs: vec![make_job(1, "completed"), make_job(2, "completed")],
+            }),
+        });
+
+        app.switch_pane();
+        assert_eq!(app.pane_focus, PaneFocus::Jobs);
+        app.move_down(None);
+        assert_eq!(app.runs_selected, 0);
+        assert_eq!(app.preview_selected, 1);
+
+        app.enter();
+        assert_eq!(app.view, View::RunDetail);
+        assert_eq!(app.jobs[app.jobs_selected].id, 2);
+    }
+
+    #[test]
+    fn test_resize_keeps_a_followed_log_at_the_bottom() {
+        let (mut app, _rx) = test_app();
+        app.view = View::Logs;
+        app.set_log_content(&numbered_log(100));
+        app.resize(120, 40);
+        app.log_scroll = 5;
+        app.resize(120, 20);
+        assert_eq!(app.log_scroll, 5);
+
+        app.log_follow = true;
+        app.resize(120, 30);
+        assert_eq!(app.log_scroll, app.max_log_scroll());
+    }
+
+    #[test]
+    fn test_narrow_terminal_has_no_split() {
+        let (mut app, _rx) = test_app();
+        app.set_terminal_width(120);
+        assert!(!app.split_view());
+        app.switch_pane();
+        assert_eq!(app.pane_focus, PaneFocus::Runs);
+    }
+
+    #[tokio::test]
+    async fn test_quit_during_download_asks_first() {
+        let (mut app, _rx) = test_app();
+        app.jobs = vec![make_job(7, "completed")];
+        app.view = View::RunDetail;
+        app.enter();
+        assert_eq!(app.downloads.len(), 1);
+
+        app.request_quit();
+        assert!(app.confirm_quit);
+        assert!(!app.should_quit);
+        app.answer_quit(false);
+        assert!(!app.confirm_quit);
+        assert!(!app.should_quit);
+
+        app.request_quit();
+        app.answer_quit(true);
+        assert!(app.should_quit);
+    }
+
+    #[tokio::test]
+    async fn test_quit_after_download_finishes_is_immediate() {
+        let (mut app, _rx) = test_app();
+        app.jobs = vec![make_job(7, "completed")];
+        app.view = View::RunDetail;
+        app.enter();
+        app.handle_background(BackgroundResult::LogsFetched {
+            job_id: 7,
+            job_name: "build".into(),
+            result: Ok("line\n".into()),
+        });
+        assert!(app.downloads.is_empty());
+
+        app.request_quit();
+        assert!(!app.confirm_quit);
+        assert!(app.should_quit);
+    }
+
+    #[tokio::test]
+    async fn test_log_opened_at_a_step_shows_only_its_lines() {
+        let (mut app, _rx) = test_app();
+        let step = |number: u64, name: &str, second: u32| Step {
+            name: name.into(),
+            status: "completed".into(),
+            conclusion: Some("success".into()),
+            number,
+            started_at: Some(
+                chrono::TimeZone::with_ymd_and_hms(&Utc, 2025, 1, 12, 10, 0, second).unwrap(),
+            ),
+            completed_at: None,
+        };
+        let mut job = make_job(7, "completed");
+        job.name = "build".into();
+        job.steps = Some(vec![step(1, "Set up job", 0), step(2, "Run tests", 3)]);
+        app.jobs = vec![job];
+        app.view = View::RunDetail;
+
+        app.switch_pane();
+        assert_eq!(app.detail_focus, DetailFocus::Steps);
+        app.move_down(None);
+        app.enter();
+        assert_eq!(app.view, View::Logs);
+        app.handle_background(BackgroundResult::LogsFetched {
+            job_id: 7,
+            job_name: "build".into(),
+            result: Ok("2025-01-12T10:00:00.5Z Current runner version\n\
+                        2025-01-12T10:00:01.0Z ##[error]Setup failed\n\
+                        2025-01-12T10:00:03.2Z ##[group]Run cargo test\n\
+                        2025-01-12T10:00:04.0Z test a ... ok\n\
+                        2025-01-12T10:00:04.5Z ##[endgroup]\n"
+                .into()),
+        });
+        assert_eq!(app.log_step.as_ref().unwrap().lines, Some(2..5));
+        assert_eq!(app.visible_log_lines(), [2, 3, 4]);
+        // The setup step's error is out of reach until the whole log shows
+        app.next_log_error();
+        assert_eq!(app.status_message, "No errors in this log");
+
+        app.toggle_log_step();
+        assert_eq!(app.visible_log_lines(), [0, 1, 2, 3, 4]);
+        app.next_log_error();
+        assert_eq!(app.status_message, "error 1/1");
+
+        // Back on the steps; opened from the jobs list, the log isn't sliced
+        app.back();
+        app.switch_pane();
+        assert_eq!(app.detail_focus, DetailFocus::Jobs);
+        app.enter();
+        assert_eq!(app.log_step, None);
+    }
+
+    #[tokio::test]
+    async fn test_steps_pane_scrolls_on_its_own() {
+        let (mut app, _rx) = test_app();
+        let mut job = make_job(7, "completed");
+        job.steps = Some(
+            (1..=40)
+                .map(|number| Step {
+                    name: format!("step {}", number),
+                    status: "completed".into(),
+                    conclusion: Some("success".into()),
+                    number,
+                    started_at: None,
+                    completed_at: None,
+                })
+                .collect(),
+        );
+        app.jobs = vec![job, make_job(8, "completed")];
+        app.view = View::RunDetail;
+        app.last_rendered_areas
+            .borrow_mut()
+            .insert(ViewArea::Steps, Rect::new(40, 4, 30, 10));
+        app.steps_offset.set(20);
+
+        // A click on the steps focuses them at the row under the pointer
+        app.click(45, 6);
+        assert_eq!(app.detail_focus, DetailFocus::Steps);
+        assert_eq!(app.steps_selected, 22);
+        app.move_down(None);
+        assert_eq!(app.steps_selected, 23);
+        assert_eq!(app.jobs_selected, 0);
+
+        // Back on the jobs, moving to another job starts its steps over
+        app.switch_pane();
+        assert_eq!(app.detail_focus, DetailFocus::Jobs);
+        app.move_down(None);
+        assert_eq!(app.jobs_selected, 1);
+        assert_eq!((app.steps_selected, app.steps_offset.get()), (0, 0));
+    }
+
+    #[tokio::test]
+    async fn test_timeline_picks_jobs_and_goes_back() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let (mut app, _rx) = test_app();
+        app.view = View::RunDetail;
+        app.open_timeline();
+        // Nothing to lay out yet
+        assert_eq!(app.view, View::RunDetail);
+
+        let started = Utc::now() - chrono::Duration::seconds(90);
+        app.jobs = (7..=9)
+            .map(|id| {
+                let mut job = make_job(id, "completed");
+                job.started_at = Some(started);
+                job.completed_at = Some(started + chrono::Duration::seconds(id as i64 * 10));
+                job
+            })
+            .collect();
+        app.current_run = Some(make_run(1, 1));
+        app.open_timeline();
+        assert_eq!(app.view, View::RunTimeline);
+        app.move_down(None);
+        assert_eq!(app.jobs_selected, 1);
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+        terminal
+            .draw(|f| crate::ui::draw(f, &app, &crate::theme::Theme::default()))
+            .unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(screen.contains("Steps of job-8"), "{}", screen);
+        assert!(screen.contains("█"));
+
+        app.back();
+        assert_eq!(app.view, View::RunDetail);
+        assert_eq!(app.jobs_selected, 1);
+    }
+
+    #[tokio::test]
+    async fn test_loaded_log_reports_cache_use() {
+        let (mut app, _rx) = test_app();
+        app.jobs = vec![make_job(7, "completed"), make_job(8, "completed")];
+        app.view = View::RunDetail;
+        assert_eq!(app.run_cache_summary(), None);
+
+        app.enter();
+        app.handle_background(BackgroundResult::LogsFetched {
+            job_id: 7,
+            job_name: "build".into(),
+            result: Ok("2025-01-12T10:00:04Z Cache Size: ~1 MB (1048576 B)\n\
+                        2025-01-12T10:00:05Z Cache restored from key: Linux-cargo-1\n"
+                .into()),
+        });
+        assert_eq!(
+            app.cache_reports[&7].label().as_deref(),
+            Some("cache: hit (1.0 MB)")
+        );
+        assert_eq!(
+            app.run_cache_summary().as_deref(),
+            Some("cache: 1 hit, 0 misses · 1.0 MB restored")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_quit_with_finish_behavior_leaves_download_running() {
+        let (mut app, _rx) = test_app();
+        app.quit_behavior = QuitBehavior::Finish;
+        app.jobs = vec![make_job(7, "completed")];
+        app.view = View::RunDetail;
+        app.enter();
+
+        app.request_quit();
+        assert!(!app.confirm_quit);
+        assert!(app.should_quit);
+        assert_eq!(app.downloads.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_form_round_trip() {
+        let (mut app, _rx) = test_app();
+        let mut run = make_run(10, 0);
+        run.workflow_id = Some(99);
+        run.path = Some(".github/workflows/deploy.yml".into());
+        app.runs = vec![run];
+
+        app.open_dispatch_form();
+        let form = app.dispatch_form.as_ref().unwrap();
+        assert_eq!(form.workflow_id, 99);
+        assert!(form.loading_inputs);
+
+        // A late answer for another workflow is ignored
+        app.handle_background(BackgroundResult::DispatchInputsFetched {
+            workflow_id: 1,
+            default_ref: None,
+            result: Ok(Some(vec![])),
+        });
+        assert!(app.dispatch_form.as_ref().unwrap().loading_inputs);
+
+        app.handle_background(BackgroundResult::DispatchInputsFetched {
+            workflow_id: 99,
+            default_ref: None,
+            result: Ok(Some(vec![DispatchInput {
+                name: "environment".into(),
+                description: None,
+                default: None,
+                required: true,
+                options: vec![],
+            }])),
+        });
+        assert_eq!(app.dispatch_form.as_ref().unwrap().fields.len(), 2);
+
+        // Missing required input keeps the form open
+        app.spawn_dispatch();
+        let form = app.dispatch_form.as_mut().unwrap();
+        assert_eq!(form.error.as_deref(), Some("environment is required"));
+
+        form.next_field();
+        form.push('s');
+        app.spawn_dispatch();
+        assert!(app.dispatch_form.is_none());
+
+        app.handle_background(BackgroundResult::DispatchComplete {
+            repo: "octo/api".into(),
+            workflow_name: "CI".into(),
+            git_ref: "main".into(),
+            result: Err(anyhow::anyhow!("GitHub API error (422): Unexpected inputs")),
+        });
+        assert!(app.status_message.contains("Unexpected inputs"));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_from_workflow_list_on_default_branch() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let api = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": 1, "full_name": "octo/api", "name": "api", "owner": { "login": "octo" },
+                "html_url": "", "stargazers_count": 0, "updated_at": "2025-01-12T10:00:00Z",
+                "private": false, "fork": false, "archived": false, "default_branch": "trunk"
+            })))
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/contents/.github/workflows/deploy.yml"))
+            .and(query_param("ref", "trunk"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "on:\n  workflow_dispatch:\n    inputs:\n      environment:\n        required: true\n",
+            ))
+            .mount(&api)
+            .await;
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "token".into(), api.uri());
+        let mut app = App::new(client, tx, &Config::default());
+        app.view = View::WorkflowList;
+        app.workflows = vec![make_workflow(9, "Deploy")];
+
+        // The "All workflows" row has nothing to dispatch
+        app.workflows_selected = 0;
+        app.open_dispatch_form();
+        assert!(app.dispatch_form.is_none());
+
+        app.workflows_selected = 1;
+        app.open_dispatch_form();
+        assert_eq!(app.dispatch_form.as_ref().unwrap().fields[0].value, "");
+        let result = rx.recv().await.unwrap();
+        app.handle_background(result);
+        let form = app.dispatch_form.as_ref().unwrap();
+        assert_eq!(form.workflow_name, "Deploy");
+        assert_eq!(form.fields[0].value, "trunk");
+        assert_eq!(form.fields[1].name, "environment");
+        assert!(!form.free_form);
+    }
+
+    #[tokio::test]
+    async fn test_unreadable_inputs_take_key_value_pairs() {
+        let (mut app, _rx) = test_app();
+        app.view = View::WorkflowList;
+        app.workflows = vec![make_workflow(9, "Deploy")];
+        app.workflows_selected = 1;
+        app.open_dispatch_form();
+        app.dispatch_form.as_mut().unwrap().push('m');
+        app.handle_background(BackgroundResult::DispatchInputsFetched {
+            workflow_id: 9,
+            default_ref: Some("trunk".into()),
+            result: Err(anyhow::anyhow!("404 Not Found")),
+        });
+        let form = app.dispatch_form.as_ref().unwrap();
+        // What was typed while waiting stays
+        assert_eq!(form.fields[0].value, "m");
+        assert!(form.free_form);
+        assert_eq!(form.fields[1].name, "inputs");
+    }
+
+    #[tokio::test]
+    async fn test_find_dispatched_run() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let run = |id: u64, event: &str| {
+            serde_json::json!({
+                "id": id, "run_number": id, "name": "Deploy", "head_sha": "abc",
+                "event": event, "html_url": "",
+                "created_at": "2025-01-12T10:00:00Z", "updated_at": "2025-01-12T10:00:00Z"
+            })
+        };
+        let api = MockServer::start().await;
+        // The new run takes a poll to appear
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/workflows/9/runs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 1, "workflow_runs": [run(1, "workflow_dispatch")]
+            })))
+            .up_to_n_times(1)
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/workflows/9/runs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 3,
+                "workflow_runs": [run(3, "push"), run(2, "workflow_dispatch"), run(1, "workflow_dispatch")]
+            })))
+            .mount(&api)
+            .await;
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "token".into(), api.uri());
+
+        let known = HashSet::from([1]);
+        let found = find_dispatched_run(&client, 9, &known, Duration::from_millis(1)).await;
+        assert_eq!(found.map(|run| run.id), Some(2));
+        assert_eq!(api.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_dispatched_run_is_selected() {
+        let (mut app, _rx) = test_app();
+        app.view = View::RunsList;
+        app.runs = vec![make_run(1, 60)];
+        let mut run = make_run(2, 0);
+        run.run_number = 57;
+        app.handle_background(BackgroundResult::DispatchedRunFound {
+            workflow_name: "Deploy".into(),
+            git_ref: "main".into(),
+            run: Some(Box::new(run)),
+        });
+        assert_eq!(app.runs_anchor.as_ref().map(|a| a.id), Some(2));
+        assert_eq!(
+            app.toast.as_ref().unwrap().message,
+            "▶ Deploy #57 started on main"
+        );
+
+        app.handle_background(BackgroundResult::DispatchedRunFound {
+            workflow_name: "Deploy".into(),
+            git_ref: "main".into(),
+            run: None,
+        });
+        assert!(app.status_message.contains("press r to look again"));
+    }
+
+    #[tokio::test]
+    async fn test_mutations_are_audited() {
+        let dir = std::env::temp_dir().join(format!("atlas-app-audit-{}", std::process::id()));
+        let (mut app, _rx) = test_app();
+        app.audit_log = Some(AuditLog::new(dir.clone()));
+        app.handle_background(BackgroundResult::LoginFetched(Ok("octocat".into())));
+        app.handle_background(BackgroundResult::CancelComplete {
+            repo: "octo/api".into(),
+            run_id: 9001,
+            run_number: 42,
+            result: Err(anyhow::anyhow!("409 Conflict")),
+        });
+        // The failure still reaches the status bar
+        assert_eq!(app.status_message, "Error: 409 Conflict");
+
+        let log = app.audit_log.clone().unwrap();
+        let mut entries = Vec::new();
+        for _ in 0..100 {
+            entries = log.load(None).unwrap();
+            if !entries.is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].login.as_deref(), Some("octocat"));
+        assert_eq!(entries[0].action, AuditAction::Cancel);
+        assert_eq!(entries[0].run_id, Some(9001));
+        assert_eq!(entries[0].run_number, Some(42));
+        assert_eq!(entries[0].outcome, "error: 409 Conflict");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_login_picks_the_account_directories() {
+        let dir = std::env::temp_dir().join(format!("atlas-app-account-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("state.json"), "{}").unwrap();
+        let (mut app, _rx) = test_app();
+        app.atlas_dir = Some(dir.clone());
+        assert!(app.state_store.is_none());
+
+        app.handle_background(BackgroundResult::LoginFetched(Ok("Octocat".into())));
+        assert!(app.state_store.is_some());
+        assert_eq!(
+            app.cache_dir,
+            Some(dir.join("cache").join("github.com").join("octocat"))
+        );
+        assert!(!dir.join("state.json").exists());
+        assert!(dir.join("state/github.com/octocat/state.json").exists());
+
+        // The open repo is remembered for the account
+        let store = app.state_store.clone().unwrap();
+        for _ in 0..100 {
+            if !store.load().unwrap().recent.is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(store.load().unwrap().recent.contains_key("owner/repo"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_dispatch_needs_a_workflow_id() {
+        let (mut app, _rx) = test_app();
+        app.runs = vec![make_run(10, 0)];
+        app.open_dispatch_form();
+        assert!(app.dispatch_form.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_branch_filter_applies_and_clears() {
+        let (mut app, _rx) = test_app();
+        app.page = 3;
+        app.runs_selected = 2;
+
+        app.start_branch_input();
+        for c in "main".chars() {
+            app.branch_input_push(c);
+        }
+        app.apply_branch_input();
+        assert_eq!(app.branch_filter.as_deref(), Some("main"));
+        assert!(app.branch_input.is_none());
+        assert_eq!(app.page, 1);
+        assert_eq!(app.runs_selected, 0);
+
+        app.handle_background(runs_page(10, 1));
+        assert!(app.status_message.contains("branch main"));
+
+        // Esc leaves the filter alone
+        app.start_branch_input();
+        app.branch_input_push('x');
+        app.cancel_branch_input();
+        assert_eq!(app.branch_filter.as_deref(), Some("main"));
+
+        // An empty name clears it
+        app.start_branch_input();
+        app.apply_branch_input();
+        assert_eq!(app.branch_filter, None);
+
+        // So does Esc on the list, before it would quit
+        app.branch_filter = Some("release".into());
+        app.page = 2;
+        app.back();
+        assert_eq!(app.branch_filter, None);
+        assert_eq!(app.page, 1);
+        assert!(!app.should_quit && !app.confirm_quit);
+        app.back();
+        assert!(app.should_quit);
+    }
+
+    #[tokio::test]
+    async fn test_spinner_turns_while_loading_then_shows_fetch_time() {
+        let (mut app, _rx) = test_app();
+        app.loading = false;
+        app.on_tick();
+        assert_eq!(app.spinner(), "⠋");
+
+        app.spawn_fetch_runs();
+        app.on_tick();
+        app.on_tick();
+        assert_eq!(app.spinner(), "⠹");
+        app.handle_background(runs_page(10, 1));
+        assert!(!app.loading);
+        assert!(app.status_message.contains(" · fetched in "));
+        assert_eq!(app.loading_since, None);
+
+        // Errors keep their message as is
+        app.spawn_fetch_runs();
+        app.handle_background(BackgroundResult::RunsFetched(Err(anyhow::anyhow!("boom"))));
+        assert_eq!(app.status_message, "Error: boom");
+
+        assert_eq!(format_fetch_time(Duration::from_millis(420)), "420ms");
+        assert_eq!(format_fetch_time(Duration::from_millis(1340)), "1.3s");
+    }
+
+    #[tokio::test]
+    async fn test_status_filter_cycles_back_to_all() {
+        let (mut app, _rx) = test_app();
+        app.page = 2;
+
+        app.cycle_status_filter();
+        assert_eq!(app.status_filter, Some("failure"));
+        assert_eq!(app.page, 1);
+        app.handle_background(runs_page(10, 1));
+        assert!(app.status_message.contains(" · failure · fetched in "));
+
+        let mut seen = vec![app.status_filter];
+        for _ in 0..5 {
+            app.cycle_status_filter();
+            seen.push(app.status_filter);
+        }
+        assert_eq!(
+            seen,
+            vec![
+                Some("failure"),
+                Some("in_progress"),
+                Some("queued"),
+                Some("success"),
+                Some("cancelled"),
+                None
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_event_filter_cycles_back_to_all() {
+        let (mut app, _rx) = test_app();
+        app.page = 3;
+
+        app.cycle_event_filter();
+        assert_eq!(app.event_filter.as_deref(), Some("push"));
+        assert_eq!(app.page, 1);
+        app.handle_background(runs_page(10, 1));
+        assert!(app.status_message.contains(" · event push · fetched in "));
+
+        let mut seen = vec![app.event_filter.clone()];
+        for _ in 0..5 {
+            app.cycle_event_filter();
+            seen.push(app.event_filter.clone());
+        }
+        let seen: Vec<Option<&str>> = seen.iter().map(Option::as_deref).collect();
+        assert_eq!(
+            seen,
+            [
+                Some("push"),
+                Some("pull_request"),
+                Some("workflow_dispatch"),
+                Some("schedule"),
+                Some("release"),
+                None
+            ]
+        );
+
+        // `--event merge_group` isn't in the cycle: `e` clears it
+        app.event_filter = Some("merge_group".into());
+        app.cycle_event_filter();
+        assert_eq!(app.event_filter, None);
+    }
+
+    fn make_repo(full_name: &str) -> Repository {
+        let (owner, name) = full_name.split_once('/').unwrap();
+        Repository {
+            id: 1,
+            full_name: full_name.into(),
+            name: name.into(),
+            owner: crate::models::RepoOwner {
+                login: owner.into(),
+            },
+            description: None,
+            html_url: String::new(),
+            language: None,
+            stargazers_count: 0,
+            updated_at: Utc::now(),
+            pushed_at: None,
+            private: false,
+            fork: false,
+            archived: false,
+            default_branch: None,
+            permissions: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_repo_list_pages() {
+        let (mut app, _rx) = test_browser_app();
+        app.repos_per_page = 2;
+        app.handle_background(BackgroundResult::ReposCounted(Ok(5)));
+        app.handle_background(BackgroundResult::ReposFetched(Ok(vec![
+            make_repo("octo/api"),
+            make_repo("octo/web"),
+        ])));
+        assert_eq!(app.repos_pages(), Some(3));
+        assert!(app.status_message.starts_with("5 repositories · page 1/3"));
+
+        app.prev_page();
+        assert_eq!(app.repos_page, 1);
+        app.move_down(None);
+        app.next_page();
+        assert_eq!((app.repos_page, app.repos_selected), (2, 0));
+        app.next_page();
+        app.next_page();
+        assert_eq!(app.repos_page, 3);
+        app.prev_page();
+        assert_eq!(app.repos_page, 2);
+
+        // Uncounted, a short page is the last
+        app.repos_total = 0;
+        app.repos = vec![make_repo("octo/api")];
+        app.next_page();
+        assert_eq!(app.repos_page, 2);
+    }
+
+    #[tokio::test]
+    async fn test_bookmarks_stay_on_top_and_persist() {
+        let (mut app, _rx) = test_browser_app();
+        let dir = std::env::temp_dir().join(format!("atlas-app-bookmarks-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let file = BookmarkFile::new(dir.join("bookmarks.json"));
+        app.bookmark_file = Some(file.clone());
+        app.repos = vec![
+            make_repo("octo/api"),
+            make_repo("octo/web"),
+            make_repo("octo/docs"),
+        ];
+
+        app.repos_selected = 2;
+        app.toggle_bookmark();
+        assert_eq!(app.status_message, "★ Bookmarked octo/docs");
+        let names: Vec<&str> = app
+            .filtered_repos()
+            .iter()
+            .map(|r| r.full_name.as_str())
+            .collect();
+        assert_eq!(names, ["octo/docs", "octo/api", "octo/web"]);
+        // The selection moved with it
+        assert_eq!(app.repos_selected, 0);
+        assert_eq!(file.load().unwrap(), HashSet::from(["octo/docs".into()]));
+
+        // Another instance bookmarks a repo in the meantime
+        file.update(|b| {
+            b.insert("octo/api".into());
+        })
+        .unwrap();
+        app.repo_filter = "web".into();
+        app.bookmark_repo("octo/web");
+        assert!(app.bookmarks.contains("octo/api"));
+        assert_eq!(app.filtered_repos()[0].full_name, "octo/web");
+        app.repo_filter.clear();
+        app.unbookmark_repo("octo/docs");
+        assert_eq!(
+            file.load().unwrap(),
+            HashSet::from(["octo/api".into(), "octo/web".into()])
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn make_workflow(id: u64, name: &str) -> Workflow {
+        Workflow {
+            id,
+            name: name.into(),
+            path: format!(".github/workflows/{}.yml", name.to_lowercase()),
+            state: "active".into(),
+            html_url: String::new(),
+            last_run_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_workflow_list_filters_runs() {
+        let (mut app, _rx) = test_browser_app();
+        app.repos = vec![make_repo("octo/api")];
+        app.enter();
+        assert_eq!(app.view, View::WorkflowList);
+        app.handle_background(BackgroundResult::WorkflowsFetched(Ok(vec![
+            make_workflow(1, "CI"),
+            make_workflow(2, "Deploy"),
+        ])));
+
+        // Row 0 is "All workflows"
+        assert!(app.selected_workflow().is_none());
+        app.move_down(None);
+        app.move_down(None);
+        app.move_down(None);
+        assert_eq!(app.workflows_selected, 2);
+        app.enter();
+        assert_eq!(app.view, View::RunsList);
+        assert_eq!(app.workflow_filter.as_ref().map(|w| w.id), Some(2));
+
+        // Back lands on the same workflow, then back to the repos
+        app.back();
+        assert_eq!(app.view, View::WorkflowList);
+        assert_eq!(app.workflows_selected, 2);
+        app.back();
+        assert_eq!(app.view, View::RepoList);
+    }
+
+    #[tokio::test]
+    async fn test_workflow_picker_filters_runs() {
+        let (mut app, _rx) = test_app();
+        app.open_workflow_picker();
+        assert!(app.workflow_picker);
+        assert!(app.loading);
+        app.handle_background(BackgroundResult::WorkflowsFetched(Ok(vec![
+            make_workflow(1, "CI"),
+            make_workflow(2, "Deploy"),
+        ])));
+        app.handle_background(runs_fetched(&[5, 4, 3]));
+        app.page = 2;
+
+        for _ in 0..3 {
+            app.move_workflow_picker(true);
+        }
+        assert_eq!(app.workflows_selected, 2);
+        app.choose_workflow();
+        assert!(!app.workflow_picker);
+        assert_eq!(app.view, View::RunsList);
+        assert_eq!(app.workflow_filter.as_ref().map(|w| w.id), Some(2));
+        assert_eq!(app.page, 1);
+        assert!(app.runs.is_empty());
+
+        // Reopening lands on the filtered workflow; w closes it unchanged
+        app.open_workflow_picker();
+        assert_eq!(app.workflows_selected, 2);
+        app.move_workflow_picker(false);
+        app.close_workflow_picker();
+        assert_eq!(app.workflow_filter.as_ref().map(|w| w.id), Some(2));
+
+        // Esc shows every workflow's runs again
+        app.open_workflow_picker();
+        app.clear_workflow_filter();
+        assert!(!app.workflow_picker);
+        assert!(app.workflow_filter.is_none());
+
+        // With the workflows loaded, back goes to the workflow list
+        app.back();
+        assert_eq!(app.view, View::WorkflowList);
+        app.back();
+        assert!(app.should_quit);
+    }
+
+    #[tokio::test]
+    async fn test_export_saves_annotated_log_and_lists_it() {
+        let (mut app, mut rx) = test_app();
+        let dir = std::env::temp_dir().join(format!("atlas-app-exports-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        app.export_log = Some(ExportLog::new(dir.clone()));
+        app.current_run = Some(make_run(7, 0));
+        app.jobs = vec![make_job(1, "completed")];
+        app.view = View::Logs;
+        app.log_content = vec!["line one".into(), "##[error]boom".into()];
+
+        app.start_export();
+        for c in "flaky".chars() {
+            app.export_prompt_push(c);
+        }
+        app.save_export();
+        assert!(app.export_prompt.is_none());
+        let result = rx.recv().await.unwrap();
+        app.handle_background(result);
+        assert!(app.status_message.starts_with("✓ Saved logs to"));
+
+        app.toggle_exports_overlay();
+        let overlay = app.exports_overlay.as_ref().unwrap();
+        assert_eq!(overlay.entries.len(), 1);
+        let (record, exists) = &overlay.entries[0];
+        assert!(exists);
+        assert_eq!(record.annotation.as_deref(), Some("flaky"));
+        assert!(record.path.to_string_lossy().ends_with("-flaky.log"));
+        assert_eq!(
+            std::fs::read_to_string(&record.path).unwrap(),
+            "line one\n##[error]boom"
+        );
+
+        let path = record.path.clone();
+        app.open_selected_export();
+        assert_eq!(app.pager_request, Some(path));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_auto_refresh_fires_on_runs_list_only() {
+        let (mut app, _rx) = test_app();
+        app.loading = false;
+        app.on_tick();
+        assert!(!app.loading, "off by default");
+
+        app.refresh_interval = Some(Duration::from_secs(30));
+        app.last_refresh = Instant::now() - Duration::from_secs(10);
+        let left = app.next_auto_refresh().unwrap();
+        assert!(left <= Duration::from_secs(20) && left > Duration::from_secs(19));
+        app.on_tick();
+        assert!(!app.loading);
+
+        app.last_refresh = Instant::now() - Duration::from_secs(31);
+        app.on_tick();
+        assert!(app.loading);
+        assert!(app.next_auto_refresh().unwrap() > Duration::from_secs(29));
+
+        // Logs aren't reloaded behind the reader's back
+        app.view = View::Logs;
+        assert_eq!(app.next_auto_refresh(), None);
+    }
+
+    fn make_artifact(id: u64, name: &str, expired: bool) -> Artifact {
+        Artifact {
+            id,
+            name: name.to_string(),
+            size_in_bytes: 2048,
+            expired,
+            created_at: None,
+            archive_download_url: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_artifacts_view_round_trip() {
+        let (mut app, _rx) = test_app();
+        let run = make_run(5, 0);
+        let run_id = run.id;
+        app.current_run = Some(run);
+        app.view = View::RunDetail;
+        app.artifacts_dir = std::env::temp_dir().join("atlas-test-artifacts");
+
+        app.open_artifacts();
+        assert_eq!(app.view, View::Artifacts);
+        assert!(app.loading);
+
+        // A late answer for another run is ignored
+        app.handle_background(BackgroundResult::ArtifactsFetched {
+            run_id: run_id + 1,
+            result: Ok(ArtifactsResponse {
+                total_count: 1,
+                artifacts: vec![make_artifact(1, "other", false)],
+            }),
+        });
+        assert!(app.artifacts.is_empty());
+
+        app.handle_background(BackgroundResult::ArtifactsFetched {
+            run_id,
+            result: Ok(ArtifactsResponse {
+                total_count: 2,
+                artifacts: vec![
+                    make_artifact(1, "old-build", true),
+                    make_artifact(2, "dist", false),
+                ],
+            }),
+        });
+        assert_eq!(app.artifacts.len(), 2);
+
+        // Expired artifacts can't be downloaded
+        app.enter();
+        assert!(app.downloads.is_empty());
+        assert!(app.status_message.contains("expired"));
+
+        app.move_down(None);
+        app.enter();
+        assert_eq!(app.downloads.len(), 1);
+        assert_eq!(app.downloads[0].id, 2);
+
+        app.handle_background(BackgroundResult::ArtifactProgress {
+            artifact_id: 2,
+            percent: 42,
+        });
+        assert_eq!(app.status_message, "Downloading dist... 42%");
+
+        app.handle_background(BackgroundResult::ArtifactDownloaded {
+            artifact_id: 2,
+            result: Ok(PathBuf::from("/tmp/dist.zip")),
+        });
+        assert!(app.downloads.is_empty());
+        assert_eq!(app.status_message, "✓ Saved /tmp/dist.zip");
+
+        app.back();
+        assert_eq!(app.view, View::RunDetail);
+    }
+
+    #[tokio::test]
+    async fn test_commit_is_fetched_once_for_the_run_opened() {
+        let (mut app, _rx) = test_app();
+        app.runs = vec![make_run(5, 0)];
+        app.view = View::RunsList;
+        app.enter();
+        assert_eq!(app.view, View::RunDetail);
+        assert!(app.commit_loading);
+
+        let commit = |sha: &str| -> Commit {
+            serde_json::from_value(serde_json::json!({
+                "sha": sha, "commit": { "message": "Fix it", "author": null }, "stats": null
+            }))
+            .unwrap()
+        };
+        app.handle_background(BackgroundResult::CommitFetched {
+            sha: "def456".into(),
+            result: Ok(commit("def456")),
+        });
+        assert!(app.run_commit().is_none());
+        assert!(app.commit_loading);
+
+        app.handle_background(BackgroundResult::CommitFetched {
+            sha: "abc123".into(),
+            result: Ok(commit("abc123")),
+        });
+        assert!(!app.commit_loading);
+        assert_eq!(app.run_commit().unwrap().subject(), "Fix it");
+
+        // Back and in again: the commit is still the one shown
+        app.back();
+        app.enter();
+        assert!(!app.commit_loading);
+        assert!(app.run_commit().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_environments_show_the_runs_deployments() {
+        let (mut app, _rx) = test_app();
+        let run = make_run(5, 0);
+        let run_id = run.id;
+        app.current_run = Some(run);
+        app.view = View::RunDetail;
+
+        app.open_environments();
+        assert_eq!(app.view, View::Environments);
+        assert!(app.loading);
+
+        let env = |id: u64, name: &str| -> Environment {
+            serde_json::from_value(serde_json::json!({
+                "id": id, "name": name, "html_url": format!("https://github.com/o/r/{}", name)
+            }))
+            .unwrap()
+        };
+        let mut deployment: Deployment = serde_json::from_value(serde_json::json!({
+            "id": 9, "environment": "production", "created_at": "2025-01-12T10:00:00Z",
+            "creator": null
+        }))
+        .unwrap();
+        deployment.status = serde_json::from_value(serde_json::json!({
+            "state": "waiting", "log_url": null, "target_url": null
+        }))
+        .ok();
+
+        app.handle_background(BackgroundResult::EnvironmentsFetched {
+            run_id: run_id + 1,
+            result: Ok((vec![env(1, "other")], Vec::new())),
+        });
+        assert!(app.environments.is_empty());
+
+        app.handle_background(BackgroundResult::EnvironmentsFetched {
+            run_id,
+            result: Ok((
+                vec![env(1, "staging"), env(2, "production")],
+                vec![deployment],
+            )),
+        });
+        assert_eq!(app.environments.len(), 2);
+        assert!(app
+            .status_message
+            .starts_with("2 environment(s) · 1 waiting for approval · fetched in "));
+        assert!(app.deployment_to("staging").is_none());
+        assert!(app.deployment_to("production").unwrap().awaiting_approval());
+
+        app.move_down(None);
+        assert_eq!(app.environments_selected, 1);
+        app.back();
+        assert_eq!(app.view, View::RunDetail);
+        assert!(app.deployments.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rerun_job_reloads_the_jobs() {
+        let (mut app, _rx) = test_app();
+        let run = make_run(3, 0);
+        app.current_run = Some(run);
+        app.jobs = vec![make_job(1, "completed"), make_job(2, "completed")];
+        app.jobs_selected = 1;
+        app.view = View::RunDetail;
+
+        app.request_rerun_job(false);
+        assert_eq!(
+            app.confirmation_pending.as_ref().map(|p| p.prompt()),
+            Some("Re-run job 'job-2' of #3 'CI' on main?".to_string())
+        );
+        app.answer_confirmation(true);
+        assert_eq!(app.status_message, "Re-running job 'job-2' of #3...");
+
+        app.handle_background(BackgroundResult::JobRerunComplete {
+            repo: "owner/repo".into(),
+            run_id: 3,
+            run_number: 3,
+            job_name: "job-2".into(),
+            debug: false,
+            result: Ok(()),
+        });
+        // Jobs are fetched again to show the new attempt
+        assert!(app.loading);
+        assert_eq!(
+            app.status_message,
+            "✓ Re-run of job 'job-2' triggered for #3"
+        );
+    }
+
+    fn make_cache(id: u64, size_in_bytes: u64) -> ActionsCache {
+        ActionsCache {
+            id,
+            ref_: "refs/heads/main".into(),
+            key: format!("Linux-cargo-{}", id),
+            version: "abc".into(),
+            last_accessed_at: Utc::now(),
+            created_at: Utc::now(),
+            size_in_bytes,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_cache_asks_then_drops_it() {
+        let (mut app, _rx) = test_app();
+        app.view = View::RunsList;
+        app.open_caches();
+        assert_eq!(app.view, View::Caches);
+        app.handle_background(BackgroundResult::CachesFetched {
+            repo: "owner/repo".into(),
+            result: Ok(vec![make_cache(1, 300 << 20), make_cache(2, 20 << 20)]),
+        });
+        assert!(app
+            .status_message
+            .starts_with("2 caches · 320.0 MB in total · fetched in "));
+
+        app.actions_caches_selected = 1;
+        app.request_delete_cache();
+        assert_eq!(
+            app.confirmation_pending.as_ref().unwrap().prompt(),
+            "Delete cache 'Linux-cargo-2' (20.0 MB)?"
+        );
+        app.answer_confirmation(false);
+        assert_eq!(app.actions_caches.len(), 2);
+
+        app.request_delete_cache();
+        app.answer_confirmation(true);
+        assert_eq!(app.status_message, "Deleting cache Linux-cargo-2...");
+        app.handle_background(BackgroundResult::CacheDeleted {
+            repo: "owner/repo".into(),
+            cache_id: 2,
+            key: "Linux-cargo-2".into(),
+            result: Ok(()),
+        });
+        assert_eq!(app.status_message, "✓ Deleted cache Linux-cargo-2");
+        assert_eq!(app.actions_caches.len(), 1);
+        assert_eq!(app.actions_caches_selected, 0);
+
+        // Caches of a repo left behind are dropped
+        app.handle_background(BackgroundResult::CachesFetched {
+            repo: "octo/other".into(),
+            result: Ok(vec![]),
+        });
+        assert_eq!(app.actions_caches.len(), 1);
+
+        app.back();
+        assert_eq!(app.view, View::RunsList);
+    }
+
+    #[tokio::test]
+    async fn test_statistics_open_from_the_runs_list() {
+        let (mut app, _rx) = test_app();
+        app.view = View::RunDetail;
+        app.open_statistics();
+        assert_eq!(app.view, View::RunDetail);
+
+        app.view = View::RunsList;
+        app.handle_background(runs_fetched(&[1, 2, 3]));
+        app.open_statistics();
+        assert_eq!(app.view, View::Statistics);
+        assert_eq!(app.status_message, "Statistics over the 3 runs loaded");
+        assert!(app.get_selected_run().is_none());
+        app.back();
+        assert_eq!(app.view, View::RunsList);
+    }
+
+    #[tokio::test]
+    async fn test_toggle_workflow_asks_then_updates_its_state() {
+        let (mut app, _rx) = test_app();
+        let mut nightly = make_workflow(2, "Nightly");
+        nightly.state = "disabled_manually".into();
+        app.workflows = vec![make_workflow(1, "CI"), nightly];
+        app.view = View::WorkflowList;
+
+        // "All workflows" has nothing to toggle
+        app.workflows_selected = 0;
+        app.request_toggle_workflow();
+        assert!(app.confirmation_pending.is_none());
+
+        app.workflows_selected = 1;
+        app.request_toggle_workflow();
+        assert_eq!(
+            app.confirmation_pending.as_ref().unwrap().prompt(),
+            "Disable workflow 'CI'?"
+        );
+        app.answer_confirmation(true);
+        assert_eq!(app.status_message, "Disabling CI...");
+        app.handle_background(BackgroundResult::WorkflowToggled {
+            repo: "owner/repo".into(),
+            workflow_id: 1,
+            name: "CI".into(),
+            enable: false,
+            result: Ok(()),
+        });
+        assert_eq!(app.workflows[0].state, "disabled_manually");
+
+        app.workflows_selected = 2;
+        app.request_toggle_workflow();
+        assert_eq!(
+            app.confirmation_pending.as_ref().unwrap().prompt(),
+            "Enable workflow 'Nightly'?"
+        );
+        app.answer_confirmation(true);
+        app.handle_background(BackgroundResult::WorkflowToggled {
+            repo: "owner/repo".into(),
+            workflow_id: 2,
+            name: "Nightly".into(),
+            enable: true,
+            result: Err(anyhow::anyhow!("it needs the 'workflow' scope")),
+        });
+        assert_eq!(app.status_message, "Error: it needs the 'workflow' scope");
+        assert_eq!(app.workflows[1].state, "disabled_manually");
     }
 
-    pub fn move_down(&mut self) {
-        match self.view {
-            View::RepoList => {
-                let count = self.filtered_repos().len();
-                if count > 0 && self.repos_selected < count - 1 {
-                    self.repos_selected += 1;
-                }
-            }
-            View::RunsList => {
-                if !self.runs.is_empty() && self.runs_selected < self.runs.len() - 1 {
-                    self.runs_selected += 1;
-                }
-            }
-            View::RunDetail => {
-                if !self.jobs.is_empty() && self.jobs_selected < self.jobs.len() - 1 {
-                    self.jobs_selected += 1;
-                }
-            }
-            View::Logs => {
-                let max_scroll = self.log_content.len().saturating_sub(10);
-                self.log_scroll = (self.log_scroll + 3).min(max_scroll);
-            }
-        }
+    #[tokio::test]
+    async fn test_runners_count_those_fitting_the_job() {
+        let (mut app, _rx) = test_app();
+        let run = make_run(5, 0);
+        let run_id = run.id;
+        app.current_run = Some(run);
+        let mut job = make_job(1, "queued");
+        job.labels = vec!["self-hosted".into(), "gpu".into()];
+        app.jobs = vec![job];
+        app.view = View::RunDetail;
+
+        app.open_runners();
+        assert_eq!(app.view, View::Runners);
+        let runner = |id: u64, status: &str, busy: bool, labels: &[&str]| -> Runner {
+            serde_json::from_value(serde_json::json!({
+                "id": id, "name": format!("runner-{}", id), "os": "Linux",
+                "status": status, "busy": busy,
+                "labels": labels.iter().map(|l| serde_json::json!({"name": l})).collect::<Vec<_>>()
+            }))
+            .unwrap()
+        };
+        app.handle_background(BackgroundResult::RunnersFetched {
+            run_id,
+            result: Ok(vec![
+                runner(1, "online", true, &["self-hosted", "GPU"]),
+                runner(2, "offline", false, &["self-hosted", "gpu"]),
+                runner(3, "online", false, &["self-hosted"]),
+            ]),
+        });
+        assert!(app.status_message.starts_with(
+            "3 runner(s) · 2 online · 1 busy · 1 online with self-hosted, gpu · fetched in "
+        ));
+
+        app.back();
+        assert_eq!(app.view, View::RunDetail);
+        assert!(app.runners.is_empty());
     }
 
-    pub fn enter(&mut self) {
-        match self.view {
-            View::RepoList => {
-                let filtered = self.filtered_repos();
-                if let Some(repo) = filtered.get(self.repos_selected).cloned() {
-                    let owner = repo.owner.login.clone();
-                    let repo_name = repo.name.clone();
-                    self.client.set_repo(owner, repo_name);
-                    self.view = View::RunsList;
-                    self.runs.clear();
-                    self.runs_selected = 0;
-                    self.runs_total = 0;
-                    self.page = 1;
-                    self.repo_filter.clear();
-                    self.searching = false;
-                    self.spawn_fetch_runs();
-                }
-            }
-            View::RunsList => {
-                if let Some(run) = self.runs.get(self.runs_selected).cloned() {
-                    self.current_run = Some(run);
-                    self.view = View::RunDetail;
-                    self.spawn_fetch_jobs();
-                }
-            }
-            View::RunDetail => {
-                self.view = View::Logs;
-                self.spawn_fetch_logs();
-            }
-            View::Logs => {}
-        }
+    fn numbered_log(n: usize) -> String {
+        (0..n).map(|i| format!("line {}\n", i)).collect()
     }
 
-    pub fn back(&mut self) {
-        match self.view {
-            View::RepoList => {
-                self.should_quit = true;
-            }
-            View::RunsList => {
-                // Go back to repo list (or quit if in single-repo mode)
-                if self.repos.is_empty() {
-                    self.should_quit = true;
-                } else {
-                    self.view = View::RepoList;
-                    self.runs.clear();
-                    self.runs_selected = 0;
-                    self.update_repo_status();
-                }
-            }
-            View::RunDetail => {
-                self.view = View::RunsList;
-                self.current_run = None;
-                self.jobs.clear();
-            }
-            View::Logs => {
-                self.view = View::RunDetail;
-                self.log_content.clear();
-                self.log_scroll = 0;
-            }
-        }
+    #[tokio::test]
+    async fn test_follow_appends_new_lines_and_stops_when_job_finishes() {
+        let (mut app, _rx) = test_app();
+        app.jobs = vec![make_job(1, "in_progress")];
+        app.view = View::Logs;
+        let logs = |result: String| BackgroundResult::LogsFetched {
+            job_id: 1,
+            job_name: "job-1".into(),
+            result: Ok(result),
+        };
+        app.handle_background(logs(numbered_log(30)));
+        assert_eq!(app.log_content.len(), 30);
+
+        app.toggle_log_follow();
+        assert!(app.log_follow);
+        assert_eq!(app.log_scroll, 20);
+
+        // Only the new lines are added and the view stays at the bottom
+        app.handle_background(logs(numbered_log(50)));
+        assert_eq!(app.log_content.len(), 50);
+        assert_eq!(app.log_content[49], "line 49");
+        assert_eq!(app.log_scroll, 40);
+        assert!(app.status_message.contains("(+20)"));
+
+        // Scrolling up pauses, F resumes
+        app.move_up(None);
+        assert!(!app.log_follow);
+        app.handle_background(logs(numbered_log(60)));
+        assert_eq!(app.log_scroll, 37);
+        app.toggle_log_follow();
+        assert!(app.log_follow);
+
+        // The job completing ends it after the last fetch
+        let mut finished = make_job(1, "completed");
+        finished.steps = Some(vec![]);
+        app.handle_background(BackgroundResult::JobHydrated {
+            job_id: 1,
+            result: Ok(finished),
+        });
+        app.handle_background(logs(numbered_log(61)));
+        assert!(!app.log_follow);
+        assert!(app.status_message.contains("finished"));
+        assert_eq!(app.log_content.len(), 61);
     }
 
-    pub fn next_page(&mut self) {
-        if self.view == View::RunsList {
-            let total_pages = self.runs_total.div_ceil(self.per_page as u64);
-            if self.page < total_pages {
-                self.page += 1;
-                self.runs_selected = 0;
-                self.spawn_fetch_runs();
-            }
-        }
+    #[tokio::test]
+    async fn test_streamed_log_appends_chunks_then_folds() {
+        let (mut app, _rx) = test_app();
+        let mut failed = make_job(3, "completed");
+        failed.conclusion = Some("failure".into());
+        app.jobs = vec![failed];
+        app.view = View::RunDetail;
+        app.enter();
+        assert!(app.log_streaming());
+        assert!(app.log_content.is_empty());
+
+        let chunk = |job_id: u64, lines: &[&str]| BackgroundResult::LogChunk {
+            job_id,
+            lines: lines.iter().map(|l| l.to_string()).collect(),
+        };
+        app.handle_background(chunk(3, &["##[group]Setup", "ok", "##[endgroup]"]));
+        // Sections arriving mid-stream stay open
+        assert_eq!(app.visible_log_lines().len(), 3);
+        app.handle_background(chunk(9, &["someone else's log"]));
+        app.handle_background(chunk(3, &["##[error]boom"]));
+        assert_eq!(app.log_content.len(), 4);
+        assert!(app.status_message.contains("4 lines"));
+
+        // A finished job can still be followed while its log arrives
+        app.toggle_log_follow();
+        assert!(app.log_follow);
+
+        app.handle_background(BackgroundResult::LogsStreamed {
+            job_id: 3,
+            job_name: "job-3".into(),
+            result: Ok(()),
+        });
+        assert!(!app.log_streaming());
+        assert!(!app.log_follow);
+        assert!(app.downloads.is_empty());
+        assert_eq!(app.visible_log_lines(), vec![0, 3]);
+        assert_eq!(
+            app.log_text(),
+            "##[group]Setup\nok\n##[endgroup]\n##[error]boom"
+        );
     }
 
-    pub fn prev_page(&mut self) {
-        if self.view == View::RunsList && self.page > 1 {
-            self.page -= 1;
-            self.runs_selected = 0;
-            self.spawn_fetch_runs();
-        }
+    #[tokio::test]
+    async fn test_leaving_a_streaming_log_stops_it() {
+        let (mut app, _rx) = test_app();
+        app.jobs = vec![make_job(3, "in_progress")];
+        app.view = View::RunDetail;
+        app.enter();
+        assert_eq!(app.downloads.len(), 1);
+        app.back();
+        assert!(!app.log_streaming());
+        assert!(app.downloads.is_empty());
+        assert!(!app.loading);
     }
 
-    pub fn refresh(&mut self) {
-        match self.view {
-            View::RepoList => self.spawn_fetch_repos(),
-            View::RunsList => self.spawn_fetch_runs(),
-            View::RunDetail => self.spawn_fetch_jobs(),
-            View::Logs => self.spawn_fetch_logs(),
-        }
+    #[test]
+    fn test_follow_refuses_finished_job() {
+        let (mut app, _rx) = test_app();
+        app.jobs = vec![make_job(1, "completed")];
+        app.view = View::Logs;
+        app.toggle_log_follow();
+        assert!(!app.log_follow);
     }
 
-    pub fn open_in_browser(&self) {
-        let url = match self.view {
-            View::RepoList => {
-                let filtered = self.filtered_repos();
-                filtered
-                    .get(self.repos_selected)
-                    .map(|r| r.html_url.clone())
-            }
-            View::RunsList => self
-                .runs
-                .get(self.runs_selected)
-                .map(|r| r.html_url.clone()),
-            View::RunDetail | View::Logs => {
-                if let Some(job) = self.jobs.get(self.jobs_selected) {
-                    job.html_url.clone()
-                } else {
-                    self.current_run.as_ref().map(|r| r.html_url.clone())
-                }
-            }
-        };
+    #[tokio::test]
+    async fn test_cancel_and_rerun_wait_for_confirmation() {
+        let (mut app, _rx) = test_app();
+        app.request_cancel();
+        assert_eq!(app.confirmation_pending, None);
 
-        if let Some(url) = url {
-            let _ = open::that(&url);
-        }
-    }
-}
+        app.runs = vec![make_run(3, 0)];
+        app.status_message.clear();
+        app.request_cancel();
+        let pending = app.confirmation_pending.clone().unwrap();
+        assert_eq!(pending.prompt(), "Cancel run #3 'CI' on main?");
+        assert!(app.status_message.is_empty());
+        app.answer_confirmation(false);
+        assert_eq!(app.confirmation_pending, None);
+        assert_eq!(app.status_message, "Nothing changed");
 
-// ── Tests ──────────────────────────────────────────────────────────
+        app.request_rerun(false);
+        assert_eq!(
+            app.confirmation_pending,
+            Some(PendingAction::RerunAll {
+                run_id: 3,
+                run_number: 3,
+                title: " 'CI' on main".into(),
+                debug: false
+            })
+        );
+        app.answer_confirmation(true);
+        assert_eq!(app.confirmation_pending, None);
+        assert_eq!(app.status_message, "Re-running workflow #3...");
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::github::GitHubClient;
+    /// An app on a mock GitHub, with run #3 failed on its first attempt
+    async fn watch_app() -> (
+        App,
+        mpsc::UnboundedReceiver<BackgroundResult>,
+        wiremock::MockServer,
+    ) {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
 
-    fn test_app() -> (App, mpsc::UnboundedReceiver<BackgroundResult>) {
+        let api = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octo/api/actions/runs/3/rerun-failed-jobs"))
+            .respond_with(ResponseTemplate::new(201))
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs/3"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": 3, "run_number": 3, "name": "CI", "head_sha": "abc123",
+                "status": "in_progress", "conclusion": null, "run_attempt": 2,
+                "event": "push", "html_url": "",
+                "created_at": "2024-01-01T12:00:00Z", "updated_at": "2024-01-01T12:05:00Z"
+            })))
+            .mount(&api)
+            .await;
         let (tx, rx) = mpsc::unbounded_channel();
-        let client = GitHubClient::new("owner".into(), "repo".into(), "token".into());
-        (App::new(client, tx), rx)
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "token".into(), api.uri());
+        let mut app = App::new(client, tx, &Config::default());
+        let mut run = make_run(3, 0);
+        run.conclusion = Some("failure".into());
+        run.run_attempt = Some(1);
+        app.runs = vec![run];
+        // The re-run goes ahead without a confirmation to answer
+        app.confirm_actions = false;
+        (app, rx, api)
     }
 
-    fn test_browser_app() -> (App, mpsc::UnboundedReceiver<BackgroundResult>) {
-        let (tx, rx) = mpsc::unbounded_channel();
-        let client = GitHubClient::new_with_token("token".into());
-        (App::new_browser(client, tx), rx)
+    #[tokio::test]
+    async fn test_rerun_and_watch_until_done() {
+        let (mut app, mut rx, _api) = watch_app().await;
+        app.rerun_failed_and_watch();
+        assert!(app.watch.as_ref().unwrap().triggering);
+        assert!(app
+            .watch_readout()
+            .unwrap()
+            .starts_with("⟳ CI #3 re-running failed jobs · "));
+
+        let triggered = rx.recv().await.unwrap();
+        app.handle_background(triggered);
+        let watch = app.watch.as_mut().unwrap();
+        assert!(!watch.triggering);
+        // Polls wait out the interval
+        app.on_tick();
+        assert!(!app.watch.as_ref().unwrap().poll_in_flight);
+
+        app.watch.as_mut().unwrap().polled_at = Instant::now() - WATCH_INTERVAL;
+        app.on_tick();
+        let polled = rx.recv().await.unwrap();
+        app.handle_background(polled);
+        assert!(app.watch_readout().unwrap().contains(" in progress · "));
+        assert_eq!(app.runs[0].status.as_deref(), Some("in_progress"));
+        assert!(app.toast.is_none());
+
+        let mut done = make_run(3, 0);
+        done.run_attempt = Some(2);
+        app.handle_background(BackgroundResult::WatchedRunFetched {
+            run_id: 3,
+            result: Ok(done),
+        });
+        assert!(app.watch.is_none());
+        assert_eq!(
+            app.status_message,
+            "✓ CI #3 success after re-running its failed jobs"
+        );
+        assert!(app.toast.as_ref().unwrap().success);
+        assert_eq!(app.notification_request.as_deref(), Some("CI #3: success"));
+        assert_eq!(app.runs[0].conclusion.as_deref(), Some("success"));
     }
 
-    #[test]
-    fn test_initial_state() {
-        let (app, _rx) = test_app();
-        assert_eq!(app.view, View::RunsList);
-        assert!(!app.should_quit);
-        assert_eq!(app.page, 1);
-        assert_eq!(app.runs_selected, 0);
+    #[tokio::test]
+    async fn test_watch_ignores_the_stale_attempt() {
+        let (mut app, mut rx, _api) = watch_app().await;
+        app.rerun_failed_and_watch();
+        let triggered = rx.recv().await.unwrap();
+        app.handle_background(triggered);
+
+        // Right after the re-run GitHub may still show the failed attempt
+        let stale = app.runs[0].clone();
+        app.handle_background(BackgroundResult::WatchedRunFetched {
+            run_id: 3,
+            result: Ok(stale),
+        });
+        assert!(app.watch.is_some());
+        assert!(app.toast.is_none());
     }
 
-    #[test]
-    fn test_browser_initial_state() {
-        let (app, _rx) = test_browser_app();
-        assert_eq!(app.view, View::RepoList);
-        assert!(!app.should_quit);
+    #[tokio::test]
+    async fn test_stopping_a_watch_leaves_the_rerun() {
+        let (mut app, mut rx, api) = watch_app().await;
+        app.rerun_failed_and_watch();
+        let triggered = rx.recv().await.unwrap();
+        app.handle_background(triggered);
+
+        app.stop_watch();
+        assert!(app.watch.is_none());
+        assert_eq!(
+            app.status_message,
+            "Stopped watching #3; its re-run carries on"
+        );
+        // Nothing polls any more, and a late poll is dropped
+        app.on_tick();
+        let mut done = make_run(3, 0);
+        done.run_attempt = Some(2);
+        app.handle_background(BackgroundResult::WatchedRunFetched {
+            run_id: 3,
+            result: Ok(done),
+        });
+        assert!(app.toast.is_none());
+        let requests = api.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, wiremock::http::Method::POST);
     }
 
-    #[test]
-    fn test_move_up_at_zero_stays() {
+    #[tokio::test]
+    async fn test_watch_needs_a_failed_run() {
+        let (mut app, _rx, _api) = watch_app().await;
+        app.runs[0].conclusion = Some("success".into());
+        app.rerun_failed_and_watch();
+        assert!(app.watch.is_none());
+        assert_eq!(app.status_message, "#3 has no failed jobs to re-run");
+
+        // Declining the confirmation drops the watch
+        app.runs[0].conclusion = Some("failure".into());
+        app.confirm_actions = true;
+        app.rerun_failed_and_watch();
+        assert!(matches!(
+            app.confirmation_pending,
+            Some(PendingAction::RerunFailed { run_id: 3, .. })
+        ));
+        app.answer_confirmation(false);
+        assert!(app.watch.is_none());
+
+        // A protected workflow's needs the number typed
+        app.runs[0].name = Some("Deploy prod".into());
+        app.rerun_failed_and_watch();
+        assert!(app.confirmation_input.is_some());
+        app.answer_confirmation(false);
+        assert!(app.watch.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_confirm_actions_off_goes_ahead_except_when_protected() {
         let (mut app, _rx) = test_app();
-        app.runs_selected = 0;
-        app.move_up();
-        assert_eq!(app.runs_selected, 0);
+        app.confirm_actions = false;
+        app.runs = vec![make_run(3, 0)];
+        app.request_cancel();
+        assert_eq!(app.confirmation_pending, None);
+        assert_eq!(app.status_message, "Cancelling workflow #3...");
+
+        app.runs[0].conclusion = Some("failure".into());
+        app.request_rerun_failed(true);
+        assert_eq!(app.confirmation_pending, None);
+        assert_eq!(
+            app.status_message,
+            "Re-running failed jobs of #3 with debug logging..."
+        );
+
+        app.runs[0].name = Some("Deploy prod".into());
+        app.request_rerun(false);
+        assert_eq!(app.confirmation_input.as_deref(), Some(""));
     }
 
-    #[test]
-    fn test_move_down_empty_list() {
+    #[tokio::test]
+    async fn test_protected_workflows_need_the_run_number_typed() {
         let (mut app, _rx) = test_app();
-        app.move_down();
-        assert_eq!(app.runs_selected, 0);
+        let mut run = make_run(3, 0);
+        run.name = Some("Deploy PROD".into());
+        run.conclusion = Some("failure".into());
+        app.runs = vec![run];
+
+        app.request_cancel();
+        assert_eq!(app.confirmation_input.as_deref(), Some(""));
+        app.confirmation_input = Some("4".into());
+        app.submit_confirmation_input();
+        assert!(app.confirmation_pending.is_some());
+        assert_eq!(app.status_message, "Type 3 to confirm, Esc to back out");
+        app.confirmation_input = Some("#3".into());
+        app.submit_confirmation_input();
+        assert_eq!(app.confirmation_pending, None);
+        assert_eq!(app.confirmation_input, None);
+        assert_eq!(app.status_message, "Cancelling workflow #3...");
+
+        // F needs the number typed too
+        app.request_rerun_failed(false);
+        assert_eq!(
+            app.confirmation_pending,
+            Some(PendingAction::RerunFailed {
+                run_id: 3,
+                run_number: 3,
+                title: " 'Deploy PROD' on main".into(),
+                debug: false
+            })
+        );
+        app.confirmation_input = Some("YES".into());
+        app.submit_confirmation_input();
+        assert_eq!(app.status_message, "Re-running failed jobs of #3...");
+
+        // Other workflows keep the single-key confirmation
+        app.runs[0].name = Some("CI".into());
+        app.request_rerun(false);
+        assert_eq!(app.confirmation_input, None);
     }
 
-    #[test]
-    fn test_back_from_runs_single_repo_quits() {
+    #[tokio::test]
+    async fn test_select_runs_and_cancel_them_together() {
         let (mut app, _rx) = test_app();
         app.view = View::RunsList;
-        app.back();
-        assert!(app.should_quit);
+        app.request_cancel_selected();
+        assert_eq!(app.status_message, "Select runs with Space first");
+
+        app.handle_background(runs_fetched(&[1, 2, 3, 4]));
+        app.toggle_run_selected();
+        app.toggle_run_selected();
+        app.move_down(None);
+        app.toggle_run_selected();
+        assert_eq!(app.selected_run_ids, HashSet::from([1, 2, 4]));
+        assert_eq!(
+            app.status_message,
+            "3 selected · C cancels, R re-runs, D deletes them"
+        );
+
+        app.request_cancel_selected();
+        assert_eq!(
+            app.confirmation_pending,
+            Some(PendingAction::CancelSelected { count: 3 })
+        );
+        app.answer_confirmation(true);
+        assert_eq!(app.status_message, "Cancelling 3 runs...");
+
+        app.handle_background(BackgroundResult::BulkActionComplete {
+            action: AuditAction::Cancel,
+            repo: "octo/api".into(),
+            results: vec![
+                (1, 1, Ok(())),
+                (2, 2, Err(anyhow::anyhow!("409 Conflict"))),
+                (4, 4, Ok(())),
+            ],
+        });
+        assert_eq!(app.status_message, "Cancelled 2, failed 1");
+        assert_eq!(app.selected_run_ids, HashSet::from([2]));
+
+        // A run that leaves the list leaves the selection
+        app.handle_background(runs_fetched(&[1, 3]));
+        assert!(app.selected_run_ids.is_empty());
     }
 
-    #[test]
-    fn test_back_from_detail_goes_to_list() {
+    #[tokio::test]
+    async fn test_rerun_and_cancel_act_on_the_selection() {
         let (mut app, _rx) = test_app();
-        app.view = View::RunDetail;
+        app.view = View::RunsList;
+        app.handle_background(runs_fetched(&[1, 2, 3]));
+        app.toggle_run_selected();
+        app.toggle_run_selected();
+
+        app.request_rerun(false);
+        assert_eq!(
+            app.confirmation_pending.as_ref().unwrap().prompt(),
+            "Re-run all jobs of 2 selected runs?"
+        );
+        app.answer_confirmation(true);
+        assert_eq!(app.status_message, "Re-running 2 runs...");
+        app.handle_background(BackgroundResult::BulkActionComplete {
+            action: AuditAction::Rerun,
+            repo: "octo/api".into(),
+            results: vec![(1, 1, Ok(())), (2, 2, Ok(()))],
+        });
+        assert_eq!(app.status_message, "✓ Re-ran 2");
+        assert!(app.selected_run_ids.is_empty());
+
+        app.toggle_run_selected();
+        app.request_cancel();
+        assert_eq!(
+            app.confirmation_pending,
+            Some(PendingAction::CancelSelected { count: 1 })
+        );
+        app.answer_confirmation(false);
+
+        // Esc clears the selection before it leaves the list
         app.back();
+        assert!(app.selected_run_ids.is_empty());
         assert_eq!(app.view, View::RunsList);
-        assert!(app.current_run.is_none());
     }
 
-    #[test]
-    fn test_back_from_logs_goes_to_detail() {
+    #[tokio::test]
+    async fn test_delete_runs_always_asks_and_drops_them_in_place() {
         let (mut app, _rx) = test_app();
-        app.view = View::Logs;
-        app.log_content = vec!["line1".into()];
-        app.log_scroll = 5;
-        app.back();
-        assert_eq!(app.view, View::RunDetail);
-        assert!(app.log_content.is_empty());
-        assert_eq!(app.log_scroll, 0);
-    }
+        app.view = View::RunsList;
+        app.confirm_actions = false;
+        app.handle_background(runs_fetched(&[1, 2, 3, 4]));
 
-    #[test]
-    fn test_log_scroll_large_values() {
-        let (mut app, _rx) = test_app();
-        app.view = View::Logs;
-        app.log_content = (0..100_000).map(|i| format!("line {}", i)).collect();
-        app.log_scroll = 99_980;
-        app.move_down();
-        assert!(app.log_scroll <= app.log_content.len());
+        // The run under the cursor, asked about even with confirm_actions off
+        app.runs_selected = 3;
+        app.request_delete_runs();
+        assert_eq!(
+            app.confirmation_pending.as_ref().unwrap().prompt(),
+            "Delete run #4 'CI' on main? This can't be undone"
+        );
+        app.answer_confirmation(true);
+        assert_eq!(app.status_message, "Deleting run #4...");
+        let repo = app.repo_key();
+        app.handle_background(BackgroundResult::RunDeleted {
+            repo: repo.clone(),
+            run_id: 4,
+            run_number: 4,
+            result: Ok(()),
+            done: 1,
+            total: 1,
+            failed: 0,
+        });
+        assert_eq!(app.status_message, "✓ Deleted run #4");
+        assert_eq!(app.runs.len(), 3);
+        assert_eq!(app.runs_total, 3);
+        assert_eq!(app.runs_selected, 2);
+
+        // The selection, one at a time; a refusal leaves its run
+        app.selected_run_ids = HashSet::from([1, 2]);
+        app.request_delete_runs();
+        assert_eq!(
+            app.confirmation_pending.as_ref().unwrap().prompt(),
+            "Delete 2 selected runs? This can't be undone"
+        );
+        app.answer_confirmation(true);
+        assert_eq!(app.status_message, "Deleting runs... 0/2");
+        app.handle_background(BackgroundResult::RunDeleted {
+            repo: repo.clone(),
+            run_id: 1,
+            run_number: 1,
+            result: Ok(()),
+            done: 1,
+            total: 2,
+            failed: 0,
+        });
+        assert_eq!(app.status_message, "Deleting runs... 1/2");
+        app.handle_background(BackgroundResult::RunDeleted {
+            repo,
+            run_id: 2,
+            run_number: 2,
+            result: Err(anyhow::anyhow!("GitHub won't delete this run")),
+            done: 2,
+            total: 2,
+            failed: 1,
+        });
+        assert_eq!(
+            app.status_message,
+            "Deleted 1/2 (1 failed — see atlas audit list)"
+        );
+        assert_eq!(app.runs.iter().map(|r| r.id).collect::<Vec<_>>(), [2, 3]);
+        assert_eq!(app.runs_total, 2);
+        assert_eq!(app.selected_run_ids, HashSet::from([2]));
     }
 
-    #[test]
-    fn test_log_scroll_saturating_sub() {
+    #[tokio::test]
+    async fn test_rerun_failed_falls_back_to_full_rerun() {
         let (mut app, _rx) = test_app();
-        app.view = View::Logs;
-        app.log_content = vec!["a".into(); 20];
-        app.log_scroll = 1;
-        app.move_up();
-        assert_eq!(app.log_scroll, 0);
-    }
+        let mut run = make_run(3, 0);
+        run.conclusion = Some("success".into());
+        app.runs = vec![run];
+        app.request_rerun_failed(false);
+        assert_eq!(app.status_message, "#3 has no failed jobs");
+        assert_eq!(
+            app.confirmation_pending,
+            Some(PendingAction::RerunAll {
+                run_id: 3,
+                run_number: 3,
+                title: " 'CI' on main".into(),
+                debug: false
+            })
+        );
+        app.answer_confirmation(false);
 
-    #[test]
-    fn test_search_mode() {
-        let (mut app, _rx) = test_browser_app();
-        assert!(!app.searching);
-        app.start_search();
-        assert!(app.searching);
-        app.search_push('t');
-        app.search_push('e');
-        assert_eq!(app.repo_filter, "te");
-        app.search_backspace();
-        assert_eq!(app.repo_filter, "t");
-        app.search_clear();
-        assert_eq!(app.repo_filter, "");
-        assert!(app.searching);
-        app.search_clear();
-        assert!(!app.searching);
+        app.runs[0].conclusion = Some("failure".into());
+        app.request_rerun_failed(false);
+        assert!(matches!(
+            app.confirmation_pending,
+            Some(PendingAction::RerunFailed { run_id: 3, .. })
+        ));
+        app.answer_confirmation(true);
+        assert_eq!(app.status_message, "Re-running failed jobs of #3...");
+
+        app.handle_background(BackgroundResult::RerunFailedComplete {
+            repo: "octo/api".into(),
+            run_id: 3,
+            run_number: 3,
+            debug: false,
+            result: Ok(()),
+        });
+        assert_eq!(
+            app.status_message,
+            "✓ Re-run of failed jobs triggered for #3"
+        );
     }
 
     #[test]
-    fn test_back_from_repo_list_quits() {
-        let (mut app, _rx) = test_browser_app();
-        app.view = View::RepoList;
-        app.back();
-        assert!(app.should_quit);
+    fn test_debug_rerun_says_so() {
+        let (mut app, _rx) = test_app();
+        app.runs = vec![make_run(3, 0)];
+        app.request_rerun(true);
+        assert_eq!(
+            app.confirmation_pending.as_ref().map(|p| p.prompt()),
+            Some("Re-run all jobs of #3 'CI' on main with debug logging?".to_string())
+        );
+        app.handle_background(BackgroundResult::RerunComplete {
+            repo: "octo/api".into(),
+            run_id: 3,
+            run_number: 3,
+            debug: true,
+            result: Ok(()),
+        });
+        assert_eq!(
+            app.status_message,
+            "✓ Re-run triggered with debug logging for #3"
+        );
     }
 }
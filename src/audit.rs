@@ -0,0 +1,254 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Local, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use tracing::warn;
+
+use crate::state::{self, FileLock};
+
+// ── Audit entries ──────────────────────────────────────────────────
+
+/// A change made to a repo's runs through Atlas
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AuditAction {
+    Rerun,
+    RerunFailed,
+    RerunJob,
+    Cancel,
+    Dispatch,
+    DeleteCache,
+    DeleteRun,
+    EnableWorkflow,
+    DisableWorkflow,
+}
+
+impl AuditAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Rerun => "rerun",
+            Self::RerunFailed => "rerun-failed",
+            Self::RerunJob => "rerun-job",
+            Self::Cancel => "cancel",
+            Self::Dispatch => "dispatch",
+            Self::DeleteCache => "delete-cache",
+            Self::DeleteRun => "delete-run",
+            Self::EnableWorkflow => "enable-workflow",
+            Self::DisableWorkflow => "disable-workflow",
+        }
+    }
+}
+
+/// One line of `~/.atlas/audit.log`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub timestamp: DateTime<Utc>,
+    /// The authenticated GitHub user, when it was known
+    pub login: Option<String>,
+    /// `owner/repo`
+    pub repo: String,
+    pub run_id: Option<u64>,
+    pub run_number: Option<u64>,
+    pub action: AuditAction,
+    /// `ok`, or the error GitHub answered with
+    pub outcome: String,
+    /// What else identifies the change, e.g. `CI on main` for a dispatch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl AuditEntry {
+    /// `2025-01-12 10:04  octocat  octo/api  #142  cancel  ok`
+    pub fn display(&self) -> String {
+        let run = self
+            .run_number
+            .map_or_else(|| "—".to_string(), |n| format!("#{}", n));
+        let mut line = format!(
+            "{}  {}  {}  {}  {}",
+            self.timestamp
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M"),
+            self.login.as_deref().unwrap_or("?"),
+            self.repo,
+            run,
+            self.action.label(),
+        );
+        if let Some(detail) = &self.detail {
+            line.push_str(&format!(" ({})", detail));
+        }
+        line.push_str("  ");
+        line.push_str(&self.outcome);
+        line
+    }
+}
+
+/// `ok`, or the error chain on one line
+pub fn outcome<T>(result: &Result<T>) -> String {
+    match result {
+        Ok(_) => "ok".to_string(),
+        Err(e) => format!("error: {:#}", e),
+    }
+}
+
+/// `30m`, `12h`, `7d` or `2w` back from now
+pub fn parse_since(s: &str) -> Result<Duration> {
+    let s = s.trim();
+    let split = s.len() - s.chars().last().map_or(0, char::len_utf8);
+    let (n, unit) = s.split_at(split);
+    let n: i64 = n
+        .parse()
+        .with_context(|| format!("Invalid duration '{}': expected e.g. 7d, 12h or 30m", s))?;
+    match unit {
+        "m" => Ok(Duration::minutes(n)),
+        "h" => Ok(Duration::hours(n)),
+        "d" => Ok(Duration::days(n)),
+        "w" => Ok(Duration::weeks(n)),
+        _ => anyhow::bail!("Invalid duration '{}': use m, h, d or w, e.g. 7d", s),
+    }
+}
+
+// ── Audit log ──────────────────────────────────────────────────────
+
+/// Append-only JSON lines, locked like the export index so concurrent
+/// instances never interleave a line
+#[derive(Debug, Clone)]
+pub struct AuditLog {
+    dir: PathBuf,
+}
+
+impl AuditLog {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// `~/.atlas`
+    pub fn default_location() -> Self {
+        Self::new(state::atlas_dir())
+    }
+
+    pub fn path(&self) -> PathBuf {
+        self.dir.join("audit.log")
+    }
+
+    fn lock_path(&self) -> PathBuf {
+        self.dir.join("audit.lock")
+    }
+
+    pub fn append(&self, entry: &AuditEntry) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        let _lock = FileLock::exclusive(&self.lock_path())?;
+        let path = self.path();
+        let mut line = serde_json::to_string(entry)?;
+        line.push('\n');
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Entries from `since` on, oldest first; lines that don't parse are
+    /// skipped
+    pub fn load(&self, since: Option<DateTime<Utc>>) -> Result<Vec<AuditEntry>> {
+        let path = self.path();
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let _lock = FileLock::shared(&self.lock_path())?;
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Ok(content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| match serde_json::from_str::<AuditEntry>(line) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    warn!(error = %e, "Skipping unreadable audit entry");
+                    None
+                }
+            })
+            .filter(|entry| since.is_none_or(|since| entry.timestamp >= since))
+            .collect())
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn entry(day: u32, action: AuditAction) -> AuditEntry {
+        AuditEntry {
+            timestamp: Utc.with_ymd_and_hms(2025, 1, day, 10, 0, 0).unwrap(),
+            login: Some("octocat".into()),
+            repo: "octo/api".into(),
+            run_id: Some(9000 + day as u64),
+            run_number: Some(140 + day as u64),
+            action,
+            outcome: "ok".into(),
+            detail: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_since() {
+        assert_eq!(parse_since("7d").unwrap(), Duration::days(7));
+        assert_eq!(parse_since("12h").unwrap(), Duration::hours(12));
+        assert_eq!(parse_since("30m").unwrap(), Duration::minutes(30));
+        assert_eq!(parse_since("2w").unwrap(), Duration::weeks(2));
+        assert!(parse_since("7").is_err());
+        assert!(parse_since("d").is_err());
+        assert!(parse_since("7y").is_err());
+        assert!(parse_since("").is_err());
+    }
+
+    #[test]
+    fn test_entries_are_json_lines() {
+        let mut dispatch = entry(3, AuditAction::Dispatch);
+        dispatch.run_id = None;
+        dispatch.run_number = None;
+        dispatch.detail = Some("CI on main".into());
+        let json = serde_json::to_string(&dispatch).unwrap();
+        assert!(json.contains(r#""action":"dispatch""#));
+        assert!(json.contains(r#""detail":"CI on main""#));
+        assert_eq!(serde_json::from_str::<AuditEntry>(&json).unwrap(), dispatch);
+
+        let json = serde_json::to_string(&entry(3, AuditAction::RerunFailed)).unwrap();
+        assert!(json.contains(r#""action":"rerun-failed""#));
+        assert!(!json.contains("detail"));
+    }
+
+    #[test]
+    fn test_append_and_load_since() {
+        let dir = std::env::temp_dir().join(format!("atlas-audit-{}", std::process::id()));
+        let log = AuditLog::new(dir.clone());
+        assert!(log.load(None).unwrap().is_empty());
+
+        let mut failed = entry(5, AuditAction::Cancel);
+        failed.outcome = outcome::<()>(&Err(anyhow::anyhow!("409 Conflict")));
+        for e in [entry(1, AuditAction::Rerun), failed.clone()] {
+            log.append(&e).unwrap();
+        }
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(log.path())
+            .unwrap()
+            .write_all(b"not json\n")
+            .unwrap();
+
+        let all = log.load(None).unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].action, AuditAction::Rerun);
+        assert_eq!(all[1].outcome, "error: 409 Conflict");
+
+        let since = Utc.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap();
+        assert_eq!(log.load(Some(since)).unwrap(), vec![failed]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,123 @@
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use crate::state::{self, FileLock};
+
+// ── Bookmarks ──────────────────────────────────────────────────────
+
+/// `~/.atlas/bookmarks.json`: bookmarked repos as a plain JSON array of
+/// `owner/repo` names, so it can be edited by hand. Read and written under
+/// `bookmarks.lock`, like the state file.
+#[derive(Debug, Clone)]
+pub struct BookmarkFile {
+    path: PathBuf,
+}
+
+impl BookmarkFile {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// `~/.atlas/bookmarks.json`
+    pub fn default_location() -> Self {
+        Self::new(state::atlas_dir().join("bookmarks.json"))
+    }
+
+    fn lock_path(&self) -> PathBuf {
+        self.path.with_extension("lock")
+    }
+
+    /// The bookmarked repos; none before the file exists
+    pub fn load(&self) -> Result<HashSet<String>> {
+        if !self.path.exists() {
+            return Ok(HashSet::new());
+        }
+        let _lock = FileLock::shared(&self.lock_path())?;
+        self.read()
+    }
+
+    /// Apply `f` to the bookmarks on disk and write them back sorted, one
+    /// per line, under an exclusive lock. Bookmarks another instance made
+    /// in between are kept; the merged set is returned.
+    pub fn update(&self, f: impl FnOnce(&mut HashSet<String>)) -> Result<HashSet<String>> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        let _lock = FileLock::exclusive(&self.lock_path())?;
+        let mut bookmarks = self.read()?;
+        f(&mut bookmarks);
+        let mut names: Vec<&String> = bookmarks.iter().collect();
+        names.sort();
+        state::write_atomic(&self.path, &serde_json::to_vec_pretty(&names)?)?;
+        Ok(bookmarks)
+    }
+
+    fn read(&self) -> Result<HashSet<String>> {
+        let content = match std::fs::read(&self.path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(HashSet::new()),
+            Err(e) => {
+                return Err(e).with_context(|| format!("Failed to read {}", self.path.display()))
+            }
+        };
+        serde_json::from_slice(&content)
+            .with_context(|| format!("{} isn't a JSON array of owner/repo", self.path.display()))
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_as_a_plain_array() {
+        let dir = std::env::temp_dir().join(format!("atlas-bookmarks-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let file = BookmarkFile::new(dir.join("bookmarks.json"));
+        assert!(file.load().unwrap().is_empty());
+
+        let bookmarks: HashSet<String> = ["octo/web".into(), "octo/api".into()].into();
+        assert_eq!(
+            file.update(|b| b.extend(bookmarks.clone())).unwrap(),
+            bookmarks
+        );
+        let written = std::fs::read_to_string(dir.join("bookmarks.json")).unwrap();
+        assert_eq!(written, "[\n  \"octo/api\",\n  \"octo/web\"\n]");
+        assert_eq!(file.load().unwrap(), bookmarks);
+
+        std::fs::write(dir.join("bookmarks.json"), "{\"octo/api\": true}").unwrap();
+        assert!(file.load().is_err());
+        assert!(file.update(|b| b.clear()).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_concurrent_updates_are_merged() {
+        let dir = std::env::temp_dir().join(format!("atlas-bookmarks-race-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let file = BookmarkFile::new(dir.join("bookmarks.json"));
+        let handles: Vec<_> = (0..2)
+            .map(|t| {
+                let file = file.clone();
+                std::thread::spawn(move || {
+                    for i in 0..25 {
+                        file.update(|b| {
+                            b.insert(format!("thread{}/repo{}", t, i));
+                        })
+                        .unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(file.load().unwrap().len(), 50);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
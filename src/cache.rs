@@ -0,0 +1,287 @@
+use crate::models::format_bytes;
+
+// ── actions/cache log lines ────────────────────────────────────────
+
+/// One cache restore attempt found in a job log
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheRestore {
+    /// The key restored from; `None` for a miss
+    pub key: Option<String>,
+    /// Size of the restored archive, when the log gives it
+    pub bytes: Option<u64>,
+}
+
+impl CacheRestore {
+    pub fn hit(&self) -> bool {
+        self.key.is_some()
+    }
+}
+
+/// What the `actions/cache` steps of one job (and the `setup-*` actions
+/// built on it) restored
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CacheReport {
+    pub restores: Vec<CacheRestore>,
+}
+
+impl CacheReport {
+    /// Scan a log for restores. Lines may carry GitHub's timestamp and the
+    /// wording differs between versions of the action, so markers are
+    /// matched anywhere in the line, ignoring case.
+    pub fn parse<S: AsRef<str>>(lines: &[S]) -> Self {
+        let mut restores: Vec<CacheRestore> = Vec::new();
+        // A size printed before the restore it belongs to
+        let mut pending_bytes: Option<u64> = None;
+        for line in lines {
+            let line = line.as_ref();
+            let lower = line.to_lowercase();
+            if let Some(key) = after_marker(line, &lower, RESTORED) {
+                // v4 can say "Cache hit for restore-key" and then restored
+                // from that same key
+                let repeat = restores
+                    .last()
+                    .is_some_and(|r| r.key.as_deref() == Some(key.as_str()));
+                if !repeat {
+                    restores.push(CacheRestore {
+                        key: Some(key),
+                        bytes: pending_bytes.take(),
+                    });
+                }
+            } else if let Some(key) = HITS.iter().find_map(|m| after_marker(line, &lower, m)) {
+                restores.push(CacheRestore {
+                    key: Some(key),
+                    bytes: pending_bytes.take(),
+                });
+            } else if MISSES.iter().any(|m| lower.contains(m)) {
+                pending_bytes = None;
+                restores.push(CacheRestore {
+                    key: None,
+                    bytes: None,
+                });
+            } else if let Some(bytes) = size(&lower) {
+                match restores.last_mut() {
+                    // Some versions print the size after the restore
+                    Some(last) if last.hit() && last.bytes.is_none() && pending_bytes.is_none() => {
+                        last.bytes = Some(bytes)
+                    }
+                    _ => pending_bytes = Some(bytes),
+                }
+            }
+        }
+        Self { restores }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.restores.is_empty()
+    }
+
+    pub fn hits(&self) -> usize {
+        self.restores.iter().filter(|r| r.hit()).count()
+    }
+
+    /// Bytes restored, counting only the hits whose size is known
+    pub fn restored_bytes(&self) -> u64 {
+        self.restores.iter().filter_map(|r| r.bytes).sum()
+    }
+
+    /// `cache: hit (312.0 MB)`, `cache: miss`, `cache: 1/2 hit (40.0 MB)`;
+    /// `None` when the job used no cache
+    pub fn label(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let hits = self.hits();
+        let outcome = match hits {
+            0 => return Some("cache: miss".to_string()),
+            n if n == self.restores.len() => "hit".to_string(),
+            n => format!("{}/{} hit", n, self.restores.len()),
+        };
+        Some(match self.restored_bytes() {
+            0 => format!("cache: {}", outcome),
+            bytes => format!("cache: {} ({})", outcome, format_bytes(bytes)),
+        })
+    }
+}
+
+/// Restores across the jobs of a run: `cache: 3 hits, 1 miss · 1.2 GB
+/// restored`; `None` when no loaded log used a cache
+pub fn run_summary<'a>(reports: impl IntoIterator<Item = &'a CacheReport>) -> Option<String> {
+    let (mut hits, mut misses, mut bytes) = (0, 0, 0);
+    for report in reports {
+        hits += report.hits();
+        misses += report.restores.len() - report.hits();
+        bytes += report.restored_bytes();
+    }
+    if hits + misses == 0 {
+        return None;
+    }
+    let plural =
+        |n: usize, one: &str, many: &str| format!("{} {}", n, if n == 1 { one } else { many });
+    let mut summary = format!(
+        "cache: {}, {}",
+        plural(hits, "hit", "hits"),
+        plural(misses, "miss", "misses")
+    );
+    if bytes > 0 {
+        summary.push_str(&format!(" · {} restored", format_bytes(bytes)));
+    }
+    Some(summary)
+}
+
+/// `Cache restored from key: Linux-cargo-3f2a`
+const RESTORED: &str = "cache restored from key:";
+/// Other ways a hit is announced
+const HITS: [&str; 2] = ["cache hit for restore-key:", "cache hit for:"];
+/// `Cache not found for input keys: …` from actions/cache, `Cache is not
+/// found` from setup-go, and a restore that failed outright
+const MISSES: [&str; 3] = [
+    "cache not found for input keys",
+    "cache is not found",
+    "failed to restore:",
+];
+
+/// The key after `marker`, in the line's original case
+fn after_marker(line: &str, lower: &str, marker: &str) -> Option<String> {
+    let start = lower.find(marker)? + marker.len();
+    // Lowercasing can change byte lengths outside ASCII; give up then
+    let key = line.get(start..)?.trim();
+    (!key.is_empty()).then(|| key.to_string())
+}
+
+/// Bytes from `Cache Size: ~312 MB (327155712 B)`, or from just the
+/// rounded `~312 MB` when older versions leave the exact count out
+fn size(lower: &str) -> Option<u64> {
+    let rest = &lower[lower.find("cache size:")? + "cache size:".len()..];
+    let exact = rest
+        .split_once('(')
+        .and_then(|(_, inner)| inner.split_once(" b)"))
+        .and_then(|(n, _)| n.trim().parse().ok());
+    exact.or_else(|| {
+        let mut words = rest.trim().trim_start_matches('~').split_whitespace();
+        let amount: f64 = words.next()?.parse().ok()?;
+        let scale = match words.next()? {
+            "b" => 1u64,
+            "kb" => 1 << 10,
+            "mb" => 1 << 20,
+            "gb" => 1 << 30,
+            _ => return None,
+        };
+        Some((amount * scale as f64) as u64)
+    })
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// actions/cache@v4 restoring an exact key
+    const V4_HIT: &str = "\
+2025-01-12T10:00:01.1000000Z ##[group]Run actions/cache@v4
+2025-01-12T10:00:01.1000000Z with:
+2025-01-12T10:00:01.1000000Z   path: ~/.cargo/registry
+2025-01-12T10:00:01.1000000Z   key: Linux-cargo-3f2a9c
+2025-01-12T10:00:01.1000000Z ##[endgroup]
+2025-01-12T10:00:02.2000000Z Received 104857600 of 327155712 (32.1%), 99.9 MBs/sec
+2025-01-12T10:00:04.3000000Z Received 327155712 of 327155712 (100.0%), 104.0 MBs/sec
+2025-01-12T10:00:04.3000000Z Cache Size: ~312 MB (327155712 B)
+2025-01-12T10:00:04.3000000Z [command]/usr/bin/tar -xf /home/runner/work/_temp/cache.tzst -P -C /home/runner/work/api/api --use-compress-program unzstd
+2025-01-12T10:00:06.9000000Z Cache restored successfully
+2025-01-12T10:00:06.9000000Z Cache restored from key: Linux-cargo-3f2a9c
+";
+
+    /// actions/cache@v3 with no key matching
+    const V3_MISS: &str = "\
+2024-06-03T08:12:44.0000000Z ##[group]Run actions/cache@v3
+2024-06-03T08:12:44.0000000Z ##[endgroup]
+2024-06-03T08:12:44.9000000Z Cache not found for input keys: Linux-node-8d1e, Linux-node-
+";
+
+    /// actions/setup-node restoring its npm cache, then setup-go missing
+    const SETUP_ACTIONS: &str = "\
+2025-02-20T15:31:09.0000000Z Cache Size: ~48 MB (50331648 B)
+2025-02-20T15:31:09.0000000Z [command]/usr/bin/tar -xf /home/runner/work/_temp/7b1/cache.tzst -P -C /home/runner/work/web/web --use-compress-program unzstd
+2025-02-20T15:31:10.0000000Z Cache restored successfully
+2025-02-20T15:31:10.0000000Z Cache restored from key: node-cache-Linux-x64-npm-c0ffee
+2025-02-20T15:31:21.0000000Z Cache is not found
+";
+
+    /// An older runner: the rounded size only, printed after the restore
+    const OLD_SIZE_AFTER: &str = "\
+Cache restored from key: Windows-pip-77aa
+Cache Size: ~2 GB
+";
+
+    fn lines(log: &str) -> Vec<&str> {
+        log.lines().collect()
+    }
+
+    #[test]
+    fn test_hit_with_exact_size() {
+        let report = CacheReport::parse(&lines(V4_HIT));
+        assert_eq!(
+            report.restores,
+            vec![CacheRestore {
+                key: Some("Linux-cargo-3f2a9c".into()),
+                bytes: Some(327_155_712),
+            }]
+        );
+        assert_eq!(report.label().as_deref(), Some("cache: hit (312.0 MB)"));
+    }
+
+    #[test]
+    fn test_miss() {
+        let report = CacheReport::parse(&lines(V3_MISS));
+        assert_eq!(report.hits(), 0);
+        assert_eq!(report.label().as_deref(), Some("cache: miss"));
+    }
+
+    #[test]
+    fn test_mixed_restores() {
+        let report = CacheReport::parse(&lines(SETUP_ACTIONS));
+        assert_eq!(report.restores.len(), 2);
+        assert_eq!(
+            report.restores[0].key.as_deref(),
+            Some("node-cache-Linux-x64-npm-c0ffee")
+        );
+        assert_eq!(report.label().as_deref(), Some("cache: 1/2 hit (48.0 MB)"));
+    }
+
+    #[test]
+    fn test_tolerates_other_wordings() {
+        let report = CacheReport::parse(&lines(OLD_SIZE_AFTER));
+        assert_eq!(report.restores[0].bytes, Some(2 << 30));
+        assert_eq!(report.label().as_deref(), Some("cache: hit (2.0 GB)"));
+
+        // A hit announced twice is one restore
+        let twice = CacheReport::parse(&[
+            "Cache hit for restore-key: Linux-cargo-",
+            "Cache restored from key: Linux-cargo-",
+        ]);
+        assert_eq!(twice.restores.len(), 1);
+
+        let failed =
+            CacheReport::parse(&["##[warning]Failed to restore: Cache service responded with 503"]);
+        assert_eq!(failed.label().as_deref(), Some("cache: miss"));
+
+        // Logs without caching say nothing
+        let plain = CacheReport::parse(&["Run cargo test", "test result: ok"]);
+        assert!(plain.is_empty());
+        assert_eq!(plain.label(), None);
+    }
+
+    #[test]
+    fn test_run_summary() {
+        let reports = [
+            CacheReport::parse(&lines(V4_HIT)),
+            CacheReport::parse(&lines(V3_MISS)),
+            CacheReport::parse(&lines(SETUP_ACTIONS)),
+        ];
+        assert_eq!(
+            run_summary(&reports).as_deref(),
+            Some("cache: 2 hits, 2 misses · 360.0 MB restored")
+        );
+        assert_eq!(run_summary(&[CacheReport::default()]), None);
+    }
+}
@@ -0,0 +1,223 @@
+use anyhow::{Context, Result};
+use std::time::{Duration, Instant};
+
+use crate::github::GitHubClient;
+use crate::lookup;
+use crate::models::{format_duration, WorkflowRun};
+
+/// How often `atlas ci` polls a run still going
+pub const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+// ── Deploy gate ────────────────────────────────────────────────────
+
+/// What `atlas ci` waits on: the latest run on `branch`, of `workflow` when
+/// given
+#[derive(Debug, Clone)]
+pub struct Gate {
+    pub branch: String,
+    /// A workflow's name or file name (`deploy.yml`)
+    pub workflow: Option<String>,
+    /// Give up after this long; `None` waits for as long as it takes
+    pub timeout: Option<Duration>,
+}
+
+/// How the gate ended
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Passed,
+    /// Anything but success: the run's conclusion
+    Failed(String),
+    TimedOut,
+}
+
+impl Outcome {
+    /// 0 passed, 1 failed or cancelled, 2 gave up waiting
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Self::Passed => 0,
+            Self::Failed(_) => 1,
+            Self::TimedOut => 2,
+        }
+    }
+}
+
+/// Find the latest run for `gate`, then poll it every `interval` until it
+/// completes or the timeout runs out, with a line on stderr per poll
+pub async fn run(client: &GitHubClient, gate: &Gate, interval: Duration) -> Result<Outcome> {
+    let workflow_id = match &gate.workflow {
+        Some(name) => Some(find_workflow(client, name).await?),
+        None => None,
+    };
+    let mut run = lookup::latest_run(client, Some(&gate.branch), None, workflow_id).await?;
+
+    let started = Instant::now();
+    loop {
+        eprintln!("{}", progress_line(&run, &gate.branch, started.elapsed()));
+        if run.status.as_deref() == Some("completed") {
+            return Ok(match run.conclusion.as_deref() {
+                Some("success") => Outcome::Passed,
+                other => Outcome::Failed(other.unwrap_or("unknown").to_string()),
+            });
+        }
+        let left = gate.timeout.map(|t| t.saturating_sub(started.elapsed()));
+        if left == Some(Duration::ZERO) {
+            eprintln!(
+                "Gave up on #{} after {}",
+                run.run_number,
+                format_duration(started.elapsed().as_secs() as i64)
+            );
+            return Ok(Outcome::TimedOut);
+        }
+        tokio::time::sleep(left.map_or(interval, |left| left.min(interval))).await;
+        run = client.get_workflow_run(run.id).await?;
+    }
+}
+
+/// The id of the workflow called `name`, or whose file is
+async fn find_workflow(client: &GitHubClient, name: &str) -> Result<u64> {
+    let workflows = client.get_workflows().await?;
+    workflows
+        .iter()
+        .find(|w| {
+            let file = w.path.rsplit('/').next().unwrap_or(&w.path);
+            [w.name.as_str(), file, w.path.as_str()]
+                .iter()
+                .any(|candidate| candidate.eq_ignore_ascii_case(name))
+        })
+        .map(|w| w.id)
+        .with_context(|| {
+            let names: Vec<_> = workflows.iter().map(|w| w.name.as_str()).collect();
+            format!(
+                "No workflow named {} (the repo has: {})",
+                name,
+                names.join(", ")
+            )
+        })
+}
+
+/// `[1m 20s] CI #42 on main: in progress`
+fn progress_line(run: &WorkflowRun, branch: &str, waited: Duration) -> String {
+    let state = match (run.status.as_deref(), run.conclusion.as_deref()) {
+        (Some("completed"), Some(conclusion)) => conclusion,
+        (status, _) => status.unwrap_or("unknown"),
+    };
+    format!(
+        "[{}] {} #{} on {}: {}",
+        format_duration(waited.as_secs() as i64),
+        run.name.as_deref().unwrap_or("Workflow"),
+        run.run_number,
+        branch,
+        state.replace('_', " ")
+    )
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, client, run_json};
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn api_with_run(first: serde_json::Value, then: serde_json::Value) -> MockServer {
+        let api = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/workflows"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 1,
+                "workflows": [{
+                    "id": 9, "name": "Deploy", "path": ".github/workflows/deploy.yml",
+                    "state": "active", "html_url": ""
+                }]
+            })))
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/workflows/9/runs"))
+            .and(query_param("branch", "main"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 1,
+                "workflow_runs": [first]
+            })))
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs/7"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(then))
+            .mount(&api)
+            .await;
+        api
+    }
+
+    fn gate(timeout: Option<Duration>) -> Gate {
+        Gate {
+            branch: "main".into(),
+            workflow: Some("deploy.yml".into()),
+            timeout,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_waits_for_the_run_to_finish() {
+        let api = api_with_run(
+            run_json("in_progress", None),
+            run_json("completed", Some("success")),
+        )
+        .await;
+        let outcome = run(&client(&api), &gate(None), Duration::from_millis(10))
+            .await
+            .unwrap();
+        assert_eq!(outcome, Outcome::Passed);
+        assert_eq!(outcome.exit_code(), 0);
+
+        let api = api_with_run(
+            run_json("queued", None),
+            run_json("completed", Some("cancelled")),
+        )
+        .await;
+        let outcome = run(&client(&api), &gate(None), Duration::from_millis(10))
+            .await
+            .unwrap();
+        assert_eq!(outcome, Outcome::Failed("cancelled".into()));
+        assert_eq!(outcome.exit_code(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_at_the_timeout() {
+        let api = api_with_run(run_json("in_progress", None), run_json("in_progress", None)).await;
+        let outcome = run(
+            &client(&api),
+            &gate(Some(Duration::from_millis(50))),
+            Duration::from_millis(10),
+        )
+        .await
+        .unwrap();
+        assert_eq!(outcome, Outcome::TimedOut);
+        assert_eq!(outcome.exit_code(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_workflow_lists_the_real_ones() {
+        let api = api_with_run(run_json("queued", None), run_json("queued", None)).await;
+        let mut gate = gate(None);
+        gate.workflow = Some("release".into());
+        let err = run(&client(&api), &gate, POLL_INTERVAL).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "No workflow named release (the repo has: Deploy)"
+        );
+    }
+
+    #[test]
+    fn test_progress_line() {
+        assert_eq!(
+            progress_line(
+                &test_support::run("in_progress", None),
+                "main",
+                Duration::from_secs(80)
+            ),
+            "[1m 20s] CI #42 on main: in progress"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use clap::CommandFactory;
+use clap_complete::Shell;
+
+use crate::account::Account;
+use crate::bookmarks::BookmarkFile;
+use crate::github::DEFAULT_BASE_URL;
+use crate::state::{self, StateStore};
+use crate::Cli;
+
+/// What the completion scripts run to list repos for `--repo`
+const REPOS_COMMAND: &str = "completions --list-repos";
+
+// ── Completion scripts ─────────────────────────────────────────────
+
+/// The completion script for `shell`, with `--repo` completing from the
+/// bookmarked and recently opened repos
+pub fn script(shell: Shell) -> String {
+    let mut buf = Vec::new();
+    clap_complete::generate(shell, &mut Cli::command(), "atlas", &mut buf);
+    let script = String::from_utf8_lossy(&buf).into_owned();
+    match shell {
+        Shell::Bash => bash_repos(script),
+        Shell::Zsh => zsh_repos(script),
+        Shell::Fish => {
+            script
+                + &format!(
+                    "complete -c atlas -s r -l repo -x -a \"(atlas {} 2>/dev/null)\"\n",
+                    REPOS_COMMAND
+                )
+        }
+        _ => script,
+    }
+}
+
+/// clap's bash script completes `--repo` with file names; list repos instead
+fn bash_repos(script: String) -> String {
+    let repos = format!(
+        "COMPREPLY=($(compgen -W \"$(atlas {} 2>/dev/null)\" -- \"${{cur}}\"))",
+        REPOS_COMMAND
+    );
+    let mut out = String::with_capacity(script.len());
+    let mut in_repo_case = false;
+    for line in script.lines() {
+        let trimmed = line.trim();
+        if trimmed == "--repo)" || trimmed == "-r)" {
+            in_repo_case = true;
+        } else if trimmed == ";;" {
+            in_repo_case = false;
+        }
+        if in_repo_case && trimmed.starts_with("COMPREPLY=") {
+            let indent = &line[..line.len() - line.trim_start().len()];
+            out.push_str(indent);
+            out.push_str(&repos);
+        } else {
+            out.push_str(line);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Point zsh's `--repo` argument at a function that lists repos
+fn zsh_repos(script: String) -> String {
+    let script = script
+        .lines()
+        .map(|line| {
+            let is_repo = line.contains("'-r+[") || line.contains("'--repo=[");
+            match line.rfind(":_default'") {
+                Some(i) if is_repo => format!("{}:_atlas_repos'{}", &line[..i], &line[i + 10..]),
+                _ => line.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        "_atlas_repos() {{\n    local -a repos\n    repos=(${{(f)\"$(atlas {} 2>/dev/null)\"}})\n    compadd -a repos\n}}\n\n{}\n",
+        REPOS_COMMAND, script
+    )
+}
+
+/// Print the bookmarked repos, then the last-used account's recent ones
+/// (newest first), one per line
+pub fn print_repos(api_url: Option<&str>) {
+    let atlas_dir = state::atlas_dir();
+    let mut bookmarks: Vec<String> = BookmarkFile::new(atlas_dir.join("bookmarks.json"))
+        .load()
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+    bookmarks.sort();
+    let state = Account::last_used(&atlas_dir, api_url.unwrap_or(DEFAULT_BASE_URL))
+        .and_then(|account| StateStore::new(account.state_dir(&atlas_dir)).load().ok())
+        .unwrap_or_default();
+    let mut recent: Vec<_> = state.recent.into_iter().collect();
+    recent.sort_by_key(|(_, t)| std::cmp::Reverse(*t));
+    let recent = recent.into_iter().map(|(name, _)| name);
+    let mut seen = std::collections::HashSet::new();
+    for name in bookmarks.into_iter().chain(recent) {
+        if seen.insert(name.clone()) {
+            println!("{}", name);
+        }
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scripts_mention_main_flags() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+            let script = script(shell);
+            assert!(!script.is_empty(), "{shell} script is empty");
+            // fish spells flags as `-l token`
+            for flag in ["repo", "token", "api-url", "latest-failure", "output"] {
+                assert!(script.contains(flag), "{shell} script lacks {flag}");
+            }
+            // Every subcommand, however deep
+            let mut commands: Vec<_> = Cli::command().get_subcommands().cloned().collect();
+            while let Some(command) = commands.pop() {
+                let name = command.get_name();
+                assert!(script.contains(name), "{shell} script lacks {name}");
+                commands.extend(command.get_subcommands().cloned());
+            }
+            // Values of enum options
+            for value in ["json", "table"] {
+                assert!(script.contains(value), "{shell} script lacks {value}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_bash_script_registers_completion() {
+        use std::io::Write;
+        use std::process::{Command, Stdio};
+
+        let Ok(mut bash) = Command::new("bash")
+            .args(["-c", "source /dev/stdin && complete -p atlas"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+        else {
+            // No bash to try it in (Windows)
+            return;
+        };
+        bash.stdin
+            .take()
+            .unwrap()
+            .write_all(script(Shell::Bash).as_bytes())
+            .unwrap();
+        let output = bash.wait_with_output().unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let registered = String::from_utf8_lossy(&output.stdout);
+        assert!(registered.contains("_atlas"), "{registered}");
+        assert!(registered.trim_end().ends_with(" atlas"), "{registered}");
+    }
+
+    #[test]
+    fn test_repo_completion_uses_state_file() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+            assert!(
+                script(shell).contains(REPOS_COMMAND),
+                "{shell} script doesn't complete repos"
+            );
+        }
+        // Other options keep clap's completion
+        let bash = script(Shell::Bash);
+        let token = bash.find("--token)").unwrap();
+        assert!(bash[token..].lines().nth(1).unwrap().contains("compgen -f"));
+    }
+}
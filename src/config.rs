@@ -0,0 +1,330 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+use crate::protected;
+use crate::theme::{ThemeRegistry, DEFAULT_THEME};
+
+/// What `atlas config init` writes: every setting at its default
+pub const DEFAULT_CONFIG: &str = r#"# Atlas configuration. Command-line flags override these settings.
+
+# Runs per page (1-100)
+per_page = 20
+
+# Reload runs and run details every N seconds; 0 turns it off (--refresh)
+refresh_secs = 0
+
+# Color theme: dark, light, gruvbox, or nord (--theme)
+theme = "dark"
+
+# Repository to open when there is no --repo and the current directory is
+# not a GitHub checkout
+# default_repo = "owner/repo"
+
+# GitHub Enterprise API base URL (--api-url, GITHUB_API_URL)
+# api_url = "https://github.example.com/api/v3"
+
+# Ask y/N before a re-run or cancel; false goes ahead at once, except for
+# protected workflows (--yes)
+confirm_actions = true
+
+# Workflows whose re-run, cancel or dispatch is confirmed by typing the run
+# number (or "yes") instead of pressing y: case-insensitive globs matched
+# against the workflow's name and file name
+protected_workflows = ["*prod*", "*release*"]
+
+# Highlight project-specific markers in job logs
+# [[log.highlight]]
+# pattern = "DEPLOY-MARKER"
+# color = "magenta"
+
+[downloads]
+# Quitting while a download runs: "confirm" asks, "finish" saves it on exit
+on_quit = "confirm"
+
+[alerts]
+# Status-bar warning when a run goes past its workflow's usual duration
+stuck_runs = true
+
+[api_cache]
+# Reuse jobs, workflows and artifacts fetched in the last N seconds, e.g.
+# going back to a run just left; refreshing always refetches. 0 turns it off
+ttl_secs = 30
+# Responses kept in memory at most
+max_entries = 200
+"#;
+
+// ── Config file ────────────────────────────────────────────────────
+
+/// User settings from `~/.atlas/config.toml` (or the path in `ATLAS_CONFIG`)
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Runs per page
+    pub per_page: u8,
+    /// Auto-refresh interval; 0 is off
+    pub refresh_secs: u64,
+    pub theme: String,
+    /// Opened when there's no `--repo` and no GitHub checkout
+    pub default_repo: Option<String>,
+    pub api_url: Option<String>,
+    /// Ask before re-runs and cancels
+    pub confirm_actions: bool,
+    /// Globs naming workflows that need a typed confirmation
+    pub protected_workflows: Vec<String>,
+    pub log: LogConfig,
+    pub downloads: DownloadsConfig,
+    pub alerts: AlertsConfig,
+    pub api_cache: ApiCacheConfig,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            per_page: 20,
+            refresh_secs: 0,
+            theme: DEFAULT_THEME.to_string(),
+            default_repo: None,
+            api_url: None,
+            confirm_actions: true,
+            protected_workflows: protected::DEFAULT_PATTERNS
+                .iter()
+                .map(|p| p.to_string())
+                .collect(),
+            log: LogConfig::default(),
+            downloads: DownloadsConfig::default(),
+            alerts: AlertsConfig::default(),
+            api_cache: ApiCacheConfig::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Settings that are usable but probably not what was meant
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let themes = ThemeRegistry::builtin();
+        if themes.get(&self.theme).is_none() {
+            warnings.push(format!(
+                "Unknown theme \"{}\" (available: {}) — using {}",
+                self.theme,
+                themes.names().join(", "),
+                DEFAULT_THEME
+            ));
+        }
+        warnings
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogConfig {
+    /// Extra patterns to highlight inside log lines
+    pub highlight: Vec<HighlightRule>,
+}
+
+/// One `[[log.highlight]]` entry: a regex and the color for its matches
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HighlightRule {
+    pub pattern: String,
+    pub color: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DownloadsConfig {
+    /// What quitting does while a download is still running
+    pub on_quit: QuitBehavior,
+}
+
+/// `downloads.on_quit`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuitBehavior {
+    /// Ask before throwing the download away
+    #[default]
+    Confirm,
+    /// Quit the TUI, then finish the download and save it to disk
+    Finish,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AlertsConfig {
+    /// Announce runs in progress for longer than their workflow's p95
+    pub stuck_runs: bool,
+}
+
+impl Default for AlertsConfig {
+    fn default() -> Self {
+        Self { stuck_runs: true }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ApiCacheConfig {
+    /// How long a cached response is reused; 0 is off
+    pub ttl_secs: u64,
+    pub max_entries: usize,
+}
+
+impl Default for ApiCacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl_secs: 30,
+            max_entries: 200,
+        }
+    }
+}
+
+/// Where the config file lives (`ATLAS_CONFIG` wins over the default)
+pub fn config_path() -> PathBuf {
+    if let Ok(path) = std::env::var("ATLAS_CONFIG") {
+        if !path.is_empty() {
+            return PathBuf::from(path);
+        }
+    }
+    std::env::var("HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("."))
+        .join(".atlas")
+        .join("config.toml")
+}
+
+/// Load the config file; a missing file means all defaults
+pub fn load() -> Result<Config> {
+    load_from(&config_path())
+}
+
+pub fn load_from(path: &Path) -> Result<Config> {
+    if !path.exists() {
+        return Ok(Config::default());
+    }
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    parse(&content).with_context(|| format!("Invalid config file {}", path.display()))
+}
+
+pub fn parse(content: &str) -> Result<Config> {
+    let config: Config = toml::from_str(content)?;
+    if !(1..=100).contains(&config.per_page) {
+        anyhow::bail!(
+            "per_page must be between 1 and 100, got {}",
+            config.per_page
+        );
+    }
+    Ok(config)
+}
+
+/// `atlas config init`: write the commented defaults to `path`
+pub fn init(path: &Path, force: bool) -> Result<()> {
+    if path.exists() && !force {
+        anyhow::bail!(
+            "{} already exists (pass --force to overwrite it)",
+            path.display()
+        );
+    }
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    std::fs::write(path, DEFAULT_CONFIG)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_config_is_default() {
+        let config = parse("").unwrap();
+        assert!(config.log.highlight.is_empty());
+    }
+
+    #[test]
+    fn test_parse_highlight_rules() {
+        let config = parse(
+            r##"
+            [[log.highlight]]
+            pattern = "DEPLOY-MARKER"
+            color = "magenta"
+
+            [[log.highlight]]
+            pattern = 'PAY-\d+'
+            color = "#ffaa00"
+            "##,
+        )
+        .unwrap();
+        assert_eq!(config.log.highlight.len(), 2);
+        assert_eq!(config.log.highlight[1].pattern, r"PAY-\d+");
+        assert_eq!(config.log.highlight[1].color, "#ffaa00");
+    }
+
+    #[test]
+    fn test_parse_quit_behavior() {
+        assert_eq!(parse("").unwrap().downloads.on_quit, QuitBehavior::Confirm);
+        let config = parse("[downloads]\non_quit = \"finish\"").unwrap();
+        assert_eq!(config.downloads.on_quit, QuitBehavior::Finish);
+        assert!(parse("[downloads]\non_quit = \"later\"").is_err());
+    }
+
+    #[test]
+    fn test_default_config_file_matches_defaults() {
+        let config = parse(DEFAULT_CONFIG).unwrap();
+        let defaults = Config::default();
+        assert_eq!(config.per_page, defaults.per_page);
+        assert_eq!(config.refresh_secs, defaults.refresh_secs);
+        assert_eq!(config.theme, defaults.theme);
+        assert_eq!(config.default_repo, None);
+        assert_eq!(config.confirm_actions, defaults.confirm_actions);
+        assert_eq!(config.protected_workflows, defaults.protected_workflows);
+        assert_eq!(config.alerts.stuck_runs, defaults.alerts.stuck_runs);
+        assert_eq!(config.api_cache.ttl_secs, defaults.api_cache.ttl_secs);
+        assert_eq!(config.api_cache.max_entries, defaults.api_cache.max_entries);
+        assert!(config.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_parse_settings() {
+        let config = parse(
+            r#"
+            per_page = 50
+            refresh_secs = 30
+            theme = "solarized"
+            default_repo = "octo/api"
+            protected_workflows = ["deploy-*"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.protected_workflows, ["deploy-*"]);
+        assert_eq!(config.per_page, 50);
+        assert_eq!(config.refresh_secs, 30);
+        assert_eq!(config.default_repo.as_deref(), Some("octo/api"));
+        assert_eq!(config.warnings().len(), 1);
+        assert!(parse("per_page = 0").is_err());
+        assert!(parse("per_page = 101").is_err());
+    }
+
+    #[test]
+    fn test_init_refuses_to_overwrite() {
+        let path = std::env::temp_dir()
+            .join(format!("atlas-config-init-{}", std::process::id()))
+            .join("config.toml");
+        init(&path, false).unwrap();
+        assert!(init(&path, false).is_err());
+        std::fs::write(&path, "per_page = 5").unwrap();
+        init(&path, true).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), DEFAULT_CONFIG);
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_unknown_keys_are_rejected() {
+        assert!(parse("[log]\nhighlights = []").is_err());
+    }
+}
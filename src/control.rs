@@ -0,0 +1,258 @@
+use anyhow::{bail, Result};
+use chrono::Utc;
+use std::time::{Duration, Instant};
+
+use crate::audit::{self, AuditAction, AuditEntry, AuditLog};
+use crate::github::GitHubClient;
+use crate::lookup;
+use crate::models::WorkflowRun;
+use crate::watch::{self, Outcome};
+
+/// How long `atlas rerun --wait` gives GitHub to start the new attempt
+const ATTEMPT_TIMEOUT: Duration = Duration::from_secs(60);
+
+// ── Rerun and cancel ───────────────────────────────────────────────
+
+/// What `atlas rerun` or `atlas cancel` does to a run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    Rerun,
+    /// Only the failed and cancelled jobs, and what depends on them
+    RerunFailed,
+    Cancel,
+}
+
+impl Change {
+    fn audit_action(self) -> AuditAction {
+        match self {
+            Self::Rerun => AuditAction::Rerun,
+            Self::RerunFailed => AuditAction::RerunFailed,
+            Self::Cancel => AuditAction::Cancel,
+        }
+    }
+}
+
+/// The run with id `run`, or else the latest on `branch`
+pub async fn resolve_run(
+    client: &GitHubClient,
+    run: Option<u64>,
+    branch: Option<&str>,
+) -> Result<WorkflowRun> {
+    if let Some(id) = run {
+        return client.get_workflow_run(id).await;
+    }
+    lookup::latest_run(client, branch, None, None).await
+}
+
+/// Apply `change` to `run` and record it in `audit_log` under the token's
+/// login; the run's URL is printed on success
+pub async fn apply(
+    client: &GitHubClient,
+    run: &WorkflowRun,
+    change: Change,
+    audit_log: &AuditLog,
+) -> Result<()> {
+    check(run, change)?;
+    // The change goes ahead without a login, but the entry says why
+    let (login, detail) = match client.get_login().await {
+        Ok(login) => (Some(login), None),
+        Err(e) => {
+            eprintln!("Warning: the audit log won't name who did this: {:#}", e);
+            (None, Some(format!("login unknown: {:#}", e)))
+        }
+    };
+    let result = match change {
+        Change::Rerun => client.rerun_workflow(run.id, false).await,
+        Change::RerunFailed => client.rerun_failed_jobs(run.id, false).await,
+        Change::Cancel => client.cancel_workflow(run.id).await,
+    };
+    let entry = AuditEntry {
+        timestamp: Utc::now(),
+        login,
+        repo: format!("{}/{}", client.owner, client.repo),
+        run_id: Some(run.id),
+        run_number: Some(run.run_number),
+        action: change.audit_action(),
+        outcome: audit::outcome(&result),
+        detail,
+    };
+    if let Err(e) = audit_log.append(&entry) {
+        eprintln!("Warning: the audit log wasn't written: {:#}", e);
+    }
+    result?;
+    println!("{}", run.html_url);
+    Ok(())
+}
+
+/// Refuse what GitHub would, before asking it
+fn check(run: &WorkflowRun, change: Change) -> Result<()> {
+    let finished = run.status.as_deref() == Some("completed");
+    match change {
+        Change::Cancel if finished => bail!(
+            "Run #{} has already finished: there's nothing to cancel",
+            run.run_number
+        ),
+        Change::Rerun | Change::RerunFailed if !finished => bail!(
+            "Run #{} is still in progress: cancel it first, or wait for it to finish",
+            run.run_number
+        ),
+        Change::RerunFailed if run.conclusion.as_deref() == Some("success") => bail!(
+            "Run #{} succeeded: there are no failed jobs to re-run",
+            run.run_number
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// Wait for GitHub to start `run`'s next attempt, then follow it like
+/// `atlas watch` until it finishes or `timeout` passes
+pub async fn wait(
+    client: &GitHubClient,
+    run: &WorkflowRun,
+    timeout: Option<Duration>,
+    interval: Duration,
+) -> Result<Outcome> {
+    let started = Instant::now();
+    // Right after the request the run may still read as the old attempt
+    loop {
+        tokio::time::sleep(interval).await;
+        let now = client.get_workflow_run(run.id).await?;
+        if now.run_attempt > run.run_attempt || now.status.as_deref() != Some("completed") {
+            break;
+        }
+        if timeout.is_some_and(|t| started.elapsed() >= t) {
+            return Ok(Outcome::TimedOut);
+        }
+        if started.elapsed() >= ATTEMPT_TIMEOUT {
+            bail!(
+                "GitHub hasn't started run #{} again after {}s",
+                run.run_number,
+                ATTEMPT_TIMEOUT.as_secs()
+            );
+        }
+    }
+    let left = timeout.map(|t| t.saturating_sub(started.elapsed()));
+    watch::run(client, &watch::Target::Run(run.id), left, interval, false).await
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{client, run, run_json};
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Attempt `attempt` of run 7, completed with `conclusion`
+    fn attempt_json(attempt: u64, conclusion: &str) -> serde_json::Value {
+        let mut run = run_json("completed", Some(conclusion));
+        run["run_attempt"] = attempt.into();
+        run
+    }
+
+    #[test]
+    fn test_check_refuses_what_github_would() {
+        let running = run("in_progress", None);
+        assert!(check(&running, Change::Cancel).is_ok());
+        assert_eq!(
+            check(&running, Change::Rerun).unwrap_err().to_string(),
+            "Run #42 is still in progress: cancel it first, or wait for it to finish"
+        );
+        let passed = run("completed", Some("success"));
+        assert!(check(&passed, Change::Rerun).is_ok());
+        assert_eq!(
+            check(&passed, Change::RerunFailed).unwrap_err().to_string(),
+            "Run #42 succeeded: there are no failed jobs to re-run"
+        );
+        assert_eq!(
+            check(&passed, Change::Cancel).unwrap_err().to_string(),
+            "Run #42 has already finished: there's nothing to cancel"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rerun_latest_on_branch_is_audited() {
+        let api = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs"))
+            .and(query_param("branch", "main"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 1,
+                "workflow_runs": [run_json("completed", Some("failure"))]
+            })))
+            .mount(&api)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octo/api/actions/runs/7/rerun-failed-jobs"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"login": "octocat"})),
+            )
+            .up_to_n_times(1)
+            .mount(&api)
+            .await;
+
+        let dir = std::env::temp_dir().join(format!("atlas-control-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let audit_log = AuditLog::new(dir.clone());
+        let client = client(&api);
+        let latest = resolve_run(&client, None, Some("main")).await.unwrap();
+        apply(&client, &latest, Change::RerunFailed, &audit_log)
+            .await
+            .unwrap();
+
+        // The login lookup fails the second time
+        apply(&client, &latest, Change::Rerun, &audit_log)
+            .await
+            .unwrap_err();
+
+        let entries = audit_log.load(None).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].action, AuditAction::RerunFailed);
+        assert_eq!(entries[0].login.as_deref(), Some("octocat"));
+        assert_eq!(entries[0].run_number, Some(42));
+        assert_eq!(entries[0].outcome, "ok");
+        assert_eq!(entries[1].login, None);
+        assert!(entries[1]
+            .detail
+            .as_deref()
+            .is_some_and(|detail| detail.starts_with("login unknown: ")));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_wait_follows_the_new_attempt() {
+        let api = MockServer::start().await;
+        // GitHub is slow to show the new attempt
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs/7"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(attempt_json(1, "failure")))
+            .up_to_n_times(1)
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs/7"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(attempt_json(2, "success")))
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs/7/jobs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 0, "jobs": []
+            })))
+            .mount(&api)
+            .await;
+
+        let failed = run("completed", Some("failure"));
+        let outcome = wait(&client(&api), &failed, None, Duration::from_millis(10))
+            .await
+            .unwrap();
+        assert_eq!(outcome, Outcome::Succeeded);
+    }
+}
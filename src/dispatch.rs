@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+
+// ── Workflow inputs ────────────────────────────────────────────────
+
+/// One `on.workflow_dispatch.inputs` entry from a workflow file
+#[derive(Debug, Clone, PartialEq)]
+pub struct DispatchInput {
+    pub name: String,
+    pub description: Option<String>,
+    pub default: Option<String>,
+    pub required: bool,
+    /// Allowed values for `type: choice`
+    pub options: Vec<String>,
+}
+
+/// The `workflow_dispatch` inputs declared in a workflow file. `None` when
+/// the file doesn't parse or has no `workflow_dispatch` trigger.
+pub fn dispatch_inputs(content: &str) -> Option<Vec<DispatchInput>> {
+    let doc: serde_norway::Value = serde_norway::from_str(content).ok()?;
+    // `on: workflow_dispatch`, `on: [push, workflow_dispatch]`, or a map
+    let on = doc.get("on")?;
+    let trigger = match on {
+        serde_norway::Value::String(s) => return (s == "workflow_dispatch").then(Vec::new),
+        serde_norway::Value::Sequence(seq) => {
+            let listed = seq.iter().any(|v| v.as_str() == Some("workflow_dispatch"));
+            return listed.then(Vec::new);
+        }
+        serde_norway::Value::Mapping(map) => map.get("workflow_dispatch")?,
+        _ => return None,
+    };
+
+    let Some(inputs) = trigger.get("inputs").and_then(|i| i.as_mapping()) else {
+        return Some(Vec::new());
+    };
+    let inputs = inputs
+        .iter()
+        .filter_map(|(name, spec)| {
+            let scalar = |key: &str| spec.get(key).and_then(yaml_scalar);
+            Some(DispatchInput {
+                name: name.as_str()?.to_string(),
+                description: scalar("description"),
+                default: scalar("default"),
+                required: spec
+                    .get("required")
+                    .and_then(|r| r.as_bool())
+                    .unwrap_or(false),
+                options: spec
+                    .get("options")
+                    .and_then(|o| o.as_sequence())
+                    .map(|seq| seq.iter().filter_map(yaml_scalar).collect())
+                    .unwrap_or_default(),
+            })
+        })
+        .collect();
+    Some(inputs)
+}
+
+/// Strings, numbers and booleans as the text GitHub would receive
+fn yaml_scalar(value: &serde_norway::Value) -> Option<String> {
+    match value {
+        serde_norway::Value::String(s) => Some(s.clone()),
+        serde_norway::Value::Bool(b) => Some(b.to_string()),
+        serde_norway::Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+// ── Dispatch form ──────────────────────────────────────────────────
+
+/// A text field in the dispatch popup
+#[derive(Debug, Clone)]
+pub struct FormField {
+    pub name: String,
+    pub value: String,
+    pub required: bool,
+    /// Description, or the allowed choices
+    pub hint: Option<String>,
+}
+
+/// Popup state for `d`: the git ref, then one field per workflow input
+#[derive(Debug, Clone)]
+pub struct DispatchForm {
+    pub workflow_id: u64,
+    pub workflow_name: String,
+    /// Field 0 is always the ref
+    pub fields: Vec<FormField>,
+    pub selected: usize,
+    /// Still fetching the workflow file for its inputs
+    pub loading_inputs: bool,
+    /// The inputs are unknown, so field 1 takes `key=value` pairs
+    pub free_form: bool,
+    pub error: Option<String>,
+}
+
+impl DispatchForm {
+    pub fn new(workflow_id: u64, workflow_name: String, git_ref: String) -> Self {
+        Self {
+            workflow_id,
+            workflow_name,
+            fields: vec![FormField {
+                name: "ref".to_string(),
+                value: git_ref,
+                required: true,
+                hint: Some("branch or tag to run on".to_string()),
+            }],
+            selected: 0,
+            loading_inputs: true,
+            free_form: false,
+            error: None,
+        }
+    }
+
+    /// Add a field for each declared input, pre-filled with its default
+    pub fn set_inputs(&mut self, inputs: Vec<DispatchInput>) {
+        self.loading_inputs = false;
+        self.fields.truncate(1);
+        self.fields
+            .extend(inputs.into_iter().map(|input| FormField {
+                hint: if input.options.is_empty() {
+                    input.description
+                } else {
+                    Some(input.options.join(" | "))
+                },
+                value: input.default.unwrap_or_default(),
+                required: input.required,
+                name: input.name,
+            }));
+    }
+
+    /// When the workflow file couldn't be read: a single field taking
+    /// whatever inputs the workflow wants as `key=value` pairs
+    pub fn set_free_inputs(&mut self) {
+        self.set_inputs(Vec::new());
+        self.free_form = true;
+        self.fields.push(FormField {
+            name: "inputs".to_string(),
+            value: String::new(),
+            required: false,
+            hint: Some("key=value pairs, separated by spaces".to_string()),
+        });
+    }
+
+    pub fn next_field(&mut self) {
+        self.selected = (self.selected + 1) % self.fields.len();
+    }
+
+    pub fn prev_field(&mut self) {
+        self.selected = (self.selected + self.fields.len() - 1) % self.fields.len();
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.fields[self.selected].value.push(c);
+        self.error = None;
+    }
+
+    pub fn backspace(&mut self) {
+        self.fields[self.selected].value.pop();
+        self.error = None;
+    }
+
+    /// The ref and inputs to send, or which required field is empty.
+    /// Empty optional inputs are left out so the workflow's defaults apply.
+    pub fn submission(&self) -> Result<(String, HashMap<String, String>), String> {
+        if let Some(missing) = self
+            .fields
+            .iter()
+            .find(|f| f.required && f.value.trim().is_empty())
+        {
+            return Err(format!("{} is required", missing.name));
+        }
+        let git_ref = self.fields[0].value.trim().to_string();
+        if self.free_form {
+            return Ok((git_ref, parse_pairs(&self.fields[1].value)?));
+        }
+        let inputs = self.fields[1..]
+            .iter()
+            .filter(|f| !f.value.is_empty())
+            .map(|f| (f.name.clone(), f.value.clone()))
+            .collect();
+        Ok((git_ref, inputs))
+    }
+}
+
+/// `environment=staging dry_run=true` as inputs
+fn parse_pairs(text: &str) -> Result<HashMap<String, String>, String> {
+    text.split_whitespace()
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+            _ => Err(format!("expected key=value, got {}", pair)),
+        })
+        .collect()
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEPLOY: &str = r#"
+name: Deploy
+on:
+  push:
+    branches: [main]
+  workflow_dispatch:
+    inputs:
+      environment:
+        description: Where to deploy
+        required: true
+        type: choice
+        options: [staging, production]
+      version:
+        description: Release to ship
+      dry_run:
+        type: boolean
+        default: true
+jobs: {}
+"#;
+
+    #[test]
+    fn test_dispatch_inputs_from_mapping() {
+        let inputs = dispatch_inputs(DEPLOY).unwrap();
+        assert_eq!(inputs.len(), 3);
+        assert_eq!(inputs[0].name, "environment");
+        assert!(inputs[0].required);
+        assert_eq!(inputs[0].options, vec!["staging", "production"]);
+        assert_eq!(inputs[1].description.as_deref(), Some("Release to ship"));
+        assert_eq!(inputs[2].default.as_deref(), Some("true"));
+    }
+
+    #[test]
+    fn test_dispatch_inputs_short_forms() {
+        assert_eq!(dispatch_inputs("on: workflow_dispatch\n"), Some(vec![]));
+        assert_eq!(
+            dispatch_inputs("on: [push, workflow_dispatch]\n"),
+            Some(vec![])
+        );
+        assert_eq!(dispatch_inputs("on:\n  workflow_dispatch:\n"), Some(vec![]));
+        assert_eq!(dispatch_inputs("on: push\n"), None);
+        assert_eq!(dispatch_inputs("on: [push\n"), None);
+    }
+
+    #[test]
+    fn test_form_submission() {
+        let mut form = DispatchForm::new(1, "Deploy".into(), "main".into());
+        form.set_inputs(dispatch_inputs(DEPLOY).unwrap());
+        assert_eq!(form.fields.len(), 4);
+
+        assert_eq!(form.submission().unwrap_err(), "environment is required");
+
+        form.next_field();
+        for c in "staging".chars() {
+            form.push(c);
+        }
+        let (git_ref, inputs) = form.submission().unwrap();
+        assert_eq!(git_ref, "main");
+        assert_eq!(
+            inputs.get("environment").map(String::as_str),
+            Some("staging")
+        );
+        assert_eq!(inputs.get("dry_run").map(String::as_str), Some("true"));
+        assert!(!inputs.contains_key("version"));
+    }
+
+    #[test]
+    fn test_free_form_inputs() {
+        let mut form = DispatchForm::new(1, "Deploy".into(), "main".into());
+        form.set_free_inputs();
+        assert_eq!(form.fields.len(), 2);
+        let (_, inputs) = form.submission().unwrap();
+        assert!(inputs.is_empty());
+
+        form.fields[1].value = " environment=staging  note=a=b ".into();
+        let (_, inputs) = form.submission().unwrap();
+        assert_eq!(inputs.len(), 2);
+        assert_eq!(inputs["environment"], "staging");
+        // Only the first `=` splits
+        assert_eq!(inputs["note"], "a=b");
+
+        form.fields[1].value = "environment=staging dry_run".into();
+        assert_eq!(
+            form.submission().unwrap_err(),
+            "expected key=value, got dry_run"
+        );
+    }
+
+    #[test]
+    fn test_field_navigation_wraps() {
+        let mut form = DispatchForm::new(1, "Deploy".into(), "main".into());
+        form.set_inputs(dispatch_inputs(DEPLOY).unwrap());
+        form.prev_field();
+        assert_eq!(form.selected, 3);
+        form.next_field();
+        assert_eq!(form.selected, 0);
+    }
+}
@@ -0,0 +1,447 @@
+use std::path::Path;
+use std::time::Instant;
+
+use crate::account::Account;
+use crate::auth;
+use crate::bookmarks::BookmarkFile;
+use crate::config;
+use crate::github::{GitHubClient, DEFAULT_BASE_URL};
+use crate::highlight::LogHighlighter;
+use crate::models::TokenInfo;
+use crate::state::{self, StateStore};
+use crate::term;
+
+// ── ANSI Color helpers ─────────────────────────────────────────────
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+
+/// Scopes a classic PAT needs for everything Atlas does
+const REQUIRED_SCOPES: &[&str] = &["repo", "workflow"];
+
+// ── Check results ──────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+    /// A failing critical check makes `atlas doctor` exit non-zero
+    pub critical: bool,
+}
+
+impl CheckResult {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Pass,
+            detail: detail.into(),
+            critical: false,
+        }
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Warn,
+            detail: detail.into(),
+            critical: false,
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Fail,
+            detail: detail.into(),
+            critical: false,
+        }
+    }
+
+    fn critical(mut self) -> Self {
+        self.critical = true;
+        self
+    }
+
+    fn print(&self) {
+        let (marker, color) = match self.status {
+            CheckStatus::Pass => ("[+]", GREEN),
+            CheckStatus::Warn => ("[!]", YELLOW),
+            CheckStatus::Fail => ("[x]", RED),
+        };
+        println!(
+            "  {color}{marker}{RESET} {BOLD}{:<16}{RESET} {DIM}{}{RESET}",
+            self.name, self.detail
+        );
+    }
+}
+
+/// True when no critical check failed
+pub fn all_critical_passed(results: &[CheckResult]) -> bool {
+    !results
+        .iter()
+        .any(|r| r.critical && r.status == CheckStatus::Fail)
+}
+
+// ── Entry point ────────────────────────────────────────────────────
+
+/// Run every check, print a pass/fail line for each, and report whether
+/// all critical checks passed.
+pub async fn run(cli_token: Option<String>, api_url: Option<String>) -> bool {
+    println!();
+    println!(
+        "  {BOLD}Atlas doctor{RESET} {DIM}v{}{RESET}",
+        env!("CARGO_PKG_VERSION")
+    );
+    println!();
+
+    let mut results = Vec::new();
+
+    let base_url = api_url
+        .clone()
+        .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+    // Whose state file to check: the token's owner, else the last account used
+    let mut login = None;
+
+    let credentials = match auth::find_token_with_app(cli_token, api_url.as_deref()).await {
+        Ok(credentials) => {
+            results.push(check_token_present(
+                credentials.as_ref().map(|credentials| credentials.source),
+            ));
+            credentials
+        }
+        Err(e) => {
+            results.push(CheckResult::fail("Token present", format!("{:#}", e)).critical());
+            None
+        }
+    };
+
+    if let Some(credentials) = credentials {
+        let client = match api_url {
+            Some(url) => GitHubClient::new_with_token_and_base(credentials.token, url),
+            None => GitHubClient::new_with_token(credentials.token),
+        }
+        .with_app_token(credentials.app_token);
+
+        let started = Instant::now();
+        let rate = client.get_rate_limit().await;
+        let latency_ms = started.elapsed().as_millis();
+        match rate {
+            Ok(rate) => {
+                results.push(CheckResult::pass(
+                    "API reachable",
+                    format!("{}ms", latency_ms),
+                ));
+                let core = &rate.resources.core;
+                let reset_in = core.reset - chrono::Utc::now().timestamp();
+                results.push(check_rate_limit(core.remaining, core.limit, reset_in));
+            }
+            Err(e) => {
+                results.push(CheckResult::fail("API reachable", format!("{:#}", e)).critical());
+            }
+        }
+
+        if let Some(installation) = client.installation() {
+            // The token was just created for it, and /user isn't open to it
+            results.push(CheckResult::pass(
+                "Token valid",
+                format!("acting as {}", installation),
+            ));
+            login = Some(installation);
+        } else {
+            match client.get_authenticated_user().await {
+                Ok(info) => {
+                    let who = match &info.user.name {
+                        Some(name) => format!("{} ({})", info.user.login, name),
+                        None => info.user.login.clone(),
+                    };
+                    results.push(CheckResult::pass(
+                        "Token valid",
+                        format!("logged in as {}", who),
+                    ));
+                    results.push(check_scopes(&info));
+                    login = Some(info.user.login);
+                }
+                Err(e) => {
+                    results.push(CheckResult::fail("Token valid", format!("{:#}", e)).critical());
+                }
+            }
+        }
+    }
+
+    results.push(check_git_repo(&base_url));
+    results.push(check_config(&config::config_path()));
+    let atlas_dir = state::atlas_dir();
+    let account = match login {
+        Some(login) => Some(Account::new(&base_url, &login)),
+        None => Account::last_used(&atlas_dir, &base_url),
+    };
+    results.push(check_state(&atlas_dir, account.as_ref()));
+    results.push(check_bookmarks(&BookmarkFile::new(
+        atlas_dir.join("bookmarks.json"),
+    )));
+    results.push(check_keychain());
+    results.push(check_terminal(
+        term::detect_color_support(),
+        term::supports_alternate_screen(),
+    ));
+
+    for result in &results {
+        result.print();
+    }
+    println!();
+
+    let ok = all_critical_passed(&results);
+    if ok {
+        println!("  {GREEN}{BOLD}All critical checks passed.{RESET}");
+    } else {
+        println!("  {RED}{BOLD}Some critical checks failed.{RESET}");
+    }
+    println!();
+
+    ok
+}
+
+// ── Individual checks ──────────────────────────────────────────────
+
+fn check_token_present(source: Option<&str>) -> CheckResult {
+    match source {
+        Some(source) => CheckResult::pass("Token present", format!("from {}", source)),
+        None => CheckResult::fail(
+            "Token present",
+            "no token found (run: atlas auth login, or set GITHUB_TOKEN)",
+        )
+        .critical(),
+    }
+}
+
+fn check_scopes(info: &TokenInfo) -> CheckResult {
+    match &info.scopes {
+        Some(scopes) => {
+            let missing = missing_scopes(scopes);
+            if missing.is_empty() {
+                CheckResult::pass("Token scopes", scopes.join(", "))
+            } else {
+                CheckResult::warn("Token scopes", format!("missing: {}", missing.join(", ")))
+            }
+        }
+        None => CheckResult::warn(
+            "Token scopes",
+            "fine-grained token — scopes can't be verified",
+        ),
+    }
+}
+
+/// Required scopes absent from `scopes`
+pub fn missing_scopes(scopes: &[String]) -> Vec<&'static str> {
+    REQUIRED_SCOPES
+        .iter()
+        .filter(|required| !scopes.iter().any(|s| s == *required))
+        .copied()
+        .collect()
+}
+
+fn check_rate_limit(remaining: u64, limit: u64, reset_in_secs: i64) -> CheckResult {
+    let detail = format!(
+        "{}/{} requests remaining, resets in {}m",
+        remaining,
+        limit,
+        reset_in_secs.max(0) / 60
+    );
+    if remaining == 0 {
+        CheckResult::fail("Rate limit", detail)
+    } else if remaining < 100 {
+        CheckResult::warn("Rate limit", detail)
+    } else {
+        CheckResult::pass("Rate limit", detail)
+    }
+}
+
+fn check_git_repo(api_url: &str) -> CheckResult {
+    match crate::detect_repo_from_git(Some(api_url)) {
+        Ok((owner, repo)) => CheckResult::pass("Git repository", format!("{}/{}", owner, repo)),
+        Err(e) => CheckResult::warn(
+            "Git repository",
+            format!(
+                "{} (browser mode will be used)",
+                e.to_string().lines().next().unwrap_or_default()
+            ),
+        ),
+    }
+}
+
+fn check_config(path: &Path) -> CheckResult {
+    if !path.exists() {
+        return CheckResult::pass(
+            "Config file",
+            format!("{} not present, using defaults", path.display()),
+        );
+    }
+    match config::load_from(path) {
+        Ok(config) => {
+            let (_, mut warnings) = LogHighlighter::compile(&config.log.highlight);
+            warnings.extend(config.warnings());
+            if warnings.is_empty() {
+                CheckResult::pass("Config file", format!("{} parsed", path.display()))
+            } else {
+                CheckResult::warn("Config file", warnings.join("; "))
+            }
+        }
+        Err(e) => CheckResult::fail("Config file", format!("{:#}", e)),
+    }
+}
+
+fn check_state(atlas_dir: &Path, account: Option<&Account>) -> CheckResult {
+    let Some(account) = account else {
+        return CheckResult::pass("State file", "none yet (no account has used Atlas)");
+    };
+    match StateStore::new(account.state_dir(atlas_dir)).load() {
+        Ok(state) => CheckResult::pass(
+            "State file",
+            format!(
+                "{} recent repositories for {} on {}",
+                state.recent.len(),
+                account.login,
+                account.host
+            ),
+        ),
+        Err(e) => CheckResult::warn("State file", format!("{:#}", e)),
+    }
+}
+
+fn check_bookmarks(file: &BookmarkFile) -> CheckResult {
+    match file.load() {
+        Ok(bookmarks) => CheckResult::pass(
+            "Bookmarks",
+            format!("{} bookmarked repositories", bookmarks.len()),
+        ),
+        Err(e) => CheckResult::warn("Bookmarks", format!("{:#}", e)),
+    }
+}
+
+fn check_keychain() -> CheckResult {
+    match auth::keychain_round_trip() {
+        Ok(()) => CheckResult::pass("Keychain", "read/write round-trip OK"),
+        Err(e) => CheckResult::warn("Keychain", format!("{:#}", e)),
+    }
+}
+
+fn check_terminal(colors: term::ColorSupport, alternate_screen: bool) -> CheckResult {
+    let detail = format!(
+        "{}, alternate screen {}",
+        colors.label(),
+        if alternate_screen {
+            "available"
+        } else {
+            "unavailable"
+        }
+    );
+    if alternate_screen && colors != term::ColorSupport::None {
+        CheckResult::pass("Terminal", detail)
+    } else {
+        CheckResult::warn("Terminal", detail)
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::User;
+
+    fn token_info(scopes: Option<&[&str]>) -> TokenInfo {
+        TokenInfo {
+            user: User {
+                login: "octocat".into(),
+                name: None,
+            },
+            scopes: scopes.map(|s| s.iter().map(|s| s.to_string()).collect()),
+        }
+    }
+
+    #[test]
+    fn test_missing_scopes() {
+        let scopes = vec!["repo".to_string(), "read:org".to_string()];
+        assert_eq!(missing_scopes(&scopes), vec!["workflow"]);
+        let scopes = vec!["repo".to_string(), "workflow".to_string()];
+        assert!(missing_scopes(&scopes).is_empty());
+    }
+
+    #[test]
+    fn test_check_scopes_fine_grained_warns() {
+        assert_eq!(check_scopes(&token_info(None)).status, CheckStatus::Warn);
+        assert_eq!(
+            check_scopes(&token_info(Some(&["repo", "workflow"]))).status,
+            CheckStatus::Pass
+        );
+    }
+
+    #[test]
+    fn test_missing_token_is_critical() {
+        let result = check_token_present(None);
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert!(!all_critical_passed(&[result]));
+    }
+
+    #[test]
+    fn test_non_critical_failure_still_passes() {
+        let results = vec![
+            check_token_present(Some("GITHUB_TOKEN")),
+            check_rate_limit(0, 5000, 600),
+        ];
+        assert!(all_critical_passed(&results));
+    }
+
+    #[test]
+    fn test_check_config() {
+        let dir = std::env::temp_dir().join(format!("atlas-doctor-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let missing = dir.join("missing.toml");
+        assert_eq!(check_config(&missing).status, CheckStatus::Pass);
+
+        let bad_regex = dir.join("bad-regex.toml");
+        std::fs::write(
+            &bad_regex,
+            "[[log.highlight]]\npattern = \"(oops\"\ncolor = \"red\"\n",
+        )
+        .unwrap();
+        let result = check_config(&bad_regex);
+        assert_eq!(result.status, CheckStatus::Warn);
+        assert!(result.detail.contains("(oops"));
+
+        let bad_toml = dir.join("bad.toml");
+        std::fs::write(&bad_toml, "log = [").unwrap();
+        assert_eq!(check_config(&bad_toml).status, CheckStatus::Fail);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_check_terminal() {
+        assert_eq!(
+            check_terminal(term::ColorSupport::TrueColor, true).status,
+            CheckStatus::Pass
+        );
+        assert_eq!(
+            check_terminal(term::ColorSupport::None, true).status,
+            CheckStatus::Warn
+        );
+        assert_eq!(
+            check_terminal(term::ColorSupport::Ansi256, false).status,
+            CheckStatus::Warn
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+use crate::models::{format_duration, WorkflowRun};
+use crate::state::write_atomic;
+
+/// Successful runs remembered per workflow; older ones are dropped
+const MAX_SAMPLES: usize = 50;
+/// Fewer successful runs than this say too little about "usual"
+const MIN_SAMPLES: usize = 5;
+
+// ── Duration history ───────────────────────────────────────────────
+
+/// How long a repo's successful runs took, per workflow
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DurationHistory {
+    /// Workflow id → run id → seconds
+    #[serde(default)]
+    workflows: BTreeMap<u64, BTreeMap<u64, u64>>,
+}
+
+impl DurationHistory {
+    /// Remember the runs that finished successfully; returns whether any
+    /// were new
+    pub fn record(&mut self, runs: &[WorkflowRun]) -> bool {
+        let mut added = false;
+        for run in runs {
+            let (Some(workflow_id), Some(secs)) = (run.workflow_id, success_secs(run)) else {
+                continue;
+            };
+            let samples = self.workflows.entry(workflow_id).or_default();
+            // Run ids grow over time, so the first ones are the oldest
+            let full = samples.len() >= MAX_SAMPLES;
+            if full
+                && samples
+                    .first_key_value()
+                    .is_some_and(|(&oldest, _)| run.id < oldest)
+            {
+                continue;
+            }
+            added |= samples.insert(run.id, secs).is_none();
+            while samples.len() > MAX_SAMPLES {
+                samples.pop_first();
+            }
+        }
+        added
+    }
+
+    /// 95th percentile of the workflow's successful runs, once there are
+    /// enough of them
+    pub fn p95(&self, workflow_id: u64) -> Option<u64> {
+        let samples: Vec<u64> = self
+            .workflows
+            .get(&workflow_id)?
+            .values()
+            .copied()
+            .collect();
+        p95(&samples)
+    }
+
+    /// An in-progress run that has gone on longer than its workflow
+    /// usually takes
+    pub fn stuck(&self, run: &WorkflowRun, now: DateTime<Utc>) -> Option<Stuck> {
+        if run.status.as_deref() != Some("in_progress") {
+            return None;
+        }
+        let usual = self.p95(run.workflow_id?)?;
+        let started = run.run_started_at.unwrap_or(run.created_at);
+        let elapsed = u64::try_from((now - started).num_seconds()).ok()?;
+        (elapsed > usual).then_some(Stuck { elapsed, usual })
+    }
+}
+
+/// Nearest-rank 95th percentile; `None` for sparse data
+pub fn p95(samples: &[u64]) -> Option<u64> {
+    if samples.len() < MIN_SAMPLES {
+        return None;
+    }
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable();
+    let rank = (sorted.len() * 95).div_ceil(100);
+    Some(sorted[rank - 1])
+}
+
+/// Wall time of a run that completed successfully
+fn success_secs(run: &WorkflowRun) -> Option<u64> {
+    if run.conclusion.as_deref() != Some("success") {
+        return None;
+    }
+    let started = run.run_started_at?;
+    u64::try_from((run.updated_at - started).num_seconds()).ok()
+}
+
+/// A run past its workflow's p95
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stuck {
+    pub elapsed: u64,
+    pub usual: u64,
+}
+
+impl Stuck {
+    /// `running 41m 12s (usually ≤ 8m)`
+    pub fn label(&self) -> String {
+        format!(
+            "running {} (usually ≤ {})",
+            format_duration(self.elapsed as i64),
+            format_duration(self.usual as i64)
+        )
+    }
+}
+
+// ── Cache file ─────────────────────────────────────────────────────
+
+/// `~/.atlas/cache/<host>/<login>/durations/<owner>/<repo>.json`
+pub fn history_path(cache_dir: &Path, repo: &str) -> PathBuf {
+    cache_dir.join("durations").join(format!("{}.json", repo))
+}
+
+/// A missing or unreadable cache starts the history over
+pub fn load(path: &Path) -> DurationHistory {
+    let Ok(content) = std::fs::read(path) else {
+        return DurationHistory::default();
+    };
+    serde_json::from_slice(&content).unwrap_or_else(|e| {
+        warn!(error = %e, path = %path.display(), "Unreadable duration history");
+        DurationHistory::default()
+    })
+}
+
+pub fn save(path: &Path, history: &DurationHistory) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    write_atomic(path, &serde_json::to_vec(history)?)
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn run(id: u64, conclusion: Option<&str>, started: DateTime<Utc>, secs: i64) -> WorkflowRun {
+        WorkflowRun {
+            id,
+            name: Some("Deploy".into()),
+            display_title: None,
+            head_branch: Some("main".into()),
+            head_sha: "abc1234".into(),
+            status: Some(
+                if conclusion.is_some() {
+                    "completed"
+                } else {
+                    "in_progress"
+                }
+                .into(),
+            ),
+            conclusion: conclusion.map(str::to_string),
+            run_number: id,
+            event: "push".into(),
+            created_at: started,
+            updated_at: started + chrono::Duration::seconds(secs),
+            run_started_at: Some(started),
+            html_url: String::new(),
+            actor: None,
+            run_attempt: None,
+            workflow_id: Some(7),
+            path: None,
+            check_suite_id: None,
+        }
+    }
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 12, 10, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_p95_needs_enough_samples() {
+        assert_eq!(p95(&[]), None);
+        assert_eq!(p95(&[300, 320, 340, 360]), None);
+        assert_eq!(p95(&[300, 320, 340, 360, 380]), Some(380));
+        // 20 samples: the 19th smallest, so one outlier doesn't count
+        let mut samples: Vec<u64> = (1..=19).map(|i| 300 + i).collect();
+        samples.push(5000);
+        assert_eq!(p95(&samples), Some(319));
+    }
+
+    #[test]
+    fn test_only_successful_runs_are_recorded_and_trimmed() {
+        let mut history = DurationHistory::default();
+        let runs: Vec<WorkflowRun> = (1..=60)
+            .map(|id| run(id, Some("success"), start(), 360 + id as i64))
+            .chain([
+                run(61, Some("failure"), start(), 10),
+                run(62, None, start(), 0),
+            ])
+            .collect();
+        assert!(history.record(&runs));
+        assert!(!history.record(&runs));
+        let samples = &history.workflows[&7];
+        assert_eq!(samples.len(), MAX_SAMPLES);
+        assert_eq!(samples.keys().next(), Some(&11));
+        assert_eq!(history.p95(7), Some(360 + 58));
+        assert_eq!(history.p95(8), None);
+    }
+
+    #[test]
+    fn test_stuck_runs_are_past_p95() {
+        let mut history = DurationHistory::default();
+        let past: Vec<WorkflowRun> = (1..=10)
+            .map(|id| run(id, Some("success"), start(), 300 + 20 * id as i64))
+            .collect();
+        history.record(&past);
+        assert_eq!(history.p95(7), Some(500));
+
+        let running = run(11, None, start(), 0);
+        let at = |secs| start() + chrono::Duration::seconds(secs);
+        assert_eq!(history.stuck(&running, at(400)), None);
+        let stuck = history.stuck(&running, at(2460)).unwrap();
+        assert_eq!(stuck.label(), "running 41m 0s (usually ≤ 8m 20s)");
+        // Finished runs are never stuck
+        assert_eq!(history.stuck(&past[0], at(9999)), None);
+
+        // Sparse history: no verdict
+        let sparse = DurationHistory::default();
+        assert_eq!(sparse.stuck(&running, at(99_999)), None);
+    }
+
+    #[test]
+    fn test_history_round_trips_through_the_cache() {
+        let dir = std::env::temp_dir().join(format!("atlas-durations-{}", std::process::id()));
+        let path = history_path(&dir, "octo/api");
+        assert_eq!(load(&path), DurationHistory::default());
+
+        let mut history = DurationHistory::default();
+        history.record(&[run(1, Some("success"), start(), 60)]);
+        save(&path, &history).unwrap();
+        assert_eq!(load(&path), history);
+
+        std::fs::write(&path, "not json").unwrap();
+        assert_eq!(load(&path), DurationHistory::default());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,5 +1,7 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
+use crate::app::View;
+
 // ── Actions ────────────────────────────────────────────────────────
 
 /// Mapped action from a key event
@@ -15,17 +17,71 @@ pub enum Action {
     PrevPage,
     ToggleLogs,
     Rerun,
+    RerunFailed,
+    /// Ctrl+R / Ctrl+F: the same re-runs with step debug logging
+    RerunDebug,
+    RerunFailedDebug,
+    /// `W`: re-run failed jobs and watch the run until it finishes
+    RerunFailedWatch,
     Cancel,
     OpenInBrowser,
     Search,
+    ToggleFollow,
+    ToggleSplit,
+    SwitchPane,
+    Dispatch,
+    FilterBranch,
+    CycleStatusFilter,
+    /// `e` in the runs list: cycle the event filter
+    CycleEventFilter,
+    Workflows,
+    SaveLog,
+    Exports,
+    Artifacts,
+    Environments,
+    Runners,
+    FollowLogs,
+    NextMatch,
+    PrevMatch,
+    NextError,
+    ToggleTimestamps,
+    /// `x`: a step's log alone, or the whole job's
+    ToggleStepLog,
+    FailedFirst,
+    /// `K`: the repo's Actions caches
+    Caches,
+    Statistics,
+    /// `t` in run details: the jobs on a time axis
+    Timeline,
+    /// `m` in the repo list: bookmark the selected repo, or drop it
+    Bookmark,
+    DeleteCache,
+    ToggleWorkflow,
+    DeleteRuns,
+    SelectRun,
+    CancelSelected,
+    JumpTop,
+    JumpBottom,
+    PageUp,
+    PageDown,
+    HalfPageUp,
+    HalfPageDown,
+    Help,
     None,
 }
 
 /// Map key events to app actions
 pub fn map_key_to_action(key: KeyEvent) -> Action {
-    // Ctrl+C always quits
-    if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
-        return Action::Quit;
+    // Ctrl+C always quits; Ctrl+D / Ctrl+U move half a page like in vim
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        match key.code {
+            KeyCode::Char('c') => return Action::Quit,
+            KeyCode::Char('d') => return Action::HalfPageDown,
+            KeyCode::Char('u') => return Action::HalfPageUp,
+            KeyCode::Char('r') => return Action::RerunDebug,
+            KeyCode::Char('f') => return Action::RerunFailedDebug,
+            _ => {}
+        }
     }
 
     match key.code {
@@ -40,12 +96,163 @@ pub fn map_key_to_action(key: KeyEvent) -> Action {
         KeyCode::Char('L') => Action::ToggleLogs,
         KeyCode::Char('R') => Action::Rerun,
         KeyCode::Char('C') => Action::Cancel,
+        KeyCode::Char('X') => Action::CancelSelected,
         KeyCode::Char('o') => Action::OpenInBrowser,
         KeyCode::Char('/') => Action::Search,
+        KeyCode::Char('P') => Action::ToggleFollow,
+        KeyCode::Char('v') => Action::ToggleSplit,
+        KeyCode::Tab => Action::SwitchPane,
+        KeyCode::Char('d') => Action::Dispatch,
+        KeyCode::Char('b') => Action::FilterBranch,
+        KeyCode::Char('f') | KeyCode::Char('S') => Action::CycleStatusFilter,
+        KeyCode::Char('w') => Action::Workflows,
+        KeyCode::Char('s') => Action::SaveLog,
+        KeyCode::Char('E') => Action::Exports,
+        KeyCode::Char('a') => Action::Artifacts,
+        KeyCode::Char('e') => Action::Environments,
+        KeyCode::Char('u') => Action::Runners,
+        KeyCode::Char('K') => Action::Caches,
+        KeyCode::Char('T') => Action::Statistics,
+        KeyCode::Char('F') => Action::RerunFailed,
+        KeyCode::Char('W') => Action::RerunFailedWatch,
+        KeyCode::Char('g') | KeyCode::Home => Action::JumpTop,
+        KeyCode::Char('G') | KeyCode::End => Action::JumpBottom,
+        KeyCode::PageUp => Action::PageUp,
+        KeyCode::PageDown => Action::PageDown,
+        KeyCode::Char('?') => Action::Help,
         _ => Action::None,
     }
 }
 
+/// Map a key pressed in `view`, where a few keys take another meaning
+pub fn map_key_in_view(key: KeyEvent, view: &View) -> Action {
+    // Ctrl chords mean the same in every view; no view's letter shadows them
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        return map_key_to_action(key);
+    }
+    match (view, key.code) {
+        (View::Logs, KeyCode::Char('F') | KeyCode::Char('f')) => Action::FollowLogs,
+        (View::Logs, KeyCode::Char('n')) => Action::NextMatch,
+        (View::Logs, KeyCode::Char('N')) => Action::PrevMatch,
+        (View::Logs, KeyCode::Char('e')) => Action::NextError,
+        (View::Logs, KeyCode::Char(' ')) => Action::Enter,
+        (View::Logs, KeyCode::Char('t')) => Action::ToggleTimestamps,
+        (View::Logs, KeyCode::Char('x')) => Action::ToggleStepLog,
+        (View::RunDetail, KeyCode::Char('f')) => Action::FailedFirst,
+        (View::RunDetail, KeyCode::Char('t')) => Action::Timeline,
+        (View::RepoList, KeyCode::Char('m')) => Action::Bookmark,
+        (View::RunsList, KeyCode::Char(' ')) => Action::SelectRun,
+        (View::RunsList, KeyCode::Char('e')) => Action::CycleEventFilter,
+        (View::RunsList, KeyCode::Char('D')) => Action::DeleteRuns,
+        // Not Ctrl+D, which pages down here as anywhere
+        (View::Caches, KeyCode::Char('d') | KeyCode::Delete)
+            if matches!(key.modifiers, KeyModifiers::NONE | KeyModifiers::SHIFT) =>
+        {
+            Action::DeleteCache
+        }
+        (View::WorkflowList, KeyCode::Char('t')) => Action::ToggleWorkflow,
+        _ => map_key_to_action(key),
+    }
+}
+
+// ── Help ───────────────────────────────────────────────────────────
+
+/// Every key by the view it works in, as the `?` popup lists them
+pub const HELP: &[(&str, &[(&str, &str)])] = &[
+    (
+        "Global",
+        &[
+            ("↑↓ / jk", "move"),
+            ("gg / G", "first / last"),
+            ("PgUp PgDn", "a screenful"),
+            ("Ctrl+u Ctrl+d", "half a screenful"),
+            ("Enter / l", "open"),
+            ("Esc / h", "back"),
+            ("r", "refresh"),
+            ("o", "open in browser"),
+            ("?", "this help"),
+            ("q / Ctrl+c", "quit"),
+        ],
+    ),
+    (
+        "Repositories",
+        &[
+            ("/", "filter by name"),
+            ("←→ / np", "previous / next page"),
+            ("m", "bookmark, keeping the repo at the top"),
+        ],
+    ),
+    (
+        "Workflows",
+        &[
+            ("d", "dispatch the workflow on the default branch"),
+            ("t", "disable the workflow, or enable it again"),
+        ],
+    ),
+    (
+        "Runs",
+        &[
+            ("←→ / np", "previous / next page"),
+            ("b", "filter by branch"),
+            ("f / S", "cycle the status filter"),
+            ("e", "cycle the event filter"),
+            ("w", "pick a workflow (d there dispatches it)"),
+            ("P", "follow the selected run across pages"),
+            ("v", "split view"),
+            ("Tab", "switch pane in split view"),
+            ("d", "dispatch the workflow"),
+            ("R", "re-run, or every selected run (asks first)"),
+            ("F", "re-run failed jobs (asks first)"),
+            ("Ctrl+R/F", "same, with debug logging"),
+            ("W", "re-run failed jobs, watch, notify (Esc stops)"),
+            ("C", "cancel, or every selected run (asks first)"),
+            ("Space", "select the run, for C, R, X and D (Esc clears)"),
+            ("X", "cancel the selected runs (asks first)"),
+            ("D", "delete the selected runs, or this one (asks first)"),
+            ("K", "Actions caches"),
+            ("T", "build health statistics"),
+        ],
+    ),
+    (
+        "Run details",
+        &[
+            ("Enter / l", "job logs, or the selected step's"),
+            ("Tab", "jobs → steps → annotations (Enter copies path:line)"),
+            ("f", "failed jobs first"),
+            ("t", "timeline of the jobs, and the selected job's steps"),
+            ("a", "artifacts (Enter downloads one)"),
+            ("e", "environments"),
+            ("u", "self-hosted runners"),
+            ("R", "re-run the selected job (asks first)"),
+            ("F", "re-run failed jobs (asks first)"),
+            ("Ctrl+R/F", "same, with debug logging"),
+            ("W", "re-run failed jobs, watch, notify (Esc stops)"),
+            ("C", "cancel (asks first)"),
+        ],
+    ),
+    ("Caches", &[("d / Del", "delete the cache (asks first)")]),
+    (
+        "Logs",
+        &[
+            ("/", "search"),
+            ("n / N", "next / previous match"),
+            ("e", "next error"),
+            ("Enter / Space", "fold or unfold a section"),
+            ("t", "timestamps"),
+            ("x", "the whole job log, or just the step it was opened at"),
+            ("F / f", "follow a running job"),
+            ("s", "save with a note"),
+            ("E", "recent exports"),
+        ],
+    ),
+];
+
+/// Lines the help takes: a heading per view, its keys, and a blank line
+/// between views
+pub fn help_len() -> usize {
+    HELP.iter().map(|(_, keys)| keys.len() + 1).sum::<usize>() + HELP.len() - 1
+}
+
 // ── Tests ──────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -101,6 +308,124 @@ mod tests {
             map_key_to_action(key(KeyCode::Char('o'))),
             Action::OpenInBrowser
         );
+        assert_eq!(
+            map_key_to_action(key(KeyCode::Char('P'))),
+            Action::ToggleFollow
+        );
+        assert_eq!(
+            map_key_to_action(key(KeyCode::Char('v'))),
+            Action::ToggleSplit
+        );
+        assert_eq!(map_key_to_action(key(KeyCode::Tab)), Action::SwitchPane);
+        assert_eq!(map_key_to_action(key(KeyCode::Char('d'))), Action::Dispatch);
+        assert_eq!(
+            map_key_to_action(key(KeyCode::Char('b'))),
+            Action::FilterBranch
+        );
+        assert_eq!(
+            map_key_to_action(key(KeyCode::Char('w'))),
+            Action::Workflows
+        );
+        assert_eq!(
+            map_key_to_action(key(KeyCode::Char('f'))),
+            Action::CycleStatusFilter
+        );
+        assert_eq!(
+            map_key_to_action(key(KeyCode::Char('S'))),
+            Action::CycleStatusFilter
+        );
+        assert_eq!(map_key_to_action(key(KeyCode::Char('s'))), Action::SaveLog);
+        assert_eq!(map_key_to_action(key(KeyCode::Char('E'))), Action::Exports);
+        assert_eq!(
+            map_key_to_action(key(KeyCode::Char('a'))),
+            Action::Artifacts
+        );
+        assert_eq!(
+            map_key_to_action(key(KeyCode::Char('F'))),
+            Action::RerunFailed
+        );
+        assert_eq!(
+            map_key_to_action(key(KeyCode::Char('W'))),
+            Action::RerunFailedWatch
+        );
+        assert_eq!(map_key_to_action(key(KeyCode::Char('?'))), Action::Help);
+    }
+
+    #[test]
+    fn test_view_specific_keys() {
+        let f = key(KeyCode::Char('F'));
+        assert_eq!(map_key_in_view(f, &View::Logs), Action::FollowLogs);
+        assert_eq!(map_key_in_view(f, &View::RunsList), Action::RerunFailed);
+        assert_eq!(map_key_in_view(f, &View::RunDetail), Action::RerunFailed);
+        let ctrl_f = key_with_mod(KeyCode::Char('f'), KeyModifiers::CONTROL);
+        for view in [View::RunsList, View::RunDetail, View::Logs] {
+            assert_eq!(map_key_in_view(ctrl_f, &view), Action::RerunFailedDebug);
+        }
+        let ctrl_r = key_with_mod(KeyCode::Char('r'), KeyModifiers::CONTROL);
+        assert_eq!(map_key_in_view(ctrl_r, &View::Logs), Action::RerunDebug);
+        assert_eq!(
+            map_key_in_view(key(KeyCode::Char('f')), &View::Logs),
+            Action::FollowLogs
+        );
+        let n = key(KeyCode::Char('n'));
+        assert_eq!(map_key_in_view(n, &View::Logs), Action::NextMatch);
+        assert_eq!(map_key_in_view(n, &View::RunsList), Action::NextPage);
+        assert_eq!(
+            map_key_in_view(key(KeyCode::Char('N')), &View::Logs),
+            Action::PrevMatch
+        );
+        assert_eq!(
+            map_key_in_view(key(KeyCode::Char('e')), &View::Logs),
+            Action::NextError
+        );
+        assert_eq!(
+            map_key_in_view(key(KeyCode::Char('e')), &View::RunDetail),
+            Action::Environments
+        );
+        let d = key(KeyCode::Char('d'));
+        assert_eq!(map_key_in_view(d, &View::Caches), Action::DeleteCache);
+        assert_eq!(map_key_in_view(d, &View::RunsList), Action::Dispatch);
+        assert_eq!(
+            map_key_in_view(key(KeyCode::Delete), &View::Caches),
+            Action::DeleteCache
+        );
+        assert_eq!(
+            map_key_in_view(
+                key_with_mod(KeyCode::Char('d'), KeyModifiers::CONTROL),
+                &View::Caches
+            ),
+            Action::HalfPageDown
+        );
+        assert_eq!(
+            map_key_in_view(key(KeyCode::Char('D')), &View::RunsList),
+            Action::DeleteRuns
+        );
+        assert_eq!(
+            map_key_in_view(key(KeyCode::Char('t')), &View::WorkflowList),
+            Action::ToggleWorkflow
+        );
+        assert_eq!(
+            map_key_in_view(key(KeyCode::Char('K')), &View::RunsList),
+            Action::Caches
+        );
+        let f = key(KeyCode::Char('f'));
+        assert_eq!(map_key_in_view(f, &View::RunDetail), Action::FailedFirst);
+        assert_eq!(
+            map_key_in_view(f, &View::RunsList),
+            Action::CycleStatusFilter
+        );
+        let space = key(KeyCode::Char(' '));
+        assert_eq!(map_key_in_view(space, &View::Logs), Action::Enter);
+        assert_eq!(
+            map_key_in_view(key(KeyCode::Char('t')), &View::Logs),
+            Action::ToggleTimestamps
+        );
+        assert_eq!(map_key_in_view(space, &View::RunsList), Action::SelectRun);
+        assert_eq!(map_key_in_view(space, &View::RunDetail), Action::None);
+        assert_eq!(
+            map_key_in_view(key(KeyCode::Char('R')), &View::Logs),
+            Action::Rerun
+        );
     }
 
     #[test]
@@ -111,6 +436,29 @@ mod tests {
         assert_eq!(map_key_to_action(key(KeyCode::Left)), Action::PrevPage);
     }
 
+    #[test]
+    fn test_jump_and_page_keys() {
+        assert_eq!(map_key_to_action(key(KeyCode::Char('g'))), Action::JumpTop);
+        assert_eq!(map_key_to_action(key(KeyCode::Home)), Action::JumpTop);
+        assert_eq!(
+            map_key_to_action(key(KeyCode::Char('G'))),
+            Action::JumpBottom
+        );
+        assert_eq!(map_key_to_action(key(KeyCode::End)), Action::JumpBottom);
+        assert_eq!(map_key_to_action(key(KeyCode::PageUp)), Action::PageUp);
+        assert_eq!(map_key_to_action(key(KeyCode::PageDown)), Action::PageDown);
+        assert_eq!(
+            map_key_to_action(key_with_mod(KeyCode::Char('u'), KeyModifiers::CONTROL)),
+            Action::HalfPageUp
+        );
+        assert_eq!(
+            map_key_to_action(key_with_mod(KeyCode::Char('d'), KeyModifiers::CONTROL)),
+            Action::HalfPageDown
+        );
+        // Plain `d` still dispatches
+        assert_eq!(map_key_to_action(key(KeyCode::Char('d'))), Action::Dispatch);
+    }
+
     #[test]
     fn test_unknown_key_returns_none() {
         assert_eq!(map_key_to_action(key(KeyCode::Char('z'))), Action::None);
@@ -0,0 +1,284 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tracing::warn;
+
+use crate::state::{self, FileLock};
+
+/// Oldest entries are dropped from `exports.json` beyond this
+const MAX_EXPORTS: usize = 200;
+/// Annotations are cut to this many characters in file names
+const MAX_ANNOTATION_CHARS: usize = 40;
+
+// ── Export records ─────────────────────────────────────────────────
+
+/// One saved log, as listed in `~/.atlas/exports.json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportRecord {
+    pub path: PathBuf,
+    /// `owner/repo`
+    pub repo: String,
+    pub run_number: Option<u64>,
+    pub job: Option<String>,
+    /// Unix time the file was written
+    pub saved_at: i64,
+    pub annotation: Option<String>,
+}
+
+/// What a saved log is about; becomes its file name and its record
+#[derive(Debug, Clone, Default)]
+pub struct ExportMeta {
+    pub repo: String,
+    pub run_number: Option<u64>,
+    pub job: Option<String>,
+    pub annotation: Option<String>,
+}
+
+/// `octo-api-run42-build-flaky-cache.log`
+pub fn export_file_name(meta: &ExportMeta) -> String {
+    let mut parts = vec![slug(&meta.repo)];
+    if let Some(run) = meta.run_number {
+        parts.push(format!("run{}", run));
+    }
+    if let Some(job) = &meta.job {
+        parts.push(slug(job));
+    }
+    if let Some(annotation) = &meta.annotation {
+        let annotation: String = annotation.chars().take(MAX_ANNOTATION_CHARS).collect();
+        parts.push(slug(&annotation));
+    }
+    parts.retain(|p| !p.is_empty());
+    format!("{}.log", parts.join("-"))
+}
+
+/// Lowercase letters and digits, everything else collapsed to `-`
+fn slug(s: &str) -> String {
+    let mut out = String::new();
+    for c in s.chars() {
+        if c.is_ascii_alphanumeric() {
+            out.push(c.to_ascii_lowercase());
+        } else if !out.is_empty() && !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    out.trim_end_matches('-').to_string()
+}
+
+// ── Export log ─────────────────────────────────────────────────────
+
+/// Saves exported files under `exports/` and lists them in
+/// `exports.json`, locked like the state file
+#[derive(Debug, Clone)]
+pub struct ExportLog {
+    dir: PathBuf,
+}
+
+impl ExportLog {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// `~/.atlas`
+    pub fn default_location() -> Self {
+        Self::new(state::atlas_dir())
+    }
+
+    fn index_path(&self) -> PathBuf {
+        self.dir.join("exports.json")
+    }
+
+    fn lock_path(&self) -> PathBuf {
+        self.dir.join("exports.lock")
+    }
+
+    /// Where exported files are written
+    pub fn files_dir(&self) -> PathBuf {
+        self.dir.join("exports")
+    }
+
+    /// Recorded exports, newest first
+    pub fn load(&self) -> Result<Vec<ExportRecord>> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        let _lock = FileLock::shared(&self.lock_path())?;
+        let mut records = self.read_index();
+        records.reverse();
+        Ok(records)
+    }
+
+    /// Write `content` to a new file named from `meta` and record it
+    pub fn save(&self, content: &str, meta: ExportMeta) -> Result<ExportRecord> {
+        let files = self.files_dir();
+        std::fs::create_dir_all(&files)
+            .with_context(|| format!("Failed to create {}", files.display()))?;
+
+        // Never overwrite an earlier export with the same name
+        let name = export_file_name(&meta);
+        let stem = name.trim_end_matches(".log");
+        let mut path = files.join(&name);
+        let mut n = 2;
+        while path.exists() {
+            path = files.join(format!("{}-{}.log", stem, n));
+            n += 1;
+        }
+        std::fs::write(&path, content)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        let record = ExportRecord {
+            path,
+            repo: meta.repo,
+            run_number: meta.run_number,
+            job: meta.job,
+            saved_at: chrono::Utc::now().timestamp(),
+            annotation: meta.annotation,
+        };
+        self.update(|records| {
+            records.push(record.clone());
+            let excess = records.len().saturating_sub(MAX_EXPORTS);
+            records.drain(..excess);
+        })?;
+        Ok(record)
+    }
+
+    /// Forget exports whose file has been deleted; returns how many
+    pub fn prune_missing(&self) -> Result<usize> {
+        let mut pruned = 0;
+        self.update(|records| {
+            let before = records.len();
+            records.retain(|r| r.path.exists());
+            pruned = before - records.len();
+        })?;
+        Ok(pruned)
+    }
+
+    fn update(&self, f: impl FnOnce(&mut Vec<ExportRecord>)) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        let _lock = FileLock::exclusive(&self.lock_path())?;
+        let mut records = self.read_index();
+        f(&mut records);
+        state::write_atomic(&self.index_path(), &serde_json::to_vec_pretty(&records)?)
+    }
+
+    /// Oldest first; a missing or unreadable index reads as empty
+    fn read_index(&self) -> Vec<ExportRecord> {
+        let path = self.index_path();
+        let Ok(content) = std::fs::read(&path) else {
+            return Vec::new();
+        };
+        serde_json::from_slice(&content).unwrap_or_else(|e| {
+            warn!(error = %e, path = %path.display(), "Unreadable exports index");
+            Vec::new()
+        })
+    }
+}
+
+// ── Exports overlay ────────────────────────────────────────────────
+
+/// The `E` popup: recent exports and whether each file still exists
+#[derive(Debug, Clone)]
+pub struct ExportsOverlay {
+    pub entries: Vec<(ExportRecord, bool)>,
+    pub selected: usize,
+}
+
+impl ExportsOverlay {
+    pub fn new(records: Vec<ExportRecord>) -> Self {
+        let entries = records
+            .into_iter()
+            .map(|r| {
+                let exists = r.path.exists();
+                (r, exists)
+            })
+            .collect();
+        Self {
+            entries,
+            selected: 0,
+        }
+    }
+
+    pub fn selected(&self) -> Option<&(ExportRecord, bool)> {
+        self.entries.get(self.selected)
+    }
+
+    pub fn move_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.selected + 1 < self.entries.len() {
+            self.selected += 1;
+        }
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_log(name: &str) -> ExportLog {
+        let dir =
+            std::env::temp_dir().join(format!("atlas-exports-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        ExportLog::new(dir)
+    }
+
+    fn meta(annotation: Option<&str>) -> ExportMeta {
+        ExportMeta {
+            repo: "octo/api".into(),
+            run_number: Some(42),
+            job: Some("Build (ubuntu)".into()),
+            annotation: annotation.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_export_file_name() {
+        assert_eq!(
+            export_file_name(&meta(Some("Flaky cache!"))),
+            "octo-api-run42-build-ubuntu-flaky-cache.log"
+        );
+        assert_eq!(
+            export_file_name(&meta(None)),
+            "octo-api-run42-build-ubuntu.log"
+        );
+        let long = "x".repeat(100);
+        assert!(export_file_name(&meta(Some(&long))).len() < 80);
+    }
+
+    #[test]
+    fn test_save_records_and_never_overwrites() {
+        let log = temp_log("save");
+        let first = log.save("one", meta(Some("first"))).unwrap();
+        let second = log.save("two", meta(Some("first"))).unwrap();
+        assert_ne!(first.path, second.path);
+        assert_eq!(std::fs::read_to_string(&first.path).unwrap(), "one");
+
+        let records = log.load().unwrap();
+        assert_eq!(records.len(), 2);
+        // Newest first
+        assert_eq!(records[0].path, second.path);
+        assert_eq!(records[0].annotation.as_deref(), Some("first"));
+        std::fs::remove_dir_all(&log.dir).unwrap();
+    }
+
+    #[test]
+    fn test_prune_missing() {
+        let log = temp_log("prune");
+        let kept = log.save("keep", meta(None)).unwrap();
+        let gone = log.save("gone", meta(Some("gone"))).unwrap();
+        std::fs::remove_file(&gone.path).unwrap();
+
+        let overlay = ExportsOverlay::new(log.load().unwrap());
+        assert_eq!(
+            overlay.entries.iter().filter(|(_, exists)| !exists).count(),
+            1
+        );
+
+        assert_eq!(log.prune_missing().unwrap(), 1);
+        assert_eq!(log.load().unwrap(), vec![kept]);
+        std::fs::remove_dir_all(&log.dir).unwrap();
+    }
+}
@@ -1,16 +1,119 @@
 use anyhow::{Context, Result};
-use reqwest::header::{ACCEPT, AUTHORIZATION, USER_AGENT};
+use futures::StreamExt;
+use reqwest::header::{HeaderMap, ACCEPT, AUTHORIZATION, USER_AGENT};
+use serde::de::DeserializeOwned;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
+use tokio::sync::mpsc;
 use tracing::{debug, instrument, warn};
 
-use crate::models::{JobsResponse, Repository, WorkflowRunsResponse};
+use crate::auth::AppToken;
+use crate::models::{
+    ActionsCache, ActionsCachesResponse, Annotation, ArtifactsResponse, CheckRun,
+    CheckRunsResponse, Commit, Deployment, DeploymentStatus, Environment, EnvironmentsResponse,
+    InstallationRepositories, InstallationToken, Job, JobDetail, JobsResponse, RateLimit,
+    RateLimitResponse, Repository, RetentionResponse, RunTiming, Runner, RunnersResponse,
+    TokenInfo, User, Workflow, WorkflowRun, WorkflowRunsResponse, WorkflowsResponse,
+};
+use crate::response_cache::ResponseCache;
 
 // ── Constants ──────────────────────────────────────────────────────
 
-const DEFAULT_BASE_URL: &str = "https://api.github.com";
+pub const DEFAULT_BASE_URL: &str = "https://api.github.com";
 const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
 const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
 const MAX_RETRIES: u32 = 3;
+/// Redirect hops followed for one request (logs and artifacts take one)
+const MAX_REDIRECTS: usize = 5;
+/// Where GitHub Enterprise Server serves its REST API
+const GHES_API_PATH: &str = "/api/v3";
+/// Pages of 100 caches read before giving up on the rest
+const MAX_CACHE_PAGES: u64 = 10;
+/// Pages of 100 check runs or annotations read before giving up on the rest
+const MAX_CHECK_PAGES: u64 = 10;
+/// 403 body when a fine-grained token lacks a repository permission
+const FINE_GRAINED_DENIED: &str = "Resource not accessible by personal access token";
+
+// ── Base URL probe ─────────────────────────────────────────────────
+
+/// An API base URL that answered `GET /meta`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProbedBaseUrl {
+    pub url: String,
+    /// `/api/v3` was appended to the URL given
+    pub corrected: bool,
+}
+
+/// `GET {base}/meta` must answer with JSON, not a web page or an error
+async fn probe_meta(client: &reqwest::Client, base: &str) -> Result<()> {
+    let url = format!("{}/meta", base);
+    debug!(%url, "Probing API base URL");
+    let resp = client
+        .get(&url)
+        .header(USER_AGENT, "atlas-prod-monitor")
+        .header(ACCEPT, "application/vnd.github+json")
+        .send()
+        .await
+        .with_context(|| format!("Could not reach {}", url))?;
+    let status = resp.status();
+    if !status.is_success() {
+        anyhow::bail!("GET {} returned HTTP {}", url, status.as_u16());
+    }
+    let is_json = resp
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.contains("json"));
+    if !is_json {
+        anyhow::bail!("GET {} returned a web page, not the API", url);
+    }
+    Ok(())
+}
+
+// ── Errors ─────────────────────────────────────────────────────────
+
+/// A fine-grained token was refused for want of a repository permission
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionDenied {
+    /// As named on the token settings page: "Actions", "Contents", ...
+    pub permission: &'static str,
+    /// The request changed something (re-run, cancel, dispatch)
+    pub write: bool,
+}
+
+impl PermissionDenied {
+    /// Which permission a refused `method path` request needed
+    fn for_request(method: &reqwest::Method, path: &str) -> Self {
+        let permission = if path.contains("/contents/") {
+            "Contents"
+        } else if path.contains("/check-suites/") || path.contains("/check-runs/") {
+            "Checks"
+        } else {
+            "Actions"
+        };
+        Self {
+            permission,
+            write: *method != reqwest::Method::GET,
+        }
+    }
+}
+
+impl std::fmt::Display for PermissionDenied {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "your fine-grained token lacks the '{}: {}' repository permission — edit it at github.com/settings/personal-access-tokens",
+            self.permission,
+            if self.write { "Read and write" } else { "Read-only" }
+        )
+    }
+}
+
+impl std::error::Error for PermissionDenied {}
 
 // ── GitHub API Client ──────────────────────────────────────────────
 
@@ -21,6 +124,39 @@ pub struct GitHubClient {
     pub owner: String,
     pub repo: String,
     base_url: String,
+    /// Deprecation notices, shared by every clone of the client
+    notices: Arc<Mutex<ApiNotices>>,
+    /// Cached GET bodies by path and query hash, shared by every clone
+    responses: Arc<Mutex<Responses>>,
+    /// The rate limit as of the last response, shared by every clone
+    rate_limit: Arc<Mutex<Option<RateLimit>>>,
+    /// Renews a GitHub App's installation token, which then stands in for
+    /// `token`
+    app_token: Option<Arc<AppToken>>,
+}
+
+/// GET bodies keyed by path and a hash of the query
+type Responses = ResponseCache<(String, u64), Vec<u8>>;
+
+/// `Sunset` / `Deprecation` / `Warning` headers seen this session
+#[derive(Debug, Default)]
+struct ApiNotices {
+    /// Endpoints already reported, so each is mentioned once
+    seen: HashSet<String>,
+    /// Messages not yet shown in the UI
+    pending: Vec<String>,
+}
+
+impl ApiNotices {
+    /// Queue `message` unless `endpoint` was already reported
+    fn record(&mut self, endpoint: &str, message: String) -> bool {
+        if !self.seen.insert(endpoint.to_string()) {
+            return false;
+        }
+        warn!(endpoint, "{}", message);
+        self.pending.push(message);
+        true
+    }
 }
 
 impl GitHubClient {
@@ -49,6 +185,8 @@ impl GitHubClient {
             .timeout(REQUEST_TIMEOUT)
             .connect_timeout(CONNECT_TIMEOUT)
             .pool_max_idle_per_host(5)
+            // Followed by hand in `send` so the token stays on the API host
+            .redirect(reqwest::redirect::Policy::none())
             .build()
             .expect("Failed to build HTTP client");
 
@@ -58,7 +196,97 @@ impl GitHubClient {
             owner,
             repo,
             base_url: base_url.trim_end_matches('/').to_string(),
+            notices: Arc::default(),
+            responses: Arc::new(Mutex::new(ResponseCache::disabled())),
+            rate_limit: Arc::default(),
+            app_token: None,
+        }
+    }
+
+    /// Send a GitHub App's installation token, renewed as it expires,
+    /// instead of the fixed one
+    pub fn with_app_token(mut self, app_token: Option<Arc<AppToken>>) -> Self {
+        self.app_token = app_token;
+        self
+    }
+
+    /// Serve repeated GETs of jobs, workflows and artifacts from memory for
+    /// `ttl`, keeping at most `max_entries` responses
+    pub fn with_response_cache(mut self, ttl: Duration, max_entries: usize) -> Self {
+        self.responses = Arc::new(Mutex::new(ResponseCache::new(ttl, max_entries)));
+        self
+    }
+
+    /// Forget every cached response, so the next requests go to GitHub
+    pub fn invalidate_cache(&self) {
+        if let Ok(mut responses) = self.responses.lock() {
+            responses.clear();
+        }
+    }
+
+    /// The API root every request goes to
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Trim an `--api-url` and check it is an http(s) URL with a host
+    pub fn normalize_base_url(input: &str) -> Result<String> {
+        let trimmed = input.trim().trim_end_matches('/');
+        if !trimmed.contains("://") {
+            anyhow::bail!(
+                "API URL \"{}\" has no scheme — did you mean https://{}{}?",
+                trimmed,
+                trimmed,
+                GHES_API_PATH
+            );
+        }
+        let url = reqwest::Url::parse(trimmed)
+            .with_context(|| format!("API URL \"{}\" is not a valid URL", trimmed))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            anyhow::bail!(
+                "API URL \"{}\" must start with http:// or https://",
+                trimmed
+            );
+        }
+        if url.host_str().is_none_or(str::is_empty) {
+            anyhow::bail!("API URL \"{}\" has no host", trimmed);
+        }
+        Ok(trimmed.to_string())
+    }
+
+    /// Normalize an `--api-url` and make sure a GitHub API answers there.
+    /// A web host (`https://github.example.com`) is corrected to its
+    /// `/api/v3` API when only that answers.
+    pub async fn probe_base_url(input: &str) -> Result<ProbedBaseUrl> {
+        let url = Self::normalize_base_url(input)?;
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .connect_timeout(CONNECT_TIMEOUT)
+            .redirect(reqwest::redirect::Policy::none())
+            .build()?;
+
+        let err = match probe_meta(&client, &url).await {
+            Ok(()) => {
+                return Ok(ProbedBaseUrl {
+                    url,
+                    corrected: false,
+                })
+            }
+            Err(e) => e,
+        };
+        if !url.ends_with(GHES_API_PATH) {
+            let api = format!("{}{}", url, GHES_API_PATH);
+            if probe_meta(&client, &api).await.is_ok() {
+                return Ok(ProbedBaseUrl {
+                    url: api,
+                    corrected: true,
+                });
+            }
         }
+        Err(err.context(format!(
+            "No GitHub API at {} — pass the API base URL, e.g. https://github.example.com{}",
+            url, GHES_API_PATH
+        )))
     }
 
     /// Switch to a different repository.
@@ -67,6 +295,44 @@ impl GitHubClient {
         self.repo = repo;
     }
 
+    /// Deprecation notices not yet shown; each endpoint is reported once
+    pub fn take_api_notices(&self) -> Vec<String> {
+        match self.notices.lock() {
+            Ok(mut notices) => std::mem::take(&mut notices.pending),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Requests left this hour, once GitHub has said; GitHub Enterprise
+    /// servers without rate limiting never do
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        *self.rate_limit.lock().ok()?
+    }
+
+    fn note_rate_limit(&self, headers: &HeaderMap) {
+        let Some(limit) = rate_limit_from_headers(headers) else {
+            return;
+        };
+        debug!(
+            remaining = limit.remaining,
+            limit = limit.limit,
+            reset = %limit.reset,
+            "Rate limit"
+        );
+        if let Ok(mut current) = self.rate_limit.lock() {
+            *current = Some(limit);
+        }
+    }
+
+    fn note_deprecation(&self, path: &str, headers: &HeaderMap) {
+        let endpoint = endpoint_key(path);
+        if let Some(message) = api_notice(&endpoint, headers) {
+            if let Ok(mut notices) = self.notices.lock() {
+                notices.record(&endpoint, message);
+            }
+        }
+    }
+
     // ── Core request engine with retry + rate-limit handling ───────
 
     async fn execute_with_retry(
@@ -74,9 +340,24 @@ impl GitHubClient {
         method: reqwest::Method,
         path: &str,
         query: &[(&str, String)],
+    ) -> Result<reqwest::Response> {
+        self.execute_with_accept(method, path, query, "application/vnd.github+json", None)
+            .await
+    }
+
+    /// Like `execute_with_retry`, with a custom `Accept` media type and an
+    /// optional JSON body
+    async fn execute_with_accept(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        query: &[(&str, String)],
+        accept: &str,
+        body: Option<&serde_json::Value>,
     ) -> Result<reqwest::Response> {
         let url = format!("{}{}", self.base_url, path);
         let mut last_error: Option<anyhow::Error> = None;
+        let mut renewed = false;
 
         for attempt in 0..MAX_RETRIES {
             if attempt > 0 {
@@ -89,18 +370,25 @@ impl GitHubClient {
                 tokio::time::sleep(delay).await;
             }
 
+            let token = match &self.app_token {
+                Some(app_token) => app_token.get().await?,
+                None => self.token.clone(),
+            };
             let mut req = self
                 .client
                 .request(method.clone(), &url)
                 .header(USER_AGENT, "atlas-prod-monitor")
-                .header(ACCEPT, "application/vnd.github+json")
-                .header(AUTHORIZATION, format!("Bearer {}", self.token));
+                .header(ACCEPT, accept)
+                .header(AUTHORIZATION, format!("Bearer {}", token));
 
             for (k, v) in query {
                 req = req.query(&[(*k, v.as_str())]);
             }
+            if let Some(body) = body {
+                req = req.json(body);
+            }
 
-            let resp = match req.send().await {
+            let resp = match self.send(req, &token, method == reqwest::Method::GET).await {
                 Ok(r) => r,
                 Err(e) if e.is_timeout() || e.is_connect() => {
                     warn!(attempt = attempt + 1, error = %e, "Request failed (transient)");
@@ -112,6 +400,23 @@ impl GitHubClient {
                 }
             };
 
+            self.note_deprecation(path, resp.headers());
+            self.note_rate_limit(resp.headers());
+
+            // An installation token can be revoked before it expires: renew
+            // it once and try again
+            if let Some(app_token) = &self.app_token {
+                if resp.status() == reqwest::StatusCode::UNAUTHORIZED && !renewed {
+                    app_token.renew(&token).await?;
+                    renewed = true;
+                    last_error = Some(anyhow::anyhow!(
+                        "GitHub API error ({}): installation token refused",
+                        resp.status()
+                    ));
+                    continue;
+                }
+            }
+
             // Rate limit handling (429 or 403 with x-ratelimit-remaining: 0)
             let is_rate_limited = resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS
                 || (resp.status() == reqwest::StatusCode::FORBIDDEN
@@ -160,9 +465,16 @@ impl GitHubClient {
             if !resp.status().is_success() {
                 let status = resp.status();
                 let body = resp.text().await.unwrap_or_default();
+                if status == reqwest::StatusCode::FORBIDDEN && body.contains(FINE_GRAINED_DENIED) {
+                    return Err(PermissionDenied::for_request(&method, path).into());
+                }
                 anyhow::bail!("GitHub API error ({}): {}", status, body);
             }
 
+            if method != reqwest::Method::GET {
+                // A re-run, cancel or delete changes what GETs would return
+                self.invalidate_cache();
+            }
             return Ok(resp);
         }
 
@@ -170,11 +482,126 @@ impl GitHubClient {
             .unwrap_or_else(|| anyhow::anyhow!("Request failed after {} retries", MAX_RETRIES)))
     }
 
+    /// GET `path`'s body, from the response cache while it is fresh
+    async fn get_cached(&self, path: &str, query: &[(&str, String)]) -> Result<Vec<u8>> {
+        let mut hasher = DefaultHasher::new();
+        query.hash(&mut hasher);
+        let key = (path.to_string(), hasher.finish());
+        if let Some(body) = self.responses.lock().ok().and_then(|mut r| r.get(&key)) {
+            debug!(path, "Response cache hit");
+            return Ok(body);
+        }
+        let body = self
+            .execute_with_retry(reqwest::Method::GET, path, query)
+            .await?
+            .bytes()
+            .await?
+            .to_vec();
+        if let Ok(mut responses) = self.responses.lock() {
+            responses.insert(key, body.clone());
+        }
+        Ok(body)
+    }
+
+    /// Send `req`, following GET redirects (job logs and artifact zips
+    /// bounce to signed blob-storage URLs). The token is only sent along
+    /// when the redirect stays on the API host.
+    async fn send(
+        &self,
+        req: reqwest::RequestBuilder,
+        token: &str,
+        follow_redirects: bool,
+    ) -> reqwest::Result<reqwest::Response> {
+        let mut resp = req.send().await?;
+        for _ in 0..MAX_REDIRECTS {
+            if !follow_redirects || !resp.status().is_redirection() {
+                break;
+            }
+            let Some(next) = redirect_target(resp.url(), resp.headers()) else {
+                break;
+            };
+            let keep_auth = same_origin(&next, &self.base_url);
+            debug!(
+                host = next.host_str().unwrap_or(""),
+                keep_auth, "Following redirect"
+            );
+
+            let mut follow = self
+                .client
+                .get(next)
+                .header(USER_AGENT, "atlas-prod-monitor");
+            if keep_auth {
+                follow = follow.header(AUTHORIZATION, format!("Bearer {}", token));
+            }
+            resp = follow.send().await?;
+        }
+        Ok(resp)
+    }
+
     // ── API methods ────────────────────────────────────────────────
 
-    /// Fetch user repositories (sorted by most recently pushed)
+    /// Fetch the authenticated user along with the token's OAuth scopes
+    #[instrument(skip(self))]
+    pub async fn get_authenticated_user(&self) -> Result<TokenInfo> {
+        let resp = self
+            .execute_with_retry(reqwest::Method::GET, "/user", &[])
+            .await
+            .context("Failed to fetch authenticated user")?;
+
+        let scopes = resp
+            .headers()
+            .get("x-oauth-scopes")
+            .and_then(|v| v.to_str().ok())
+            .map(parse_scopes_header);
+
+        let user = resp
+            .json::<User>()
+            .await
+            .context("Failed to parse user response")?;
+
+        Ok(TokenInfo { user, scopes })
+    }
+
+    /// `installation:<id>` when the client acts as a GitHub App installation
+    pub fn installation(&self) -> Option<String> {
+        self.app_token
+            .as_ref()
+            .map(|app_token| app_token.identity())
+    }
+
+    /// Who the token acts as: the user's login, or `installation:<id>` for
+    /// an app installation, which can't read `/user`
+    #[instrument(skip(self))]
+    pub async fn get_login(&self) -> Result<String> {
+        match self.installation() {
+            Some(installation) => Ok(installation),
+            None => Ok(self.get_authenticated_user().await?.user.login),
+        }
+    }
+
+    /// Fetch the current rate limit status (does not count against the limit)
+    #[instrument(skip(self))]
+    pub async fn get_rate_limit(&self) -> Result<RateLimitResponse> {
+        let resp = self
+            .execute_with_retry(reqwest::Method::GET, "/rate_limit", &[])
+            .await
+            .context("Failed to fetch rate limit")?;
+
+        resp.json::<RateLimitResponse>()
+            .await
+            .context("Failed to parse rate limit response")
+    }
+
+    /// Fetch user repositories (sorted by most recently pushed). An app
+    /// installation lists the repositories it was granted instead.
     #[instrument(skip(self))]
     pub async fn get_user_repos(&self, per_page: u8, page: u64) -> Result<Vec<Repository>> {
+        if self.app_token.is_some() {
+            return Ok(self
+                .get_installation_repos(per_page, page)
+                .await?
+                .repositories);
+        }
         let query = vec![
             ("per_page", per_page.to_string()),
             ("page", page.to_string()),
@@ -193,7 +620,108 @@ impl GitHubClient {
             .context("Failed to parse repositories response")
     }
 
-    /// Fetch recent workflow runs for the repo
+    /// How many repositories `get_user_repos` pages through. The list
+    /// carries no count, so this asks for one repo a page and reads the
+    /// last page's number off the `Link` header
+    #[instrument(skip(self))]
+    pub async fn get_user_repos_count(&self) -> Result<u64> {
+        if self.app_token.is_some() {
+            return Ok(self.get_installation_repos(1, 1).await?.total_count);
+        }
+        let query = vec![
+            ("per_page", "1".to_string()),
+            ("page", "1".to_string()),
+            ("type", "all".to_string()),
+        ];
+
+        let resp = self
+            .execute_with_retry(reqwest::Method::GET, "/user/repos", &query)
+            .await
+            .context("Failed to count repositories")?;
+        let last = resp
+            .headers()
+            .get(reqwest::header::LINK)
+            .and_then(|v| v.to_str().ok())
+            .and_then(last_page);
+        match last {
+            Some(last) => Ok(last),
+            // A single page: the one repo there is, or none
+            None => Ok(resp
+                .json::<Vec<serde_json::Value>>()
+                .await
+                .context("Failed to parse repositories response")?
+                .len() as u64),
+        }
+    }
+
+    /// One page of the repositories a GitHub App installation can see
+    async fn get_installation_repos(
+        &self,
+        per_page: u8,
+        page: u64,
+    ) -> Result<InstallationRepositories> {
+        let query = vec![
+            ("per_page", per_page.to_string()),
+            ("page", page.to_string()),
+        ];
+        let resp = self
+            .execute_with_retry(reqwest::Method::GET, "/installation/repositories", &query)
+            .await
+            .context("Failed to fetch the installation's repositories")?;
+
+        resp.json::<InstallationRepositories>()
+            .await
+            .context("Failed to parse repositories response")
+    }
+
+    /// One repository by name, whichever repo the client points at
+    #[instrument(skip(self))]
+    pub async fn get_repo(&self, owner: &str, repo: &str) -> Result<Repository> {
+        let path = format!("/repos/{}/{}", owner, repo);
+        let resp = self
+            .execute_with_retry(reqwest::Method::GET, &path, &[])
+            .await
+            .with_context(|| format!("Failed to fetch {}/{}", owner, repo))?;
+
+        resp.json::<Repository>()
+            .await
+            .context("Failed to parse repository response")
+    }
+
+    /// Trade the client's token, a GitHub App's JWT, for an access token of
+    /// one of the app's installations
+    #[instrument(skip(self), fields(installation_id))]
+    pub async fn create_installation_token(
+        &self,
+        installation_id: u64,
+    ) -> Result<InstallationToken> {
+        let path = format!("/app/installations/{}/access_tokens", installation_id);
+        let status_is = |e: &anyhow::Error, status: reqwest::StatusCode| {
+            e.to_string()
+                .starts_with(&format!("GitHub API error ({})", status))
+        };
+
+        let resp = match self
+            .execute_with_retry(reqwest::Method::POST, &path, &[])
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) if status_is(&e, reqwest::StatusCode::UNAUTHORIZED) => anyhow::bail!(
+                "GitHub rejected the app's JWT: check GITHUB_APP_ID and that the private key is the app's"
+            ),
+            Err(e) if is_not_found(&e) => anyhow::bail!(
+                "The app has no installation {} — check GITHUB_APP_INSTALLATION_ID",
+                installation_id
+            ),
+            Err(e) => return Err(e.context("Failed to create an installation token")),
+        };
+
+        resp.json::<InstallationToken>()
+            .await
+            .context("Failed to parse installation token response")
+    }
+
+    /// Fetch recent workflow runs for the repo, or for one workflow
     #[instrument(skip(self), fields(owner = %self.owner, repo = %self.repo))]
     pub async fn get_workflow_runs(
         &self,
@@ -201,8 +729,16 @@ impl GitHubClient {
         page: u64,
         branch: Option<&str>,
         status: Option<&str>,
+        event: Option<&str>,
+        workflow_id: Option<u64>,
     ) -> Result<WorkflowRunsResponse> {
-        let path = format!("/repos/{}/{}/actions/runs", self.owner, self.repo);
+        let path = match workflow_id {
+            Some(id) => format!(
+                "/repos/{}/{}/actions/workflows/{}/runs",
+                self.owner, self.repo, id
+            ),
+            None => format!("/repos/{}/{}/actions/runs", self.owner, self.repo),
+        };
 
         let mut query = vec![
             ("per_page", per_page.to_string()),
@@ -214,6 +750,9 @@ impl GitHubClient {
         if let Some(status) = status {
             query.push(("status", status.to_string()));
         }
+        if let Some(event) = event {
+            query.push(("event", event.to_string()));
+        }
 
         let resp = self
             .execute_with_retry(reqwest::Method::GET, &path, &query)
@@ -225,6 +764,22 @@ impl GitHubClient {
             .context("Failed to parse workflow runs response")
     }
 
+    /// Fetch the repo's workflow files
+    #[instrument(skip(self))]
+    pub async fn get_workflows(&self) -> Result<Vec<Workflow>> {
+        let path = format!("/repos/{}/{}/actions/workflows", self.owner, self.repo);
+        let query = vec![("per_page", "100".to_string())];
+
+        let body = self
+            .get_cached(&path, &query)
+            .await
+            .context("Failed to fetch workflows")?;
+
+        let body = serde_json::from_slice::<WorkflowsResponse>(&body)
+            .context("Failed to parse workflows response")?;
+        Ok(body.workflows)
+    }
+
     /// Fetch jobs for a specific workflow run
     #[instrument(skip(self), fields(run_id))]
     pub async fn get_jobs(&self, run_id: u64) -> Result<JobsResponse> {
@@ -234,14 +789,44 @@ impl GitHubClient {
         );
         let query = vec![("per_page", "100".to_string())];
 
-        let resp = self
-            .execute_with_retry(reqwest::Method::GET, &path, &query)
+        let body = self
+            .get_cached(&path, &query)
             .await
             .context("Failed to fetch jobs")?;
 
-        resp.json::<JobsResponse>()
+        serde_json::from_slice::<JobsResponse>(&body).context("Failed to parse jobs response")
+    }
+
+    /// Fetch a single job including its steps
+    #[instrument(skip(self), fields(job_id))]
+    pub async fn get_job(&self, job_id: u64) -> Result<Job> {
+        let path = format!(
+            "/repos/{}/{}/actions/jobs/{}",
+            self.owner, self.repo, job_id
+        );
+
+        let resp = self
+            .execute_with_retry(reqwest::Method::GET, &path, &[])
+            .await
+            .context("Failed to fetch job")?;
+
+        resp.json::<JobDetail>()
+            .await
+            .map(Job::from)
+            .context("Failed to parse job response")
+    }
+
+    /// Fetch a commit with its message, author and diff stats
+    #[instrument(skip(self))]
+    pub async fn get_commit(&self, sha: &str) -> Result<Commit> {
+        let path = format!("/repos/{}/{}/commits/{}", self.owner, self.repo, sha);
+
+        let resp = self
+            .execute_with_retry(reqwest::Method::GET, &path, &[])
             .await
-            .context("Failed to parse jobs response")
+            .context("Failed to fetch commit")?;
+
+        resp.json().await.context("Failed to parse commit response")
     }
 
     /// Get logs for a specific job (returns raw text)
@@ -260,68 +845,1534 @@ impl GitHubClient {
         resp.text().await.context("Failed to read log body")
     }
 
-    /// Re-run a failed workflow run
-    #[instrument(skip(self), fields(run_id))]
-    pub async fn rerun_workflow(&self, run_id: u64) -> Result<()> {
+    /// Stream a job's log as it downloads, sending each run of whole lines
+    /// on `tx`. Stops early, without error, once `tx` is closed.
+    #[instrument(skip(self, tx), fields(job_id))]
+    pub async fn get_job_logs_streaming(
+        &self,
+        job_id: u64,
+        tx: mpsc::Sender<String>,
+    ) -> Result<()> {
         let path = format!(
-            "/repos/{}/{}/actions/runs/{}/rerun",
-            self.owner, self.repo, run_id
+            "/repos/{}/{}/actions/jobs/{}/logs",
+            self.owner, self.repo, job_id
         );
 
-        self.execute_with_retry(reqwest::Method::POST, &path, &[])
+        let resp = self
+            .execute_with_retry(reqwest::Method::GET, &path, &[])
             .await
-            .context("Failed to re-run workflow")?;
+            .context("Failed to fetch job logs")?;
 
+        let mut body = resp.bytes_stream();
+        let mut pending: Vec<u8> = Vec::new();
+        while let Some(chunk) = body.next().await {
+            pending.extend_from_slice(&chunk.context("Failed to read log body")?);
+            // Hold back the unfinished last line; a newline never falls
+            // inside a UTF-8 sequence, so each batch decodes whole
+            let Some(end) = pending.iter().rposition(|&b| b == b'\n') else {
+                continue;
+            };
+            let batch: Vec<u8> = pending.drain(..=end).collect();
+            if tx
+                .send(String::from_utf8_lossy(&batch).into_owned())
+                .await
+                .is_err()
+            {
+                return Ok(());
+            }
+        }
+        if !pending.is_empty() {
+            let _ = tx
+                .send(String::from_utf8_lossy(&pending).into_owned())
+                .await;
+        }
         Ok(())
     }
 
-    /// Cancel a workflow run
+    /// Days the repo keeps run logs and artifacts. Only repo admins may
+    /// read it; `/actions/permissions` itself doesn't carry the setting.
+    #[instrument(skip(self))]
+    pub async fn get_retention_days(&self) -> Result<u32> {
+        let path = format!(
+            "/repos/{}/{}/actions/permissions/artifact-and-log-retention",
+            self.owner, self.repo
+        );
+
+        let resp = self
+            .execute_with_retry(reqwest::Method::GET, &path, &[])
+            .await
+            .context("Failed to fetch log retention")?;
+
+        let retention: RetentionResponse = resp
+            .json()
+            .await
+            .context("Failed to parse log retention response")?;
+        Ok(retention.days)
+    }
+
+    /// Artifacts uploaded by a run
     #[instrument(skip(self), fields(run_id))]
-    pub async fn cancel_workflow(&self, run_id: u64) -> Result<()> {
+    pub async fn get_artifacts(&self, run_id: u64) -> Result<ArtifactsResponse> {
         let path = format!(
-            "/repos/{}/{}/actions/runs/{}/cancel",
+            "/repos/{}/{}/actions/runs/{}/artifacts",
             self.owner, self.repo, run_id
         );
+        let query = vec![("per_page", "100".to_string())];
 
-        self.execute_with_retry(reqwest::Method::POST, &path, &[])
+        let body = self
+            .get_cached(&path, &query)
             .await
-            .context("Failed to cancel workflow")?;
+            .context("Failed to fetch artifacts")?;
 
-        Ok(())
+        serde_json::from_slice(&body).context("Failed to parse artifacts response")
     }
-}
 
-// ── Tests ──────────────────────────────────────────────────────────
+    /// Stream an artifact's zip into `dest`, following GitHub's redirect to
+    /// the blob store. `progress` gets the bytes written so far; returns the
+    /// total.
+    #[instrument(skip(self, progress), fields(artifact_id))]
+    pub async fn download_artifact(
+        &self,
+        artifact_id: u64,
+        dest: &Path,
+        mut progress: impl FnMut(u64),
+    ) -> Result<u64> {
+        use tokio::io::AsyncWriteExt;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let path = format!(
+            "/repos/{}/{}/actions/artifacts/{}/zip",
+            self.owner, self.repo, artifact_id
+        );
+        let mut resp = self
+            .execute_with_retry(reqwest::Method::GET, &path, &[])
+            .await
+            .context("Failed to download artifact")?;
 
-    #[test]
-    fn test_new_client_default_base_url() {
-        let client = GitHubClient::new("owner".into(), "repo".into(), "token".into());
-        assert_eq!(client.base_url, DEFAULT_BASE_URL);
-        assert_eq!(client.owner, "owner");
-        assert_eq!(client.repo, "repo");
+        // Write beside the destination so a failed download leaves no zip
+        let partial = dest.with_extension("zip.part");
+        let mut file = tokio::fs::File::create(&partial)
+            .await
+            .with_context(|| format!("Failed to create {}", partial.display()))?;
+        let copied: Result<u64> = async {
+            let mut written = 0u64;
+            while let Some(chunk) = resp
+                .chunk()
+                .await
+                .context("Artifact download interrupted")?
+            {
+                file.write_all(&chunk)
+                    .await
+                    .with_context(|| format!("Failed to write {}", partial.display()))?;
+                written += chunk.len() as u64;
+                progress(written);
+            }
+            file.flush().await?;
+            Ok(written)
+        }
+        .await;
+        let written = match copied {
+            Ok(written) => written,
+            Err(e) => {
+                let _ = tokio::fs::remove_file(&partial).await;
+                return Err(e);
+            }
+        };
+        tokio::fs::rename(&partial, dest)
+            .await
+            .with_context(|| format!("Failed to move download to {}", dest.display()))?;
+        Ok(written)
     }
 
-    #[test]
-    fn test_with_base_url_trims_trailing_slash() {
-        let client = GitHubClient::with_base_url(
-            "owner".into(),
-            "repo".into(),
-            "token".into(),
-            "https://github.example.com/api/v3/".into(),
-        );
-        assert_eq!(client.base_url, "https://github.example.com/api/v3");
+    /// Raw content of a file in the repo at a given commit
+    #[instrument(skip(self), fields(path, git_ref))]
+    pub async fn get_file_content(&self, path: &str, git_ref: &str) -> Result<String> {
+        let path = format!("/repos/{}/{}/contents/{}", self.owner, self.repo, path);
+        let query = vec![("ref", git_ref.to_string())];
+
+        let resp = self
+            .execute_with_accept(
+                reqwest::Method::GET,
+                &path,
+                &query,
+                "application/vnd.github.raw+json",
+                None,
+            )
+            .await
+            .context("Failed to fetch file content")?;
+
+        resp.text().await.context("Failed to read file content")
     }
 
-    #[test]
-    fn test_client_is_clone() {
-        let client = GitHubClient::new("owner".into(), "repo".into(), "token".into());
-        let cloned = client.clone();
-        assert_eq!(cloned.owner, client.owner);
-        assert_eq!(cloned.repo, client.repo);
-        assert_eq!(cloned.base_url, client.base_url);
+    /// Annotations from every check run in a check suite. The suite is the
+    /// run's own: a commit's check runs would take in other workflows too.
+    #[instrument(skip(self), fields(check_suite_id))]
+    pub async fn get_check_suite_annotations(
+        &self,
+        check_suite_id: u64,
+    ) -> Result<Vec<Annotation>> {
+        let mut annotations = Vec::new();
+        for check_run in self.get_check_suite_runs(check_suite_id).await? {
+            let path = format!(
+                "/repos/{}/{}/check-runs/{}/annotations",
+                self.owner, self.repo, check_run.id
+            );
+            let page = self
+                .get_every_page(&path, |annotations: Vec<Annotation>| annotations)
+                .await
+                .context("Failed to fetch annotations")?;
+            annotations.extend(page);
+        }
+        Ok(annotations)
+    }
+
+    /// Annotations across a check suite's check runs, counted from each
+    /// run's summary rather than fetched
+    #[instrument(skip(self), fields(check_suite_id))]
+    pub async fn get_annotation_count(&self, check_suite_id: u64) -> Result<u64> {
+        let check_runs = self.get_check_suite_runs(check_suite_id).await?;
+        Ok(check_runs
+            .iter()
+            .filter_map(|c| c.output.as_ref())
+            .map(|o| o.annotations_count)
+            .sum())
+    }
+
+    /// Every check run in a check suite
+    async fn get_check_suite_runs(&self, check_suite_id: u64) -> Result<Vec<CheckRun>> {
+        let path = format!(
+            "/repos/{}/{}/check-suites/{}/check-runs",
+            self.owner, self.repo, check_suite_id
+        );
+        self.get_every_page(&path, |response: CheckRunsResponse| response.check_runs)
+            .await
+            .context("Failed to fetch check runs")
+    }
+
+    /// Read `path` 100 at a time up to the `Link` header's last page (or
+    /// `MAX_CHECK_PAGES`), with `items` taking each page's entries
+    async fn get_every_page<P: DeserializeOwned, T>(
+        &self,
+        path: &str,
+        items: impl Fn(P) -> Vec<T>,
+    ) -> Result<Vec<T>> {
+        let mut all = Vec::new();
+        for page in 1..=MAX_CHECK_PAGES {
+            let query = vec![("per_page", "100".to_string()), ("page", page.to_string())];
+            let resp = self
+                .execute_with_retry(reqwest::Method::GET, path, &query)
+                .await?;
+            let last = resp
+                .headers()
+                .get(reqwest::header::LINK)
+                .and_then(|v| v.to_str().ok())
+                .and_then(last_page)
+                .unwrap_or(page);
+            all.extend(items(
+                resp.json::<P>()
+                    .await
+                    .context("Failed to parse the response")?,
+            ));
+            if page >= last {
+                break;
+            }
+        }
+        Ok(all)
+    }
+
+    /// One run as it is now, for watching it finish
+    #[instrument(skip(self), fields(run_id))]
+    pub async fn get_workflow_run(&self, run_id: u64) -> Result<WorkflowRun> {
+        let path = format!(
+            "/repos/{}/{}/actions/runs/{}",
+            self.owner, self.repo, run_id
+        );
+        let resp = self
+            .execute_with_retry(reqwest::Method::GET, &path, &[])
+            .await
+            .context("Failed to fetch workflow run")?;
+
+        resp.json()
+            .await
+            .context("Failed to parse workflow run response")
+    }
+
+    /// Billable minutes per runner OS for a run
+    #[instrument(skip(self), fields(run_id))]
+    pub async fn get_run_timing(&self, run_id: u64) -> Result<RunTiming> {
+        let path = format!(
+            "/repos/{}/{}/actions/runs/{}/timing",
+            self.owner, self.repo, run_id
+        );
+        let resp = self
+            .execute_with_retry(reqwest::Method::GET, &path, &[])
+            .await
+            .context("Failed to fetch run timing")?;
+
+        resp.json()
+            .await
+            .context("Failed to parse run timing response")
+    }
+
+    /// The repo's deployment environments with their protection rules
+    #[instrument(skip(self))]
+    pub async fn get_environments(&self) -> Result<Vec<Environment>> {
+        let path = format!("/repos/{}/{}/environments", self.owner, self.repo);
+        let query = vec![("per_page", "100".to_string())];
+
+        let resp = self
+            .execute_with_retry(reqwest::Method::GET, &path, &query)
+            .await
+            .context("Failed to fetch environments")?;
+
+        let envs: EnvironmentsResponse = resp
+            .json()
+            .await
+            .context("Failed to parse environments response")?;
+        Ok(envs.environments)
+    }
+
+    /// Self-hosted runners registered to the repo
+    #[instrument(skip(self))]
+    pub async fn get_repo_runners(&self) -> Result<Vec<Runner>> {
+        let path = format!("/repos/{}/{}/actions/runners", self.owner, self.repo);
+        self.get_runners(&path).await
+    }
+
+    /// Self-hosted runners registered to `org`, shared by its repos. Needs
+    /// an org admin's token.
+    #[instrument(skip(self))]
+    pub async fn get_org_runners(&self, org: &str) -> Result<Vec<Runner>> {
+        let path = format!("/orgs/{}/actions/runners", org);
+        self.get_runners(&path).await
+    }
+
+    async fn get_runners(&self, path: &str) -> Result<Vec<Runner>> {
+        let query = vec![("per_page", "100".to_string())];
+
+        let resp = self
+            .execute_with_retry(reqwest::Method::GET, path, &query)
+            .await
+            .context("Failed to fetch runners")?;
+
+        let runners: RunnersResponse = resp
+            .json()
+            .await
+            .context("Failed to parse runners response")?;
+        Ok(runners.runners)
+    }
+
+    /// The repo's Actions caches, largest first
+    #[instrument(skip(self), fields(owner = %self.owner, repo = %self.repo))]
+    pub async fn get_caches(&self) -> Result<Vec<ActionsCache>> {
+        let path = format!("/repos/{}/{}/actions/caches", self.owner, self.repo);
+        let mut caches: Vec<ActionsCache> = Vec::new();
+        for page in 1..=MAX_CACHE_PAGES {
+            let query = vec![
+                ("per_page", "100".to_string()),
+                ("page", page.to_string()),
+                ("sort", "size_in_bytes".to_string()),
+                ("direction", "desc".to_string()),
+            ];
+            let resp = self
+                .execute_with_retry(reqwest::Method::GET, &path, &query)
+                .await
+                .context("Failed to fetch caches")?;
+            let response: ActionsCachesResponse = resp
+                .json()
+                .await
+                .context("Failed to parse caches response")?;
+            let last = response.actions_caches.len() < 100;
+            caches.extend(response.actions_caches);
+            if last || caches.len() as u64 >= response.total_count {
+                break;
+            }
+        }
+        caches.sort_by_key(|c| std::cmp::Reverse(c.size_in_bytes));
+        Ok(caches)
+    }
+
+    /// Delete one Actions cache
+    #[instrument(skip(self), fields(cache_id))]
+    pub async fn delete_cache(&self, cache_id: u64) -> Result<()> {
+        let path = format!(
+            "/repos/{}/{}/actions/caches/{}",
+            self.owner, self.repo, cache_id
+        );
+
+        self.execute_with_retry(reqwest::Method::DELETE, &path, &[])
+            .await
+            .context("Failed to delete cache")?;
+
+        Ok(())
+    }
+
+    /// Deployments a run made, newest first, each with its latest status.
+    /// GitHub lists deployments by commit; the statuses tell which run of
+    /// that commit made them.
+    #[instrument(skip(self), fields(run_id))]
+    pub async fn get_deployments(&self, run_id: u64, head_sha: &str) -> Result<Vec<Deployment>> {
+        let path = format!("/repos/{}/{}/deployments", self.owner, self.repo);
+        let query = vec![
+            ("sha", head_sha.to_string()),
+            ("per_page", "30".to_string()),
+        ];
+
+        let resp = self
+            .execute_with_retry(reqwest::Method::GET, &path, &query)
+            .await
+            .context("Failed to fetch deployments")?;
+        let deployments: Vec<Deployment> = resp
+            .json()
+            .await
+            .context("Failed to parse deployments response")?;
+
+        let statuses =
+            futures::future::join_all(deployments.iter().map(|d| self.latest_status(d.id))).await;
+        let mut found = Vec::new();
+        for (mut deployment, status) in deployments.into_iter().zip(statuses) {
+            deployment.status = status?;
+            if deployment
+                .status
+                .as_ref()
+                .is_none_or(|s| s.belongs_to_run(run_id))
+            {
+                found.push(deployment);
+            }
+        }
+        Ok(found)
+    }
+
+    async fn latest_status(&self, deployment_id: u64) -> Result<Option<DeploymentStatus>> {
+        let path = format!(
+            "/repos/{}/{}/deployments/{}/statuses",
+            self.owner, self.repo, deployment_id
+        );
+        let query = vec![("per_page", "1".to_string())];
+
+        let resp = self
+            .execute_with_retry(reqwest::Method::GET, &path, &query)
+            .await
+            .context("Failed to fetch deployment statuses")?;
+        let statuses: Vec<DeploymentStatus> = resp
+            .json()
+            .await
+            .context("Failed to parse deployment statuses")?;
+        Ok(statuses.into_iter().next())
+    }
+
+    /// Re-run a finished workflow run. GitHub refuses (403) runs still
+    /// going or more than 30 days old.
+    #[instrument(skip(self), fields(run_id))]
+    pub async fn rerun_workflow(&self, run_id: u64, debug_logging: bool) -> Result<()> {
+        let path = format!(
+            "/repos/{}/{}/actions/runs/{}/rerun",
+            self.owner, self.repo, run_id
+        );
+
+        match self.post_rerun(&path, debug_logging).await {
+            Ok(_) => Ok(()),
+            Err(e) if is_forbidden(&e) => Err(rerun_refused()),
+            Err(e) => Err(e.context("Failed to re-run workflow")),
+        }
+    }
+
+    /// Re-run only the failed and cancelled jobs of a run (and what
+    /// depends on them)
+    #[instrument(skip(self), fields(run_id))]
+    pub async fn rerun_failed_jobs(&self, run_id: u64, debug_logging: bool) -> Result<()> {
+        let path = format!(
+            "/repos/{}/{}/actions/runs/{}/rerun-failed-jobs",
+            self.owner, self.repo, run_id
+        );
+
+        match self.post_rerun(&path, debug_logging).await {
+            Ok(_) => Ok(()),
+            Err(e) if is_forbidden(&e) => Err(rerun_refused()),
+            Err(e) => Err(e.context("Failed to re-run failed jobs")),
+        }
+    }
+
+    /// Re-run one job of a finished run. GitHub refuses (403) jobs of runs
+    /// still going or more than 30 days old.
+    #[instrument(skip(self), fields(job_id))]
+    pub async fn rerun_job(&self, job_id: u64, debug_logging: bool) -> Result<()> {
+        let path = format!(
+            "/repos/{}/{}/actions/jobs/{}/rerun",
+            self.owner, self.repo, job_id
+        );
+
+        match self.post_rerun(&path, debug_logging).await {
+            Ok(_) => Ok(()),
+            Err(e) if is_forbidden(&e) => Err(anyhow::anyhow!(
+                "GitHub won't re-run this job: its run is still in progress or more than 30 days old"
+            )),
+            Err(e) => Err(e.context("Failed to re-run job")),
+        }
+    }
+
+    /// `debug_logging` turns on step debug logs for the new attempt
+    async fn post_rerun(&self, path: &str, debug_logging: bool) -> Result<reqwest::Response> {
+        let body = serde_json::json!({ "enable_debug_logging": debug_logging });
+        self.execute_with_accept(
+            reqwest::Method::POST,
+            path,
+            &[],
+            "application/vnd.github+json",
+            Some(&body),
+        )
+        .await
+    }
+
+    /// Delete a finished run with its logs and artifacts
+    #[instrument(skip(self), fields(run_id))]
+    pub async fn delete_workflow_run(&self, run_id: u64) -> Result<()> {
+        let path = format!(
+            "/repos/{}/{}/actions/runs/{}",
+            self.owner, self.repo, run_id
+        );
+
+        match self
+            .execute_with_retry(reqwest::Method::DELETE, &path, &[])
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) if is_forbidden(&e) => Err(anyhow::anyhow!(
+                "GitHub won't delete this run: deleting runs needs write access to the repo"
+            )),
+            Err(e) if is_not_found(&e) => Err(anyhow::anyhow!(
+                "GitHub has no such run: it was already deleted"
+            )),
+            Err(e) => Err(e.context("Failed to delete run")),
+        }
+    }
+
+    /// Turn a workflow back on after it was disabled
+    #[instrument(skip(self), fields(workflow_id))]
+    pub async fn enable_workflow(&self, workflow_id: u64) -> Result<()> {
+        self.set_workflow_enabled(workflow_id, true).await
+    }
+
+    /// Stop a workflow from running (its past runs stay)
+    #[instrument(skip(self), fields(workflow_id))]
+    pub async fn disable_workflow(&self, workflow_id: u64) -> Result<()> {
+        self.set_workflow_enabled(workflow_id, false).await
+    }
+
+    async fn set_workflow_enabled(&self, workflow_id: u64, enable: bool) -> Result<()> {
+        let verb = if enable { "enable" } else { "disable" };
+        let path = format!(
+            "/repos/{}/{}/actions/workflows/{}/{}",
+            self.owner, self.repo, workflow_id, verb
+        );
+
+        match self
+            .execute_with_retry(reqwest::Method::PUT, &path, &[])
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) if is_forbidden(&e) => Err(anyhow::anyhow!(
+                "GitHub won't {} this workflow for your token: it needs the 'workflow' scope — run `atlas auth login` again, or add the scope at github.com/settings/tokens",
+                verb
+            )),
+            Err(e) => Err(e.context(format!("Failed to {} workflow", verb))),
+        }
+    }
+
+    /// Cancel a workflow run. GitHub refuses (409) runs already finished.
+    #[instrument(skip(self), fields(run_id))]
+    pub async fn cancel_workflow(&self, run_id: u64) -> Result<()> {
+        let path = format!(
+            "/repos/{}/{}/actions/runs/{}/cancel",
+            self.owner, self.repo, run_id
+        );
+
+        match self
+            .execute_with_retry(reqwest::Method::POST, &path, &[])
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) if is_conflict(&e) => Err(anyhow::anyhow!(
+                "GitHub won't cancel this run: it has already finished"
+            )),
+            Err(e) => Err(e.context("Failed to cancel workflow")),
+        }
+    }
+
+    /// Trigger a `workflow_dispatch` event on `git_ref` with the given inputs
+    #[instrument(skip(self, inputs), fields(workflow_id, git_ref))]
+    pub async fn dispatch_workflow(
+        &self,
+        workflow_id: u64,
+        git_ref: &str,
+        inputs: HashMap<String, String>,
+    ) -> Result<()> {
+        let path = format!(
+            "/repos/{}/{}/actions/workflows/{}/dispatches",
+            self.owner, self.repo, workflow_id
+        );
+        let body = serde_json::json!({ "ref": git_ref, "inputs": inputs });
+
+        let result = self
+            .execute_with_accept(
+                reqwest::Method::POST,
+                &path,
+                &[],
+                "application/vnd.github+json",
+                Some(&body),
+            )
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            // A missing required input, an unknown one, a ref that doesn't
+            // exist: GitHub's message says which
+            Err(e) => match validation_message(&e) {
+                Some(message) => Err(anyhow::anyhow!("GitHub rejected the dispatch: {}", message)),
+                None => Err(e.context("Failed to dispatch workflow")),
+            },
+        }
+    }
+}
+
+// ── Helpers ────────────────────────────────────────────────────────
+
+/// Split an `x-oauth-scopes` header ("repo, workflow") into scope names
+pub fn parse_scopes_header(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// `/repos/o/r/actions/jobs/42/logs` → `/actions/jobs/:id/logs`, so every
+/// job or run counts as the same endpoint
+fn endpoint_key(path: &str) -> String {
+    let mut segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    if segments.first() == Some(&"repos") && segments.len() >= 3 {
+        segments.drain(..3);
+    }
+    let key: Vec<&str> = segments
+        .into_iter()
+        .map(|s| {
+            if s.chars().all(|c| c.is_ascii_digit()) {
+                ":id"
+            } else {
+                s
+            }
+        })
+        .collect();
+    format!("/{}", key.join("/"))
+}
+
+/// Where a 3xx response points, resolved against the URL it came from
+fn redirect_target(from: &reqwest::Url, headers: &HeaderMap) -> Option<reqwest::Url> {
+    let location = headers.get(reqwest::header::LOCATION)?.to_str().ok()?;
+    from.join(location).ok()
+}
+
+/// A plain 403 from `send`; a missing token permission is reported as
+/// `PermissionDenied` instead
+fn is_forbidden(error: &anyhow::Error) -> bool {
+    error.downcast_ref::<PermissionDenied>().is_none()
+        && error.to_string().starts_with(&format!(
+            "GitHub API error ({})",
+            reqwest::StatusCode::FORBIDDEN
+        ))
+}
+
+/// A 404 from `send`
+fn is_not_found(error: &anyhow::Error) -> bool {
+    error.to_string().starts_with(&format!(
+        "GitHub API error ({})",
+        reqwest::StatusCode::NOT_FOUND
+    ))
+}
+
+/// A 409 from `send`
+fn is_conflict(error: &anyhow::Error) -> bool {
+    error.to_string().starts_with(&format!(
+        "GitHub API error ({})",
+        reqwest::StatusCode::CONFLICT
+    ))
+}
+
+/// Why a whole run can't be re-run, for the 403 GitHub answers with
+fn rerun_refused() -> anyhow::Error {
+    anyhow::anyhow!(
+        "GitHub won't re-run this run: it is still in progress or more than 30 days old"
+    )
+}
+
+/// The `message` of a 422 response: GitHub's own words for what it refused
+fn validation_message(error: &anyhow::Error) -> Option<String> {
+    let text = error.to_string();
+    let body = text.strip_prefix(&format!(
+        "GitHub API error ({}): ",
+        reqwest::StatusCode::UNPROCESSABLE_ENTITY
+    ))?;
+    let json: serde_json::Value = serde_json::from_str(body).ok()?;
+    json.get("message")?.as_str().map(str::to_string)
+}
+
+/// Same scheme, host and port as the API base URL
+fn same_origin(url: &reqwest::Url, base_url: &str) -> bool {
+    reqwest::Url::parse(base_url).is_ok_and(|base| {
+        base.scheme() == url.scheme()
+            && base.host_str() == url.host_str()
+            && base.port_or_known_default() == url.port_or_known_default()
+    })
+}
+
+/// `N` of the `<…&page=N>; rel="last"` entry in a `Link` header
+fn last_page(link: &str) -> Option<u64> {
+    link.split(',').find_map(|entry| {
+        let (url, rel) = entry.split_once(';')?;
+        if !rel.contains("rel=\"last\"") {
+            return None;
+        }
+        let url =
+            reqwest::Url::parse(url.trim().trim_start_matches('<').trim_end_matches('>')).ok()?;
+        let page = url.query_pairs().find(|(key, _)| key == "page")?.1;
+        page.parse().ok()
+    })
+}
+
+/// The core limit from a response's `x-ratelimit-*` headers. Search and
+/// other resources count apart, so their headers are left out.
+fn rate_limit_from_headers(headers: &HeaderMap) -> Option<RateLimit> {
+    let header = |name: &str| headers.get(name)?.to_str().ok();
+    if header("x-ratelimit-resource").is_some_and(|resource| resource != "core") {
+        return None;
+    }
+    Some(RateLimit {
+        remaining: header("x-ratelimit-remaining")?.parse().ok()?,
+        limit: header("x-ratelimit-limit")?.parse().ok()?,
+        reset: chrono::DateTime::from_timestamp(header("x-ratelimit-reset")?.parse().ok()?, 0)?,
+    })
+}
+
+/// Describe the deprecation headers on a response, if it has any
+fn api_notice(endpoint: &str, headers: &HeaderMap) -> Option<String> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+    };
+
+    let status = if let Some(sunset) = header("sunset") {
+        Some(format!("deprecation scheduled {}", header_date(sunset)))
+    } else {
+        header("deprecation").map(|deprecation| match deprecation {
+            "true" => "deprecated".to_string(),
+            date => format!("deprecated since {}", header_date(date)),
+        })
+    };
+    // Warning: 299 - "message"; keep the quoted text
+    let warning = header("warning").map(|w| match (w.find('"'), w.rfind('"')) {
+        (Some(start), Some(end)) if end > start => w[start + 1..end].to_string(),
+        _ => w.to_string(),
+    });
+
+    match (status, warning) {
+        (Some(status), Some(warning)) => {
+            Some(format!("GitHub API: {} {} — {}", endpoint, status, warning))
+        }
+        (Some(status), None) => Some(format!("GitHub API: {} {}", endpoint, status)),
+        (None, Some(warning)) => Some(format!("GitHub API: {}: {}", endpoint, warning)),
+        (None, None) => None,
+    }
+}
+
+/// An HTTP date (`Sat, 01 Jun 2025 00:00:00 GMT`) or `@<unix>` as
+/// YYYY-MM-DD; anything else is shown as sent
+fn header_date(value: &str) -> String {
+    if let Ok(date) = chrono::DateTime::parse_from_rfc2822(value) {
+        return date.format("%Y-%m-%d").to_string();
+    }
+    value
+        .strip_prefix('@')
+        .and_then(|secs| secs.parse::<i64>().ok())
+        .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| value.to_string())
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_new_client_default_base_url() {
+        let client = GitHubClient::new("owner".into(), "repo".into(), "token".into());
+        assert_eq!(client.base_url, DEFAULT_BASE_URL);
+        assert_eq!(client.owner, "owner");
+        assert_eq!(client.repo, "repo");
+    }
+
+    #[test]
+    fn test_with_base_url_trims_trailing_slash() {
+        let client = GitHubClient::with_base_url(
+            "owner".into(),
+            "repo".into(),
+            "token".into(),
+            "https://github.example.com/api/v3/".into(),
+        );
+        assert_eq!(client.base_url, "https://github.example.com/api/v3");
+    }
+
+    #[test]
+    fn test_normalize_base_url() {
+        let normalize = GitHubClient::normalize_base_url;
+        assert_eq!(
+            normalize(" https://github.example.com/api/v3// ").unwrap(),
+            "https://github.example.com/api/v3"
+        );
+        assert_eq!(
+            normalize("http://localhost:8080/").unwrap(),
+            "http://localhost:8080"
+        );
+        let missing = normalize("github.example.com").unwrap_err().to_string();
+        assert!(missing.contains("https://github.example.com/api/v3"));
+        assert!(normalize("ftp://github.example.com").is_err());
+        assert!(normalize("https://").is_err());
+    }
+
+    #[test]
+    fn test_client_is_clone() {
+        let client = GitHubClient::new("owner".into(), "repo".into(), "token".into());
+        let cloned = client.clone();
+        assert_eq!(cloned.owner, client.owner);
+        assert_eq!(cloned.repo, client.repo);
+        assert_eq!(cloned.base_url, client.base_url);
+    }
+
+    #[test]
+    fn test_permission_denied_names_permission_and_access() {
+        let get = reqwest::Method::GET;
+        let post = reqwest::Method::POST;
+
+        let runs = PermissionDenied::for_request(&get, "/repos/o/r/actions/runs");
+        assert_eq!(
+            runs,
+            PermissionDenied {
+                permission: "Actions",
+                write: false
+            }
+        );
+        assert!(runs.to_string().contains("'Actions: Read-only'"));
+
+        let rerun = PermissionDenied::for_request(&post, "/repos/o/r/actions/runs/1/rerun");
+        assert!(rerun
+            .to_string()
+            .contains("'Actions: Read and write' repository permission"));
+
+        let file = PermissionDenied::for_request(&get, "/repos/o/r/contents/.github/ci.yml");
+        assert_eq!(file.permission, "Contents");
+
+        // Survives the context added by each API method
+        let err = anyhow::Error::from(rerun).context("Failed to re-run workflow");
+        assert!(err.downcast_ref::<PermissionDenied>().unwrap().write);
+        assert!(format!("{:#}", err).contains("github.com/settings/personal-access-tokens"));
+    }
+
+    #[test]
+    fn test_parse_scopes_header() {
+        assert_eq!(
+            parse_scopes_header("repo, workflow,read:org"),
+            vec!["repo", "workflow", "read:org"]
+        );
+        assert!(parse_scopes_header("").is_empty());
+    }
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.insert(*name, value.parse().unwrap());
+        }
+        map
+    }
+
+    #[test]
+    fn test_endpoint_key() {
+        assert_eq!(
+            endpoint_key("/repos/octo/api/actions/jobs/42/logs"),
+            "/actions/jobs/:id/logs"
+        );
+        assert_eq!(
+            endpoint_key("/repos/octo/api/actions/runs"),
+            "/actions/runs"
+        );
+        assert_eq!(endpoint_key("/user/repos"), "/user/repos");
+    }
+
+    #[test]
+    fn test_api_notice_from_headers() {
+        assert_eq!(api_notice("/actions/runs", &HeaderMap::new()), None);
+        assert_eq!(
+            api_notice(
+                "/actions/runs",
+                &headers(&[("sunset", "Sun, 01 Jun 2025 00:00:00 GMT")])
+            )
+            .as_deref(),
+            Some("GitHub API: /actions/runs deprecation scheduled 2025-06-01")
+        );
+        assert_eq!(
+            api_notice("/actions/runs", &headers(&[("deprecation", "@1748736000")])).as_deref(),
+            Some("GitHub API: /actions/runs deprecated since 2025-06-01")
+        );
+        assert_eq!(
+            api_notice(
+                "/user",
+                &headers(&[("deprecation", "true"), ("warning", "299 - \"Use /users\"")])
+            )
+            .as_deref(),
+            Some("GitHub API: /user deprecated — Use /users")
+        );
+    }
+
+    #[test]
+    fn test_notices_deduplicated_per_endpoint() {
+        let client = GitHubClient::new("octo".into(), "api".into(), "token".into());
+        let sunset = headers(&[("sunset", "Sun, 01 Jun 2025 00:00:00 GMT")]);
+        client.note_deprecation("/repos/octo/api/actions/jobs/1/logs", &sunset);
+        client
+            .clone()
+            .note_deprecation("/repos/octo/api/actions/jobs/2/logs", &sunset);
+        client.note_deprecation("/repos/octo/api/actions/runs", &HeaderMap::new());
+        assert_eq!(client.take_api_notices().len(), 1);
+
+        // Already reported this session
+        client.note_deprecation("/repos/octo/api/actions/jobs/3/logs", &sunset);
+        assert!(client.take_api_notices().is_empty());
+    }
+
+    #[test]
+    fn test_last_page() {
+        let link = "<https://api.github.com/user/repos?per_page=1&page=2>; rel=\"next\", \
+                    <https://api.github.com/user/repos?per_page=1&page=437>; rel=\"last\"";
+        assert_eq!(last_page(link), Some(437));
+        // On the last page itself there's no `last`
+        assert_eq!(
+            last_page("<https://api.github.com/user/repos?page=1>; rel=\"prev\""),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_user_repos_count() {
+        let server = MockServer::start().await;
+        let last = format!(
+            "<{}/user/repos?per_page=1&page=237>; rel=\"last\"",
+            server.uri()
+        );
+        Mock::given(method("GET"))
+            .and(path("/user/repos"))
+            .and(query_param("per_page", "1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("link", last.as_str())
+                    .set_body_json(serde_json::json!([{}])),
+            )
+            .mount(&server)
+            .await;
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), server.uri());
+        assert_eq!(client.get_user_repos_count().await.unwrap(), 237);
+
+        // Without a `Link`, everything fit on the one page
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user/repos"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&server)
+            .await;
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), server.uri());
+        assert_eq!(client.get_user_repos_count().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_workflow_runs_by_event() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs"))
+            .and(query_param("event", "pull_request"))
+            .and(query_param("status", "failure"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 0, "workflow_runs": []
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), server.uri());
+        let runs = client
+            .get_workflow_runs(20, 1, None, Some("failure"), Some("pull_request"), None)
+            .await
+            .unwrap();
+        assert_eq!(runs.total_count, 0);
+    }
+
+    #[test]
+    fn test_rate_limit_from_headers() {
+        let limit = rate_limit_from_headers(&headers(&[
+            ("x-ratelimit-limit", "5000"),
+            ("x-ratelimit-remaining", "4823"),
+            ("x-ratelimit-reset", "1736676000"),
+            ("x-ratelimit-resource", "core"),
+        ]))
+        .unwrap();
+        assert_eq!((limit.remaining, limit.limit), (4823, 5000));
+        assert_eq!(limit.reset.timestamp(), 1736676000);
+
+        let search = headers(&[
+            ("x-ratelimit-limit", "30"),
+            ("x-ratelimit-remaining", "29"),
+            ("x-ratelimit-reset", "1736676000"),
+            ("x-ratelimit-resource", "search"),
+        ]);
+        assert_eq!(rate_limit_from_headers(&search), None);
+        assert_eq!(rate_limit_from_headers(&HeaderMap::new()), None);
+    }
+
+    // ── Redirects ──────────────────────────────────────────────────
+
+    fn redirect_to(location: &str) -> ResponseTemplate {
+        ResponseTemplate::new(302).insert_header("location", location)
+    }
+
+    #[tokio::test]
+    async fn test_cross_host_redirect_drops_token() {
+        let api = MockServer::start().await;
+        let storage = MockServer::start().await;
+        // Another host name for the same machine, like objects.githubusercontent.com
+        let blob = format!(
+            "http://localhost:{}/blob/logs?sig=abc",
+            storage.address().port()
+        );
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/jobs/7/logs"))
+            .and(header("authorization", "Bearer secret"))
+            .respond_with(redirect_to(&blob))
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/blob/logs"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("step 1\nstep 2"))
+            .mount(&storage)
+            .await;
+
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), api.uri());
+        assert_eq!(client.get_job_logs(7).await.unwrap(), "step 1\nstep 2");
+
+        let requests = storage.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(!requests[0].headers.contains_key("authorization"));
+    }
+
+    #[tokio::test]
+    async fn test_same_host_redirect_keeps_token() {
+        // GitHub Enterprise serves logs from its own host
+        let ghe = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/repos/octo/api/actions/jobs/7/logs"))
+            .respond_with(redirect_to("/storage/logs/7"))
+            .mount(&ghe)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/storage/logs/7"))
+            .and(header("authorization", "Bearer secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ghe log"))
+            .mount(&ghe)
+            .await;
+
+        let client = GitHubClient::with_base_url(
+            "octo".into(),
+            "api".into(),
+            "secret".into(),
+            format!("{}/api/v3", ghe.uri()),
+        );
+        assert_eq!(client.get_job_logs(7).await.unwrap(), "ghe log");
+    }
+
+    #[tokio::test]
+    async fn test_probe_corrects_web_host_to_api() {
+        let ghe = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/meta"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(
+                    serde_json::json!({ "verifiable_password_authentication": true }),
+                ),
+            )
+            .mount(&ghe)
+            .await;
+        // The web host answers /meta with its 404 page
+        Mock::given(method("GET"))
+            .and(path("/meta"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("<html>Not Found</html>"))
+            .mount(&ghe)
+            .await;
+
+        let probed = GitHubClient::probe_base_url(&format!("{}/", ghe.uri()))
+            .await
+            .unwrap();
+        assert_eq!(probed.url, format!("{}/api/v3", ghe.uri()));
+        assert!(probed.corrected);
+
+        let direct = GitHubClient::probe_base_url(&probed.url).await.unwrap();
+        assert!(!direct.corrected);
+    }
+
+    #[tokio::test]
+    async fn test_probe_names_the_url_when_nothing_answers() {
+        let server = MockServer::start().await;
+        let err = GitHubClient::probe_base_url(&server.uri())
+            .await
+            .unwrap_err();
+        let message = format!("{:#}", err);
+        assert!(message.contains(&format!("No GitHub API at {}", server.uri())));
+        assert!(message.contains("HTTP 404"));
+    }
+
+    // ── Deployments ────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_deployments_belong_to_the_run() {
+        let server = MockServer::start().await;
+        let deployment = |id: u64, env: &str| {
+            serde_json::json!({
+                "id": id, "environment": env, "created_at": "2025-01-12T10:00:00Z",
+                "creator": { "login": "octocat", "avatar_url": null }
+            })
+        };
+        let status = |state: &str, run_id: u64| {
+            serde_json::json!([{
+                "state": state,
+                "log_url": format!("https://github.com/octo/api/actions/runs/{}/job/1", run_id),
+                "target_url": null
+            }])
+        };
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/deployments"))
+            .and(query_param("sha", "abc1234"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                deployment(2, "production"),
+                deployment(1, "staging"),
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/deployments/2/statuses"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(status("waiting", 42)))
+            .mount(&server)
+            .await;
+        // Made by an earlier run of the same commit
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/deployments/1/statuses"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(status("success", 41)))
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), server.uri());
+        let deployments = client.get_deployments(42, "abc1234").await.unwrap();
+        assert_eq!(deployments.len(), 1);
+        assert_eq!(deployments[0].environment, "production");
+        assert!(deployments[0].awaiting_approval());
+    }
+
+    // ── Commits ────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_get_commit() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/commits/abc1234"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "sha": "abc1234",
+                "commit": {
+                    "message": "Fix the flaky test\n\nIt raced the server's startup.",
+                    "author": { "name": "Mona", "email": "mona@example.com", "date": "2025-01-12T09:00:00Z" }
+                },
+                "stats": { "total": 15, "additions": 12, "deletions": 3 }
+            })))
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), server.uri());
+        let commit = client.get_commit("abc1234").await.unwrap();
+        assert_eq!(commit.subject(), "Fix the flaky test");
+        assert_eq!(commit.commit.author.unwrap().name, "Mona");
+        let stats = commit.stats.unwrap();
+        assert_eq!((stats.additions, stats.deletions), (12, 3));
+    }
+
+    // ── Log streaming ──────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_rerun_sends_debug_logging_flag() {
+        let api = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octo/api/actions/runs/9/rerun-failed-jobs"))
+            .and(body_json(
+                serde_json::json!({ "enable_debug_logging": true }),
+            ))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&api)
+            .await;
+
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), api.uri());
+        client.rerun_failed_jobs(9, true).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rerun_job_explains_a_refusal() {
+        let api = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octo/api/actions/jobs/5/rerun"))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .set_body_string(r#"{"message": "Unable to re-run this job"}"#),
+            )
+            .mount(&api)
+            .await;
+
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), api.uri());
+        let err = client.rerun_job(5, false).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "GitHub won't re-run this job: its run is still in progress or more than 30 days old"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_refusals_are_explained() {
+        let api = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octo/api/actions/runs/9/rerun-failed-jobs"))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .set_body_string(r#"{"message": "This workflow run is not rerunnable"}"#),
+            )
+            .mount(&api)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octo/api/actions/runs/9/cancel"))
+            .respond_with(ResponseTemplate::new(409).set_body_string(
+                r#"{"message": "Cannot cancel a workflow run that is completed."}"#,
+            ))
+            .mount(&api)
+            .await;
+
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), api.uri());
+        let err = client.rerun_failed_jobs(9, false).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "GitHub won't re-run this run: it is still in progress or more than 30 days old"
+        );
+        let err = client.cancel_workflow(9).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "GitHub won't cancel this run: it has already finished"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_rejection_is_githubs_message() {
+        let api = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octo/api/actions/workflows/9/dispatches"))
+            .and(body_json(serde_json::json!({
+                "ref": "main", "inputs": { "version": "1.2" }
+            })))
+            .respond_with(ResponseTemplate::new(422).set_body_string(
+                r#"{"message": "Required input 'environment' not provided", "documentation_url": "https://docs.github.com/rest"}"#,
+            ))
+            .mount(&api)
+            .await;
+
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), api.uri());
+        let inputs = HashMap::from([("version".to_string(), "1.2".to_string())]);
+        let err = client
+            .dispatch_workflow(9, "main", inputs)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "GitHub rejected the dispatch: Required input 'environment' not provided"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_caches_come_largest_first_across_pages() {
+        let api = MockServer::start().await;
+        let cache = |id: u64, size: u64| {
+            serde_json::json!({
+                "id": id, "ref": "refs/heads/main", "key": format!("Linux-cargo-{}", id),
+                "version": "abc", "size_in_bytes": size,
+                "last_accessed_at": "2025-01-12T10:00:00Z", "created_at": "2025-01-10T10:00:00Z"
+            })
+        };
+        let first: Vec<_> = (1..=100).map(|id| cache(id, 1000 + id)).collect();
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/caches"))
+            .and(query_param("page", "1"))
+            .and(query_param("sort", "size_in_bytes"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 101, "actions_caches": first
+            })))
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/caches"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 101, "actions_caches": [cache(101, 5_000_000)]
+            })))
+            .mount(&api)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/repos/octo/api/actions/caches/101"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&api)
+            .await;
+
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), api.uri());
+        let caches = client.get_caches().await.unwrap();
+        assert_eq!(caches.len(), 101);
+        assert_eq!(caches[0].id, 101);
+        assert_eq!(caches[0].ref_, "refs/heads/main");
+        assert_eq!(caches[1].size_in_bytes, 1100);
+        client.delete_cache(101).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_streamed_log_arrives_in_whole_lines() {
+        let server = MockServer::start().await;
+        let log: String = (1..=2000)
+            .map(|i| format!("2025-01-12T10:43:21.1234567Z line {} ✓\n", i))
+            .chain(["no trailing newline".to_string()])
+            .collect();
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/jobs/7/logs"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(log.clone()))
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), server.uri());
+        let (tx, mut rx) = mpsc::channel(4);
+        let (result, batches) = tokio::join!(client.get_job_logs_streaming(7, tx), async {
+            let mut batches = Vec::new();
+            while let Some(batch) = rx.recv().await {
+                batches.push(batch);
+            }
+            batches
+        });
+        result.unwrap();
+        assert_eq!(batches.concat(), log);
+        // Only the final batch may end mid-line
+        let (last, whole) = batches.split_last().unwrap();
+        assert!(whole.iter().all(|b| b.ends_with('\n')));
+        assert_eq!(*last, "no trailing newline");
+    }
+
+    #[tokio::test]
+    async fn test_repeated_jobs_come_from_the_cache() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs/7/jobs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 0, "jobs": []
+            })))
+            .expect(3)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octo/api/actions/runs/7/cancel"))
+            .respond_with(ResponseTemplate::new(202))
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), server.uri())
+                .with_response_cache(Duration::from_secs(30), 10);
+        client.get_jobs(7).await.unwrap();
+        client.clone().get_jobs(7).await.unwrap();
+        client.invalidate_cache();
+        client.get_jobs(7).await.unwrap();
+        // Acting on the run makes the cached jobs stale
+        client.cancel_workflow(7).await.unwrap();
+        client.get_jobs(7).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_installation_token_exchange() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/app/installations/42/access_tokens"))
+            .and(header("authorization", "Bearer app.jwt"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "token": "ghs_installation",
+                "expires_at": "2025-01-12T11:00:00Z"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/app/installations/7/access_tokens"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("{}"))
+            .mount(&server)
+            .await;
+
+        let client = GitHubClient::new_with_token_and_base("app.jwt".into(), server.uri());
+        let token = client.create_installation_token(42).await.unwrap();
+        assert_eq!(token.token, "ghs_installation");
+        let err = client.create_installation_token(7).await.unwrap_err();
+        assert!(err.to_string().contains("GITHUB_APP_INSTALLATION_ID"));
+    }
+
+    #[tokio::test]
+    async fn test_delete_run_refusals_are_explained() {
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/repos/octo/api/actions/runs/1"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/repos/octo/api/actions/runs/2"))
+            .respond_with(ResponseTemplate::new(403).set_body_string("{}"))
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/repos/octo/api/actions/runs/3"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("{}"))
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), server.uri());
+        client.delete_workflow_run(1).await.unwrap();
+        let forbidden = client.delete_workflow_run(2).await.unwrap_err();
+        assert!(forbidden.to_string().contains("needs write access"));
+        let missing = client.delete_workflow_run(3).await.unwrap_err();
+        assert!(missing.to_string().contains("already deleted"));
+    }
+
+    #[tokio::test]
+    async fn test_toggling_a_workflow_names_the_missing_scope() {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/repos/octo/api/actions/workflows/9/disable"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/repos/octo/api/actions/workflows/9/enable"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "message": "Must have admin rights to Repository."
+            })))
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), server.uri());
+        client.disable_workflow(9).await.unwrap();
+        let err = client.enable_workflow(9).await.unwrap_err().to_string();
+        assert!(
+            err.starts_with("GitHub won't enable this workflow"),
+            "{}",
+            err
+        );
+        assert!(err.contains("'workflow' scope"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_annotations_follow_every_page() {
+        let server = MockServer::start().await;
+        let annotation = |line: u64| {
+            serde_json::json!({
+                "path": "src/lib.rs", "start_line": line,
+                "annotation_level": "failure", "message": "mismatched types"
+            })
+        };
+        // Page 1 of `url` links on to a last page 2, when there is one
+        let serve = |url: &str, page: &str, body: serde_json::Value, more: bool| {
+            let mut response = ResponseTemplate::new(200).set_body_json(body);
+            if more {
+                let last = format!(
+                    "<{}{}?per_page=100&page=2>; rel=\"last\"",
+                    server.uri(),
+                    url
+                );
+                response = response.insert_header("link", last.as_str());
+            }
+            Mock::given(method("GET"))
+                .and(path(url))
+                .and(query_param("per_page", "100"))
+                .and(query_param("page", page))
+                .respond_with(response)
+        };
+
+        let runs = "/repos/octo/api/check-suites/70/check-runs";
+        let first = "/repos/octo/api/check-runs/1/annotations";
+        let second = "/repos/octo/api/check-runs/2/annotations";
+        let full: Vec<_> = (1..=100).map(annotation).collect();
+        for mock in [
+            serve(
+                runs,
+                "1",
+                serde_json::json!({"check_runs": [{"id": 1}]}),
+                true,
+            ),
+            serve(
+                runs,
+                "2",
+                serde_json::json!({"check_runs": [{"id": 2}]}),
+                false,
+            ),
+            serve(first, "1", serde_json::json!(full), true),
+            serve(first, "2", serde_json::json!([annotation(101)]), false),
+            serve(second, "1", serde_json::json!([annotation(7)]), false),
+        ] {
+            mock.mount(&server).await;
+        }
+
+        let client =
+            GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), server.uri());
+        let annotations = client.get_check_suite_annotations(70).await.unwrap();
+        assert_eq!(annotations.len(), 102);
+        assert_eq!(annotations[100].start_line, Some(101));
+        assert_eq!(annotations[101].start_line, Some(7));
+    }
+
+    #[test]
+    fn test_same_origin() {
+        let url = |s: &str| reqwest::Url::parse(s).unwrap();
+        let api = "https://api.github.com";
+        assert!(same_origin(&url("https://api.github.com/x"), api));
+        assert!(!same_origin(
+            &url("https://objects.githubusercontent.com/x"),
+            api
+        ));
+        assert!(!same_origin(&url("http://api.github.com/x"), api));
+        assert!(!same_origin(&url("https://api.github.com:8443/x"), api));
     }
 }
@@ -0,0 +1,245 @@
+use chrono::{DateTime, Duration, Utc};
+use std::ops::Range;
+
+use crate::models::log_timestamp_len;
+
+// ── Markers ────────────────────────────────────────────────────────
+
+/// Opens a collapsible section in an Actions log
+const GROUP: &str = "##[group]";
+/// Closes the innermost open section
+const END_GROUP: &str = "##[endgroup]";
+
+// ── Log lines ──────────────────────────────────────────────────────
+
+/// Where one log line sits in the `##[group]` sections
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LogLine {
+    /// Sections the line is inside; a header sits outside its own section
+    pub depth: u8,
+    /// Opens a section, drawn with ▸ / ▾
+    pub header: bool,
+    /// Header only: the section's lines are hidden
+    pub collapsed: bool,
+}
+
+/// Section structure of `lines`, every section collapsed
+pub fn parse(lines: &[String]) -> Vec<LogLine> {
+    let mut depth: u8 = 0;
+    lines
+        .iter()
+        .map(|line| {
+            // Markers follow the timestamp GitHub puts on each line
+            if line.contains(GROUP) {
+                let header = LogLine {
+                    depth,
+                    header: true,
+                    collapsed: true,
+                };
+                depth = depth.saturating_add(1);
+                header
+            } else if line.contains(END_GROUP) {
+                let end = LogLine {
+                    depth,
+                    ..LogLine::default()
+                };
+                depth = depth.saturating_sub(1);
+                end
+            } else {
+                LogLine {
+                    depth,
+                    ..LogLine::default()
+                }
+            }
+        })
+        .collect()
+}
+
+/// Re-parse a log that grew, keeping the sections already folded or
+/// unfolded; sections that are new start open so arriving output shows
+pub fn reparse(lines: &[String], old: &[LogLine]) -> Vec<LogLine> {
+    let mut groups = parse(lines);
+    for (i, line) in groups.iter_mut().enumerate() {
+        if line.header {
+            line.collapsed = old.get(i).is_some_and(|o| o.header && o.collapsed);
+        }
+    }
+    groups
+}
+
+/// Indices of the lines not hidden inside a collapsed section. Lines past
+/// the end of `groups` count as plain lines.
+pub fn visible(groups: &[LogLine], len: usize) -> Vec<usize> {
+    let mut shown = Vec::with_capacity(len);
+    // Depth of the collapsed header whose section is being skipped
+    let mut hiding: Option<u8> = None;
+    for i in 0..len {
+        let line = groups.get(i).copied().unwrap_or_default();
+        if let Some(depth) = hiding {
+            if line.depth > depth {
+                continue;
+            }
+            hiding = None;
+        }
+        shown.push(i);
+        if line.header && line.collapsed {
+            hiding = Some(line.depth);
+        }
+    }
+    shown
+}
+
+/// Header of the innermost section containing line `i`
+pub fn enclosing_header(groups: &[LogLine], i: usize) -> Option<usize> {
+    let depth = groups.get(i)?.depth;
+    if depth == 0 {
+        return None;
+    }
+    groups[..i]
+        .iter()
+        .rposition(|line| line.header && line.depth == depth - 1)
+}
+
+/// Open every section around line `i` so it can be shown
+pub fn expand_to(groups: &mut [LogLine], i: usize) {
+    let mut line = i;
+    while let Some(header) = enclosing_header(groups, line) {
+        groups[header].collapsed = false;
+        line = header;
+    }
+}
+
+// ── Steps ──────────────────────────────────────────────────────────
+
+/// The lines of each step in a job log, told apart by when each step
+/// started: a step runs from its first line up to the next step's. `None`
+/// for a step that never started, or whose lines haven't arrived.
+pub fn step_ranges(
+    lines: &[String],
+    starts: &[Option<DateTime<Utc>>],
+) -> Vec<Option<Range<usize>>> {
+    let mut first_lines: Vec<Option<usize>> = Vec::with_capacity(starts.len());
+    let mut from = 0;
+    for start in starts {
+        let found = start.and_then(|start| step_start(lines, from, start, from == 0));
+        if let Some(line) = found {
+            from = line + 1;
+        }
+        first_lines.push(found);
+    }
+
+    first_lines
+        .iter()
+        .enumerate()
+        .map(|(i, first)| {
+            let first = (*first)?;
+            let end = first_lines[i + 1..]
+                .iter()
+                .flatten()
+                .next()
+                .copied()
+                .unwrap_or(lines.len());
+            Some(first..end)
+        })
+        .collect()
+}
+
+/// First line at or after `from` stamped `start` or later. Step times only
+/// go down to the second, so a `##[group]` opening within that second is
+/// taken as the start instead, unless this is the job's first step.
+fn step_start(lines: &[String], from: usize, start: DateTime<Utc>, first: bool) -> Option<usize> {
+    let stamped = |i: usize| line_time(&lines[i]).map(|time| (i, time));
+    let (at, _) = (from..lines.len())
+        .filter_map(stamped)
+        .find(|(_, time)| *time >= start)?;
+    if first {
+        return Some(from);
+    }
+    let header = (at..lines.len())
+        .filter_map(stamped)
+        .take_while(|(_, time)| *time < start + Duration::seconds(1))
+        .find(|(i, _)| lines[*i].contains(GROUP))
+        .map(|(i, _)| i);
+    Some(header.unwrap_or(at))
+}
+
+fn line_time(line: &str) -> Option<DateTime<Utc>> {
+    let len = log_timestamp_len(line);
+    (len > 0).then(|| line[..len].trim_end().parse().ok())?
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn test_collapsed_sections_are_hidden() {
+        let lines = log(&[
+            "##[group]Run actions/checkout@v4",
+            "Syncing repository",
+            "##[endgroup]",
+            "##[group]Run cargo test",
+            "test a ... ok",
+            "##[error]Process completed with exit code 101.",
+            "##[endgroup]",
+            "Post job cleanup.",
+        ]);
+        let mut groups = parse(&lines);
+        assert!(groups[0].header && groups[3].header);
+        assert_eq!(groups[1].depth, 1);
+        assert_eq!(groups[7].depth, 0);
+        assert_eq!(visible(&groups, lines.len()), vec![0, 3, 7]);
+
+        expand_to(&mut groups, 5);
+        assert_eq!(enclosing_header(&groups, 5), Some(3));
+        assert_eq!(visible(&groups, lines.len()), vec![0, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_reparse_keeps_folds_and_opens_new_sections() {
+        let mut lines = log(&["##[group]Setup", "x", "##[endgroup]"]);
+        let groups = parse(&lines);
+        lines.extend(log(&["##[group]Build", "compiling"]));
+        let grown = reparse(&lines, &groups);
+        assert!(grown[0].collapsed);
+        assert!(!grown[3].collapsed);
+        assert_eq!(visible(&grown, lines.len()), vec![0, 3, 4]);
+    }
+
+    #[test]
+    fn test_step_ranges_follow_step_start_times() {
+        let lines = log(&[
+            "2025-01-12T10:00:00.1000000Z Current runner version: '2.321.0'",
+            "2025-01-12T10:00:01.2000000Z ##[group]Run actions/checkout@v4",
+            "2025-01-12T10:00:02.0000000Z Syncing repository",
+            "2025-01-12T10:00:02.9000000Z ##[endgroup]",
+            "2025-01-12T10:00:03.3000000Z ##[group]Run cargo test",
+            "2025-01-12T10:00:08.0000000Z test a ... ok",
+            "2025-01-12T10:00:09.5000000Z Post job cleanup.",
+        ]);
+        let at = |s: &str| Some(format!("2025-01-12T10:00:{}Z", s).parse().unwrap());
+        // Checkout's group opens a second after the runner's first line,
+        // and a skipped step has no lines
+        let starts = [at("00"), at("01"), at("03"), None, at("09")];
+        assert_eq!(
+            step_ranges(&lines, &starts),
+            vec![Some(0..1), Some(1..4), Some(4..6), None, Some(6..7)]
+        );
+
+        // Without timestamps nothing can be told apart
+        let bare = log(&["##[group]Run cargo test", "ok"]);
+        assert_eq!(step_ranges(&bare, &starts[..1]), vec![None]);
+    }
+
+    #[test]
+    fn test_lines_without_groups_are_all_visible() {
+        assert_eq!(visible(&[], 3), vec![0, 1, 2]);
+        assert_eq!(enclosing_header(&[], 0), None);
+    }
+}
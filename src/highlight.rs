@@ -0,0 +1,176 @@
+use ratatui::style::Color;
+use regex::Regex;
+use std::ops::Range;
+
+use crate::config::HighlightRule;
+
+// ── Limits ─────────────────────────────────────────────────────────
+
+/// Only the start of very long lines is scanned for user patterns
+const MAX_SCAN_BYTES: usize = 4096;
+/// Stop after this many highlighted ranges on one line
+const MAX_MATCHES_PER_LINE: usize = 32;
+
+// ── Log highlighter ────────────────────────────────────────────────
+
+/// Byte ranges of one log line to draw in a highlight color
+pub type LogHighlight = Vec<(Range<usize>, Color)>;
+
+/// User-defined log patterns, compiled once at startup
+#[derive(Debug, Default)]
+pub struct LogHighlighter {
+    rules: Vec<(Regex, Color)>,
+}
+
+impl LogHighlighter {
+    /// Compile the configured rules. Rules that fail to compile are left
+    /// out and reported as warnings naming the offending pattern.
+    pub fn compile(rules: &[HighlightRule]) -> (Self, Vec<String>) {
+        let mut compiled = Vec::new();
+        let mut warnings = Vec::new();
+
+        for rule in rules {
+            let Some(color) = parse_color(&rule.color) else {
+                warnings.push(format!(
+                    "Unknown highlight color '{}' for pattern '{}'",
+                    rule.color, rule.pattern
+                ));
+                continue;
+            };
+            match Regex::new(&rule.pattern) {
+                Ok(re) => compiled.push((re, color)),
+                Err(e) => warnings.push(format!(
+                    "Invalid highlight pattern '{}': {}",
+                    rule.pattern, e
+                )),
+            }
+        }
+
+        (Self { rules: compiled }, warnings)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Non-overlapping byte ranges to highlight in `line`, in order.
+    /// Earlier rules win where matches overlap.
+    pub fn matches(&self, line: &str) -> LogHighlight {
+        let mut scan_end = line.len().min(MAX_SCAN_BYTES);
+        while !line.is_char_boundary(scan_end) {
+            scan_end -= 1;
+        }
+        let haystack = &line[..scan_end];
+
+        let mut found = LogHighlight::new();
+        for (re, color) in &self.rules {
+            for m in re.find_iter(haystack) {
+                if found.len() >= MAX_MATCHES_PER_LINE {
+                    break;
+                }
+                if m.is_empty() {
+                    continue;
+                }
+                let range = m.range();
+                let overlaps = found
+                    .iter()
+                    .any(|(r, _)| r.start < range.end && range.start < r.end);
+                if !overlaps {
+                    found.push((range, *color));
+                }
+            }
+        }
+
+        found.sort_by_key(|(r, _)| r.start);
+        found
+    }
+}
+
+/// Color names understood in the config, plus `#rrggbb`
+pub fn parse_color(name: &str) -> Option<Color> {
+    let name = name.trim().to_lowercase();
+    if let Some(hex) = name.strip_prefix('#') {
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        return Some(Color::Rgb(channel(0)?, channel(2)?, channel(4)?));
+    }
+
+    let color = match name.as_str() {
+        "red" => Color::Rgb(248, 81, 73),
+        "green" => Color::Rgb(72, 199, 142),
+        "yellow" => Color::Rgb(210, 153, 34),
+        "blue" => Color::Rgb(88, 166, 255),
+        "magenta" | "purple" => Color::Rgb(188, 140, 255),
+        "cyan" => Color::Rgb(57, 197, 207),
+        "orange" => Color::Rgb(255, 166, 87),
+        "white" => Color::Rgb(230, 237, 243),
+        "gray" | "grey" => Color::Rgb(125, 133, 144),
+        _ => return None,
+    };
+    Some(color)
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: &str, color: &str) -> HighlightRule {
+        HighlightRule {
+            pattern: pattern.into(),
+            color: color.into(),
+        }
+    }
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("#ff0080"), Some(Color::Rgb(255, 0, 128)));
+        assert_eq!(parse_color("Magenta"), parse_color("purple"));
+        assert_eq!(parse_color("#fff"), None);
+        assert_eq!(parse_color("chartreuse"), None);
+    }
+
+    #[test]
+    fn test_invalid_rules_are_reported_by_pattern() {
+        let (hl, warnings) = LogHighlighter::compile(&[
+            rule("PAY-\\d+", "red"),
+            rule("(unclosed", "red"),
+            rule("ok", "chartreuse"),
+        ]);
+        assert_eq!(hl.rules.len(), 1);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("(unclosed"));
+        assert!(warnings[1].contains("chartreuse"));
+    }
+
+    #[test]
+    fn test_matches_are_ordered_and_non_overlapping() {
+        let (hl, _) = LogHighlighter::compile(&[
+            rule("PAY-\\d+", "red"),
+            rule("\\d+", "blue"),
+            rule("DEPLOY-MARKER", "magenta"),
+        ]);
+        let line = "DEPLOY-MARKER for PAY-1234 took 42s";
+        let ranges: Vec<&str> = hl
+            .matches(line)
+            .into_iter()
+            .map(|(r, _)| &line[r])
+            .collect();
+        assert_eq!(ranges, vec!["DEPLOY-MARKER", "PAY-1234", "42"]);
+    }
+
+    #[test]
+    fn test_matching_is_capped_on_long_lines() {
+        let (hl, _) = LogHighlighter::compile(&[rule("x", "red")]);
+        let line = "x".repeat(1_000_000);
+        let found = hl.matches(&line);
+        assert_eq!(found.len(), MAX_MATCHES_PER_LINE);
+
+        // Matches past the scan window are ignored
+        let line = format!("{}x", "é".repeat(MAX_SCAN_BYTES));
+        assert!(hl.matches(&line).is_empty());
+    }
+}
@@ -0,0 +1,95 @@
+use crate::models::Annotation;
+
+/// Lines shown above and below the offending line
+const CONTEXT_LINES: usize = 3;
+
+// ── Workflow problems ──────────────────────────────────────────────
+
+/// Why a `startup_failure` run never started
+#[derive(Debug, Clone)]
+pub enum WorkflowProblem {
+    /// The workflow file isn't valid YAML
+    Syntax {
+        path: String,
+        error: YamlError,
+        /// `(line number, text)` around the error, 1-based
+        context: Vec<(usize, String)>,
+    },
+    /// Valid YAML, so the problem is semantic — GitHub's own annotations
+    Annotations(Vec<Annotation>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct YamlError {
+    pub message: String,
+    /// 1-based
+    pub line: usize,
+    /// 1-based
+    pub column: usize,
+}
+
+/// Parse a workflow file, returning the syntax error if it isn't YAML
+pub fn lint_workflow(content: &str) -> Option<YamlError> {
+    let err = serde_norway::from_str::<serde_norway::Value>(content).err()?;
+    let (line, column) = err
+        .location()
+        .map(|loc| (loc.line(), loc.column()))
+        .unwrap_or((1, 1));
+    // serde_norway appends "at line X column Y" itself; keep just the reason
+    let message = err.to_string();
+    let message = match message.find(" at line ") {
+        Some(i) => message[..i].to_string(),
+        None => message,
+    };
+    Some(YamlError {
+        message,
+        line,
+        column,
+    })
+}
+
+/// The lines of `content` around 1-based `line`
+pub fn context_lines(content: &str, line: usize) -> Vec<(usize, String)> {
+    let first = line.saturating_sub(CONTEXT_LINES).max(1);
+    let last = line + CONTEXT_LINES;
+    content
+        .lines()
+        .enumerate()
+        .map(|(i, text)| (i + 1, text))
+        .filter(|(n, _)| (first..=last).contains(n))
+        .map(|(n, text)| (n, text.to_string()))
+        .collect()
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_workflow_has_no_error() {
+        let yaml = "name: CI\non: push\njobs:\n  build:\n    runs-on: ubuntu-latest\n";
+        assert_eq!(lint_workflow(yaml), None);
+    }
+
+    #[test]
+    fn test_syntax_error_location() {
+        let yaml = "name: CI\non: push\njobs:\n  build:\n    runs-on: [ubuntu-latest\n    steps:\n";
+        let err = lint_workflow(yaml).unwrap();
+        assert!(err.line >= 5, "line was {}", err.line);
+        assert!(!err.message.contains(" at line "));
+    }
+
+    #[test]
+    fn test_context_lines_clamped_to_file() {
+        let content = "a\nb\nc\nd\ne\nf\ng\nh\n";
+        let lines = context_lines(content, 2);
+        assert_eq!(lines.first().map(|(n, _)| *n), Some(1));
+        assert_eq!(lines.last().map(|(n, _)| *n), Some(5));
+
+        let lines = context_lines(content, 8);
+        assert_eq!(lines.first(), Some(&(5, "e".to_string())));
+        assert_eq!(lines.last(), Some(&(8, "h".to_string())));
+    }
+}
@@ -0,0 +1,377 @@
+use anyhow::{bail, Result};
+use std::io::{ErrorKind, Write};
+use std::time::Duration;
+
+use crate::github::GitHubClient;
+use crate::lookup;
+use crate::models::{Job, WorkflowRun};
+
+/// How often `atlas logs --follow` re-reads a running job's log
+pub const FOLLOW_INTERVAL: Duration = Duration::from_secs(3);
+/// Pages of 100 runs searched for a run number
+const NUMBER_PAGES: u64 = 5;
+
+// ── Log dump ───────────────────────────────────────────────────────
+
+/// Which run `atlas logs` reads
+#[derive(Debug, Clone, PartialEq)]
+pub enum RunRef {
+    Id(u64),
+    /// A run number (`#42`), looked up among the latest runs
+    Number(u64),
+    /// The latest run, on `branch` when given; the latest failed one when
+    /// `failed`
+    Latest {
+        branch: Option<String>,
+        failed: bool,
+    },
+}
+
+/// Print the log of the job in `run` matching `job` to `out`, following it
+/// while it runs when `follow`; without `job`, every failed job's log
+/// under a header
+pub async fn run(
+    client: &GitHubClient,
+    run: &RunRef,
+    job: Option<&str>,
+    follow: bool,
+    out: &mut impl Write,
+) -> Result<()> {
+    let run = resolve_run(client, run).await?;
+    let jobs = client.get_jobs(run.id).await?.jobs;
+    let written = match job {
+        Some(name) => {
+            let job = pick_job(&jobs, name)?;
+            if follow {
+                follow_job(client, job, FOLLOW_INTERVAL, out).await
+            } else {
+                let logs = client.get_job_logs(job.id).await?;
+                write_flushed(out, logs.as_bytes())
+            }
+        }
+        None => write_failed(client, &run, &jobs, out).await,
+    };
+    // `atlas logs | head` closing the pipe early is no error
+    match written {
+        Err(e)
+            if e.downcast_ref::<std::io::Error>()
+                .is_some_and(|e| e.kind() == ErrorKind::BrokenPipe) =>
+        {
+            Ok(())
+        }
+        other => other,
+    }
+}
+
+async fn resolve_run(client: &GitHubClient, run: &RunRef) -> Result<WorkflowRun> {
+    match run {
+        RunRef::Id(id) => client.get_workflow_run(*id).await,
+        RunRef::Number(number) => {
+            let mut found: Vec<WorkflowRun> = Vec::new();
+            for page in 1..=NUMBER_PAGES {
+                let runs = client
+                    .get_workflow_runs(100, page, None, None, None, None)
+                    .await?
+                    .workflow_runs;
+                let last = runs.len() < 100;
+                found.extend(runs.into_iter().filter(|r| r.run_number == *number));
+                if last {
+                    break;
+                }
+            }
+            // Each workflow counts its own runs
+            match found.len() {
+                0 => bail!(
+                    "No run #{} among the latest {} runs: pass --run with its id",
+                    number,
+                    NUMBER_PAGES * 100
+                ),
+                1 => Ok(found.remove(0)),
+                _ => {
+                    let candidates: Vec<String> = found
+                        .iter()
+                        .map(|r| {
+                            format!(
+                                "{} (--run {})",
+                                r.name.as_deref().unwrap_or("Workflow"),
+                                r.id
+                            )
+                        })
+                        .collect();
+                    bail!(
+                        "More than one workflow has a run #{}: {}",
+                        number,
+                        candidates.join(", ")
+                    )
+                }
+            }
+        }
+        RunRef::Latest { branch, failed } => {
+            let status = failed.then_some("failure");
+            lookup::latest_run(client, branch.as_deref(), status, None).await
+        }
+    }
+}
+
+/// The job whose name contains `name`, ignoring case; an exact match wins
+/// over the others
+fn pick_job<'a>(jobs: &'a [Job], name: &str) -> Result<&'a Job> {
+    let wanted = name.to_lowercase();
+    let matches: Vec<&Job> = jobs
+        .iter()
+        .filter(|job| job.name.to_lowercase().contains(&wanted))
+        .collect();
+    if let Some(exact) = matches.iter().find(|job| job.name.to_lowercase() == wanted) {
+        return Ok(exact);
+    }
+    match matches.as_slice() {
+        [job] => Ok(job),
+        [] => {
+            let names: Vec<&str> = jobs.iter().map(|job| job.name.as_str()).collect();
+            bail!(
+                "No job matching \"{}\" (the run has: {})",
+                name,
+                names.join(", ")
+            )
+        }
+        _ => {
+            let names: Vec<&str> = matches.iter().map(|job| job.name.as_str()).collect();
+            bail!(
+                "\"{}\" matches more than one job: {}",
+                name,
+                names.join(", ")
+            )
+        }
+    }
+}
+
+/// Every failed job's log, each under `==> name (conclusion) <==`
+async fn write_failed(
+    client: &GitHubClient,
+    run: &WorkflowRun,
+    jobs: &[Job],
+    out: &mut impl Write,
+) -> Result<()> {
+    let failed: Vec<&Job> = jobs
+        .iter()
+        .filter(|job| {
+            matches!(
+                job.conclusion.as_deref(),
+                Some("failure" | "timed_out" | "startup_failure")
+            )
+        })
+        .collect();
+    if failed.is_empty() {
+        let names: Vec<&str> = jobs.iter().map(|job| job.name.as_str()).collect();
+        bail!(
+            "No failed jobs in run #{}: pass --job with one of {}",
+            run.run_number,
+            names.join(", ")
+        );
+    }
+    for (i, job) in failed.iter().enumerate() {
+        let logs = client.get_job_logs(job.id).await?;
+        let separator = if i == 0 { "" } else { "\n" };
+        let header = format!(
+            "{}==> {} ({}) <==\n",
+            separator,
+            job.name,
+            job.conclusion.as_deref().unwrap_or("unknown")
+        );
+        write_flushed(out, header.as_bytes())?;
+        write_flushed(out, logs.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Print `job`'s log, then what it grows by every `interval` until the
+/// job finishes
+async fn follow_job(
+    client: &GitHubClient,
+    job: &Job,
+    interval: Duration,
+    out: &mut impl Write,
+) -> Result<()> {
+    let mut printed = String::new();
+    let mut finished = job.status.as_deref() == Some("completed");
+    loop {
+        match client.get_job_logs(job.id).await {
+            Ok(logs) => {
+                // A log only grows; one that doesn't start with what was
+                // printed was replaced (a re-run) and is printed whole
+                let start = if logs.starts_with(&printed) {
+                    printed.len()
+                } else {
+                    0
+                };
+                write_flushed(out, &logs.as_bytes()[start..])?;
+                printed = logs;
+            }
+            // A job that has just started may have no log yet
+            Err(e) if !finished => tracing::debug!(error = %e, "No log to follow yet"),
+            Err(e) => return Err(e),
+        }
+        if finished {
+            return Ok(());
+        }
+        tokio::time::sleep(interval).await;
+        finished = client.get_job(job.id).await?.status.as_deref() == Some("completed");
+    }
+}
+
+/// Write and flush at once, so a pager or `grep` sees lines as they come
+fn write_flushed(out: &mut impl Write, bytes: &[u8]) -> Result<()> {
+    out.write_all(bytes)?;
+    out.flush()?;
+    Ok(())
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, client, run_json};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Job `id` named `name`, completed with `conclusion`
+    fn job_json(id: u64, name: &str, conclusion: &str) -> serde_json::Value {
+        let mut job = test_support::job_json(id, "completed", Some(conclusion));
+        job["name"] = name.into();
+        job
+    }
+
+    async fn api_with_jobs(jobs: serde_json::Value) -> MockServer {
+        let api = MockServer::start().await;
+        let latest = run_json("completed", Some("failure"));
+        let mut older = latest.clone();
+        older["id"] = 8.into();
+        older["run_number"] = 41.into();
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 2,
+                "workflow_runs": [latest, older]
+            })))
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs/7/jobs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 3, "jobs": jobs
+            })))
+            .mount(&api)
+            .await;
+        for (id, log) in [(1, "lint ok\n"), (2, "error: test failed\n"), (3, "boom\n")] {
+            Mock::given(method("GET"))
+                .and(path(format!("/repos/octo/api/actions/jobs/{}/logs", id)))
+                .respond_with(ResponseTemplate::new(200).set_body_string(log))
+                .mount(&api)
+                .await;
+        }
+        api
+    }
+
+    fn job(name: &str) -> Job {
+        serde_json::from_value(job_json(1, name, "success")).unwrap()
+    }
+
+    #[test]
+    fn test_pick_job() {
+        let jobs = [job("Build (ubuntu)"), job("Build (macos)"), job("build")];
+        assert_eq!(pick_job(&jobs, "MACOS").unwrap().name, "Build (macos)");
+        // `build` is in all three, but one is named just that
+        assert_eq!(pick_job(&jobs, "Build").unwrap().name, "build");
+        assert_eq!(
+            pick_job(&jobs, "build (").unwrap_err().to_string(),
+            "\"build (\" matches more than one job: Build (ubuntu), Build (macos)"
+        );
+        assert_eq!(
+            pick_job(&jobs, "lint").unwrap_err().to_string(),
+            "No job matching \"lint\" (the run has: Build (ubuntu), Build (macos), build)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_jobs_under_headers() {
+        let api = api_with_jobs(serde_json::json!([
+            job_json(1, "lint", "success"),
+            job_json(2, "test", "failure"),
+            job_json(3, "deploy", "timed_out"),
+        ]))
+        .await;
+        let mut out = Vec::new();
+        run(&client(&api), &RunRef::Number(42), None, false, &mut out)
+            .await
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "==> test (failure) <==\nerror: test failed\n\n==> deploy (timed_out) <==\nboom\n"
+        );
+
+        let mut out = Vec::new();
+        let latest = RunRef::Latest {
+            branch: None,
+            failed: true,
+        };
+        run(&client(&api), &latest, Some("LINT"), false, &mut out)
+            .await
+            .unwrap();
+        assert_eq!(out, b"lint ok\n");
+
+        let err = run(&client(&api), &RunRef::Number(9), None, false, &mut out)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "No run #9 among the latest 500 runs: pass --run with its id"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_follow_prints_what_the_log_grows_by() {
+        let api = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/jobs/1/logs"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("one\n"))
+            .up_to_n_times(1)
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/jobs/1/logs"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("one\ntwo\n"))
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/jobs/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(job_json(1, "test", "success")))
+            .mount(&api)
+            .await;
+        let mut running = job("test");
+        running.status = Some("in_progress".into());
+        let mut out = Vec::new();
+        follow_job(&client(&api), &running, Duration::from_millis(10), &mut out)
+            .await
+            .unwrap();
+        assert_eq!(out, b"one\ntwo\n");
+    }
+
+    #[tokio::test]
+    async fn test_no_failed_jobs_names_them() {
+        let api = api_with_jobs(serde_json::json!([job_json(1, "lint", "success")])).await;
+        let err = run(
+            &client(&api),
+            &RunRef::Number(42),
+            None,
+            false,
+            &mut Vec::new(),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "No failed jobs in run #42: pass --job with one of lint"
+        );
+    }
+}
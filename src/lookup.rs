@@ -0,0 +1,116 @@
+use anyhow::{Context, Result};
+
+use crate::github::GitHubClient;
+use crate::models::WorkflowRun;
+
+// ── Run lookup ─────────────────────────────────────────────────────
+
+/// The latest run on `branch`, or on any branch without one; narrowed to
+/// `status` and the workflow `workflow_id` when given
+pub async fn latest_run(
+    client: &GitHubClient,
+    branch: Option<&str>,
+    status: Option<&str>,
+    workflow_id: Option<u64>,
+) -> Result<WorkflowRun> {
+    client
+        .get_workflow_runs(1, 1, branch, status, None, workflow_id)
+        .await?
+        .workflow_runs
+        .into_iter()
+        .next()
+        .with_context(|| {
+            let what = match status {
+                Some("failure") => "failed runs",
+                _ => "workflow runs",
+            };
+            match branch {
+                Some(branch) => format!("No {} on branch {}", what, branch),
+                None => format!("No {} yet", what),
+            }
+        })
+}
+
+// ── Default branch ─────────────────────────────────────────────────
+
+/// The branch to work on without `--branch`: a pull request's head branch,
+/// else the pushed branch GitHub Actions names, else the checkout's
+pub fn default_branch() -> Option<String> {
+    let git = std::process::Command::new("git")
+        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .map(|out| String::from_utf8_lossy(&out.stdout).trim().to_string());
+    pick_branch(
+        std::env::var("GITHUB_HEAD_REF").ok(),
+        std::env::var("GITHUB_REF_NAME").ok(),
+        git,
+    )
+}
+
+fn pick_branch(
+    head_ref: Option<String>,
+    ref_name: Option<String>,
+    git: Option<String>,
+) -> Option<String> {
+    // A detached checkout (most CI systems) says `HEAD`
+    [head_ref, ref_name, git]
+        .into_iter()
+        .flatten()
+        .find(|branch| !branch.is_empty() && branch != "HEAD")
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{client, run_json};
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_latest_run() {
+        let api = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs"))
+            .and(query_param("branch", "main"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 1,
+                "workflow_runs": [run_json("completed", Some("failure"))]
+            })))
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 0, "workflow_runs": []
+            })))
+            .mount(&api)
+            .await;
+
+        let client = client(&api);
+        let run = latest_run(&client, Some("main"), None, None).await.unwrap();
+        assert_eq!(run.run_number, 42);
+        let err = latest_run(&client, Some("dev"), Some("failure"), None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "No failed runs on branch dev");
+        let err = latest_run(&client, None, None, None).await.unwrap_err();
+        assert_eq!(err.to_string(), "No workflow runs yet");
+    }
+
+    #[test]
+    fn test_pick_branch() {
+        let some = |s: &str| Some(s.to_string());
+        assert_eq!(
+            pick_branch(some("feature"), some("42/merge"), some("HEAD")),
+            some("feature")
+        );
+        // Pushes leave GITHUB_HEAD_REF empty
+        assert_eq!(pick_branch(some(""), some("main"), None), some("main"));
+        assert_eq!(pick_branch(None, None, some("topic")), some("topic"));
+        assert_eq!(pick_branch(None, None, some("HEAD")), None);
+    }
+}
@@ -0,0 +1,443 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::github::GitHubClient;
+use crate::models::WorkflowRun;
+
+/// Pages of 100 runs read before the window is cut short
+const MAX_PAGES: u64 = 10;
+/// Upper bounds of the run duration histogram, in seconds
+const DURATION_BUCKETS: [f64; 9] = [
+    60.0, 120.0, 300.0, 600.0, 900.0, 1800.0, 3600.0, 7200.0, 21600.0,
+];
+/// Upper bounds of the queue time histogram, in seconds
+const QUEUE_BUCKETS: [f64; 8] = [5.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1800.0];
+
+// ── Fetching ───────────────────────────────────────────────────────
+
+/// Every run created since `since`, newest first, paging until the runs get
+/// older. `true` alongside when the page cap cut the window short.
+pub async fn fetch_window(
+    client: &GitHubClient,
+    since: DateTime<Utc>,
+) -> Result<(Vec<WorkflowRun>, bool)> {
+    let mut runs = Vec::new();
+    for page in 1..=MAX_PAGES {
+        let response = client
+            .get_workflow_runs(100, page, None, None, None, None)
+            .await?;
+        let last_page = response.workflow_runs.len() < 100;
+        let reached_start = response
+            .workflow_runs
+            .last()
+            .is_some_and(|run| run.created_at < since);
+        runs.extend(
+            response
+                .workflow_runs
+                .into_iter()
+                .filter(|run| run.created_at >= since),
+        );
+        if last_page || reached_start {
+            return Ok((runs, false));
+        }
+    }
+    Ok((runs, true))
+}
+
+// ── Aggregation ────────────────────────────────────────────────────
+
+/// Cumulative buckets, as Prometheus keeps them
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Histogram {
+    pub buckets: Vec<Bucket>,
+    pub sum: f64,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Bucket {
+    /// Upper bound, in seconds
+    pub le: f64,
+    /// Observations at or under `le`
+    pub count: u64,
+}
+
+impl Histogram {
+    fn new(bounds: &[f64]) -> Self {
+        Self {
+            buckets: bounds.iter().map(|&le| Bucket { le, count: 0 }).collect(),
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, value: f64) {
+        for bucket in self.buckets.iter_mut().filter(|b| value <= b.le) {
+            bucket.count += 1;
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+/// One workflow's runs in the window
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WorkflowMetrics {
+    /// Runs by conclusion, or by status for runs not finished yet
+    pub runs: BTreeMap<String, u64>,
+    /// Runs still queued or in progress
+    pub active: u64,
+    /// Start to finish, for completed runs
+    pub duration_seconds: Histogram,
+    /// Created to started
+    pub queue_seconds: Histogram,
+}
+
+/// What `atlas metrics` prints, in either format
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Snapshot {
+    pub repo: String,
+    pub since: DateTime<Utc>,
+    pub generated_at: DateTime<Utc>,
+    pub workflows: BTreeMap<String, WorkflowMetrics>,
+}
+
+impl Snapshot {
+    pub fn aggregate(
+        repo: String,
+        since: DateTime<Utc>,
+        generated_at: DateTime<Utc>,
+        runs: &[WorkflowRun],
+    ) -> Self {
+        let mut workflows: BTreeMap<String, WorkflowMetrics> = BTreeMap::new();
+        for run in runs {
+            let name = run.name.clone().unwrap_or_else(|| "unknown".to_string());
+            let metrics = workflows.entry(name).or_insert_with(|| WorkflowMetrics {
+                runs: BTreeMap::new(),
+                active: 0,
+                duration_seconds: Histogram::new(&DURATION_BUCKETS),
+                queue_seconds: Histogram::new(&QUEUE_BUCKETS),
+            });
+            let completed = run.status.as_deref() == Some("completed");
+            let outcome = run
+                .conclusion
+                .as_deref()
+                .or(run.status.as_deref())
+                .unwrap_or("unknown");
+            *metrics.runs.entry(outcome.to_string()).or_default() += 1;
+            if !completed {
+                metrics.active += 1;
+            }
+            if let Some(started) = run.run_started_at {
+                let queued = started.signed_duration_since(run.created_at);
+                metrics
+                    .queue_seconds
+                    .observe(queued.num_seconds().max(0) as f64);
+                if completed {
+                    let took = run.updated_at.signed_duration_since(started);
+                    metrics
+                        .duration_seconds
+                        .observe(took.num_seconds().max(0) as f64);
+                }
+            }
+        }
+        Self {
+            repo,
+            since,
+            generated_at,
+            workflows,
+        }
+    }
+
+    /// The text exposition format, for node_exporter's textfile collector
+    pub fn prometheus(&self) -> String {
+        let mut out = String::new();
+        let labels = |workflow: &str| {
+            format!(
+                "repo=\"{}\",workflow=\"{}\"",
+                escape_label(&self.repo),
+                escape_label(workflow)
+            )
+        };
+
+        family(
+            &mut out,
+            "atlas_workflow_runs_total",
+            "counter",
+            "Workflow runs created in the window, by conclusion (or status while unfinished)",
+        );
+        for (workflow, metrics) in &self.workflows {
+            for (conclusion, count) in &metrics.runs {
+                let _ = writeln!(
+                    out,
+                    "atlas_workflow_runs_total{{{},conclusion=\"{}\"}} {}",
+                    labels(workflow),
+                    escape_label(conclusion),
+                    count
+                );
+            }
+        }
+
+        family(
+            &mut out,
+            "atlas_workflow_runs_active",
+            "gauge",
+            "Workflow runs created in the window and still queued or in progress",
+        );
+        for (workflow, metrics) in &self.workflows {
+            let _ = writeln!(
+                out,
+                "atlas_workflow_runs_active{{{}}} {}",
+                labels(workflow),
+                metrics.active
+            );
+        }
+
+        family(
+            &mut out,
+            "atlas_workflow_run_duration_seconds",
+            "histogram",
+            "Time from a completed run starting to finishing",
+        );
+        for (workflow, metrics) in &self.workflows {
+            histogram(
+                &mut out,
+                "atlas_workflow_run_duration_seconds",
+                &labels(workflow),
+                &metrics.duration_seconds,
+            );
+        }
+
+        family(
+            &mut out,
+            "atlas_workflow_run_queue_seconds",
+            "histogram",
+            "Time from a run being created to starting",
+        );
+        for (workflow, metrics) in &self.workflows {
+            histogram(
+                &mut out,
+                "atlas_workflow_run_queue_seconds",
+                &labels(workflow),
+                &metrics.queue_seconds,
+            );
+        }
+
+        family(
+            &mut out,
+            "atlas_metrics_window_start_seconds",
+            "gauge",
+            "Unix time the aggregated window starts at",
+        );
+        let _ = writeln!(
+            out,
+            "atlas_metrics_window_start_seconds{{repo=\"{}\"}} {}",
+            escape_label(&self.repo),
+            self.since.timestamp()
+        );
+        out
+    }
+}
+
+/// The `# HELP` and `# TYPE` lines heading a metric family
+fn family(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+/// One series' `_bucket` lines, `+Inf` included, then `_sum` and `_count`
+fn histogram(out: &mut String, name: &str, labels: &str, histogram: &Histogram) {
+    for bucket in &histogram.buckets {
+        let _ = writeln!(
+            out,
+            "{}_bucket{{{},le=\"{}\"}} {}",
+            name, labels, bucket.le, bucket.count
+        );
+    }
+    let _ = writeln!(
+        out,
+        "{}_bucket{{{},le=\"+Inf\"}} {}",
+        name, labels, histogram.count
+    );
+    let _ = writeln!(out, "{}_sum{{{}}} {}", name, labels, histogram.sum);
+    let _ = writeln!(out, "{}_count{{{}}} {}", name, labels, histogram.count);
+}
+
+/// A label value as the exposition format quotes it: backslash, double
+/// quote and line feed escaped
+fn escape_label(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+
+    fn run(
+        id: u64,
+        name: &str,
+        status: &str,
+        conclusion: Option<&str>,
+        times: [&str; 3],
+    ) -> WorkflowRun {
+        serde_json::from_value(serde_json::json!({
+            "id": id, "name": name, "head_sha": "abc", "run_number": id, "event": "push",
+            "html_url": "", "status": status, "conclusion": conclusion,
+            "created_at": times[0], "run_started_at": times[1], "updated_at": times[2]
+        }))
+        .unwrap()
+    }
+
+    fn snapshot() -> Snapshot {
+        let at = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+        let runs = [
+            run(
+                1,
+                "CI",
+                "completed",
+                Some("success"),
+                [
+                    "2025-01-12T10:00:00Z",
+                    "2025-01-12T10:00:10Z",
+                    "2025-01-12T10:01:40Z",
+                ],
+            ),
+            run(
+                2,
+                "CI",
+                "completed",
+                Some("failure"),
+                [
+                    "2025-01-12T11:00:00Z",
+                    "2025-01-12T11:02:00Z",
+                    "2025-01-12T11:12:00Z",
+                ],
+            ),
+            run(
+                3,
+                "Deploy \"prod\"\\eu",
+                "in_progress",
+                None,
+                [
+                    "2025-01-12T12:00:00Z",
+                    "2025-01-12T12:00:03Z",
+                    "2025-01-12T12:00:03Z",
+                ],
+            ),
+        ];
+        Snapshot::aggregate(
+            "octo/api".into(),
+            at("2025-01-12T00:00:00Z"),
+            at("2025-01-13T00:00:00Z"),
+            &runs,
+        )
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let snapshot = snapshot();
+        let ci = &snapshot.workflows["CI"];
+        assert_eq!(ci.runs["success"], 1);
+        assert_eq!(ci.runs["failure"], 1);
+        assert_eq!(ci.active, 0);
+        // 90s and 600s
+        assert_eq!(ci.duration_seconds.count, 2);
+        assert_eq!(ci.duration_seconds.sum, 690.0);
+        assert_eq!(ci.duration_seconds.buckets[0].count, 0);
+        assert_eq!(ci.duration_seconds.buckets[1].count, 1);
+        assert_eq!(ci.duration_seconds.buckets[3].count, 2);
+        // 10s and 120s
+        assert_eq!(ci.queue_seconds.sum, 130.0);
+        assert_eq!(ci.queue_seconds.buckets[1].count, 1);
+
+        let deploy = &snapshot.workflows["Deploy \"prod\"\\eu"];
+        assert_eq!(deploy.runs["in_progress"], 1);
+        assert_eq!(deploy.active, 1);
+        // Unfinished runs have no duration yet
+        assert_eq!(deploy.duration_seconds.count, 0);
+        assert_eq!(deploy.queue_seconds.count, 1);
+    }
+
+    #[test]
+    fn test_escape_label() {
+        assert_eq!(escape_label("plain"), "plain");
+        assert_eq!(escape_label("a \"b\" c\\d\ne"), "a \\\"b\\\" c\\\\d\\ne");
+    }
+
+    #[test]
+    fn test_prometheus_exposition() {
+        let text = snapshot().prometheus();
+        assert!(text.contains(
+            "atlas_workflow_runs_total{repo=\"octo/api\",workflow=\"CI\",conclusion=\"failure\"} 1\n"
+        ));
+        assert!(text.contains(
+            "atlas_workflow_runs_active{repo=\"octo/api\",workflow=\"Deploy \\\"prod\\\"\\\\eu\"} 1\n"
+        ));
+        assert!(text.contains(
+            "atlas_workflow_run_duration_seconds_bucket{repo=\"octo/api\",workflow=\"CI\",le=\"120\"} 1\n"
+        ));
+        assert!(text.contains(
+            "atlas_workflow_run_duration_seconds_bucket{repo=\"octo/api\",workflow=\"CI\",le=\"+Inf\"} 2\n"
+        ));
+        assert!(text.contains(
+            "atlas_workflow_run_duration_seconds_sum{repo=\"octo/api\",workflow=\"CI\"} 690\n"
+        ));
+        assert!(text.contains("atlas_metrics_window_start_seconds{repo=\"octo/api\"} 1736640000\n"));
+
+        // Every line is a comment naming a declared family, or a sample
+        // whose metric and label names are valid and whose value parses
+        let sample = Regex::new(
+            r#"^([a-zA-Z_:][a-zA-Z0-9_:]*)\{([a-zA-Z_][a-zA-Z0-9_]*="(?:[^"\\]|\\.)*")(?:,([a-zA-Z_][a-zA-Z0-9_]*="(?:[^"\\]|\\.)*"))*\} (\S+)$"#,
+        )
+        .unwrap();
+        let mut declared: Vec<&str> = Vec::new();
+        for line in text.lines() {
+            if let Some(rest) = line.strip_prefix("# TYPE ") {
+                let (name, kind) = rest.split_once(' ').unwrap();
+                assert!(["counter", "gauge", "histogram"].contains(&kind));
+                declared.push(name);
+            } else if line.starts_with("# HELP ") {
+                continue;
+            } else {
+                let captures = sample.captures(line).unwrap_or_else(|| panic!("{}", line));
+                let name = &captures[1];
+                assert!(
+                    declared.iter().any(|family| name == *family
+                        || ["_bucket", "_sum", "_count"]
+                            .iter()
+                            .any(|suffix| name == format!("{}{}", family, suffix))),
+                    "{} has no # TYPE",
+                    name
+                );
+                captures[4].parse::<f64>().unwrap();
+            }
+        }
+        assert_eq!(declared.len(), 5);
+    }
+
+    #[test]
+    fn test_json_shares_the_snapshot() {
+        let json = serde_json::to_value(snapshot()).unwrap();
+        assert_eq!(json["repo"], "octo/api");
+        assert_eq!(json["workflows"]["CI"]["runs"]["success"], 1);
+        assert_eq!(
+            json["workflows"]["CI"]["duration_seconds"]["buckets"][1],
+            serde_json::json!({ "le": 120.0, "count": 1 })
+        );
+    }
+}
@@ -1,11 +1,11 @@
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
+use std::collections::HashMap;
 
 // ── Repository types ───────────────────────────────────────────────
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct Repository {
-    #[allow(dead_code)]
     pub id: u64,
     pub full_name: String,
     pub name: String,
@@ -21,6 +21,22 @@ pub struct Repository {
     pub fork: bool,
     #[allow(dead_code)]
     pub archived: bool,
+    pub default_branch: Option<String>,
+    /// What the token may do in the repo; only sent to signed-in requests
+    #[serde(default)]
+    pub permissions: Option<RepoPermissions>,
+}
+
+/// `GET /installation/repositories`: what a GitHub App installation may see
+#[derive(Debug, Clone, Deserialize)]
+pub struct InstallationRepositories {
+    pub total_count: u64,
+    pub repositories: Vec<Repository>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct RepoPermissions {
+    pub push: bool,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -29,19 +45,40 @@ pub struct RepoOwner {
 }
 
 impl Repository {
+    /// A repo named on the command line, until `GET /repos` fills it in
+    pub fn stub(owner: &str, name: &str) -> Self {
+        Self {
+            id: 0,
+            full_name: format!("{}/{}", owner, name),
+            name: name.to_string(),
+            owner: RepoOwner {
+                login: owner.to_string(),
+            },
+            description: None,
+            html_url: format!("https://github.com/{}/{}", owner, name),
+            language: None,
+            stargazers_count: 0,
+            updated_at: DateTime::UNIX_EPOCH,
+            pushed_at: None,
+            private: false,
+            fork: false,
+            archived: false,
+            default_branch: None,
+            permissions: None,
+        }
+    }
+
+    pub fn is_stub(&self) -> bool {
+        self.id == 0
+    }
+
     /// Human-readable "last active" string
     pub fn last_active_display(&self) -> String {
-        let ts = self.pushed_at.unwrap_or(self.updated_at);
-        let secs = Utc::now().signed_duration_since(ts).num_seconds();
-        if secs < 60 {
-            format!("{}s ago", secs)
-        } else if secs < 3600 {
-            format!("{}m ago", secs / 60)
-        } else if secs < 86400 {
-            format!("{}h ago", secs / 3600)
-        } else {
-            format!("{}d ago", secs / 86400)
+        if self.is_stub() {
+            return "—".to_string();
         }
+        let ts = self.pushed_at.unwrap_or(self.updated_at);
+        format_age(Utc::now().signed_duration_since(ts).num_seconds())
     }
 
     #[allow(dead_code)]
@@ -54,8 +91,78 @@ impl Repository {
     }
 }
 
+// ── User / rate limit types ────────────────────────────────────────
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct User {
+    pub login: String,
+    pub name: Option<String>,
+}
+
+/// Authenticated user plus the classic-PAT scopes GitHub reported
+/// (`None` for fine-grained tokens, which don't send `x-oauth-scopes`)
+#[derive(Debug, Clone)]
+pub struct TokenInfo {
+    pub user: User,
+    pub scopes: Option<Vec<String>>,
+}
+
+/// A GitHub App installation's access token, good for an hour
+#[derive(Debug, Clone, Deserialize)]
+pub struct InstallationToken {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// The core API limit as of the last response, from its
+/// `x-ratelimit-*` headers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    pub remaining: u32,
+    pub limit: u32,
+    pub reset: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RateLimitResponse {
+    pub resources: RateLimitResources,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RateLimitResources {
+    pub core: RateLimitBucket,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RateLimitBucket {
+    pub limit: u64,
+    pub remaining: u64,
+    pub reset: i64,
+}
+
 // ── GitHub API response types ──────────────────────────────────────
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkflowsResponse {
+    #[allow(dead_code)]
+    pub total_count: u64,
+    pub workflows: Vec<Workflow>,
+}
+
+/// A workflow file (`GET /repos/{owner}/{repo}/actions/workflows`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct Workflow {
+    pub id: u64,
+    pub name: String,
+    pub path: String,
+    /// `active`, `disabled_manually`, `disabled_inactivity`, ...
+    pub state: String,
+    pub html_url: String,
+    /// When its newest run was created, filled in from the recent runs
+    #[serde(skip)]
+    pub last_run_at: Option<DateTime<Utc>>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct WorkflowRunsResponse {
     pub total_count: u64,
@@ -78,8 +185,11 @@ pub struct WorkflowRun {
     pub run_started_at: Option<DateTime<Utc>>,
     pub html_url: String,
     pub actor: Option<Actor>,
-    #[allow(dead_code)]
     pub run_attempt: Option<u64>,
+    pub workflow_id: Option<u64>,
+    /// Workflow file, e.g. `.github/workflows/ci.yml`
+    pub path: Option<String>,
+    pub check_suite_id: Option<u64>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -106,8 +216,32 @@ pub struct Job {
     pub conclusion: Option<String>,
     pub started_at: Option<DateTime<Utc>>,
     pub completed_at: Option<DateTime<Utc>>,
+    /// Not read from the jobs list; hydrated per job via `JobDetail`
+    #[serde(skip_deserializing)]
     pub steps: Option<Vec<Step>>,
     pub html_url: Option<String>,
+    /// The job's `runs-on` labels
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+/// Single-job response (`GET /actions/jobs/{id}`), keeping the steps
+/// the jobs list skips
+#[derive(Debug, Clone, Deserialize)]
+pub struct JobDetail {
+    #[serde(flatten)]
+    pub job: Job,
+    #[serde(default)]
+    pub steps: Vec<Step>,
+}
+
+impl From<JobDetail> for Job {
+    fn from(detail: JobDetail) -> Self {
+        Job {
+            steps: Some(detail.steps),
+            ..detail.job
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -121,13 +255,422 @@ pub struct Step {
     pub completed_at: Option<DateTime<Utc>>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckRunsResponse {
+    pub check_runs: Vec<CheckRun>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckRun {
+    pub id: u64,
+    pub output: Option<CheckRunOutput>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckRunOutput {
+    #[serde(default)]
+    pub annotations_count: u64,
+}
+
+/// `GET /actions/runs/{id}/timing`
+#[derive(Debug, Clone, Deserialize)]
+pub struct RunTiming {
+    /// Per runner OS: `UBUNTU`, `MACOS`, `WINDOWS`
+    #[serde(default)]
+    pub billable: HashMap<String, BillableTime>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BillableTime {
+    pub total_ms: u64,
+}
+
+impl RunTiming {
+    /// Billable time across all runner OSes
+    pub fn billable_ms(&self) -> u64 {
+        self.billable.values().map(|b| b.total_ms).sum()
+    }
+}
+
+/// A check-run annotation (`GET /check-runs/{id}/annotations`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct Annotation {
+    pub path: String,
+    pub start_line: Option<u64>,
+    pub annotation_level: Option<String>,
+    pub title: Option<String>,
+    pub message: String,
+}
+
+impl Annotation {
+    /// `path:line`, or just the path for a file-wide annotation
+    pub fn location(&self) -> String {
+        match self.start_line {
+            Some(line) => format!("{}:{}", self.path, line),
+            None => self.path.clone(),
+        }
+    }
+
+    /// Failures before warnings before notices
+    pub fn severity_rank(&self) -> u8 {
+        match self.annotation_level.as_deref() {
+            Some("failure") => 0,
+            Some("warning") => 1,
+            _ => 2,
+        }
+    }
+}
+
+/// `GET /actions/permissions/artifact-and-log-retention`
+#[derive(Debug, Clone, Deserialize)]
+pub struct RetentionResponse {
+    /// Days runs keep their logs and artifacts
+    pub days: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArtifactsResponse {
+    pub total_count: u64,
+    pub artifacts: Vec<Artifact>,
+}
+
+/// A run's uploaded artifact (`GET /actions/runs/{id}/artifacts`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct Artifact {
+    pub id: u64,
+    pub name: String,
+    pub size_in_bytes: u64,
+    /// Past the retention period; the zip can no longer be downloaded
+    pub expired: bool,
+    pub created_at: Option<DateTime<Utc>>,
+    #[allow(dead_code)]
+    pub archive_download_url: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EnvironmentsResponse {
+    #[allow(dead_code)]
+    pub total_count: u64,
+    pub environments: Vec<Environment>,
+}
+
+/// A deployment target (`GET /repos/{owner}/{repo}/environments`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct Environment {
+    pub id: u64,
+    pub name: String,
+    pub html_url: String,
+    #[serde(default)]
+    pub protection_rules: Vec<ProtectionRule>,
+}
+
+impl Environment {
+    /// `2 reviewers · wait 5m · branch policy`, or `none`
+    pub fn protection_display(&self) -> String {
+        if self.protection_rules.is_empty() {
+            return "none".to_string();
+        }
+        self.protection_rules
+            .iter()
+            .map(ProtectionRule::label)
+            .collect::<Vec<_>>()
+            .join(" · ")
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProtectionRule {
+    /// `required_reviewers`, `wait_timer` or `branch_policy`
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Minutes, for `wait_timer`
+    pub wait_timer: Option<u64>,
+    #[serde(default)]
+    pub reviewers: Vec<serde_json::Value>,
+}
+
+impl ProtectionRule {
+    pub fn label(&self) -> String {
+        match self.kind.as_str() {
+            "required_reviewers" => match self.reviewers.len() {
+                1 => "1 reviewer".to_string(),
+                n => format!("{} reviewers", n),
+            },
+            "wait_timer" => format!("wait {}m", self.wait_timer.unwrap_or(0)),
+            "branch_policy" => "branch policy".to_string(),
+            other => other.replace('_', " "),
+        }
+    }
+}
+
+/// A deployment of the run's commit (`GET /repos/{owner}/{repo}/deployments`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct Deployment {
+    pub id: u64,
+    pub environment: String,
+    pub creator: Option<Actor>,
+    pub created_at: DateTime<Utc>,
+    /// The latest of its statuses, fetched separately
+    #[serde(skip)]
+    pub status: Option<DeploymentStatus>,
+}
+
+impl Deployment {
+    /// `waiting`, `in_progress`, `success`, ... — `pending` before any
+    /// status is posted
+    pub fn state(&self) -> &str {
+        self.status.as_ref().map_or("pending", |s| s.state.as_str())
+    }
+
+    /// Held by a protection rule until someone approves it
+    pub fn awaiting_approval(&self) -> bool {
+        self.state() == "waiting"
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeploymentStatus {
+    pub state: String,
+    pub log_url: Option<String>,
+    pub target_url: Option<String>,
+}
+
+impl DeploymentStatus {
+    /// Whether the status points at `run_id`'s logs; Actions links every
+    /// deployment status back to the run that made it
+    pub fn belongs_to_run(&self, run_id: u64) -> bool {
+        let marker = format!("/actions/runs/{}/", run_id);
+        let urls = [&self.log_url, &self.target_url];
+        let mut urls = urls.iter().filter_map(|url| url.as_deref()).peekable();
+        // Deployments made outside Actions link nowhere; keep them
+        urls.peek().is_none() || urls.any(|url| format!("{}/", url).contains(&marker))
+    }
+}
+
+/// A commit with its diff stats (`GET /repos/{owner}/{repo}/commits/{sha}`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct Commit {
+    pub sha: String,
+    pub commit: CommitData,
+    /// Left out of commits too large to diff
+    pub stats: Option<CommitStats>,
+}
+
+/// The git side of a commit: message and author as written
+#[derive(Debug, Clone, Deserialize)]
+pub struct CommitData {
+    pub message: String,
+    pub author: Option<CommitAuthor>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CommitAuthor {
+    pub name: String,
+    pub date: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct CommitStats {
+    pub additions: u64,
+    pub deletions: u64,
+}
+
+impl Commit {
+    /// The message's first line
+    pub fn subject(&self) -> &str {
+        self.commit.message.lines().next().unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RunnersResponse {
+    #[allow(dead_code)]
+    pub total_count: u64,
+    pub runners: Vec<Runner>,
+}
+
+/// A self-hosted runner (`GET /repos/{owner}/{repo}/actions/runners`, or
+/// the org's)
+#[derive(Debug, Clone, Deserialize)]
+pub struct Runner {
+    pub id: u64,
+    pub name: String,
+    pub os: String,
+    /// `online` or `offline`
+    pub status: String,
+    pub busy: bool,
+    #[serde(default)]
+    pub labels: Vec<RunnerLabel>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RunnerLabel {
+    pub name: String,
+}
+
+impl Runner {
+    /// `online`, `busy` or `offline`
+    pub fn state(&self) -> &str {
+        if self.busy && self.status == "online" {
+            "busy"
+        } else {
+            &self.status
+        }
+    }
+
+    /// `self-hosted, linux, x64`
+    pub fn labels_display(&self) -> String {
+        self.labels
+            .iter()
+            .map(|l| l.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Whether the runner has every label a job's `runs-on` asks for
+    pub fn fits(&self, wanted: &[String]) -> bool {
+        !wanted.is_empty()
+            && wanted
+                .iter()
+                .all(|w| self.labels.iter().any(|l| l.name.eq_ignore_ascii_case(w)))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionsCachesResponse {
+    pub total_count: u64,
+    pub actions_caches: Vec<ActionsCache>,
+}
+
+/// A cache saved by `actions/cache` (`GET /repos/{owner}/{repo}/actions/caches`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionsCache {
+    pub id: u64,
+    /// The ref it was saved on, e.g. `refs/heads/main` or
+    /// `refs/pull/42/merge`
+    #[serde(rename = "ref")]
+    pub ref_: String,
+    pub key: String,
+    #[allow(dead_code)]
+    pub version: String,
+    pub last_accessed_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub size_in_bytes: u64,
+}
+
 // ── Display helpers ────────────────────────────────────────────────
 
+/// `1536` → `1.5 KB`, `0` → `0 B`
+pub fn format_bytes(n: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if n < 1024 {
+        return format!("{} B", n);
+    }
+    let mut size = n as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// `12345` → `12,345`
+pub fn format_count(n: u64) -> String {
+    let digits = n.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Length of the `2025-01-12T10:43:21.1234567Z ` prefix GitHub puts on
+/// each log line, or 0 when the line has none
+pub fn log_timestamp_len(line: &str) -> usize {
+    let b = line.as_bytes();
+    let digits = |at: usize, n: usize| {
+        b.get(at..at + n)
+            .is_some_and(|s| s.iter().all(u8::is_ascii_digit))
+    };
+    let sep = |at: usize, c: u8| b.get(at) == Some(&c);
+    let date_time = digits(0, 4)
+        && sep(4, b'-')
+        && digits(5, 2)
+        && sep(7, b'-')
+        && digits(8, 2)
+        && sep(10, b'T')
+        && digits(11, 2)
+        && sep(13, b':')
+        && digits(14, 2)
+        && sep(16, b':')
+        && digits(17, 2);
+    if !date_time {
+        return 0;
+    }
+    let mut end = 19;
+    if sep(end, b'.') {
+        end += 1;
+        while b.get(end).is_some_and(u8::is_ascii_digit) {
+            end += 1;
+        }
+    }
+    if !sep(end, b'Z') {
+        return 0;
+    }
+    end += 1;
+    if sep(end, b' ') {
+        end += 1;
+    }
+    end
+}
+
+/// Elapsed time, truncated to the two largest units: `45s`, `2m 30s`,
+/// `1h 30m`, `2d 1h 12m` (days keep the minutes so stuck runs still tick)
+pub fn format_duration(secs: i64) -> String {
+    let secs = secs.max(0);
+    let (d, h, m, s) = (
+        secs / 86400,
+        secs % 86400 / 3600,
+        secs % 3600 / 60,
+        secs % 60,
+    );
+    if secs < 60 {
+        format!("{}s", s)
+    } else if secs < 3600 {
+        format!("{}m {}s", m, s)
+    } else if secs < 86400 {
+        format!("{}h {}m", h, m)
+    } else {
+        format!("{}d {}h {}m", d, h, m)
+    }
+}
+
+/// Time since an event in its largest unit (`3m ago`); anything under 5s
+/// (or in the future, from clock skew) is `just now`
+pub fn format_age(secs: i64) -> String {
+    if secs < 5 {
+        "just now".to_string()
+    } else if secs < 60 {
+        format!("{}s ago", secs)
+    } else if secs < 3600 {
+        format!("{}m ago", secs / 60)
+    } else if secs < 86400 {
+        format!("{}h ago", secs / 3600)
+    } else {
+        format!("{}d ago", secs / 86400)
+    }
+}
+
 impl WorkflowRun {
     pub fn status_display(&self) -> &str {
         match self.conclusion.as_deref() {
             Some("success") => "✓ Success",
             Some("failure") => "✗ Failure",
+            Some("startup_failure") => "✗ Startup Failure",
             Some("cancelled") => "⊘ Cancelled",
             Some("skipped") => "⊘ Skipped",
             Some("timed_out") => "⏱ Timed Out",
@@ -142,25 +685,28 @@ impl WorkflowRun {
         }
     }
 
-    pub fn duration_display(&self) -> String {
-        if let Some(started) = self.run_started_at {
-            let end = if self.status.as_deref() == Some("completed") {
-                self.updated_at
-            } else {
-                Utc::now()
-            };
-            let dur = end.signed_duration_since(started);
-            let secs = dur.num_seconds();
-            if secs < 60 {
-                format!("{}s", secs)
-            } else if secs < 3600 {
-                format!("{}m {}s", secs / 60, secs % 60)
-            } else {
-                format!("{}h {}m", secs / 3600, (secs % 3600) / 60)
-            }
+    /// Seconds from start to finish, or until now while it runs
+    pub fn duration_secs(&self) -> Option<i64> {
+        let started = self.run_started_at?;
+        let end = if self.status.as_deref() == Some("completed") {
+            self.updated_at
         } else {
-            "—".to_string()
-        }
+            Utc::now()
+        };
+        Some(end.signed_duration_since(started).num_seconds())
+    }
+
+    pub fn duration_display(&self) -> String {
+        self.duration_secs()
+            .map_or_else(|| "—".to_string(), format_duration)
+    }
+
+    /// Failed, timed out, or never started for a broken workflow file
+    pub fn failed(&self) -> bool {
+        matches!(
+            self.conclusion.as_deref(),
+            Some("failure" | "timed_out" | "startup_failure")
+        )
     }
 
     pub fn short_sha(&self) -> &str {
@@ -172,16 +718,92 @@ impl WorkflowRun {
     }
 
     pub fn age_display(&self) -> String {
-        let dur = Utc::now().signed_duration_since(self.created_at);
-        let secs = dur.num_seconds();
-        if secs < 60 {
-            format!("{}s ago", secs)
-        } else if secs < 3600 {
-            format!("{}m ago", secs / 60)
-        } else if secs < 86400 {
-            format!("{}h ago", secs / 3600)
+        format_age(
+            Utc::now()
+                .signed_duration_since(self.created_at)
+                .num_seconds(),
+        )
+    }
+
+    /// What `head_branch` names. GitHub puts a pushed tag's name there like a
+    /// branch's, so a push of `v1.2.3` counts as a tag.
+    pub fn ref_kind(&self) -> RefKind {
+        let name = self.head_branch.as_deref().unwrap_or("");
+        if name.starts_with("refs/tags/") || self.event == "release" {
+            RefKind::Tag
+        } else if name.starts_with("gh-readonly-queue/") || self.event == "merge_group" {
+            RefKind::MergeQueue
+        } else if self.event == "push" && is_version_tag(name) {
+            RefKind::Tag
         } else {
-            format!("{}d ago", secs / 86400)
+            RefKind::Branch
+        }
+    }
+
+    /// `head_branch` without `refs/heads/`, `refs/tags/` or the merge queue's
+    /// `gh-readonly-queue/` prefix
+    pub fn ref_name(&self) -> &str {
+        let name = self.head_branch.as_deref().unwrap_or("—");
+        ["refs/heads/", "refs/tags/", "gh-readonly-queue/"]
+            .iter()
+            .find_map(|prefix| name.strip_prefix(prefix))
+            .unwrap_or(name)
+    }
+}
+
+/// The kind of ref a run was triggered for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefKind {
+    Branch,
+    Tag,
+    MergeQueue,
+}
+
+impl RefKind {
+    pub fn icon(self) -> &'static str {
+        match self {
+            Self::Branch => "⎇",
+            Self::Tag => "◆",
+            Self::MergeQueue => "⇶",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Branch => "branch",
+            Self::Tag => "tag",
+            Self::MergeQueue => "merge queue",
+        }
+    }
+}
+
+/// `v1.2.3`, `2.0`, `v10.4.1-rc.1`: a `v` or digit, then dotted numbers
+fn is_version_tag(name: &str) -> bool {
+    let version = name.strip_prefix('v').unwrap_or(name);
+    let core = version.split(['-', '+']).next().unwrap_or("");
+    let parts: Vec<&str> = core.split('.').collect();
+    parts.len() >= 2
+        && parts
+            .iter()
+            .all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()))
+}
+
+impl Workflow {
+    pub fn state_display(&self) -> &str {
+        match self.state.as_str() {
+            "active" => "● Active",
+            "disabled_manually" => "⊘ Disabled",
+            "disabled_inactivity" => "⊘ Inactive",
+            "disabled_fork" => "⊘ Fork",
+            "deleted" => "✗ Deleted",
+            other => other,
+        }
+    }
+
+    pub fn last_run_display(&self) -> String {
+        match self.last_run_at {
+            Some(at) => format_age(Utc::now().signed_duration_since(at).num_seconds()),
+            None => "—".to_string(),
         }
     }
 }
@@ -205,22 +827,64 @@ impl Job {
     pub fn duration_display(&self) -> String {
         match (self.started_at, self.completed_at) {
             (Some(start), Some(end)) => {
-                let secs = end.signed_duration_since(start).num_seconds();
-                if secs < 60 {
-                    format!("{}s", secs)
-                } else if secs < 3600 {
-                    format!("{}m {}s", secs / 60, secs % 60)
-                } else {
-                    format!("{}h {}m", secs / 3600, (secs % 3600) / 60)
-                }
-            }
-            (Some(start), None) => {
-                let secs = Utc::now().signed_duration_since(start).num_seconds();
-                format!("{}s (running)", secs)
+                format_duration(end.signed_duration_since(start).num_seconds())
             }
+            (Some(start), None) => format!(
+                "{} (running)",
+                format_duration(Utc::now().signed_duration_since(start).num_seconds())
+            ),
             _ => "—".to_string(),
         }
     }
+
+    pub fn failed(&self) -> bool {
+        self.conclusion.as_deref() == Some("failure")
+    }
+}
+
+/// How a run's jobs ended, with the icons the jobs pane shows
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct JobCounts {
+    pub succeeded: usize,
+    pub failed: usize,
+    pub cancelled: usize,
+    /// Running, queued, skipped
+    pub other: usize,
+}
+
+impl JobCounts {
+    pub fn of(jobs: &[Job]) -> Self {
+        let mut counts = Self::default();
+        for job in jobs {
+            match job.conclusion.as_deref() {
+                Some("success") => counts.succeeded += 1,
+                Some("failure") => counts.failed += 1,
+                Some("cancelled") => counts.cancelled += 1,
+                _ => counts.other += 1,
+            }
+        }
+        counts
+    }
+
+    /// `36✓ 3✗ 1⊘`, leaving out what's zero
+    pub fn summary(&self) -> String {
+        [
+            (self.succeeded, "✓"),
+            (self.failed, "✗"),
+            (self.cancelled, "⊘"),
+            (self.other, "●"),
+        ]
+        .iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, icon)| format!("{}{}", n, icon))
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+}
+
+/// Float failed jobs to the top; each group keeps its order
+pub fn sort_failed_first(jobs: &mut [Job]) {
+    jobs.sort_by_key(|job| !job.failed());
 }
 
 impl Step {
@@ -241,12 +905,7 @@ impl Step {
     pub fn duration_display(&self) -> String {
         match (self.started_at, self.completed_at) {
             (Some(start), Some(end)) => {
-                let secs = end.signed_duration_since(start).num_seconds();
-                if secs < 60 {
-                    format!("{}s", secs)
-                } else {
-                    format!("{}m {}s", secs / 60, secs % 60)
-                }
+                format_duration(end.signed_duration_since(start).num_seconds())
             }
             _ => "—".to_string(),
         }
@@ -280,9 +939,25 @@ mod tests {
                 avatar_url: None,
             }),
             run_attempt: Some(1),
+            workflow_id: None,
+            path: None,
+            check_suite_id: None,
         }
     }
 
+    #[test]
+    fn test_billable_ms_sums_runner_oses() {
+        let timing: RunTiming = serde_json::from_str(
+            r#"{"billable": {"UBUNTU": {"total_ms": 180000, "jobs": 2},
+                             "MACOS": {"total_ms": 60000, "jobs": 1}},
+                "run_duration_ms": 500000}"#,
+        )
+        .unwrap();
+        assert_eq!(timing.billable_ms(), 240000);
+        let empty: RunTiming = serde_json::from_str(r#"{"run_duration_ms": 1}"#).unwrap();
+        assert_eq!(empty.billable_ms(), 0);
+    }
+
     #[test]
     fn test_status_display_success() {
         let run = make_run(Some("completed"), Some("success"));
@@ -319,6 +994,84 @@ mod tests {
         assert_eq!(run.status_display(), "? Unknown");
     }
 
+    fn make_job(id: u64, conclusion: Option<&str>) -> Job {
+        Job {
+            id,
+            run_id: 1,
+            name: format!("job-{}", id),
+            status: Some(
+                if conclusion.is_some() {
+                    "completed"
+                } else {
+                    "in_progress"
+                }
+                .into(),
+            ),
+            conclusion: conclusion.map(String::from),
+            started_at: None,
+            completed_at: None,
+            steps: None,
+            html_url: None,
+            labels: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_job_counts() {
+        let jobs = [
+            make_job(1, Some("success")),
+            make_job(2, Some("failure")),
+            make_job(3, Some("success")),
+            make_job(4, Some("cancelled")),
+            make_job(5, None),
+        ];
+        let counts = JobCounts::of(&jobs);
+        assert_eq!(counts.summary(), "2✓ 1✗ 1⊘ 1●");
+        let passing = JobCounts::of(&jobs[..1]);
+        assert_eq!(passing.summary(), "1✓");
+        assert_eq!(JobCounts::of(&[]).summary(), "");
+    }
+
+    #[test]
+    fn test_sort_failed_first_is_stable() {
+        let mut jobs = vec![
+            make_job(1, Some("success")),
+            make_job(2, Some("failure")),
+            make_job(3, None),
+            make_job(4, Some("failure")),
+            make_job(5, Some("cancelled")),
+        ];
+        sort_failed_first(&mut jobs);
+        let ids: Vec<u64> = jobs.iter().map(|j| j.id).collect();
+        assert_eq!(ids, [2, 4, 1, 3, 5]);
+    }
+
+    #[test]
+    fn test_ref_kind_and_name() {
+        let with = |branch: &str, event: &str| {
+            let mut run = make_run(Some("completed"), Some("success"));
+            run.head_branch = Some(branch.to_string());
+            run.event = event.to_string();
+            run
+        };
+        let tag = with("refs/tags/v1.2.3", "push");
+        assert_eq!(tag.ref_kind(), RefKind::Tag);
+        assert_eq!(tag.ref_name(), "v1.2.3");
+        assert_eq!(with("v1.2.3", "push").ref_kind(), RefKind::Tag);
+        assert_eq!(with("2.0.1-rc.1", "push").ref_kind(), RefKind::Tag);
+        assert_eq!(with("stable", "release").ref_kind(), RefKind::Tag);
+
+        let queue = with("gh-readonly-queue/main/pr-123-abcdef", "merge_group");
+        assert_eq!(queue.ref_kind(), RefKind::MergeQueue);
+        assert_eq!(queue.ref_name(), "main/pr-123-abcdef");
+
+        assert_eq!(with("main", "push").ref_kind(), RefKind::Branch);
+        assert_eq!(with("refs/heads/main", "push").ref_name(), "main");
+        // A version-looking branch someone opened a PR from stays a branch
+        assert_eq!(with("v2.0", "pull_request").ref_kind(), RefKind::Branch);
+        assert_eq!(with("release/1.2", "push").ref_kind(), RefKind::Branch);
+    }
+
     #[test]
     fn test_short_sha() {
         let run = make_run(None, None);
@@ -384,6 +1137,7 @@ mod tests {
             completed_at: None,
             steps: None,
             html_url: None,
+            labels: Vec::new(),
         };
         assert_eq!(job.status_display(), "✓ Success");
     }
@@ -402,6 +1156,7 @@ mod tests {
             completed_at: Some(ended),
             steps: None,
             html_url: None,
+            labels: Vec::new(),
         };
         assert_eq!(job.duration_display(), "1m 15s");
     }
@@ -448,9 +1203,155 @@ mod tests {
 
     #[test]
     fn test_age_display() {
-        // Just verify it doesn't panic and returns a string with "ago"
-        let run = make_run(None, None);
-        let age = run.age_display();
-        assert!(age.contains("ago"));
+        let mut run = make_run(None, None);
+        assert_eq!(run.age_display(), "just now");
+        run.created_at = Utc::now() - chrono::Duration::minutes(3);
+        assert_eq!(run.age_display(), "3m ago");
+    }
+
+    #[test]
+    fn test_log_timestamp_len() {
+        let line = "2025-01-12T10:43:21.1234567Z ##[group]Run cargo test";
+        assert_eq!(&line[log_timestamp_len(line)..], "##[group]Run cargo test");
+        assert_eq!(log_timestamp_len("2025-01-12T10:43:21Z done"), 21);
+        assert_eq!(log_timestamp_len("2025-01-12T10:43:21.5Z"), 22);
+        assert_eq!(log_timestamp_len("no timestamp here"), 0);
+        assert_eq!(log_timestamp_len("2025-01-12 10:43:21Z x"), 0);
+        assert_eq!(log_timestamp_len("2025-01-12T10:43:21.12"), 0);
+        assert_eq!(log_timestamp_len(""), 0);
+    }
+
+    #[test]
+    fn test_format_count() {
+        assert_eq!(format_count(0), "0");
+        assert_eq!(format_count(999), "999");
+        assert_eq!(format_count(1_000), "1,000");
+        assert_eq!(format_count(12_345), "12,345");
+        assert_eq!(format_count(123_456), "123,456");
+        assert_eq!(format_count(1_234_567), "1,234,567");
+        assert_eq!(format_count(u64::MAX), "18,446,744,073,709,551,615");
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024), "3.0 GB");
+    }
+
+    #[test]
+    fn test_format_duration_tiers() {
+        assert_eq!(format_duration(-3), "0s");
+        assert_eq!(format_duration(0), "0s");
+        assert_eq!(format_duration(59), "59s");
+        assert_eq!(format_duration(60), "1m 0s");
+        assert_eq!(format_duration(3599), "59m 59s");
+        assert_eq!(format_duration(3600), "1h 0m");
+        assert_eq!(format_duration(86_399), "23h 59m");
+        assert_eq!(format_duration(86_400), "1d 0h 0m");
+        assert_eq!(format_duration(49 * 3600 + 12 * 60 + 5), "2d 1h 12m");
+    }
+
+    #[test]
+    fn test_format_age_tiers() {
+        assert_eq!(format_age(-30), "just now");
+        assert_eq!(format_age(0), "just now");
+        assert_eq!(format_age(4), "just now");
+        assert_eq!(format_age(5), "5s ago");
+        assert_eq!(format_age(59), "59s ago");
+        assert_eq!(format_age(60), "1m ago");
+        assert_eq!(format_age(3599), "59m ago");
+        assert_eq!(format_age(3600), "1h ago");
+        assert_eq!(format_age(86_399), "23h ago");
+        assert_eq!(format_age(86_400), "1d ago");
+        assert_eq!(format_age(30 * 86_400), "30d ago");
+    }
+
+    #[test]
+    fn test_jobs_list_skips_steps_but_detail_keeps_them() {
+        let json = r#"{
+            "id": 7, "run_id": 1, "name": "build", "status": "completed",
+            "conclusion": "success", "started_at": null, "completed_at": null,
+            "html_url": null,
+            "steps": [{"name": "Checkout", "status": "completed",
+                       "conclusion": "success", "number": 1,
+                       "started_at": null, "completed_at": null}]
+        }"#;
+
+        let listed: Job = serde_json::from_str(json).unwrap();
+        assert!(listed.steps.is_none());
+
+        let detail: Job = serde_json::from_str::<JobDetail>(json).unwrap().into();
+        assert_eq!(detail.id, 7);
+        assert_eq!(detail.steps.unwrap()[0].name, "Checkout");
+    }
+
+    #[test]
+    fn test_runner_state_and_fit() {
+        let json = r#"{
+            "id": 1, "name": "gpu-1", "os": "Linux", "status": "online", "busy": true,
+            "labels": [{"id": 1, "name": "self-hosted", "type": "read-only"},
+                       {"id": 2, "name": "GPU", "type": "custom"}]
+        }"#;
+        let runner: Runner = serde_json::from_str(json).unwrap();
+        assert_eq!(runner.state(), "busy");
+        assert_eq!(runner.labels_display(), "self-hosted, GPU");
+        assert!(runner.fits(&["self-hosted".into(), "gpu".into()]));
+        assert!(!runner.fits(&["self-hosted".into(), "arm64".into()]));
+        assert!(!runner.fits(&[]));
+
+        let offline = Runner {
+            status: "offline".into(),
+            ..runner
+        };
+        assert_eq!(offline.state(), "offline");
+    }
+
+    #[test]
+    fn test_environment_protection_and_deployment_state() {
+        let json = r#"{
+            "id": 3, "name": "production",
+            "html_url": "https://github.com/octo/api/deployments/production",
+            "protection_rules": [
+                {"id": 1, "type": "required_reviewers",
+                 "reviewers": [{"type": "User", "reviewer": {"login": "octocat"}},
+                               {"type": "Team", "reviewer": {"slug": "ops"}}]},
+                {"id": 2, "type": "wait_timer", "wait_timer": 5},
+                {"id": 3, "type": "branch_policy"}
+            ]
+        }"#;
+        let env: Environment = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            env.protection_display(),
+            "2 reviewers · wait 5m · branch policy"
+        );
+        let open: Environment =
+            serde_json::from_str(r#"{"id": 4, "name": "staging", "html_url": ""}"#).unwrap();
+        assert_eq!(open.protection_display(), "none");
+
+        let json = r#"{
+            "id": 9, "environment": "production", "created_at": "2025-01-12T10:00:00Z",
+            "creator": {"login": "octocat", "avatar_url": null}
+        }"#;
+        let mut deployment: Deployment = serde_json::from_str(json).unwrap();
+        assert_eq!(deployment.state(), "pending");
+        deployment.status = Some(DeploymentStatus {
+            state: "waiting".into(),
+            log_url: Some("https://github.com/octo/api/actions/runs/123/job/7".into()),
+            target_url: None,
+        });
+        assert!(deployment.awaiting_approval());
+
+        let status = deployment.status.as_ref().unwrap();
+        assert!(status.belongs_to_run(123));
+        assert!(!status.belongs_to_run(12));
+        let elsewhere = DeploymentStatus {
+            state: "success".into(),
+            log_url: None,
+            target_url: None,
+        };
+        assert!(elsewhere.belongs_to_run(123));
     }
 }
@@ -0,0 +1,93 @@
+/// What `protected_workflows` is when the config doesn't say
+pub const DEFAULT_PATTERNS: &[&str] = &["*prod*", "*release*"];
+
+// ── Protected workflows ────────────────────────────────────────────
+
+/// Workflows whose re-runs, cancels and dispatches must be confirmed by
+/// typing rather than a single key
+#[derive(Debug, Clone, Default)]
+pub struct ProtectedWorkflows {
+    patterns: Vec<String>,
+}
+
+impl ProtectedWorkflows {
+    pub fn new(patterns: &[String]) -> Self {
+        Self {
+            patterns: patterns.to_vec(),
+        }
+    }
+
+    /// A pattern matches the workflow's name or its file name
+    /// (`deploy-prod.yml` of `.github/workflows/deploy-prod.yml`)
+    pub fn covers(&self, name: Option<&str>, path: Option<&str>) -> bool {
+        let file = path.map(|p| p.rsplit('/').next().unwrap_or(p));
+        self.patterns.iter().any(|pattern| {
+            [name, file]
+                .into_iter()
+                .flatten()
+                .any(|text| glob_match(pattern, text))
+        })
+    }
+}
+
+/// Case-insensitive glob: `*` is any run of characters, `?` any one
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Where the last `*` was, and how much text it has swallowed so far
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                // Let the `*` take one more character and try again
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    star = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*prod*", "Deploy PROD"));
+        assert!(glob_match("*prod*", "prod"));
+        assert!(glob_match("deploy-?.yml", "deploy-1.yml"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("a*b*c", "aXbYbZc"));
+        assert!(!glob_match("*prod*", "Lint"));
+        assert!(!glob_match("deploy", "deploy-prod"));
+        assert!(!glob_match("?", ""));
+    }
+
+    #[test]
+    fn test_covers_name_or_file() {
+        let defaults: Vec<String> = DEFAULT_PATTERNS.iter().map(|p| p.to_string()).collect();
+        let protected = ProtectedWorkflows::new(&defaults);
+        assert!(protected.covers(Some("Release"), None));
+        assert!(protected.covers(Some("Deploy"), Some(".github/workflows/deploy-prod.yml")));
+        assert!(!protected.covers(Some("CI"), Some(".github/workflows/ci.yml")));
+        // `prod` in a directory name doesn't count
+        assert!(!protected.covers(None, Some("prod/ci.yml")));
+        assert!(!ProtectedWorkflows::default().covers(Some("Release"), None));
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+// ── Response cache ─────────────────────────────────────────────────
+
+/// Recently fetched values, each fresh for `ttl`, at most `max_entries` of
+/// them: past that the least recently used goes first
+#[derive(Debug)]
+pub struct ResponseCache<K, V> {
+    ttl: Duration,
+    max_entries: usize,
+    entries: HashMap<K, (Instant, V)>,
+    /// Keys from least to most recently used
+    order: VecDeque<K>,
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> ResponseCache<K, V> {
+    /// A zero `ttl` or `max_entries` caches nothing
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            ttl,
+            max_entries,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// A cache that never holds anything
+    pub fn disabled() -> Self {
+        Self::new(Duration::ZERO, 0)
+    }
+
+    /// The value stored for `key`, unless it has gone stale
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        let (stored, value) = self.entries.get(key)?;
+        if stored.elapsed() >= self.ttl {
+            self.remove(key);
+            return None;
+        }
+        let value = value.clone();
+        self.touch(key);
+        Some(value)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        if self.ttl.is_zero() || self.max_entries == 0 {
+            return;
+        }
+        if self
+            .entries
+            .insert(key.clone(), (Instant::now(), value))
+            .is_some()
+        {
+            self.touch(&key);
+            return;
+        }
+        self.order.push_back(key);
+        while self.entries.len() > self.max_entries {
+            let Some(oldest) = self.order.pop_front() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Move `key` to the most recently used end
+    fn touch(&mut self, key: &K) {
+        if let Some(at) = self.order.iter().position(|k| k == key) {
+            self.order.remove(at);
+        }
+        self.order.push_back(key.clone());
+    }
+
+    fn remove(&mut self, key: &K) {
+        self.entries.remove(key);
+        self.order.retain(|k| k != key);
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hit_then_stale() {
+        let mut cache = ResponseCache::new(Duration::from_millis(30), 10);
+        cache.insert("jobs", 1);
+        assert_eq!(cache.get(&"jobs"), Some(1));
+        assert_eq!(cache.get(&"runs"), None);
+        std::thread::sleep(Duration::from_millis(40));
+        assert_eq!(cache.get(&"jobs"), None);
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn test_least_recently_used_goes_first() {
+        let mut cache = ResponseCache::new(Duration::from_secs(30), 2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        // Reading `a` leaves `b` as the oldest
+        assert_eq!(cache.get(&"a"), Some(1));
+        cache.insert("c", 3);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&"b"), None);
+        assert_eq!(cache.get(&"a"), Some(1));
+        assert_eq!(cache.get(&"c"), Some(3));
+
+        // Replacing a value doesn't grow the cache
+        cache.insert("c", 4);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&"c"), Some(4));
+    }
+
+    #[test]
+    fn test_disabled_and_cleared() {
+        let mut cache = ResponseCache::disabled();
+        cache.insert("a", 1);
+        assert_eq!(cache.get(&"a"), None);
+
+        let mut cache = ResponseCache::new(Duration::from_secs(30), 2);
+        cache.insert("a", 1);
+        cache.clear();
+        assert_eq!(cache.get(&"a"), None);
+    }
+}
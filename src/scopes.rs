@@ -0,0 +1,123 @@
+use anyhow::Result;
+
+use crate::doctor::missing_scopes;
+use crate::github::GitHubClient;
+
+// ── Token scope ────────────────────────────────────────────────────
+
+/// What the token is allowed to do, as far as GitHub will say
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TokenScope {
+    /// A classic token's scopes; `None` for a fine-grained token, which has
+    /// per-repo permissions instead
+    pub scopes: Option<Vec<String>>,
+    /// What's missing: "the 'workflow' scope", "write access to octo/api"
+    pub missing: Vec<String>,
+}
+
+impl TokenScope {
+    /// Re-runs, cancels or dispatches will fail
+    pub fn is_limited(&self) -> bool {
+        !self.missing.is_empty()
+    }
+
+    /// "Token lacks the 'workflow' scope: re-runs, cancels and dispatches
+    /// will fail"
+    pub fn summary(&self) -> String {
+        format!(
+            "Token lacks {}: re-runs, cancels and dispatches will fail",
+            self.missing.join(" and ")
+        )
+    }
+}
+
+/// Check the client's token: a classic token by its `x-oauth-scopes`, and
+/// either kind by whether the repo the client points at lets it push
+pub async fn detect(client: &GitHubClient) -> Result<TokenScope> {
+    // An app installation has permissions rather than scopes, and no user
+    let scopes = match client.installation() {
+        Some(_) => None,
+        None => client.get_authenticated_user().await?.scopes,
+    };
+    let mut missing: Vec<String> = match &scopes {
+        Some(scopes) => missing_scopes(scopes)
+            .into_iter()
+            .map(|scope| format!("the '{}' scope", scope))
+            .collect(),
+        None => Vec::new(),
+    };
+
+    if !client.repo.is_empty() {
+        let repo = client.get_repo(&client.owner, &client.repo).await?;
+        if repo.permissions.is_some_and(|p| !p.push) {
+            missing.push(format!("write access to {}", repo.full_name));
+        }
+    }
+
+    Ok(TokenScope { scopes, missing })
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn server(scopes: Option<&str>, push: bool) -> MockServer {
+        let server = MockServer::start().await;
+        let mut user = ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "login": "octocat"
+        }));
+        if let Some(scopes) = scopes {
+            user = user.insert_header("x-oauth-scopes", scopes);
+        }
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(user)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": 1, "full_name": "octo/api", "name": "api",
+                "owner": {"login": "octo"}, "html_url": "", "stargazers_count": 0,
+                "updated_at": "2025-01-01T00:00:00Z", "private": false,
+                "fork": false, "archived": false,
+                "permissions": {"admin": false, "push": push, "pull": true}
+            })))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    fn client(server: &MockServer) -> GitHubClient {
+        GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), server.uri())
+    }
+
+    #[tokio::test]
+    async fn test_classic_token_missing_a_scope() {
+        let server = server(Some("repo, read:org"), true).await;
+        let scope = detect(&client(&server)).await.unwrap();
+        assert_eq!(scope.missing, ["the 'workflow' scope"]);
+        assert_eq!(
+            scope.summary(),
+            "Token lacks the 'workflow' scope: re-runs, cancels and dispatches will fail"
+        );
+
+        let server = self::server(Some("repo, workflow"), true).await;
+        assert!(!detect(&client(&server)).await.unwrap().is_limited());
+    }
+
+    #[tokio::test]
+    async fn test_fine_grained_token_without_write_access() {
+        let server = server(None, false).await;
+        let scope = detect(&client(&server)).await.unwrap();
+        assert_eq!(scope.scopes, None);
+        assert_eq!(scope.missing, ["write access to octo/api"]);
+
+        let server = self::server(None, true).await;
+        assert!(!detect(&client(&server)).await.unwrap().is_limited());
+    }
+}
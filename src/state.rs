@@ -0,0 +1,250 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// Recently opened repos kept in the state file
+const MAX_RECENT: usize = 20;
+
+// ── Persistent state ───────────────────────────────────────────────
+
+/// Small bits of state shared by every Atlas instance, one file per account
+/// (`~/.atlas/state/<host>/<login>/state.json`)
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct State {
+    /// Recently opened repositories → unix time they were last opened
+    #[serde(default)]
+    pub recent: BTreeMap<String, i64>,
+}
+
+impl State {
+    pub fn touch_recent(&mut self, full_name: &str, now: i64) {
+        self.recent.insert(full_name.to_string(), now);
+        while self.recent.len() > MAX_RECENT {
+            let oldest = self
+                .recent
+                .iter()
+                .min_by_key(|(_, t)| **t)
+                .map(|(name, _)| name.clone());
+            match oldest {
+                Some(name) => self.recent.remove(&name),
+                None => break,
+            };
+        }
+    }
+}
+
+// ── File locking ───────────────────────────────────────────────────
+
+/// Advisory lock on a file, released on drop. Every reader and writer of
+/// a shared file takes the lock, so concurrent instances never interleave.
+pub struct FileLock {
+    file: File,
+}
+
+impl FileLock {
+    pub fn exclusive(path: &Path) -> Result<Self> {
+        let file = open_lock_file(path)?;
+        file.lock()
+            .with_context(|| format!("Failed to lock {}", path.display()))?;
+        Ok(Self { file })
+    }
+
+    pub fn shared(path: &Path) -> Result<Self> {
+        let file = open_lock_file(path)?;
+        file.lock_shared()
+            .with_context(|| format!("Failed to lock {}", path.display()))?;
+        Ok(Self { file })
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = self.file.unlock();
+    }
+}
+
+fn open_lock_file(path: &Path) -> Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path)
+        .with_context(|| format!("Failed to open lock file {}", path.display()))
+}
+
+// ── State store ────────────────────────────────────────────────────
+
+/// Reads and writes `state.json` under `state.lock` in one directory
+#[derive(Debug, Clone)]
+pub struct StateStore {
+    dir: PathBuf,
+}
+
+impl StateStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn state_path(&self) -> PathBuf {
+        self.dir.join("state.json")
+    }
+
+    fn lock_path(&self) -> PathBuf {
+        self.dir.join("state.lock")
+    }
+
+    /// Current state; an unreadable file reads as empty
+    pub fn load(&self) -> Result<State> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        let _lock = FileLock::shared(&self.lock_path())?;
+        Ok(read_state(&self.state_path()).unwrap_or_default())
+    }
+
+    /// Apply `f` to the latest state on disk and write it back, all under
+    /// an exclusive lock. Changes from other instances made in between are
+    /// kept, since `f` only touches the entries it cares about.
+    pub fn update(&self, f: impl FnOnce(&mut State)) -> Result<State> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        let _lock = FileLock::exclusive(&self.lock_path())?;
+
+        let path = self.state_path();
+        let mut state = match read_state(&path) {
+            Some(state) => state,
+            None => {
+                set_aside_if_corrupt(&path);
+                State::default()
+            }
+        };
+        f(&mut state);
+
+        write_atomic(&path, &serde_json::to_vec_pretty(&state)?)?;
+        Ok(state)
+    }
+}
+
+/// `~/.atlas`, where state, config, and exports live
+pub fn atlas_dir() -> PathBuf {
+    std::env::var("HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("."))
+        .join(".atlas")
+}
+
+/// Write to a temp file and rename, so a crash never leaves `path` truncated
+pub fn write_atomic(path: &Path, content: &[u8]) -> Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    std::fs::write(&tmp, content).with_context(|| format!("Failed to write {}", tmp.display()))?;
+    std::fs::rename(&tmp, path).with_context(|| format!("Failed to replace {}", path.display()))
+}
+
+/// `None` when the file is missing or can't be parsed
+fn read_state(path: &Path) -> Option<State> {
+    let content = std::fs::read(path).ok()?;
+    match serde_json::from_slice(&content) {
+        Ok(state) => Some(state),
+        Err(e) => {
+            warn!(error = %e, path = %path.display(), "Unreadable state file");
+            None
+        }
+    }
+}
+
+/// Rename a state file that exists but doesn't parse, so it can be
+/// inspected later instead of being overwritten
+fn set_aside_if_corrupt(path: &Path) {
+    if !path.exists() {
+        return;
+    }
+    let aside = path.with_extension(format!("json.corrupt-{}", chrono::Utc::now().timestamp()));
+    match std::fs::rename(path, &aside) {
+        Ok(()) => warn!(path = %aside.display(), "Moved corrupt state file aside"),
+        Err(e) => warn!(error = %e, "Failed to move corrupt state file aside"),
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store(name: &str) -> StateStore {
+        let dir = std::env::temp_dir().join(format!("atlas-state-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        StateStore::new(dir)
+    }
+
+    #[test]
+    fn test_update_round_trip() {
+        let store = temp_store("round-trip");
+        store
+            .update(|s| {
+                s.touch_recent("octo/web", 100);
+            })
+            .unwrap();
+        let state = store.load().unwrap();
+        assert_eq!(state.recent.get("octo/web"), Some(&100));
+        std::fs::remove_dir_all(&store.dir).unwrap();
+    }
+
+    #[test]
+    fn test_concurrent_updates_are_merged() {
+        let store = temp_store("concurrent");
+        let handles: Vec<_> = (0..2)
+            .map(|t| {
+                let store = store.clone();
+                std::thread::spawn(move || {
+                    for i in 0..25 {
+                        store
+                            .update(|s| {
+                                s.recent.insert(format!("thread{}/repo{}", t, i), i);
+                            })
+                            .unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let state = store.load().unwrap();
+        assert_eq!(state.recent.len(), 50);
+        std::fs::remove_dir_all(&store.dir).unwrap();
+    }
+
+    #[test]
+    fn test_corrupt_file_is_set_aside() {
+        let store = temp_store("corrupt");
+        std::fs::create_dir_all(&store.dir).unwrap();
+        std::fs::write(store.state_path(), b"{\"recent\": {\"trunc").unwrap();
+
+        assert_eq!(store.load().unwrap(), State::default());
+        store.update(|s| s.touch_recent("octo/api", 100)).unwrap();
+
+        let aside = std::fs::read_dir(&store.dir)
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .any(|e| e.file_name().to_string_lossy().contains("corrupt"));
+        assert!(aside);
+        assert_eq!(store.load().unwrap().recent.len(), 1);
+        std::fs::remove_dir_all(&store.dir).unwrap();
+    }
+
+    #[test]
+    fn test_recent_is_capped() {
+        let mut state = State::default();
+        for i in 0..(MAX_RECENT as i64 + 5) {
+            state.touch_recent(&format!("octo/repo{}", i), i);
+        }
+        assert_eq!(state.recent.len(), MAX_RECENT);
+        assert!(!state.recent.contains_key("octo/repo0"));
+    }
+}
@@ -0,0 +1,232 @@
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+
+use crate::durations;
+use crate::models::WorkflowRun;
+
+/// How far back the statistics look
+pub const WINDOW_DAYS: i64 = 30;
+/// Days in the success-rate sparkline
+pub const TREND_DAYS: usize = 14;
+/// Rows of the top failing workflows table
+const TOP_FAILING: usize = 5;
+
+// ── Build health ───────────────────────────────────────────────────
+
+/// Build health of the runs loaded for a repo, over the last
+/// `WINDOW_DAYS` days
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepoStats {
+    /// Runs created in the window, finished or not
+    pub total: usize,
+    /// Successes over finished runs, cancelled and skipped ones aside;
+    /// `None` before any finished
+    pub success_rate: Option<f64>,
+    pub avg_secs: Option<u64>,
+    /// `None` below the handful of runs a percentile needs
+    pub p95_secs: Option<u64>,
+    /// The conclusion failed runs end with most, and how many did
+    pub top_failure: Option<(String, usize)>,
+    /// Success rate per day, oldest first, ending today; `None` for a day
+    /// without finished runs
+    pub daily: Vec<Option<f64>>,
+    /// Workflows with failed runs, most failures first
+    pub failing: Vec<FailingWorkflow>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FailingWorkflow {
+    pub name: String,
+    pub failures: usize,
+    /// Finished runs of the workflow in the window
+    pub runs: usize,
+}
+
+impl RepoStats {
+    pub fn compute(runs: &[WorkflowRun], now: DateTime<Utc>) -> Self {
+        let since = now - Duration::days(WINDOW_DAYS);
+        let recent: Vec<&WorkflowRun> = runs.iter().filter(|r| r.created_at >= since).collect();
+        let finished: Vec<&WorkflowRun> = recent.iter().copied().filter(|r| counts(r)).collect();
+        let succeeded = |runs: &[&WorkflowRun]| {
+            runs.iter()
+                .filter(|r| r.conclusion.as_deref() == Some("success"))
+                .count()
+        };
+
+        let durations: Vec<u64> = recent.iter().filter_map(|r| run_secs(r)).collect();
+        let avg_secs =
+            (!durations.is_empty()).then(|| durations.iter().sum::<u64>() / durations.len() as u64);
+
+        let mut reasons: HashMap<&str, usize> = HashMap::new();
+        let mut workflows: HashMap<&str, (usize, usize)> = HashMap::new();
+        for run in &finished {
+            let name = run.name.as_deref().unwrap_or("Workflow");
+            let entry = workflows.entry(name).or_default();
+            entry.1 += 1;
+            match run.conclusion.as_deref() {
+                Some("success") => {}
+                conclusion => {
+                    *reasons.entry(conclusion.unwrap_or("unknown")).or_default() += 1;
+                    entry.0 += 1;
+                }
+            }
+        }
+        // Ties go to the alphabetically first, so the panel doesn't flicker
+        let top_failure = reasons
+            .into_iter()
+            .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(a.0)))
+            .map(|(reason, count)| (reason.to_string(), count));
+        let mut failing: Vec<FailingWorkflow> = workflows
+            .into_iter()
+            .filter(|(_, (failures, _))| *failures > 0)
+            .map(|(name, (failures, runs))| FailingWorkflow {
+                name: name.to_string(),
+                failures,
+                runs,
+            })
+            .collect();
+        failing.sort_by(|a, b| b.failures.cmp(&a.failures).then(a.name.cmp(&b.name)));
+        failing.truncate(TOP_FAILING);
+
+        let today = now.date_naive();
+        let daily = (0..TREND_DAYS as i64)
+            .rev()
+            .map(|days_ago| {
+                let day = today - Duration::days(days_ago);
+                let on_day: Vec<&WorkflowRun> = finished
+                    .iter()
+                    .copied()
+                    .filter(|r| r.created_at.date_naive() == day)
+                    .collect();
+                rate(succeeded(&on_day), on_day.len())
+            })
+            .collect();
+
+        Self {
+            total: recent.len(),
+            success_rate: rate(succeeded(&finished), finished.len()),
+            avg_secs,
+            p95_secs: durations::p95(&durations),
+            top_failure,
+            daily,
+            failing,
+        }
+    }
+}
+
+/// A finished run that says something about health: cancelled and
+/// skipped runs don't
+fn counts(run: &WorkflowRun) -> bool {
+    run.status.as_deref() == Some("completed")
+        && !matches!(run.conclusion.as_deref(), Some("cancelled" | "skipped"))
+}
+
+/// Start to finish of a completed run
+fn run_secs(run: &WorkflowRun) -> Option<u64> {
+    if run.status.as_deref() != Some("completed") {
+        return None;
+    }
+    let started = run.run_started_at.unwrap_or(run.created_at);
+    u64::try_from((run.updated_at - started).num_seconds()).ok()
+}
+
+fn rate(succeeded: usize, of: usize) -> Option<f64> {
+    (of > 0).then(|| succeeded as f64 / of as f64)
+}
+
+/// `▁▃█·▇`: one block per day, its height the success rate, `·` for a day
+/// without finished runs
+pub fn sparkline(daily: &[Option<f64>]) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    daily
+        .iter()
+        .map(|day| match day {
+            Some(rate) => BLOCKS[((rate.clamp(0.0, 1.0) * 7.0).round()) as usize],
+            None => '·',
+        })
+        .collect()
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(name: &str, conclusion: Option<&str>, days_ago: i64, secs: i64) -> WorkflowRun {
+        let now: DateTime<Utc> = "2025-01-31T12:00:00Z".parse().unwrap();
+        let created = now - Duration::days(days_ago);
+        let status = if conclusion.is_some() {
+            "completed"
+        } else {
+            "in_progress"
+        };
+        serde_json::from_value(serde_json::json!({
+            "id": 1, "name": name, "head_sha": "abc", "run_number": 1, "event": "push",
+            "html_url": "", "status": status, "conclusion": conclusion,
+            "created_at": created, "run_started_at": created,
+            "updated_at": created + Duration::seconds(secs)
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_compute() {
+        let now = "2025-01-31T12:00:00Z".parse().unwrap();
+        let runs = [
+            run("CI", Some("success"), 0, 60),
+            run("CI", Some("failure"), 0, 120),
+            run("CI", Some("success"), 1, 60),
+            run("Deploy", Some("timed_out"), 2, 600),
+            run("Deploy", Some("failure"), 3, 300),
+            run("Lint", Some("cancelled"), 3, 10),
+            run("Lint", None, 0, 0),
+            // Outside the window
+            run("CI", Some("failure"), 40, 60),
+        ];
+        let stats = RepoStats::compute(&runs, now);
+        assert_eq!(stats.total, 7);
+        // 2 of 5: the cancelled and unfinished runs don't count
+        assert_eq!(stats.success_rate, Some(0.4));
+        // Over the 6 completed runs, the cancelled one included
+        assert_eq!(stats.avg_secs, Some(191));
+        assert_eq!(stats.p95_secs, Some(600));
+        assert_eq!(stats.top_failure, Some(("failure".to_string(), 2)));
+        assert_eq!(
+            stats.failing,
+            [
+                FailingWorkflow {
+                    name: "Deploy".into(),
+                    failures: 2,
+                    runs: 2
+                },
+                FailingWorkflow {
+                    name: "CI".into(),
+                    failures: 1,
+                    runs: 3
+                },
+            ]
+        );
+        assert_eq!(stats.daily.len(), TREND_DAYS);
+        assert_eq!(
+            stats.daily[TREND_DAYS - 4..],
+            [Some(0.0), Some(0.0), Some(1.0), Some(0.5)]
+        );
+        assert_eq!(stats.daily[0], None);
+    }
+
+    #[test]
+    fn test_nothing_loaded() {
+        let stats = RepoStats::compute(&[], Utc::now());
+        assert_eq!(stats.total, 0);
+        assert_eq!(stats.success_rate, None);
+        assert_eq!(stats.avg_secs, None);
+        assert_eq!(stats.top_failure, None);
+        assert!(stats.failing.is_empty());
+    }
+
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(sparkline(&[Some(0.0), Some(0.5), None, Some(1.0)]), "▁▅·█");
+    }
+}
@@ -0,0 +1,215 @@
+use anyhow::{bail, Result};
+use std::collections::HashSet;
+
+use crate::github::GitHubClient;
+use crate::models::WorkflowRun;
+
+const RESET: &str = "\x1b[0m";
+const DIM: &str = "\x1b[2m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+
+/// Runs read for `atlas status`: one page, enough to reach the latest of
+/// every workflow on a branch
+const PAGE_SIZE: u8 = 100;
+
+// ── Branch status ──────────────────────────────────────────────────
+
+/// Where a workflow's latest run leaves the gate
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Verdict {
+    Passed,
+    Failed,
+    Pending,
+}
+
+impl Verdict {
+    fn of(run: &WorkflowRun) -> Self {
+        match (run.status.as_deref(), run.conclusion.as_deref()) {
+            (Some("completed"), Some("success" | "neutral" | "skipped")) => Self::Passed,
+            (Some("completed"), _) => Self::Failed,
+            _ => Self::Pending,
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            Self::Passed => "✓",
+            Self::Failed => "✗",
+            Self::Pending => "●",
+        }
+    }
+
+    fn color(self) -> &'static str {
+        match self {
+            Self::Passed => GREEN,
+            Self::Failed => RED,
+            Self::Pending => YELLOW,
+        }
+    }
+}
+
+/// Print the latest run of each workflow on `branch`, as aligned lines
+/// (colored when `colored`) or as one JSON object, and report whether they
+/// all passed; runs still going pass unless `fail_on_pending`
+pub async fn run(
+    client: &GitHubClient,
+    branch: &str,
+    fail_on_pending: bool,
+    json: bool,
+    colored: bool,
+) -> Result<bool> {
+    let runs = client
+        .get_workflow_runs(PAGE_SIZE, 1, Some(branch), None, None, None)
+        .await?
+        .workflow_runs;
+    let latest = latest_per_workflow(runs);
+    if latest.is_empty() {
+        bail!("No workflow runs on branch {}", branch);
+    }
+    let passed = passed(&latest, fail_on_pending);
+    if json {
+        let workflows: Vec<serde_json::Value> = latest.iter().map(workflow_json).collect();
+        let status = serde_json::json!({
+            "branch": branch,
+            "passed": passed,
+            "workflows": workflows,
+        });
+        println!("{}", serde_json::to_string_pretty(&status)?);
+    } else {
+        print!("{}", summary(&latest, colored));
+    }
+    Ok(passed)
+}
+
+/// The newest run of each workflow, by name, in the order GitHub lists
+/// them: newest first
+fn latest_per_workflow(runs: Vec<WorkflowRun>) -> Vec<WorkflowRun> {
+    let mut seen = HashSet::new();
+    runs.into_iter()
+        .filter(|run| seen.insert(workflow_name(run).to_string()))
+        .collect()
+}
+
+fn passed(runs: &[WorkflowRun], fail_on_pending: bool) -> bool {
+    runs.iter().all(|run| match Verdict::of(run) {
+        Verdict::Passed => true,
+        Verdict::Failed => false,
+        Verdict::Pending => !fail_on_pending,
+    })
+}
+
+fn workflow_name(run: &WorkflowRun) -> &str {
+    run.name.as_deref().unwrap_or("Workflow")
+}
+
+/// `CI  ✓ success  1m 5s  https://…`, one line per run with the columns
+/// lined up
+fn summary(runs: &[WorkflowRun], colored: bool) -> String {
+    let state = |run: &WorkflowRun| {
+        run.conclusion
+            .as_deref()
+            .or(run.status.as_deref())
+            .unwrap_or("unknown")
+            .to_string()
+    };
+    let name_width = runs
+        .iter()
+        .map(|run| workflow_name(run).chars().count())
+        .max()
+        .unwrap_or(0);
+    let state_width = runs.iter().map(|run| state(run).len()).max().unwrap_or(0);
+
+    let mut out = String::new();
+    for run in runs {
+        let verdict = Verdict::of(run);
+        let marked = format!(
+            "{} {:<width$}",
+            verdict.icon(),
+            state(run),
+            width = state_width
+        );
+        let (marked, url) = if colored {
+            (
+                format!("{}{}{}", verdict.color(), marked, RESET),
+                format!("{}{}{}", DIM, run.html_url, RESET),
+            )
+        } else {
+            (marked, run.html_url.clone())
+        };
+        let line = format!(
+            "{:<width$}  {}  {:>7}  {}",
+            workflow_name(run),
+            marked,
+            run.duration_display(),
+            url,
+            width = name_width
+        );
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+/// One workflow of `--json`
+fn workflow_json(run: &WorkflowRun) -> serde_json::Value {
+    serde_json::json!({
+        "workflow": workflow_name(run),
+        "run_id": run.id,
+        "run_number": run.run_number,
+        "status": run.status,
+        "conclusion": run.conclusion,
+        "duration_secs": run.duration_secs(),
+        "url": run.html_url,
+    })
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(id: u64, name: &str, status: &str, conclusion: Option<&str>) -> WorkflowRun {
+        serde_json::from_value(serde_json::json!({
+            "id": id, "run_number": id, "name": name, "head_sha": "abc",
+            "head_branch": "main", "status": status, "conclusion": conclusion,
+            "event": "push", "html_url": format!("https://github.com/octo/api/actions/runs/{}", id),
+            "run_started_at": "2025-01-12T10:00:00Z",
+            "created_at": "2025-01-12T10:00:00Z", "updated_at": "2025-01-12T10:01:05Z"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_latest_run_of_each_workflow_gates() {
+        let latest = latest_per_workflow(vec![
+            run(4, "CI", "completed", Some("success")),
+            run(3, "Deploy", "in_progress", None),
+            run(2, "CI", "completed", Some("failure")),
+            run(1, "Lint", "completed", Some("skipped")),
+        ]);
+        let ids: Vec<u64> = latest.iter().map(|run| run.id).collect();
+        assert_eq!(ids, [4, 3, 1]);
+        assert!(passed(&latest, false));
+        assert!(!passed(&latest, true));
+
+        let cancelled = [run(5, "CI", "completed", Some("cancelled"))];
+        assert!(!passed(&cancelled, false));
+    }
+
+    #[test]
+    fn test_summary_lines_up() {
+        let runs = [
+            run(4, "CI", "completed", Some("success")),
+            run(1, "Lint & test", "completed", Some("failure")),
+        ];
+        assert_eq!(
+            summary(&runs, false),
+            "CI           ✓ success    1m 5s  https://github.com/octo/api/actions/runs/4\n\
+             Lint & test  ✗ failure    1m 5s  https://github.com/octo/api/actions/runs/1\n"
+        );
+        assert!(summary(&runs, true).contains("\x1b[31m✗ failure\x1b[0m"));
+    }
+}
@@ -0,0 +1,422 @@
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+use ratatui::text::Span;
+use std::io::IsTerminal;
+use std::sync::OnceLock;
+
+// ── Terminal capabilities ──────────────────────────────────────────
+
+/// How many colors the terminal can render
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSupport {
+    /// 24-bit RGB escapes
+    TrueColor,
+    /// xterm 256-color palette
+    Ansi256,
+    /// The 16 basic ANSI colors
+    Basic,
+    /// Dumb terminal: no escapes at all
+    None,
+}
+
+impl ColorSupport {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColorSupport::TrueColor => "truecolor (24-bit)",
+            ColorSupport::Ansi256 => "256 colors",
+            ColorSupport::Basic => "16 colors",
+            ColorSupport::None => "no color",
+        }
+    }
+}
+
+/// Color support of the current terminal, detected once per process
+pub fn color_support() -> ColorSupport {
+    static SUPPORT: OnceLock<ColorSupport> = OnceLock::new();
+    *SUPPORT.get_or_init(detect_color_support)
+}
+
+/// Color support for the splash printed before the TUI starts: none at
+/// all when `NO_COLOR` is set
+pub fn splash_color_support() -> ColorSupport {
+    if no_color(std::env::var("NO_COLOR").ok().as_deref()) {
+        ColorSupport::None
+    } else {
+        color_support()
+    }
+}
+
+/// <https://no-color.org>: any non-empty `NO_COLOR` turns color off
+pub fn no_color(value: Option<&str>) -> bool {
+    value.is_some_and(|value| !value.is_empty())
+}
+
+/// Detect color support from the current environment
+pub fn detect_color_support() -> ColorSupport {
+    color_support_from(
+        std::env::var("COLORTERM").ok().as_deref(),
+        std::env::var("TERM").ok().as_deref(),
+    )
+}
+
+/// Heuristic color detection from `COLORTERM` and `TERM`
+pub fn color_support_from(colorterm: Option<&str>, term: Option<&str>) -> ColorSupport {
+    let colorterm = colorterm.unwrap_or("").to_lowercase();
+    if colorterm == "truecolor" || colorterm == "24bit" {
+        return ColorSupport::TrueColor;
+    }
+
+    let term = term.unwrap_or("").to_lowercase();
+    if term.is_empty() || term == "dumb" {
+        return ColorSupport::None;
+    }
+    if term.contains("truecolor") || term.contains("24bit") || term.contains("direct") {
+        ColorSupport::TrueColor
+    } else if term.contains("256") {
+        ColorSupport::Ansi256
+    } else {
+        ColorSupport::Basic
+    }
+}
+
+/// Whether the TUI can take over the screen (alternate screen + raw mode)
+pub fn supports_alternate_screen() -> bool {
+    alternate_screen_from(
+        std::io::stdout().is_terminal(),
+        std::env::var("TERM").ok().as_deref(),
+    )
+}
+
+pub fn alternate_screen_from(stdout_is_tty: bool, term: Option<&str>) -> bool {
+    stdout_is_tty && !matches!(term, None | Some("") | Some("dumb"))
+}
+
+// ── Notifications ──────────────────────────────────────────────────
+
+/// OSC 9 escape asking the terminal for a desktop notification (iTerm2,
+/// WezTerm, kitty, Ghostty, Windows Terminal); others ignore it
+pub fn osc9_notify(text: &str) -> String {
+    // A control character would end the escape early
+    let text: String = text.chars().filter(|c| !c.is_control()).collect();
+    format!("\x1b]9;{}\x07", text)
+}
+
+// ── Clipboard ──────────────────────────────────────────────────────
+
+/// OSC 52 escape asking the terminal to put `text` on the clipboard
+pub fn osc52_copy(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64(text.as_bytes()))
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+// ── Color degradation ──────────────────────────────────────────────
+
+/// Foreground escape for an RGB color, degraded to `support`.
+/// Basic and dumb terminals get no color at all (callers keep bold/dim).
+pub fn rgb_fg(support: ColorSupport, r: u8, g: u8, b: u8) -> String {
+    match support {
+        ColorSupport::TrueColor => format!("\x1b[38;2;{};{};{}m", r, g, b),
+        ColorSupport::Ansi256 => format!("\x1b[38;5;{}m", rgb_to_ansi256(r, g, b)),
+        ColorSupport::Basic | ColorSupport::None => String::new(),
+    }
+}
+
+/// Pass an SGR escape (bold, dim, reset…) through unless the terminal is dumb
+pub fn sgr(support: ColorSupport, code: &'static str) -> &'static str {
+    if support == ColorSupport::None {
+        ""
+    } else {
+        code
+    }
+}
+
+/// Nearest xterm 256-palette index (6×6×6 cube or grayscale ramp)
+pub fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    fn cube_index(v: u8) -> u8 {
+        match v {
+            0..=47 => 0,
+            48..=114 => 1,
+            _ => (v - 35) / 40,
+        }
+    }
+    fn cube_value(i: u8) -> u8 {
+        if i == 0 {
+            0
+        } else {
+            55 + i * 40
+        }
+    }
+    fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+        let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+        d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+    }
+
+    let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+    let cube = 16 + 36 * ri + 6 * gi + bi;
+    let cube_rgb = (cube_value(ri), cube_value(gi), cube_value(bi));
+
+    let avg = ((r as u16 + g as u16 + b as u16) / 3) as u8;
+    let gray_i = if avg > 238 {
+        23
+    } else {
+        avg.saturating_sub(3) / 10
+    };
+    let gray = 232 + gray_i;
+    let gray_v = 8 + gray_i * 10;
+
+    if distance((r, g, b), (gray_v, gray_v, gray_v)) < distance((r, g, b), cube_rgb) {
+        gray
+    } else {
+        cube
+    }
+}
+
+/// Closest of the 8 basic ANSI colors
+fn rgb_to_basic(r: u8, g: u8, b: u8) -> Color {
+    let max = r.max(g).max(b);
+    if max < 64 {
+        return Color::Black;
+    }
+    let threshold = max / 2;
+    match (r > threshold, g > threshold, b > threshold) {
+        (true, false, false) => Color::Red,
+        (false, true, false) => Color::Green,
+        (true, true, false) => Color::Yellow,
+        (false, false, true) => Color::Blue,
+        (true, false, true) => Color::Magenta,
+        (false, true, true) => Color::Cyan,
+        _ if max > 192 => Color::White,
+        _ => Color::Gray,
+    }
+}
+
+/// Degrade a TUI color to what the terminal can display
+pub fn degrade(color: Color, support: ColorSupport) -> Color {
+    match (color, support) {
+        (_, ColorSupport::TrueColor) => color,
+        (Color::Rgb(r, g, b), ColorSupport::Ansi256) => Color::Indexed(rgb_to_ansi256(r, g, b)),
+        (Color::Rgb(r, g, b), ColorSupport::Basic) => rgb_to_basic(r, g, b),
+        (_, ColorSupport::None) => Color::Reset,
+        _ => color,
+    }
+}
+
+// ── Snapshots ──────────────────────────────────────────────────────
+
+/// A rendered frame as text, one line per row, with SGR escapes for its
+/// colors unless the terminal is dumb. Printed as is, it needs neither the
+/// alternate screen nor cursor movement (`--once`).
+pub fn buffer_to_ansi(buffer: &Buffer, support: ColorSupport) -> String {
+    let area = buffer.area;
+    let mut out = String::new();
+    for y in area.top()..area.bottom() {
+        let mut style = None;
+        // Columns covered by the wide character before them
+        let mut hidden = 0;
+        for x in area.left()..area.right() {
+            if hidden > 0 {
+                hidden -= 1;
+                continue;
+            }
+            let cell = &buffer[(x, y)];
+            hidden = Span::raw(cell.symbol()).width().saturating_sub(1);
+            let next = (cell.fg, cell.bg, cell.modifier);
+            if support != ColorSupport::None && style != Some(next) {
+                out.push_str(&style_escape(cell.fg, cell.bg, cell.modifier));
+                style = Some(next);
+            }
+            out.push_str(cell.symbol());
+        }
+        out.push_str(sgr(support, "\x1b[0m"));
+        out.push('\n');
+    }
+    out
+}
+
+/// One SGR escape setting exactly this style, starting from a reset
+fn style_escape(fg: Color, bg: Color, modifier: Modifier) -> String {
+    let mut codes = vec!["0".to_string()];
+    for (flag, code) in [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::CROSSED_OUT, "9"),
+    ] {
+        if modifier.contains(flag) {
+            codes.push(code.to_string());
+        }
+    }
+    codes.extend(color_code(fg, 30));
+    codes.extend(color_code(bg, 40));
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+/// SGR parameters for `color`; `base` is 30 for foreground, 40 for
+/// background
+fn color_code(color: Color, base: u8) -> Option<String> {
+    let code = match color {
+        Color::Reset => return None,
+        Color::Black => base,
+        Color::Red => base + 1,
+        Color::Green => base + 2,
+        Color::Yellow => base + 3,
+        Color::Blue => base + 4,
+        Color::Magenta => base + 5,
+        Color::Cyan => base + 6,
+        Color::Gray => base + 7,
+        Color::DarkGray => base + 60,
+        Color::LightRed => base + 61,
+        Color::LightGreen => base + 62,
+        Color::LightYellow => base + 63,
+        Color::LightBlue => base + 64,
+        Color::LightMagenta => base + 65,
+        Color::LightCyan => base + 66,
+        Color::White => base + 67,
+        Color::Indexed(i) => return Some(format!("{};5;{}", base + 8, i)),
+        Color::Rgb(r, g, b) => return Some(format!("{};2;{};{};{}", base + 8, r, g, b)),
+    };
+    Some(code.to_string())
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colorterm_truecolor_wins() {
+        assert_eq!(
+            color_support_from(Some("truecolor"), Some("xterm")),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(
+            color_support_from(Some("24bit"), None),
+            ColorSupport::TrueColor
+        );
+    }
+
+    #[test]
+    fn test_term_heuristics() {
+        assert_eq!(
+            color_support_from(None, Some("screen-256color")),
+            ColorSupport::Ansi256
+        );
+        assert_eq!(
+            color_support_from(None, Some("xterm-direct")),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(color_support_from(None, Some("xterm")), ColorSupport::Basic);
+        assert_eq!(color_support_from(None, Some("dumb")), ColorSupport::None);
+        assert_eq!(color_support_from(None, None), ColorSupport::None);
+    }
+
+    #[test]
+    fn test_no_color() {
+        assert!(no_color(Some("1")));
+        assert!(!no_color(Some("")));
+        assert!(!no_color(None));
+    }
+
+    #[test]
+    fn test_alternate_screen() {
+        assert!(alternate_screen_from(true, Some("xterm-256color")));
+        assert!(!alternate_screen_from(false, Some("xterm-256color")));
+        assert!(!alternate_screen_from(true, Some("dumb")));
+        assert!(!alternate_screen_from(true, None));
+    }
+
+    #[test]
+    fn test_osc52_copy() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(osc52_copy("/tmp/a.log"), "\x1b]52;c;L3RtcC9hLmxvZw==\x07");
+    }
+
+    #[test]
+    fn test_osc9_notify() {
+        assert_eq!(
+            osc9_notify("CI #42: success\x07\n"),
+            "\x1b]9;CI #42: success\x07"
+        );
+    }
+
+    #[test]
+    fn test_rgb_to_ansi256() {
+        assert_eq!(rgb_to_ansi256(0, 0, 0), 16);
+        assert_eq!(rgb_to_ansi256(255, 255, 255), 231);
+        assert_eq!(rgb_to_ansi256(255, 0, 0), 196);
+        // Near-gray picks the grayscale ramp
+        assert_eq!(rgb_to_ansi256(128, 128, 128), 244);
+    }
+
+    #[test]
+    fn test_rgb_fg_degrades() {
+        assert_eq!(rgb_fg(ColorSupport::TrueColor, 1, 2, 3), "\x1b[38;2;1;2;3m");
+        assert_eq!(rgb_fg(ColorSupport::Ansi256, 255, 0, 0), "\x1b[38;5;196m");
+        assert_eq!(rgb_fg(ColorSupport::Basic, 255, 0, 0), "");
+        assert_eq!(sgr(ColorSupport::None, "\x1b[1m"), "");
+        assert_eq!(sgr(ColorSupport::Basic, "\x1b[1m"), "\x1b[1m");
+    }
+
+    #[test]
+    fn test_degrade_tui_colors() {
+        let red = Color::Rgb(248, 81, 73);
+        assert_eq!(degrade(red, ColorSupport::TrueColor), red);
+        assert!(matches!(
+            degrade(red, ColorSupport::Ansi256),
+            Color::Indexed(_)
+        ));
+        assert_eq!(degrade(red, ColorSupport::Basic), Color::Red);
+        assert_eq!(degrade(red, ColorSupport::None), Color::Reset);
+        assert_eq!(degrade(Color::Reset, ColorSupport::Basic), Color::Reset);
+    }
+
+    #[test]
+    fn test_buffer_to_ansi() {
+        use ratatui::layout::Rect;
+        use ratatui::style::Style;
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        buffer.set_string(
+            0,
+            0,
+            "ok",
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        );
+        buffer.set_string(0, 1, "漢x", Style::default());
+
+        assert_eq!(
+            buffer_to_ansi(&buffer, ColorSupport::None),
+            "ok    \n漢x   \n"
+        );
+        assert_eq!(
+            buffer_to_ansi(&buffer, ColorSupport::TrueColor),
+            "\x1b[0;1;32mok\x1b[0m    \x1b[0m\n\x1b[0m漢x   \x1b[0m\n"
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use wiremock::MockServer;
+
+use crate::github::GitHubClient;
+use crate::models::{Job, WorkflowRun};
+
+// ── Test fixtures ──────────────────────────────────────────────────
+
+/// Run 7, `CI #42` on main, as GitHub sends it
+pub fn run_json(status: &str, conclusion: Option<&str>) -> serde_json::Value {
+    serde_json::json!({
+        "id": 7, "run_number": 42, "name": "CI", "head_sha": "abc",
+        "head_branch": "main", "status": status, "conclusion": conclusion,
+        "event": "push", "html_url": "https://github.com/octo/api/actions/runs/7",
+        "run_attempt": 1,
+        "created_at": "2025-01-12T10:00:00Z", "updated_at": "2025-01-12T10:00:00Z"
+    })
+}
+
+pub fn run(status: &str, conclusion: Option<&str>) -> WorkflowRun {
+    serde_json::from_value(run_json(status, conclusion)).unwrap()
+}
+
+/// Job `id` of run 7, named `job-<id>`: started unless queued, and 1m 5s
+/// long once completed
+pub fn job_json(id: u64, status: &str, conclusion: Option<&str>) -> serde_json::Value {
+    let started = (status != "queued").then_some("2025-01-12T10:00:00Z");
+    let completed = (status == "completed").then_some("2025-01-12T10:01:05Z");
+    serde_json::json!({
+        "id": id, "run_id": 7, "name": format!("job-{}", id), "status": status,
+        "conclusion": conclusion, "started_at": started, "completed_at": completed,
+        "html_url": null
+    })
+}
+
+pub fn job(id: u64, status: &str, conclusion: Option<&str>) -> Job {
+    serde_json::from_value(job_json(id, status, conclusion)).unwrap()
+}
+
+/// A client for `octo/api` on the mock API
+pub fn client(api: &MockServer) -> GitHubClient {
+    GitHubClient::with_base_url("octo".into(), "api".into(), "secret".into(), api.uri())
+}
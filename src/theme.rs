@@ -0,0 +1,180 @@
+use ratatui::style::Color;
+use std::collections::BTreeMap;
+
+/// Used when the config names no theme, or one that doesn't exist
+pub const DEFAULT_THEME: &str = "dark";
+
+// ── Themes ─────────────────────────────────────────────────────────
+
+/// Every color the TUI draws with
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    pub green: Color,
+    pub red: Color,
+    pub yellow: Color,
+    pub blue: Color,
+    pub purple: Color,
+    pub gray: Color,
+    /// Borders, separators, placeholder rows
+    pub dim: Color,
+    pub bg: Color,
+    pub fg: Color,
+    pub header_bg: Color,
+    pub selected_bg: Color,
+    pub orange: Color,
+    /// Log lines with a `/` hit
+    pub match_bg: Color,
+    /// The log line `n` / `N` landed on
+    pub current_match_bg: Color,
+    /// The error line `e` centered
+    pub error_target_bg: Color,
+    /// The band sweeping over placeholder rows
+    pub skeleton_shine: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        DARK
+    }
+}
+
+/// GitHub's dark palette; what Atlas has always looked like
+pub const DARK: Theme = Theme {
+    green: Color::Rgb(72, 199, 142),
+    red: Color::Rgb(248, 81, 73),
+    yellow: Color::Rgb(210, 153, 34),
+    blue: Color::Rgb(88, 166, 255),
+    purple: Color::Rgb(188, 140, 255),
+    gray: Color::Rgb(125, 133, 144),
+    dim: Color::Rgb(48, 54, 61),
+    bg: Color::Rgb(13, 17, 23),
+    fg: Color::Rgb(230, 237, 243),
+    header_bg: Color::Rgb(22, 27, 34),
+    selected_bg: Color::Rgb(33, 38, 45),
+    orange: Color::Rgb(210, 105, 30),
+    match_bg: Color::Rgb(58, 46, 12),
+    current_match_bg: Color::Rgb(110, 80, 10),
+    error_target_bg: Color::Rgb(75, 22, 24),
+    skeleton_shine: Color::Rgb(72, 80, 90),
+};
+
+/// GitHub's light palette
+pub const LIGHT: Theme = Theme {
+    green: Color::Rgb(26, 127, 55),
+    red: Color::Rgb(207, 34, 46),
+    yellow: Color::Rgb(154, 103, 0),
+    blue: Color::Rgb(9, 105, 218),
+    purple: Color::Rgb(130, 80, 223),
+    gray: Color::Rgb(101, 109, 118),
+    dim: Color::Rgb(208, 215, 222),
+    bg: Color::Rgb(255, 255, 255),
+    fg: Color::Rgb(31, 35, 40),
+    header_bg: Color::Rgb(246, 248, 250),
+    selected_bg: Color::Rgb(234, 238, 242),
+    orange: Color::Rgb(188, 76, 0),
+    match_bg: Color::Rgb(255, 248, 197),
+    current_match_bg: Color::Rgb(255, 223, 93),
+    error_target_bg: Color::Rgb(255, 235, 233),
+    skeleton_shine: Color::Rgb(175, 184, 193),
+};
+
+/// Gruvbox dark
+pub const GRUVBOX: Theme = Theme {
+    green: Color::Rgb(184, 187, 38),
+    red: Color::Rgb(251, 73, 52),
+    yellow: Color::Rgb(250, 189, 47),
+    blue: Color::Rgb(131, 165, 152),
+    purple: Color::Rgb(211, 134, 155),
+    gray: Color::Rgb(146, 131, 116),
+    dim: Color::Rgb(80, 73, 69),
+    bg: Color::Rgb(40, 40, 40),
+    fg: Color::Rgb(235, 219, 178),
+    header_bg: Color::Rgb(50, 48, 47),
+    selected_bg: Color::Rgb(60, 56, 54),
+    orange: Color::Rgb(254, 128, 25),
+    match_bg: Color::Rgb(80, 64, 24),
+    current_match_bg: Color::Rgb(121, 92, 20),
+    error_target_bg: Color::Rgb(94, 36, 30),
+    skeleton_shine: Color::Rgb(102, 92, 84),
+};
+
+/// Nord
+pub const NORD: Theme = Theme {
+    green: Color::Rgb(163, 190, 140),
+    red: Color::Rgb(191, 97, 106),
+    yellow: Color::Rgb(235, 203, 139),
+    blue: Color::Rgb(136, 192, 208),
+    purple: Color::Rgb(180, 142, 173),
+    gray: Color::Rgb(143, 153, 168),
+    dim: Color::Rgb(76, 86, 106),
+    bg: Color::Rgb(46, 52, 64),
+    fg: Color::Rgb(236, 239, 244),
+    header_bg: Color::Rgb(59, 66, 82),
+    selected_bg: Color::Rgb(67, 76, 94),
+    orange: Color::Rgb(208, 135, 112),
+    match_bg: Color::Rgb(88, 82, 64),
+    current_match_bg: Color::Rgb(128, 110, 66),
+    error_target_bg: Color::Rgb(94, 60, 68),
+    skeleton_shine: Color::Rgb(97, 110, 136),
+};
+
+// ── Registry ───────────────────────────────────────────────────────
+
+/// Themes by the name `theme` / `--theme` uses
+pub struct ThemeRegistry {
+    themes: BTreeMap<&'static str, Theme>,
+}
+
+impl ThemeRegistry {
+    pub fn builtin() -> Self {
+        Self {
+            themes: BTreeMap::from([
+                ("dark", DARK),
+                ("light", LIGHT),
+                ("gruvbox", GRUVBOX),
+                ("nord", NORD),
+            ]),
+        }
+    }
+
+    /// `"default"` is what config files written before themes say
+    pub fn get(&self, name: &str) -> Option<&Theme> {
+        let name = if name == "default" {
+            DEFAULT_THEME
+        } else {
+            name
+        };
+        self.themes.get(name)
+    }
+
+    pub fn names(&self) -> Vec<&'static str> {
+        self.themes.keys().copied().collect()
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_themes() {
+        let registry = ThemeRegistry::builtin();
+        assert_eq!(registry.names(), ["dark", "gruvbox", "light", "nord"]);
+        assert_eq!(registry.get("nord"), Some(&NORD));
+        assert_eq!(registry.get("default"), Some(&DARK));
+        assert_eq!(registry.get(DEFAULT_THEME), Some(&Theme::default()));
+        assert_eq!(registry.get("solarized"), None);
+    }
+
+    #[test]
+    fn test_text_stands_out_from_the_background() {
+        for name in ThemeRegistry::builtin().names() {
+            let theme = ThemeRegistry::builtin().get(name).copied().unwrap();
+            assert_ne!(theme.fg, theme.bg, "{}", name);
+            assert_ne!(theme.selected_bg, theme.bg, "{}", name);
+            assert_ne!(theme.match_bg, theme.current_match_bg, "{}", name);
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use chrono::{DateTime, Utc};
+
+use crate::models::{Job, WorkflowRun};
+
+/// Tick spacings the axis picks from, in seconds
+const TICK_STEPS: [u64; 13] = [1, 5, 10, 15, 30, 60, 120, 300, 600, 900, 1800, 3600, 7200];
+/// Columns each axis label needs, its gap included
+const TICK_WIDTH: u64 = 8;
+
+// ── Run timeline ───────────────────────────────────────────────────
+
+/// One bar of the timeline: when something started, counted from the
+/// run's start, and how long it ran
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bar {
+    pub name: String,
+    /// Seconds after the run started; `None` until it starts
+    pub start: Option<u64>,
+    pub secs: u64,
+    pub conclusion: Option<String>,
+}
+
+impl Bar {
+    fn new(
+        name: &str,
+        started: Option<DateTime<Utc>>,
+        completed: Option<DateTime<Utc>>,
+        conclusion: Option<&str>,
+        origin: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let secs = |from: DateTime<Utc>, to: DateTime<Utc>| {
+            u64::try_from((to - from).num_seconds()).unwrap_or(0)
+        };
+        Self {
+            name: name.to_string(),
+            start: started.map(|started| secs(origin, started)),
+            secs: started.map_or(0, |started| secs(started, completed.unwrap_or(now))),
+            conclusion: conclusion.map(str::to_string),
+        }
+    }
+
+    fn end(&self) -> u64 {
+        self.start.map_or(0, |start| start + self.secs)
+    }
+}
+
+/// A run's jobs laid out on one time axis, with the selected job's steps
+/// on the same scale
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timeline {
+    /// From the run's start to the last bar's end; at least a second
+    pub total_secs: u64,
+    pub jobs: Vec<Bar>,
+    /// Steps of the selected job, empty until they're loaded
+    pub steps: Vec<Bar>,
+}
+
+impl Timeline {
+    pub fn compute(
+        run: Option<&WorkflowRun>,
+        jobs: &[Job],
+        selected: usize,
+        now: DateTime<Utc>,
+    ) -> Self {
+        // A job can report a start a little before the run's own; without
+        // a run start, the first job's will do
+        let origin = jobs
+            .iter()
+            .filter_map(|job| job.started_at)
+            .chain(run.and_then(|run| run.run_started_at))
+            .min()
+            .unwrap_or(now);
+
+        let jobs_bars: Vec<Bar> = jobs
+            .iter()
+            .map(|job| {
+                Bar::new(
+                    &job.name,
+                    job.started_at,
+                    job.completed_at,
+                    job.conclusion.as_deref(),
+                    origin,
+                    now,
+                )
+            })
+            .collect();
+        let steps: Vec<Bar> = jobs
+            .get(selected)
+            .and_then(|job| job.steps.as_deref())
+            .unwrap_or_default()
+            .iter()
+            .map(|step| {
+                Bar::new(
+                    &step.name,
+                    step.started_at,
+                    step.completed_at,
+                    step.conclusion.as_deref(),
+                    origin,
+                    now,
+                )
+            })
+            .collect();
+
+        let total_secs = jobs_bars
+            .iter()
+            .chain(&steps)
+            .map(Bar::end)
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        Self {
+            total_secs,
+            jobs: jobs_bars,
+            steps,
+        }
+    }
+
+    /// Time the jobs ran, added up: well over `total_secs` when they ran
+    /// side by side
+    pub fn job_secs(&self) -> u64 {
+        self.jobs.iter().map(|bar| bar.secs).sum()
+    }
+
+    /// Columns `bar` covers on a track `width` columns wide: at least one
+    /// once it has started
+    pub fn span(&self, bar: &Bar, width: u16) -> Option<(u16, u16)> {
+        let start = bar.start?;
+        let column = |secs: u64| (secs * u64::from(width) / self.total_secs) as u16;
+        let from = column(start).min(width.saturating_sub(1));
+        let to = column(start + bar.secs).clamp(from + 1, width.max(1));
+        Some((from, to))
+    }
+
+    /// Axis ticks for a track `width` columns wide: the column and label
+    /// of each, in seconds for short runs and minutes past that
+    pub fn ticks(&self, width: u16) -> Vec<(u16, String)> {
+        let fits = (u64::from(width) / TICK_WIDTH).max(1);
+        let step = TICK_STEPS
+            .iter()
+            .copied()
+            .find(|step| self.total_secs / step < fits)
+            .unwrap_or(TICK_STEPS[TICK_STEPS.len() - 1]);
+        (0..=self.total_secs / step)
+            .map(|i| {
+                let secs = i * step;
+                let label = if step < 60 {
+                    format!("{}s", secs)
+                } else {
+                    format!("{}m", secs / 60)
+                };
+                let column = (secs * u64::from(width) / self.total_secs) as u16;
+                (column, label)
+            })
+            .collect()
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Step;
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        "2025-01-12T10:00:00Z".parse::<DateTime<Utc>>().unwrap() + chrono::Duration::seconds(secs)
+    }
+
+    fn job(name: &str, start: Option<i64>, end: Option<i64>, conclusion: Option<&str>) -> Job {
+        serde_json::from_value(serde_json::json!({
+            "id": 1, "run_id": 1, "name": name, "status": "completed",
+            "conclusion": conclusion, "started_at": start.map(at),
+            "completed_at": end.map(at), "html_url": null
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_compute() {
+        let mut build = job("build", Some(2), Some(62), Some("success"));
+        build.steps = Some(vec![Step {
+            name: "Run tests".into(),
+            status: "completed".into(),
+            conclusion: Some("success".into()),
+            number: 1,
+            started_at: Some(at(10)),
+            completed_at: Some(at(40)),
+        }]);
+        let jobs = [
+            build,
+            job("lint", Some(0), Some(30), Some("failure")),
+            // Still running at `now`
+            job("deploy", Some(62), None, None),
+            job("queued", None, None, None),
+        ];
+        let timeline = Timeline::compute(None, &jobs, 0, at(120));
+        assert_eq!(timeline.total_secs, 120);
+        assert_eq!(
+            timeline
+                .jobs
+                .iter()
+                .map(|bar| (bar.start, bar.secs))
+                .collect::<Vec<_>>(),
+            [(Some(2), 60), (Some(0), 30), (Some(62), 58), (None, 0)]
+        );
+        assert_eq!(timeline.steps[0].start, Some(10));
+        assert_eq!(timeline.steps[0].secs, 30);
+        assert_eq!(timeline.job_secs(), 148);
+
+        // 120s over 60 columns: two seconds a column
+        assert_eq!(timeline.span(&timeline.jobs[0], 60), Some((1, 31)));
+        assert_eq!(timeline.span(&timeline.jobs[3], 60), None);
+    }
+
+    #[test]
+    fn test_short_bars_still_show() {
+        let jobs = [
+            job("build", Some(0), Some(600), Some("success")),
+            job("notify", Some(600), Some(600), Some("success")),
+        ];
+        let timeline = Timeline::compute(None, &jobs, 0, at(600));
+        // Starting at the very end, it takes the last column
+        assert_eq!(timeline.span(&timeline.jobs[1], 40), Some((39, 40)));
+    }
+
+    #[test]
+    fn test_ticks() {
+        let jobs = [job("build", Some(0), Some(45), Some("success"))];
+        let timeline = Timeline::compute(None, &jobs, 0, at(45));
+        assert_eq!(
+            timeline.ticks(80),
+            [
+                (0, "0s".to_string()),
+                (8, "5s".to_string()),
+                (17, "10s".to_string()),
+                (26, "15s".to_string()),
+                (35, "20s".to_string()),
+                (44, "25s".to_string()),
+                (53, "30s".to_string()),
+                (62, "35s".to_string()),
+                (71, "40s".to_string()),
+                (80, "45s".to_string()),
+            ]
+        );
+
+        let jobs = [job("build", Some(0), Some(1500), Some("success"))];
+        let timeline = Timeline::compute(None, &jobs, 0, at(1500));
+        let labels: Vec<String> = timeline.ticks(40).into_iter().map(|(_, l)| l).collect();
+        assert_eq!(labels, ["0m", "10m", "20m"]);
+    }
+}
@@ -1,39 +1,49 @@
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{
-        Block, BorderType, Borders, Cell, Padding, Paragraph, Row, Scrollbar, ScrollbarOrientation,
-        ScrollbarState, Table, TableState, Wrap,
+        Block, BorderType, Borders, Cell, Clear, Gauge, Padding, Paragraph, Row, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, Table, TableState, Wrap,
     },
     Frame,
 };
 
-use crate::app::{App, View};
-use crate::models::Job;
-
-// ── Color palette ──────────────────────────────────────────────────
-
-const GREEN: Color = Color::Rgb(72, 199, 142);
-const RED: Color = Color::Rgb(248, 81, 73);
-const YELLOW: Color = Color::Rgb(210, 153, 34);
-const BLUE: Color = Color::Rgb(88, 166, 255);
-const PURPLE: Color = Color::Rgb(188, 140, 255);
-const GRAY: Color = Color::Rgb(125, 133, 144);
-const DIM: Color = Color::Rgb(48, 54, 61);
-const BG: Color = Color::Rgb(13, 17, 23);
-const FG: Color = Color::Rgb(230, 237, 243);
-const HEADER_BG: Color = Color::Rgb(22, 27, 34);
-const SELECTED_BG: Color = Color::Rgb(33, 38, 45);
-const ORANGE: Color = Color::Rgb(210, 105, 30);
+use crate::ansi::{self, AnsiSegments};
+use crate::app::{
+    keep_in_view, search_hits, App, DetailFocus, PaneFocus, PendingAction, RenderedAreas,
+    RunCounts, StepSlice, Toast, View, ViewArea,
+};
+use crate::cache::CacheReport;
+use crate::dispatch::DispatchForm;
+use crate::durations::Stuck;
+use crate::event::{help_len, HELP};
+use crate::exports::ExportsOverlay;
+use crate::groups::LogLine;
+use crate::highlight::LogHighlight;
+use crate::lint::WorkflowProblem;
+use crate::models::{
+    format_age, format_bytes, format_count, format_duration, log_timestamp_len, Annotation, Commit,
+    Deployment, Job, JobCounts, Repository, WorkflowRun,
+};
+use crate::scopes::TokenScope;
+use crate::stats::{self, RepoStats};
+use crate::term;
+use crate::theme::Theme;
+use crate::timeline::{Bar, Timeline};
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::time::Instant;
 
 // ── Main draw entry point ──────────────────────────────────────────
 
-pub fn draw(f: &mut Frame, app: &App) {
+pub fn draw(f: &mut Frame, app: &App, theme: &Theme) {
     let size = f.area();
+    app.last_rendered_areas.borrow_mut().clear();
 
     // Fill background
-    let bg_block = Block::default().style(Style::default().bg(BG));
+    let bg_block = Block::default().style(Style::default().bg(theme.bg));
     f.render_widget(bg_block, size);
 
     let chunks = Layout::default()
@@ -46,86 +56,392 @@ pub fn draw(f: &mut Frame, app: &App) {
         ])
         .split(size);
 
-    draw_header(f, app, chunks[0]);
+    draw_header(f, theme, app, chunks[0]);
 
     match app.view {
-        View::RepoList => draw_repo_list(f, app, chunks[1]),
-        View::RunsList => draw_runs_list(f, app, chunks[1]),
-        View::RunDetail => draw_run_detail(f, app, chunks[1]),
-        View::Logs => draw_log_view(f, app, chunks[1]),
+        View::RepoList => draw_repo_list(f, theme, &RepoListCtx::from_app(app), chunks[1]),
+        View::WorkflowList => draw_workflow_list(f, theme, app, chunks[1]),
+        View::RunsList if app.split_view() => {
+            draw_split_runs(f, theme, &RunsListCtx::from_app(app), chunks[1])
+        }
+        View::RunsList => draw_runs_list(f, theme, &RunsListCtx::from_app(app), false, chunks[1]),
+        View::RunDetail => draw_run_detail(f, theme, &RunDetailCtx::from_app(app), chunks[1]),
+        View::Logs => draw_log_view(f, theme, &LogsCtx::from_app(app), chunks[1]),
+        View::Artifacts => draw_artifacts(f, theme, app, chunks[1]),
+        View::Environments => draw_environments(f, theme, app, chunks[1]),
+        View::Runners => draw_runners(f, theme, app, chunks[1]),
+        View::Caches => draw_caches(f, theme, app, chunks[1]),
+        View::Statistics => draw_statistics(f, theme, app, chunks[1]),
+        View::RunTimeline => draw_run_timeline(f, theme, app, chunks[1]),
+    }
+
+    draw_status_bar(f, theme, app, chunks[2]);
+    draw_keybindings(f, theme, app, chunks[3]);
+
+    if let Some(toast) = &app.toast {
+        draw_toast(f, theme, toast, chunks[1]);
+    }
+    if let Some(form) = &app.dispatch_form {
+        draw_dispatch_form(f, theme, form, size);
+    }
+    if let Some(annotation) = &app.export_prompt {
+        draw_export_prompt(f, theme, annotation, size);
+    }
+    if let Some(overlay) = &app.exports_overlay {
+        draw_exports_overlay(f, theme, overlay, size);
+    }
+    if let Some(pending) = &app.confirmation_pending {
+        match &app.confirmation_input {
+            Some(typed) => draw_typed_confirmation(f, theme, pending, typed, size),
+            None => draw_confirmation(f, theme, pending, size),
+        }
+    }
+    if app.confirm_quit {
+        draw_quit_confirm(f, theme, app, size);
+    }
+    if app.workflow_picker {
+        draw_workflow_picker(f, theme, app, size);
+    }
+    if app.show_help {
+        draw_help(f, theme, app.help_scroll, size);
+    }
+
+    degrade_colors(f);
+}
+
+/// Map the RGB palette down to what the terminal can actually show
+fn degrade_colors(f: &mut Frame) {
+    let support = term::color_support();
+    if support == term::ColorSupport::TrueColor {
+        return;
+    }
+    for cell in f.buffer_mut().content.iter_mut() {
+        cell.fg = term::degrade(cell.fg, support);
+        cell.bg = term::degrade(cell.bg, support);
+    }
+}
+
+// ── Render contexts ────────────────────────────────────────────────
+//
+// What each of the main views draws from, borrowed from `App` by `draw`
+// so the views can be rendered without one
+
+/// The repo list, already filtered by the `/` query
+struct RepoListCtx<'a> {
+    repos: Vec<&'a Repository>,
+    /// Before filtering
+    total: usize,
+    filter: &'a str,
+    bookmarks: &'a HashSet<String>,
+    selected: usize,
+    offset: &'a std::cell::Cell<usize>,
+    /// Page shown and pages in all, when there's more than one
+    pages: Option<(u64, u64)>,
+    loading: bool,
+    spinner: &'static str,
+    /// Since when the first fetch has been running
+    connecting: Option<Instant>,
+    areas: &'a RenderedAreas,
+}
+
+impl<'a> RepoListCtx<'a> {
+    fn from_app(app: &'a App) -> Self {
+        Self {
+            repos: app.filtered_repos(),
+            total: app.repos.len(),
+            filter: &app.repo_filter,
+            bookmarks: &app.bookmarks,
+            selected: app.repos_selected,
+            offset: &app.repos_offset,
+            pages: app.repos_pages().map(|pages| (app.repos_page, pages)),
+            loading: app.loading,
+            spinner: app.spinner(),
+            connecting: app.connecting.as_ref().map(|c| c.since),
+            areas: &app.last_rendered_areas,
+        }
+    }
+}
+
+/// A run with what the list flags it with
+struct RunRow<'a> {
+    run: &'a WorkflowRun,
+    stuck: Option<Stuck>,
+    logs_expired: bool,
+    /// Selected with Space for `X`
+    marked: bool,
+}
+
+/// The runs list, and the jobs pane beside it in the split view
+struct RunsListCtx<'a> {
+    runs: Vec<RunRow<'a>>,
+    selected: usize,
+    offset: &'a std::cell::Cell<usize>,
+    total: u64,
+    workflow: Option<&'a str>,
+    status: Option<&'a str>,
+    event: Option<&'a str>,
+    loading: bool,
+    spinner: &'static str,
+    connecting: Option<Instant>,
+    focus: PaneFocus,
+    /// The selected run's jobs, once fetched
+    preview: Option<&'a [Job]>,
+    preview_selected: usize,
+    caches: &'a HashMap<u64, CacheReport>,
+    areas: &'a RenderedAreas,
+}
+
+impl<'a> RunsListCtx<'a> {
+    fn from_app(app: &'a App) -> Self {
+        Self {
+            runs: app
+                .runs
+                .iter()
+                .map(|run| RunRow {
+                    run,
+                    stuck: app.stuck_run(run),
+                    logs_expired: app.logs_expired(run),
+                    marked: app.selected_run_ids.contains(&run.id),
+                })
+                .collect(),
+            selected: app.runs_selected,
+            offset: &app.runs_offset,
+            total: app.runs_total,
+            workflow: app.workflow_filter.as_ref().map(|w| w.name.as_str()),
+            status: app.status_filter,
+            event: app.event_filter.as_deref(),
+            loading: app.loading,
+            spinner: app.spinner(),
+            connecting: app.connecting.as_ref().map(|c| c.since),
+            focus: app.pane_focus,
+            preview: app.preview_jobs(),
+            preview_selected: app.preview_selected,
+            caches: &app.cache_reports,
+            areas: &app.last_rendered_areas,
+        }
+    }
+}
+
+/// The run summary, its jobs and the selected job's steps
+struct RunDetailCtx<'a> {
+    run: Option<&'a WorkflowRun>,
+    stuck: Option<Stuck>,
+    /// Counts of `run`, unknown until they arrive
+    counts: RunCounts,
+    /// `run`'s head commit, once fetched
+    commit: Option<&'a Commit>,
+    commit_loading: bool,
+    jobs: &'a [Job],
+    selected: usize,
+    steps_loading: Option<u64>,
+    caches: &'a HashMap<u64, CacheReport>,
+    /// Cache use across the jobs whose logs were loaded
+    cache_summary: Option<String>,
+    problem: Option<&'a WorkflowProblem>,
+    loading: bool,
+    /// Check-run annotations of a failed run, most severe first
+    annotations: &'a [Annotation],
+    annotations_selected: usize,
+    focus: DetailFocus,
+    steps_selected: usize,
+    steps_offset: &'a std::cell::Cell<usize>,
+    areas: &'a RenderedAreas,
+}
+
+impl<'a> RunDetailCtx<'a> {
+    fn from_app(app: &'a App) -> Self {
+        let run = app.current_run.as_ref();
+        Self {
+            run,
+            stuck: run.and_then(|run| app.stuck_run(run)),
+            counts: app
+                .run_counts
+                .as_ref()
+                .filter(|(id, _)| run.is_some_and(|run| run.id == *id))
+                .map(|(_, counts)| counts.clone())
+                .unwrap_or_default(),
+            commit: app.run_commit(),
+            commit_loading: app.commit_loading,
+            jobs: &app.jobs,
+            selected: app.jobs_selected,
+            steps_loading: app.steps_loading,
+            caches: &app.cache_reports,
+            cache_summary: app.run_cache_summary(),
+            problem: app.workflow_problem.as_ref(),
+            loading: app.loading,
+            annotations: app.annotations(),
+            annotations_selected: app.annotations_selected,
+            focus: app.detail_focus,
+            steps_selected: app.steps_selected,
+            steps_offset: &app.steps_offset,
+            areas: &app.last_rendered_areas,
+        }
     }
+}
+
+/// The selected job's log with its search, folds and highlights
+struct LogsCtx<'a> {
+    job: Option<&'a Job>,
+    /// The step the log was opened at
+    step: Option<&'a StepSlice>,
+    /// Retention days, when the log is past them
+    expired: Option<u32>,
+    /// When the run finished, for the expired notice
+    finished_at: Option<DateTime<Utc>>,
+    content: &'a [String],
+    highlights: &'a [LogHighlight],
+    groups: &'a [LogLine],
+    /// `content` lines outside folded groups
+    visible: Vec<usize>,
+    scroll: usize,
+    search: &'a str,
+    searching: bool,
+    matches: &'a [usize],
+    match_cursor: usize,
+    error_target: Option<usize>,
+    timestamps: bool,
+    streaming: bool,
+    follow: bool,
+}
 
-    draw_status_bar(f, app, chunks[2]);
-    draw_keybindings(f, app, chunks[3]);
+impl<'a> LogsCtx<'a> {
+    fn from_app(app: &'a App) -> Self {
+        Self {
+            job: app.jobs.get(app.jobs_selected),
+            step: app.log_step.as_ref(),
+            expired: app.log_expired,
+            finished_at: app.current_run.as_ref().map(|r| r.updated_at),
+            content: &app.log_content,
+            highlights: &app.log_highlights,
+            groups: &app.log_groups,
+            visible: app.visible_log_lines(),
+            scroll: app.log_scroll,
+            search: &app.log_search,
+            searching: app.searching,
+            matches: &app.log_matches,
+            match_cursor: app.log_match_cursor,
+            error_target: app.log_error_target,
+            timestamps: app.log_timestamps,
+            streaming: app.log_streaming(),
+            follow: app.log_follow,
+        }
+    }
 }
 
 // ── Header ─────────────────────────────────────────────────────────
 
-fn draw_header(f: &mut Frame, app: &App, area: Rect) {
-    let title_text = match app.view {
+fn draw_header(f: &mut Frame, theme: &Theme, app: &App, area: Rect) {
+    let mut title_text = match app.view {
         View::RepoList => {
             let mut spans = vec![
                 Span::styled("  ", Style::default()),
                 Span::styled(
                     "Atlas",
-                    Style::default().fg(BLUE).add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme.blue).add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(" │ ", Style::default().fg(DIM)),
+                Span::styled(" │ ", Style::default().fg(theme.dim)),
                 Span::styled(
                     "GitHub",
-                    Style::default().fg(FG).add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme.fg).add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(" │ ", Style::default().fg(DIM)),
-                Span::styled("Repositories", Style::default().fg(PURPLE)),
+                Span::styled(" │ ", Style::default().fg(theme.dim)),
+                Span::styled("Repositories", Style::default().fg(theme.purple)),
             ];
             if app.searching {
-                spans.push(Span::styled(" │ ", Style::default().fg(DIM)));
+                spans.push(Span::styled(" │ ", Style::default().fg(theme.dim)));
                 spans.push(Span::styled("🔍 ", Style::default()));
                 spans.push(Span::styled(
                     &app.repo_filter,
-                    Style::default().fg(YELLOW).add_modifier(Modifier::BOLD),
+                    Style::default()
+                        .fg(theme.yellow)
+                        .add_modifier(Modifier::BOLD),
                 ));
-                spans.push(Span::styled("▏", Style::default().fg(YELLOW)));
+                spans.push(Span::styled("▏", Style::default().fg(theme.yellow)));
             }
             spans
         }
         _ => {
-            vec![
+            let mut spans = vec![
                 Span::styled("  ", Style::default()),
                 Span::styled(
                     "Atlas",
-                    Style::default().fg(BLUE).add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme.blue).add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(" │ ", Style::default().fg(DIM)),
+                Span::styled(" │ ", Style::default().fg(theme.dim)),
                 Span::styled(
                     "GitHub",
-                    Style::default().fg(FG).add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme.fg).add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(" │ ", Style::default().fg(DIM)),
+                Span::styled(" │ ", Style::default().fg(theme.dim)),
                 Span::styled(
                     format!("{}/{}", app.client.owner, app.client.repo),
-                    Style::default().fg(FG).add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(" │ ", Style::default().fg(DIM)),
-                Span::styled(
-                    match app.view {
-                        View::RunsList => "Workflow Runs",
-                        View::RunDetail => "Run Details",
-                        View::Logs => "Job Logs",
-                        View::RepoList => unreachable!(),
-                    },
-                    Style::default().fg(PURPLE),
+                    Style::default().fg(theme.fg).add_modifier(Modifier::BOLD),
                 ),
-            ]
+                Span::styled(" │ ", Style::default().fg(theme.dim)),
+            ];
+            // Breadcrumb: the workflow the runs are filtered by
+            if let Some(workflow) = app
+                .workflow_filter
+                .as_ref()
+                .filter(|_| app.view != View::WorkflowList)
+            {
+                spans.push(Span::styled(
+                    workflow.name.clone(),
+                    Style::default().fg(theme.blue),
+                ));
+                spans.push(Span::styled(" │ ", Style::default().fg(theme.dim)));
+            }
+            spans.push(Span::styled(
+                match app.view {
+                    View::WorkflowList => "Workflows",
+                    View::RunsList => "Workflow Runs",
+                    View::RunDetail => "Run Details",
+                    View::Logs => "Job Logs",
+                    View::Artifacts => "Artifacts",
+                    View::Environments => "Environments",
+                    View::Runners => "Runners",
+                    View::Caches => "Caches",
+                    View::Statistics => "Statistics",
+                    View::RunTimeline => "Timeline",
+                    View::RepoList => unreachable!(),
+                },
+                Style::default().fg(theme.purple),
+            ));
+            if let Some(input) = &app.branch_input {
+                spans.push(Span::styled(" │ ", Style::default().fg(theme.dim)));
+                spans.push(Span::styled("⎇ ", Style::default().fg(theme.yellow)));
+                spans.push(Span::styled(
+                    input,
+                    Style::default()
+                        .fg(theme.yellow)
+                        .add_modifier(Modifier::BOLD),
+                ));
+                spans.push(Span::styled("▏", Style::default().fg(theme.yellow)));
+            } else if let Some(branch) = &app.branch_filter {
+                spans.push(Span::styled(" │ ", Style::default().fg(theme.dim)));
+                spans.push(Span::styled(
+                    format!("⎇ {}", branch),
+                    Style::default().fg(theme.yellow),
+                ));
+            }
+            spans
         }
     };
+    // Re-runs and cancels will fail; the status bar said why when it showed
+    if app.token_scope.as_ref().is_some_and(TokenScope::is_limited) {
+        title_text.push(Span::styled(" │ ", Style::default().fg(theme.dim)));
+        title_text.push(Span::styled(
+            "⚠ limited scope",
+            Style::default().fg(theme.yellow),
+        ));
+    }
 
     let header = Paragraph::new(Line::from(title_text)).block(
         Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(DIM))
-            .style(Style::default().bg(HEADER_BG)),
+            .border_style(Style::default().fg(theme.dim))
+            .style(Style::default().bg(theme.header_bg)),
     );
 
     f.render_widget(header, area);
@@ -133,26 +449,37 @@ fn draw_header(f: &mut Frame, app: &App, area: Rect) {
 
 // ── Repo List View ─────────────────────────────────────────────────
 
-fn draw_repo_list(f: &mut Frame, app: &App, area: Rect) {
-    let filtered = app.filtered_repos();
+fn draw_repo_list(f: &mut Frame, theme: &Theme, ctx: &RepoListCtx, area: Rect) {
+    let filtered = &ctx.repos;
+
+    if let (true, Some(connecting)) = (filtered.is_empty(), ctx.connecting) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.dim))
+            .title(" Repositories ")
+            .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD));
+        draw_skeleton(f, theme, connecting, block, area);
+        return;
+    }
 
     if filtered.is_empty() {
-        let msg = if app.loading {
-            "  Loading repositories..."
-        } else if !app.repo_filter.is_empty() {
-            "  No repositories match your search."
+        let msg = if ctx.loading {
+            format!("  {} Loading repositories...", ctx.spinner)
+        } else if !ctx.filter.is_empty() {
+            "  No repositories match your search.".to_string()
         } else {
-            "  No repositories found."
+            "  No repositories found.".to_string()
         };
         let p = Paragraph::new(msg)
-            .style(Style::default().fg(GRAY).bg(BG))
+            .style(Style::default().fg(theme.gray).bg(theme.bg))
             .block(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(DIM))
+                    .border_style(Style::default().fg(theme.dim))
                     .title(" Repositories ")
-                    .title_style(Style::default().fg(FG).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD)),
             );
         f.render_widget(p, area);
         return;
@@ -172,9 +499,9 @@ fn draw_repo_list(f: &mut Frame, app: &App, area: Rect) {
     .map(|h| {
         Cell::from(*h).style(
             Style::default()
-                .fg(GRAY)
+                .fg(theme.gray)
                 .add_modifier(Modifier::BOLD)
-                .bg(HEADER_BG),
+                .bg(theme.header_bg),
         )
     });
     let header = Row::new(header_cells).height(1);
@@ -183,20 +510,28 @@ fn draw_repo_list(f: &mut Frame, app: &App, area: Rect) {
         .iter()
         .enumerate()
         .map(|(i, repo)| {
-            let is_selected = i == app.repos_selected;
-            let row_bg = if is_selected { SELECTED_BG } else { BG };
+            let is_selected = i == ctx.selected;
+            let row_bg = if is_selected {
+                theme.selected_bg
+            } else {
+                theme.bg
+            };
 
-            let visibility_color = if repo.private { YELLOW } else { GREEN };
+            let visibility_color = if repo.private {
+                theme.yellow
+            } else {
+                theme.green
+            };
             let visibility = if repo.private { "🔒" } else { "🌍" };
 
             let lang_color = match repo.language.as_deref() {
-                Some("Rust") => ORANGE,
-                Some("TypeScript" | "JavaScript") => YELLOW,
-                Some("Python") => BLUE,
+                Some("Rust") => theme.orange,
+                Some("TypeScript" | "JavaScript") => theme.yellow,
+                Some("Python") => theme.blue,
                 Some("Go") => Color::Rgb(0, 173, 216),
-                Some("Java" | "Kotlin") => RED,
-                Some("C" | "C++") => PURPLE,
-                _ => GRAY,
+                Some("Java" | "Kotlin") => theme.red,
+                Some("C" | "C++") => theme.purple,
+                _ => theme.gray,
             };
 
             let selector = if is_selected { "▸" } else { " " };
@@ -215,19 +550,31 @@ fn draw_repo_list(f: &mut Frame, app: &App, area: Rect) {
             };
 
             let cells = vec![
-                Cell::from(selector).style(Style::default().fg(BLUE).bg(row_bg)),
+                Cell::from(selector).style(Style::default().fg(theme.blue).bg(row_bg)),
                 Cell::from(visibility).style(Style::default().fg(visibility_color).bg(row_bg)),
-                Cell::from(repo.full_name.clone()).style(
+                Cell::from(Line::from(vec![
+                    Span::styled(
+                        if ctx.bookmarks.contains(&repo.full_name) {
+                            "★ "
+                        } else {
+                            ""
+                        },
+                        Style::default().fg(theme.yellow),
+                    ),
+                    Span::raw(repo.full_name.clone()),
+                ]))
+                .style(
                     Style::default()
-                        .fg(FG)
+                        .fg(theme.fg)
                         .add_modifier(Modifier::BOLD)
                         .bg(row_bg),
                 ),
                 Cell::from(repo.language.as_deref().unwrap_or("—").to_string())
                     .style(Style::default().fg(lang_color).bg(row_bg)),
-                Cell::from(desc).style(Style::default().fg(GRAY).bg(row_bg)),
-                Cell::from(repo.last_active_display()).style(Style::default().fg(GRAY).bg(row_bg)),
-                Cell::from(stars).style(Style::default().fg(YELLOW).bg(row_bg)),
+                Cell::from(desc).style(Style::default().fg(theme.gray).bg(row_bg)),
+                Cell::from(repo.last_active_display())
+                    .style(Style::default().fg(theme.gray).bg(row_bg)),
+                Cell::from(stars).style(Style::default().fg(theme.yellow).bg(row_bg)),
             ];
 
             Row::new(cells).height(1)
@@ -244,14 +591,23 @@ fn draw_repo_list(f: &mut Frame, app: &App, area: Rect) {
         Constraint::Length(5),  // stars
     ];
 
-    let title = if app.repo_filter.is_empty() {
-        format!(" Repositories ({}) ", app.repos.len())
+    let page = ctx
+        .pages
+        .map(|(page, pages)| format!(" · Page {}/{}", page, pages))
+        .unwrap_or_default();
+    let title = if ctx.filter.is_empty() {
+        format!(
+            " Repositories ({}{}) ",
+            format_count(ctx.total as u64),
+            page
+        )
     } else {
         format!(
-            " Repositories ({}/{}) — \"{}\" ",
-            filtered.len(),
-            app.repos.len(),
-            app.repo_filter
+            " Repositories ({}/{}{}) — \"{}\" ",
+            format_count(filtered.len() as u64),
+            format_count(ctx.total as u64),
+            page,
+            ctx.filter
         )
     };
 
@@ -261,46 +617,218 @@ fn draw_repo_list(f: &mut Frame, app: &App, area: Rect) {
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(DIM))
+                .border_style(Style::default().fg(theme.dim))
                 .title(title)
-                .title_style(Style::default().fg(FG).add_modifier(Modifier::BOLD))
+                .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD))
                 .padding(Padding::horizontal(1))
-                .style(Style::default().bg(BG)),
+                .style(Style::default().bg(theme.bg)),
         )
-        .row_highlight_style(Style::default().bg(SELECTED_BG));
-
-    let mut state = TableState::default();
-    state.select(Some(app.repos_selected));
+        .row_highlight_style(Style::default().bg(theme.selected_bg));
+
+    let visible = table_rows(area);
+    let offset = keep_in_view(ctx.selected, ctx.offset.get(), visible, filtered.len());
+    ctx.offset.set(offset);
+    let mut state = TableState::default()
+        .with_offset(offset)
+        .with_selected(Some(ctx.selected));
     f.render_stateful_widget(table, area, &mut state);
+    record_rows(ctx.areas, ViewArea::Repos, area, true);
+
+    draw_table_scrollbar(f, theme, filtered.len(), offset, visible, area);
+}
+
+/// Body rows a bordered table with a one-line header has room for
+fn table_rows(area: Rect) -> usize {
+    area.height.saturating_sub(3) as usize
+}
+
+/// Note where a bordered table's rows landed, for mouse clicks
+fn record_rows(areas: &RenderedAreas, view_area: ViewArea, table: Rect, header: bool) {
+    let top = 1 + u16::from(header);
+    let rows = Rect {
+        x: table.x + 1,
+        y: table.y + top,
+        width: table.width.saturating_sub(2),
+        height: table.height.saturating_sub(top + 1),
+    };
+    areas.borrow_mut().insert(view_area, rows);
+}
 
-    // Scrollbar
+/// Scrollbar whose thumb covers the rows on screen
+fn draw_table_scrollbar(
+    f: &mut Frame,
+    theme: &Theme,
+    len: usize,
+    offset: usize,
+    visible: usize,
+    area: Rect,
+) {
     let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
         .begin_symbol(Some("↑"))
         .end_symbol(Some("↓"))
-        .track_style(Style::default().fg(DIM))
-        .thumb_style(Style::default().fg(GRAY));
-    let mut scrollbar_state = ScrollbarState::new(filtered.len()).position(app.repos_selected);
+        .track_style(Style::default().fg(theme.dim))
+        .thumb_style(Style::default().fg(theme.gray));
+    // One position per offset the table can scroll to
+    let positions = if len == 0 {
+        0
+    } else {
+        len.saturating_sub(visible) + 1
+    };
+    let mut scrollbar_state = ScrollbarState::new(positions)
+        .viewport_content_length(visible)
+        .position(offset);
     f.render_stateful_widget(scrollbar, area, &mut scrollbar_state);
 }
 
+// ── Workflow List View ─────────────────────────────────────────────
+
+fn draw_workflow_list(f: &mut Frame, theme: &Theme, app: &App, area: Rect) {
+    if app.workflows.is_empty() && app.loading {
+        let p = Paragraph::new("  Loading workflows...")
+            .style(Style::default().fg(theme.gray).bg(theme.bg))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.dim))
+                    .title(" Workflows ")
+                    .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD)),
+            );
+        f.render_widget(p, area);
+        return;
+    }
+
+    let header_cells = ["", "State", "Workflow", "File", "Last run"]
+        .iter()
+        .map(|h| {
+            Cell::from(*h).style(
+                Style::default()
+                    .fg(theme.gray)
+                    .add_modifier(Modifier::BOLD)
+                    .bg(theme.header_bg),
+            )
+        });
+    let header = Row::new(header_cells).height(1);
+
+    let selector = |i: usize| {
+        if i == app.workflows_selected {
+            "▸"
+        } else {
+            " "
+        }
+    };
+    let row_bg = |i: usize| {
+        if i == app.workflows_selected {
+            theme.selected_bg
+        } else {
+            theme.bg
+        }
+    };
+
+    // Row 0 drops the workflow filter
+    let mut rows = vec![Row::new(vec![
+        Cell::from(selector(0)).style(Style::default().fg(theme.blue).bg(row_bg(0))),
+        Cell::from(""),
+        Cell::from("All workflows").style(
+            Style::default()
+                .fg(theme.fg)
+                .bg(row_bg(0))
+                .add_modifier(Modifier::BOLD),
+        ),
+        Cell::from(""),
+        Cell::from(""),
+    ])];
+    rows.extend(app.workflows.iter().enumerate().map(|(i, workflow)| {
+        let i = i + 1;
+        // Disabled workflows are dimmed; their runs can still be opened
+        let active = workflow.state == "active";
+        let (state_color, name_color, detail_color) = if active {
+            (theme.green, theme.fg, theme.gray)
+        } else {
+            (theme.gray, theme.dim, theme.dim)
+        };
+        Row::new(vec![
+            Cell::from(selector(i)).style(Style::default().fg(theme.blue).bg(row_bg(i))),
+            Cell::from(workflow.state_display().to_string())
+                .style(Style::default().fg(state_color).bg(row_bg(i))),
+            Cell::from(workflow.name.clone()).style(Style::default().fg(name_color).bg(row_bg(i))),
+            Cell::from(workflow.path.clone())
+                .style(Style::default().fg(detail_color).bg(row_bg(i))),
+            Cell::from(workflow.last_run_display())
+                .style(Style::default().fg(detail_color).bg(row_bg(i))),
+        ])
+        .height(1)
+    }));
+
+    let widths = [
+        Constraint::Length(2),  // selector
+        Constraint::Length(12), // state
+        Constraint::Min(20),    // name
+        Constraint::Min(30),    // path
+        Constraint::Length(10), // last run
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme.dim))
+                .title(format!(
+                    " Workflows ({}) ",
+                    format_count(app.workflows.len() as u64)
+                ))
+                .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD))
+                .padding(Padding::horizontal(1))
+                .style(Style::default().bg(theme.bg)),
+        )
+        .row_highlight_style(Style::default().bg(theme.selected_bg));
+
+    let mut state = TableState::default();
+    state.select(Some(app.workflows_selected));
+    f.render_stateful_widget(table, area, &mut state);
+    record_rows(&app.last_rendered_areas, ViewArea::Workflows, area, true);
+}
+
 // ── Runs List View ─────────────────────────────────────────────────
 
-fn draw_runs_list(f: &mut Frame, app: &App, area: Rect) {
-    if app.runs.is_empty() {
-        let msg = if app.loading {
-            "  Loading workflow runs..."
+/// Border color of a pane that has (or lacks) keyboard focus
+fn pane_border(theme: &Theme, focused: bool) -> Color {
+    if focused {
+        theme.blue
+    } else {
+        theme.dim
+    }
+}
+
+fn draw_runs_list(f: &mut Frame, theme: &Theme, ctx: &RunsListCtx, focused: bool, area: Rect) {
+    if let (true, Some(connecting)) = (ctx.runs.is_empty(), ctx.connecting) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(pane_border(theme, focused)))
+            .title(runs_title(ctx, None))
+            .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD));
+        draw_skeleton(f, theme, connecting, block, area);
+        return;
+    }
+
+    if ctx.runs.is_empty() {
+        let msg = if ctx.loading {
+            format!("  {} Loading workflow runs...", ctx.spinner)
         } else {
-            "No workflow runs found."
+            "No workflow runs found.".to_string()
         };
         let p = Paragraph::new(msg)
-            .style(Style::default().fg(GRAY).bg(BG))
+            .style(Style::default().fg(theme.gray).bg(theme.bg))
             .block(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(DIM))
-                    .title(" Workflow Runs ")
-                    .title_style(Style::default().fg(FG).add_modifier(Modifier::BOLD)),
+                    .border_style(Style::default().fg(pane_border(theme, focused)))
+                    .title(runs_title(ctx, None))
+                    .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD)),
             );
         f.render_widget(p, area);
         return;
@@ -314,36 +842,41 @@ fn draw_runs_list(f: &mut Frame, app: &App, area: Rect) {
     .map(|h| {
         Cell::from(*h).style(
             Style::default()
-                .fg(GRAY)
+                .fg(theme.gray)
                 .add_modifier(Modifier::BOLD)
-                .bg(HEADER_BG),
+                .bg(theme.header_bg),
         )
     });
     let header = Row::new(header_cells).height(1);
 
     // Build table rows
-    let rows: Vec<Row> = app
+    let rows: Vec<Row> = ctx
         .runs
         .iter()
         .enumerate()
-        .map(|(i, run)| {
-            let is_selected = i == app.runs_selected;
-            let row_bg = if is_selected { SELECTED_BG } else { BG };
+        .map(|(i, row)| {
+            let run = row.run;
+            let is_selected = i == ctx.selected;
+            let row_bg = if is_selected {
+                theme.selected_bg
+            } else {
+                theme.bg
+            };
 
             let status_color = match run.conclusion.as_deref() {
-                Some("success") => GREEN,
-                Some("failure") => RED,
-                Some("cancelled") => YELLOW,
+                Some("success") => theme.green,
+                Some("failure") | Some("startup_failure") => theme.red,
+                Some("cancelled") => theme.yellow,
                 _ => match run.status.as_deref() {
-                    Some("in_progress") => ORANGE,
-                    Some("queued") => GRAY,
-                    _ => GRAY,
+                    Some("in_progress") => theme.orange,
+                    Some("queued") => theme.gray,
+                    _ => theme.gray,
                 },
             };
 
             let icon = match run.conclusion.as_deref() {
                 Some("success") => "✓",
-                Some("failure") => "✗",
+                Some("failure") | Some("startup_failure") => "✗",
                 Some("cancelled") => "⊘",
                 _ => match run.status.as_deref() {
                     Some("in_progress") => "●",
@@ -353,32 +886,66 @@ fn draw_runs_list(f: &mut Frame, app: &App, area: Rect) {
             };
 
             let selector = if is_selected { "▸" } else { " " };
+            let mark = if row.marked { "✓" } else { "" };
+            let stuck = row.stuck;
 
             let cells = vec![
-                Cell::from(selector).style(Style::default().fg(BLUE).bg(row_bg)),
+                Cell::from(Line::from(vec![
+                    Span::styled(selector, Style::default().fg(theme.blue)),
+                    Span::styled(mark, Style::default().fg(theme.purple)),
+                ]))
+                .style(Style::default().bg(row_bg)),
                 Cell::from(format!("{} {}", icon, run.status_display()))
                     .style(Style::default().fg(status_color).bg(row_bg)),
-                Cell::from(
-                    run.display_title
-                        .as_deref()
-                        .or(run.name.as_deref())
-                        .unwrap_or("—")
-                        .to_string(),
-                )
-                .style(Style::default().fg(FG).bg(row_bg)),
-                Cell::from(run.head_branch.as_deref().unwrap_or("—").to_string())
-                    .style(Style::default().fg(PURPLE).bg(row_bg)),
-                Cell::from(run.short_sha().to_string()).style(Style::default().fg(GRAY).bg(row_bg)),
-                Cell::from(run.event.clone()).style(Style::default().fg(BLUE).bg(row_bg)),
-                Cell::from(run.duration_display()).style(Style::default().fg(FG).bg(row_bg)),
-                Cell::from(run.age_display()).style(Style::default().fg(GRAY).bg(row_bg)),
+                Cell::from(Line::from(vec![
+                    Span::raw(
+                        run.display_title
+                            .as_deref()
+                            .or(run.name.as_deref())
+                            .unwrap_or("—")
+                            .to_string(),
+                    ),
+                    if let Some(stuck) = stuck {
+                        Span::styled(
+                            format!("  {}", stuck.label()),
+                            Style::default().fg(theme.yellow),
+                        )
+                    } else if row.logs_expired {
+                        Span::styled(
+                            "  logs expired",
+                            Style::default().fg(theme.gray).add_modifier(Modifier::DIM),
+                        )
+                    } else {
+                        Span::raw("")
+                    },
+                ]))
+                .style(Style::default().fg(theme.fg).bg(row_bg)),
+                Cell::from(format!(
+                    "{} {}",
+                    run.ref_kind().icon(),
+                    truncate_middle(run.ref_name(), BRANCH_WIDTH - 2)
+                ))
+                .style(Style::default().fg(theme.purple).bg(row_bg)),
+                Cell::from(run.short_sha().to_string())
+                    .style(Style::default().fg(theme.gray).bg(row_bg)),
+                Cell::from(run.event.clone()).style(Style::default().fg(theme.blue).bg(row_bg)),
+                Cell::from(run.duration_display()).style(
+                    Style::default()
+                        .fg(if stuck.is_some() {
+                            theme.yellow
+                        } else {
+                            theme.fg
+                        })
+                        .bg(row_bg),
+                ),
+                Cell::from(run.age_display()).style(Style::default().fg(theme.gray).bg(row_bg)),
                 Cell::from(
                     run.actor
                         .as_ref()
                         .map(|a| a.login.clone())
                         .unwrap_or_else(|| "—".to_string()),
                 )
-                .style(Style::default().fg(GRAY).bg(row_bg)),
+                .style(Style::default().fg(theme.gray).bg(row_bg)),
             ];
 
             Row::new(cells).height(1)
@@ -389,7 +956,7 @@ fn draw_runs_list(f: &mut Frame, app: &App, area: Rect) {
         Constraint::Length(2),  // selector
         Constraint::Length(16), // status
         Constraint::Min(20),    // workflow name
-        Constraint::Length(16), // branch
+        Constraint::Length(BRANCH_WIDTH as u16),
         Constraint::Length(9),  // commit
         Constraint::Length(12), // event
         Constraint::Length(10), // duration
@@ -397,69 +964,257 @@ fn draw_runs_list(f: &mut Frame, app: &App, area: Rect) {
         Constraint::Length(14), // actor
     ];
 
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(pane_border(theme, focused)))
+        .title(runs_title(ctx, Some(ctx.total)))
+        .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD))
+        .padding(Padding::horizontal(1))
+        .style(Style::default().bg(theme.bg));
+    // The selected run's full ref, which the Branch column may have cut
+    if let Some(run) = ctx.runs.get(ctx.selected).map(|row| row.run) {
+        if let Some(full) = run.head_branch.as_deref() {
+            let kind = run.ref_kind();
+            block = block.title_bottom(Line::from(vec![
+                Span::styled(
+                    format!(" {} {} ", kind.icon(), kind.label()),
+                    Style::default().fg(theme.gray),
+                ),
+                Span::styled(format!("{} ", full), Style::default().fg(theme.purple)),
+            ]));
+        }
+    }
+
     let table = Table::new(rows, widths)
         .header(header)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(DIM))
-                .title(format!(" Workflow Runs ({}) ", app.runs_total))
-                .title_style(Style::default().fg(FG).add_modifier(Modifier::BOLD))
-                .padding(Padding::horizontal(1))
-                .style(Style::default().bg(BG)),
-        )
-        .row_highlight_style(Style::default().bg(SELECTED_BG));
-
-    let mut state = TableState::default();
-    state.select(Some(app.runs_selected));
+        .block(block)
+        .row_highlight_style(Style::default().bg(theme.selected_bg));
+
+    let visible = table_rows(area);
+    let offset = keep_in_view(ctx.selected, ctx.offset.get(), visible, ctx.runs.len());
+    ctx.offset.set(offset);
+    let mut state = TableState::default()
+        .with_offset(offset)
+        .with_selected(Some(ctx.selected));
     f.render_stateful_widget(table, area, &mut state);
+    record_rows(ctx.areas, ViewArea::Runs, area, true);
 
-    // Scrollbar
-    let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
-        .begin_symbol(Some("↑"))
-        .end_symbol(Some("↓"))
-        .track_style(Style::default().fg(DIM))
-        .thumb_style(Style::default().fg(GRAY));
-    let mut scrollbar_state = ScrollbarState::new(app.runs.len()).position(app.runs_selected);
-    f.render_stateful_widget(scrollbar, area, &mut scrollbar_state);
+    draw_table_scrollbar(f, theme, ctx.runs.len(), offset, visible, area);
+}
+
+/// The head commit's subject, then its author, age and `+added −deleted`
+/// lines, in `width` columns
+fn commit_lines<'a>(theme: &Theme, ctx: &RunDetailCtx<'a>, width: usize) -> [Line<'a>; 2] {
+    let Some(commit) = ctx.commit else {
+        let placeholder = if ctx.commit_loading {
+            "  Loading commit…"
+        } else {
+            "  —"
+        };
+        return [
+            Line::from(Span::styled(placeholder, Style::default().fg(theme.dim))),
+            Line::default(),
+        ];
+    };
+    let separator = || Span::styled(" · ", Style::default().fg(theme.dim));
+    let mut by = vec![Span::raw("  ")];
+    if let Some(author) = &commit.commit.author {
+        by.push(Span::styled(
+            author.name.clone(),
+            Style::default().fg(theme.gray),
+        ));
+        by.push(separator());
+        by.push(Span::styled(
+            format_age((Utc::now() - author.date).num_seconds()),
+            Style::default().fg(theme.gray),
+        ));
+    }
+    if let Some(stats) = commit.stats {
+        if by.len() > 1 {
+            by.push(separator());
+        }
+        by.push(Span::styled(
+            format!("+{}", stats.additions),
+            Style::default().fg(theme.green),
+        ));
+        by.push(Span::raw(" "));
+        by.push(Span::styled(
+            format!("−{}", stats.deletions),
+            Style::default().fg(theme.red),
+        ));
+    }
+    [
+        Line::from(Span::styled(
+            format!("  {}", truncate_end(commit.subject(), width)),
+            Style::default().fg(theme.fg),
+        )),
+        Line::from(by),
+    ]
+}
+
+/// Fit `text` in `width` columns, ending it with `…` when cut. Wide
+/// characters count double.
+fn truncate_end(text: &str, width: usize) -> String {
+    if Span::raw(text).width() <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let mut cols = 0;
+    let head: String = text
+        .chars()
+        .take_while(|&c| {
+            cols += Span::raw(c.to_string()).width();
+            cols < width
+        })
+        .collect();
+    format!("{}…", head)
+}
+
+/// Columns of the runs list's Branch cell, its ref-kind icon included
+const BRANCH_WIDTH: usize = 16;
+
+/// Fit `text` in `width` columns by swapping its middle for `…`, keeping
+/// more of the tail: the part that tells long refs apart
+/// (`main/pr-123-…`, `release/2025-…-hotfix`). Wide characters count double.
+fn truncate_middle(text: &str, width: usize) -> String {
+    let cols = |c: char| Span::raw(c.to_string()).width();
+    if Span::raw(text).width() <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let chars: Vec<char> = text.chars().collect();
+    let budget = width - 1;
+    let mut tail_cols = 0;
+    let tail_len = chars
+        .iter()
+        .rev()
+        .take_while(|&&c| {
+            tail_cols += cols(c);
+            tail_cols <= budget - budget / 3
+        })
+        .count();
+    let tail_cols: usize = chars[chars.len() - tail_len..]
+        .iter()
+        .map(|&c| cols(c))
+        .sum();
+    let mut head_cols = 0;
+    let head: String = chars
+        .iter()
+        .take_while(|&&c| {
+            head_cols += cols(c);
+            head_cols <= budget - tail_cols
+        })
+        .collect();
+    let tail: String = chars[chars.len() - tail_len..].iter().collect();
+    format!("{}…{}", head, tail)
+}
+
+/// " Workflow Runs · Deploy (42 total) — status: failure " — the workflow
+/// filter, the run count and the status and event filters
+fn runs_title(ctx: &RunsListCtx, total: Option<u64>) -> String {
+    let mut title = " Workflow Runs ".to_string();
+    if let Some(workflow) = ctx.workflow {
+        title.push_str(&format!("· {} ", workflow));
+    }
+    if let Some(total) = total {
+        title.push_str(&format!("({} total) ", format_count(total)));
+    }
+    if let Some(status) = ctx.status {
+        title.push_str(&format!("— status: {} ", status));
+    }
+    if let Some(event) = ctx.event {
+        title.push_str(&format!("— event: {} ", event));
+    }
+    let marked = ctx.runs.iter().filter(|row| row.marked).count();
+    if marked > 0 {
+        title.push_str(&format!("· {} selected ", format_count(marked as u64)));
+    }
+    title
+}
+
+/// Runs list on the left, the selected run's jobs on the right
+fn draw_split_runs(f: &mut Frame, theme: &Theme, ctx: &RunsListCtx, area: Rect) {
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(area);
+
+    draw_runs_list(f, theme, ctx, ctx.focus == PaneFocus::Runs, panes[0]);
+
+    let jobs_focused = ctx.focus == PaneFocus::Jobs;
+    match ctx.preview {
+        Some(jobs) if !jobs.is_empty() => {
+            let list = JobsList {
+                jobs,
+                selected: ctx.preview_selected,
+                caches: ctx.caches,
+                focused: jobs_focused,
+            };
+            draw_jobs_list(f, theme, &list, panes[1]);
+            record_rows(ctx.areas, ViewArea::PreviewJobs, panes[1], false);
+        }
+        preview => {
+            let msg = match preview {
+                Some(_) => "No jobs found for this run.",
+                None if ctx.runs.is_empty() => "",
+                None => "  Loading jobs...",
+            };
+            let p = Paragraph::new(msg)
+                .style(Style::default().fg(theme.gray).bg(theme.bg))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .border_style(Style::default().fg(pane_border(theme, jobs_focused)))
+                        .title(" Jobs ")
+                        .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD)),
+                );
+            f.render_widget(p, panes[1]);
+        }
+    }
 }
 
 // ── Run Detail View ────────────────────────────────────────────────
 
-fn draw_run_detail(f: &mut Frame, app: &App, area: Rect) {
+fn draw_run_detail(f: &mut Frame, theme: &Theme, ctx: &RunDetailCtx, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(5), // Run summary
-            Constraint::Min(8),    // Jobs + Steps
+            // Run summary, a line taller once a log shows cache use
+            Constraint::Length(if ctx.cache_summary.is_some() { 8 } else { 7 }),
+            Constraint::Min(8), // Jobs + Steps
         ])
         .split(area);
 
     // ── Run summary box ────────────────────────────────────────────
-    if let Some(run) = &app.current_run {
+    if let Some(run) = ctx.run {
         let status_color = match run.conclusion.as_deref() {
-            Some("success") => GREEN,
-            Some("failure") => RED,
-            Some("cancelled") => YELLOW,
-            _ => ORANGE,
+            Some("success") => theme.green,
+            Some("failure") | Some("startup_failure") => theme.red,
+            Some("cancelled") => theme.yellow,
+            _ => theme.orange,
         };
 
-        let summary_lines = vec![
+        let mut summary_lines = vec![
             Line::from(vec![
-                Span::styled("  Run #", Style::default().fg(GRAY)),
+                Span::styled("  Run #", Style::default().fg(theme.gray)),
                 Span::styled(
                     run.run_number.to_string(),
-                    Style::default().fg(FG).add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme.fg).add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(" · ", Style::default().fg(DIM)),
+                Span::styled(" · ", Style::default().fg(theme.dim)),
                 Span::styled(run.status_display(), Style::default().fg(status_color)),
-                Span::styled(" · ", Style::default().fg(DIM)),
-                Span::styled(&run.event, Style::default().fg(BLUE)),
-                Span::styled(" on ", Style::default().fg(GRAY)),
+                Span::styled(" · ", Style::default().fg(theme.dim)),
+                Span::styled(&run.event, Style::default().fg(theme.blue)),
+                Span::styled(" on ", Style::default().fg(theme.gray)),
                 Span::styled(
                     run.head_branch.as_deref().unwrap_or("—"),
-                    Style::default().fg(PURPLE),
+                    Style::default().fg(theme.purple),
                 ),
             ]),
             Line::from(vec![
@@ -469,19 +1224,31 @@ fn draw_run_detail(f: &mut Frame, app: &App, area: Rect) {
                         .as_deref()
                         .or(run.name.as_deref())
                         .unwrap_or("—"),
-                    Style::default().fg(FG),
+                    Style::default().fg(theme.fg),
                 ),
-                Span::styled(" · ", Style::default().fg(DIM)),
-                Span::styled(run.short_sha(), Style::default().fg(GRAY)),
-                Span::styled(" · ", Style::default().fg(DIM)),
-                Span::styled(run.duration_display(), Style::default().fg(FG)),
-                Span::styled(" · ", Style::default().fg(DIM)),
+                Span::styled(" · ", Style::default().fg(theme.dim)),
+                Span::styled(run.short_sha(), Style::default().fg(theme.gray)),
+                Span::styled(" · ", Style::default().fg(theme.dim)),
+                match ctx.stuck {
+                    Some(stuck) => Span::styled(stuck.label(), Style::default().fg(theme.yellow)),
+                    None => Span::styled(run.duration_display(), Style::default().fg(theme.fg)),
+                },
+                Span::styled(" · ", Style::default().fg(theme.dim)),
                 Span::styled(
                     run.actor.as_ref().map(|a| a.login.as_str()).unwrap_or("—"),
-                    Style::default().fg(GRAY),
+                    Style::default().fg(theme.gray),
                 ),
             ]),
         ];
+        let width = chunks[0].width.saturating_sub(4) as usize;
+        summary_lines.extend(commit_lines(theme, ctx, width));
+        summary_lines.push(run_counts_line(theme, &ctx.counts, run));
+        if let Some(cache) = &ctx.cache_summary {
+            summary_lines.push(Line::from(Span::styled(
+                format!("  {}", cache),
+                Style::default().fg(theme.gray),
+            )));
+        }
 
         let summary = Paragraph::new(summary_lines).block(
             Block::default()
@@ -489,33 +1256,52 @@ fn draw_run_detail(f: &mut Frame, app: &App, area: Rect) {
                 .border_type(BorderType::Rounded)
                 .border_style(Style::default().fg(status_color))
                 .title(" Run Summary ")
-                .title_style(Style::default().fg(FG).add_modifier(Modifier::BOLD))
-                .style(Style::default().bg(HEADER_BG)),
+                .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD))
+                .style(Style::default().bg(theme.header_bg)),
         );
         f.render_widget(summary, chunks[0]);
     }
 
     // ── Jobs & Steps ───────────────────────────────────────────────
-    if app.jobs.is_empty() {
-        let msg = if app.loading {
+    if let (true, Some(problem)) = (ctx.jobs.is_empty(), ctx.problem) {
+        draw_workflow_problem(f, theme, problem, chunks[1]);
+        return;
+    }
+    if ctx.jobs.is_empty() {
+        let msg = if ctx.loading {
             "⏳ Loading jobs..."
         } else {
             "No jobs found for this run."
         };
         let p = Paragraph::new(msg)
-            .style(Style::default().fg(GRAY).bg(BG))
+            .style(Style::default().fg(theme.gray).bg(theme.bg))
             .block(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(DIM))
+                    .border_style(Style::default().fg(theme.dim))
                     .title(" Jobs ")
-                    .title_style(Style::default().fg(FG).add_modifier(Modifier::BOLD)),
+                    .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD)),
             );
         f.render_widget(p, chunks[1]);
         return;
     }
 
+    // Annotations, when the run has any, go under the jobs and steps
+    let (jobs_area, annotations_area) = if ctx.annotations.is_empty() {
+        (chunks[1], None)
+    } else {
+        let rows = ctx.annotations.len().min(MAX_ANNOTATION_ROWS) as u16;
+        let split = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(8), Constraint::Length(rows + 2)])
+            .split(chunks[1]);
+        (split[0], Some(split[1]))
+    };
+    if let Some(area) = annotations_area {
+        draw_annotations(f, theme, ctx, area);
+    }
+
     // Split into jobs list and steps panel
     let detail_chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -523,115 +1309,343 @@ fn draw_run_detail(f: &mut Frame, app: &App, area: Rect) {
             Constraint::Percentage(40), // Jobs
             Constraint::Percentage(60), // Steps
         ])
-        .split(chunks[1]);
+        .split(jobs_area);
 
     // Jobs list
-    draw_jobs_list(f, app, detail_chunks[0]);
+    let list = JobsList {
+        jobs: ctx.jobs,
+        selected: ctx.selected,
+        caches: ctx.caches,
+        focused: ctx.focus == DetailFocus::Jobs,
+    };
+    draw_jobs_list(f, theme, &list, detail_chunks[0]);
+    record_rows(ctx.areas, ViewArea::Jobs, detail_chunks[0], false);
 
     // Steps for selected job
-    if let Some(job) = app.jobs.get(app.jobs_selected) {
-        draw_steps(f, job, detail_chunks[1]);
+    if let Some(job) = ctx.jobs.get(ctx.selected) {
+        draw_steps(f, theme, ctx, job, detail_chunks[1]);
     }
 }
 
-fn draw_jobs_list(f: &mut Frame, app: &App, area: Rect) {
-    let rows: Vec<Row> = app
-        .jobs
-        .iter()
-        .enumerate()
-        .map(|(i, job)| {
-            let is_selected = i == app.jobs_selected;
-            let row_bg = if is_selected { SELECTED_BG } else { BG };
+/// Rows of the annotations panel before it scrolls
+const MAX_ANNOTATION_ROWS: usize = 6;
 
-            let status_color = match job.conclusion.as_deref() {
-                Some("success") => GREEN,
-                Some("failure") => RED,
-                Some("cancelled") => YELLOW,
-                _ => ORANGE,
+/// A failed run's annotations, one per row and colored by level; Tab moves
+/// the cursor here, and Enter copies the `path:line` under it
+fn draw_annotations(f: &mut Frame, theme: &Theme, ctx: &RunDetailCtx, area: Rect) {
+    let rows: Vec<Row> = ctx
+        .annotations
+        .iter()
+        .map(|a| {
+            let color = match a.annotation_level.as_deref() {
+                Some("failure") => theme.red,
+                Some("warning") => theme.yellow,
+                _ => theme.blue,
             };
-
-            let icon = match job.conclusion.as_deref() {
-                Some("success") => "✓",
-                Some("failure") => "✗",
-                Some("cancelled") => "⊘",
-                _ => "●",
+            let message = match &a.title {
+                Some(title) => format!("{} · {}", title, a.message.lines().next().unwrap_or("")),
+                None => a.message.lines().next().unwrap_or("").to_string(),
             };
-
-            let selector = if is_selected { "▸" } else { " " };
-
-            let cells = vec![
-                Cell::from(selector).style(Style::default().fg(BLUE).bg(row_bg)),
-                Cell::from(icon.to_string()).style(Style::default().fg(status_color).bg(row_bg)),
-                Cell::from(job.name.clone()).style(Style::default().fg(FG).bg(row_bg)),
-                Cell::from(job.duration_display()).style(Style::default().fg(GRAY).bg(row_bg)),
-            ];
-
-            Row::new(cells).height(1)
+            Row::new(vec![
+                Cell::from(Span::styled("●", Style::default().fg(color))),
+                Cell::from(Span::styled(a.location(), Style::default().fg(theme.fg))),
+                Cell::from(Span::styled(message, Style::default().fg(color))),
+            ])
         })
         .collect();
 
+    let focused = ctx.focus == DetailFocus::Annotations;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(pane_border(theme, focused)))
+        .title(format!(
+            " Annotations ({}) · Tab to select, Enter copies path:line ",
+            format_count(ctx.annotations.len() as u64)
+        ))
+        .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD))
+        .style(Style::default().bg(theme.bg));
     let widths = [
         Constraint::Length(2),
-        Constraint::Length(2),
-        Constraint::Min(10),
-        Constraint::Length(12),
+        Constraint::Percentage(35),
+        Constraint::Min(20),
     ];
-
+    let highlight = if focused {
+        Style::default().bg(theme.selected_bg)
+    } else {
+        Style::default()
+    };
     let table = Table::new(rows, widths)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
+        .block(block)
+        .row_highlight_style(highlight);
+
+    // No header row
+    let visible = area.height.saturating_sub(2) as usize;
+    let offset = keep_in_view(ctx.annotations_selected, 0, visible, ctx.annotations.len());
+    let mut state = TableState::default()
+        .with_offset(offset)
+        .with_selected(Some(ctx.annotations_selected));
+    f.render_stateful_widget(table, area, &mut state);
+    record_rows(ctx.areas, ViewArea::Annotations, area, false);
+
+    draw_table_scrollbar(f, theme, ctx.annotations.len(), offset, visible, area);
+}
+
+/// Why a `startup_failure` run never started: the YAML error with the
+/// surrounding workflow lines, or GitHub's annotations
+fn draw_workflow_problem(f: &mut Frame, theme: &Theme, problem: &WorkflowProblem, area: Rect) {
+    let (title, lines) = match problem {
+        WorkflowProblem::Syntax {
+            path,
+            error,
+            context,
+        } => {
+            let mut lines = vec![
+                Line::from(vec![
+                    Span::styled("✗ ", Style::default().fg(theme.red)),
+                    Span::styled(
+                        format!("{}:{}:{} ", path, error.line, error.column),
+                        Style::default().fg(theme.fg).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(&error.message, Style::default().fg(theme.red)),
+                ]),
+                Line::from(""),
+            ];
+            let width = context.last().map(|(n, _)| n.to_string().len()).unwrap_or(1);
+            for (n, text) in context {
+                let is_error = *n == error.line;
+                let style = if is_error {
+                    Style::default()
+                        .fg(theme.fg)
+                        .bg(theme.selected_bg)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.gray)
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("{} {:>width$} │ ", if is_error { "→" } else { " " }, n),
+                        Style::default().fg(if is_error { theme.red } else { theme.dim }),
+                    ),
+                    Span::styled(text.as_str(), style),
+                ]));
+            }
+            (" Workflow file error ", lines)
+        }
+        WorkflowProblem::Annotations(annotations) if annotations.is_empty() => (
+            " Startup failure ",
+            vec![Line::from(Span::styled(
+                "The workflow file parses, and GitHub reported no details. Press o to open the run on GitHub.",
+                Style::default().fg(theme.gray),
+            ))],
+        ),
+        WorkflowProblem::Annotations(annotations) => {
+            let lines = annotations
+                .iter()
+                .flat_map(|a| {
+                    let color = match a.annotation_level.as_deref() {
+                        Some("failure") => theme.red,
+                        Some("warning") => theme.yellow,
+                        _ => theme.blue,
+                    };
+                    vec![
+                        Line::from(vec![
+                            Span::styled("● ", Style::default().fg(color)),
+                            Span::styled(a.location(), Style::default().fg(theme.fg).add_modifier(Modifier::BOLD)),
+                            Span::styled(
+                                a.title.as_deref().map(|t| format!(" · {}", t)).unwrap_or_default(),
+                                Style::default().fg(theme.gray),
+                            ),
+                        ]),
+                        Line::from(Span::styled(format!("  {}", a.message), Style::default().fg(color))),
+                    ]
+                })
+                .collect();
+            (" Startup failure · GitHub annotations ", lines)
+        }
+    };
+
+    let p = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.red))
+            .title(title)
+            .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD))
+            .padding(Padding::horizontal(1))
+            .style(Style::default().bg(theme.bg)),
+    );
+    f.render_widget(p, area);
+}
+
+/// A jobs table: run details, or the pane beside the runs list
+struct JobsList<'a> {
+    jobs: &'a [Job],
+    selected: usize,
+    /// Cache use of the jobs whose logs were loaded
+    caches: &'a HashMap<u64, CacheReport>,
+    focused: bool,
+}
+
+fn draw_jobs_list(f: &mut Frame, theme: &Theme, list: &JobsList, area: Rect) {
+    let JobsList {
+        jobs,
+        selected,
+        caches,
+        focused,
+    } = *list;
+    let cache_label = |job: &Job| caches.get(&job.id).and_then(CacheReport::label);
+    let cache_width = jobs
+        .iter()
+        .filter_map(cache_label)
+        .map(|label| label.chars().count() as u16)
+        .max();
+    let rows: Vec<Row> = jobs
+        .iter()
+        .enumerate()
+        .map(|(i, job)| {
+            let is_selected = i == selected;
+            let row_bg = if is_selected {
+                theme.selected_bg
+            } else {
+                theme.bg
+            };
+
+            let status_color = match job.conclusion.as_deref() {
+                Some("success") => theme.green,
+                Some("failure") => theme.red,
+                Some("cancelled") => theme.yellow,
+                _ => theme.orange,
+            };
+
+            let icon = match job.conclusion.as_deref() {
+                Some("success") => "✓",
+                Some("failure") => "✗",
+                Some("cancelled") => "⊘",
+                _ => "●",
+            };
+
+            let selector = if is_selected { "▸" } else { " " };
+
+            let mut cells = vec![
+                Cell::from(selector).style(Style::default().fg(theme.blue).bg(row_bg)),
+                Cell::from(icon.to_string()).style(Style::default().fg(status_color).bg(row_bg)),
+                Cell::from(job.name.clone()).style(Style::default().fg(theme.fg).bg(row_bg)),
+                Cell::from(job.duration_display())
+                    .style(Style::default().fg(theme.gray).bg(row_bg)),
+            ];
+            if cache_width.is_some() {
+                let report = caches.get(&job.id);
+                let color = match report.map(|r| r.hits()) {
+                    Some(0) => theme.yellow,
+                    _ => theme.green,
+                };
+                cells.push(
+                    Cell::from(report.and_then(CacheReport::label).unwrap_or_default())
+                        .style(Style::default().fg(color).bg(row_bg)),
+                );
+            }
+
+            Row::new(cells).height(1)
+        })
+        .collect();
+
+    let mut widths = vec![
+        Constraint::Length(2),
+        Constraint::Length(2),
+        Constraint::Min(10),
+        Constraint::Length(12),
+    ];
+    if let Some(width) = cache_width {
+        widths.push(Constraint::Length(width));
+    }
+
+    let table = Table::new(rows, widths)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(DIM))
-                .title(format!(" Jobs ({}) ", app.jobs.len()))
-                .title_style(Style::default().fg(FG).add_modifier(Modifier::BOLD))
+                .border_style(Style::default().fg(pane_border(theme, focused)))
+                .title(format!(
+                    " Jobs ({} · {}) ",
+                    format_count(jobs.len() as u64),
+                    JobCounts::of(jobs).summary()
+                ))
+                .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD))
                 .padding(Padding::horizontal(1))
-                .style(Style::default().bg(BG)),
+                .style(Style::default().bg(theme.bg)),
         )
-        .row_highlight_style(Style::default().bg(SELECTED_BG));
+        .row_highlight_style(Style::default().bg(theme.selected_bg));
 
     let mut state = TableState::default();
-    state.select(Some(app.jobs_selected));
+    state.select(Some(selected));
     f.render_stateful_widget(table, area, &mut state);
 }
 
-fn draw_steps(f: &mut Frame, job: &Job, area: Rect) {
+/// `selected`: the step under the cursor, when Tab put it on the steps
+/// The job's steps; they scroll on their own, and the cursor shows while
+/// Tab has them focused
+fn draw_steps(f: &mut Frame, theme: &Theme, ctx: &RunDetailCtx, job: &Job, area: Rect) {
+    let steps_loading = ctx.steps_loading == Some(job.id);
+    let focused = ctx.focus == DetailFocus::Steps;
+    let placeholder = match (&job.steps, steps_loading) {
+        (None, true) => Some("  Loading steps…"),
+        (None, false) => Some("  Steps unavailable · r to retry"),
+        _ => None,
+    };
     let steps = job.steps.as_deref().unwrap_or(&[]);
 
-    let lines: Vec<Line> = steps
+    let mut lines: Vec<Line> = steps
         .iter()
-        .map(|step| {
+        .enumerate()
+        .map(|(i, step)| {
             let status_color = match step.conclusion.as_deref() {
-                Some("success") => GREEN,
-                Some("failure") => RED,
-                Some("cancelled") => YELLOW,
-                Some("skipped") => GRAY,
-                _ => ORANGE,
+                Some("success") => theme.green,
+                Some("failure") => theme.red,
+                Some("cancelled") => theme.yellow,
+                Some("skipped") => theme.gray,
+                _ => theme.orange,
             };
 
-            Line::from(vec![
+            let line = Line::from(vec![
                 Span::styled("  ", Style::default()),
                 Span::styled(step.status_icon(), Style::default().fg(status_color)),
                 Span::styled("  ", Style::default()),
-                Span::styled(&step.name, Style::default().fg(FG)),
+                Span::styled(&step.name, Style::default().fg(theme.fg)),
                 Span::styled("  ", Style::default()),
-                Span::styled(step.duration_display(), Style::default().fg(GRAY)),
-            ])
+                Span::styled(step.duration_display(), Style::default().fg(theme.gray)),
+            ]);
+            if focused && i == ctx.steps_selected {
+                line.style(Style::default().bg(theme.selected_bg))
+            } else {
+                line
+            }
         })
         .collect();
+    if let Some(text) = placeholder {
+        lines.push(Line::from(Span::styled(
+            text,
+            Style::default().fg(theme.gray),
+        )));
+    }
 
     let status_color = match job.conclusion.as_deref() {
-        Some("success") => GREEN,
-        Some("failure") => RED,
-        Some("cancelled") => YELLOW,
-        _ => ORANGE,
+        Some("success") => theme.green,
+        Some("failure") => theme.red,
+        Some("cancelled") => theme.yellow,
+        _ => theme.orange,
     };
 
-    let p = Paragraph::new(lines).block(
+    // Borders and padding take four rows
+    let visible = area.height.saturating_sub(4) as usize;
+    let len = lines.len();
+    let offset = keep_in_view(ctx.steps_selected, ctx.steps_offset.get(), visible, len);
+    ctx.steps_offset.set(offset);
+    let p = Paragraph::new(lines).scroll((offset as u16, 0)).block(
         Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(DIM))
+            .border_style(Style::default().fg(pane_border(theme, focused)))
             .title(format!(
                 " {} · {} · {} ",
                 job.name,
@@ -640,50 +1654,182 @@ fn draw_steps(f: &mut Frame, job: &Job, area: Rect) {
             ))
             .title_style(Style::default().fg(status_color))
             .padding(Padding::vertical(1))
-            .style(Style::default().bg(BG)),
+            .style(Style::default().bg(theme.bg)),
     );
 
     f.render_widget(p, area);
+    // Rows start under the border and the top padding
+    record_rows(
+        ctx.areas,
+        ViewArea::Steps,
+        area.inner(Margin::new(0, 1)),
+        false,
+    );
+    draw_table_scrollbar(f, theme, len, offset, visible, area);
+}
+
+/// "3 artifacts [a] · 7 annotations [o] · attempt 2 · billable 14m", with
+/// a dash for whatever hasn't been counted. Annotations are listed on the
+/// run's page, hence `o`.
+fn run_counts_line<'a>(theme: &Theme, counts: &RunCounts, run: &WorkflowRun) -> Line<'a> {
+    let count = |n: Option<u64>| n.map_or("—".to_string(), format_count);
+    let sep = || Span::styled(" · ", Style::default().fg(theme.dim));
+    let hint =
+        |key: &'static str| Span::styled(format!(" [{}]", key), Style::default().fg(theme.dim));
+
+    Line::from(vec![
+        Span::styled("  ", Style::default()),
+        Span::styled(count(counts.artifacts), Style::default().fg(theme.fg)),
+        Span::styled(" artifacts", Style::default().fg(theme.gray)),
+        hint("a"),
+        sep(),
+        Span::styled(count(counts.annotations), Style::default().fg(theme.fg)),
+        Span::styled(" annotations", Style::default().fg(theme.gray)),
+        hint("o"),
+        sep(),
+        Span::styled("attempt ", Style::default().fg(theme.gray)),
+        Span::styled(count(run.run_attempt), Style::default().fg(theme.fg)),
+        sep(),
+        Span::styled("billable ", Style::default().fg(theme.gray)),
+        Span::styled(
+            counts
+                .billable_ms
+                .map_or("—".to_string(), |ms| format!("{}m", ms.div_ceil(60_000))),
+            Style::default().fg(theme.fg),
+        ),
+    ])
 }
 
 // ── Log View ───────────────────────────────────────────────────────
 
-fn draw_log_view(f: &mut Frame, app: &App, area: Rect) {
-    let lines: Vec<Line> = app
-        .log_content
+fn draw_log_view(f: &mut Frame, theme: &Theme, ctx: &LogsCtx, area: Rect) {
+    if let Some(days) = ctx.expired {
+        draw_logs_expired(f, theme, ctx.finished_at, days, area);
+        return;
+    }
+
+    let current_match = ctx.matches.get(ctx.match_cursor).copied();
+    let visible = &ctx.visible;
+    let lines: Vec<Line> = visible
         .iter()
-        .map(|line| {
-            let color = if line.contains("##[error]") || line.contains("Error") {
-                RED
-            } else if line.contains("##[warning]") || line.contains("Warning") {
-                YELLOW
-            } else if line.contains("##[group]") || line.starts_with("Run ") {
-                BLUE
+        .map(|&i| {
+            let raw = &ctx.content[i];
+            // Highlight ranges index `raw`; shift them past the timestamp
+            let skip = if ctx.timestamps {
+                0
+            } else {
+                log_timestamp_len(raw)
+            };
+            let line = &raw[skip..];
+            // The tool's own colors when it sent any, else guess from the text
+            let base = match ansi::segments(line) {
+                Some(segments) => segments
+                    .into_iter()
+                    .map(|(range, style)| (range, Style::default().fg(theme.fg).patch(style)))
+                    .collect(),
+                None => {
+                    let color = if line.contains("##[error]") || line.contains("Error") {
+                        theme.red
+                    } else if line.contains("##[warning]") || line.contains("Warning") {
+                        theme.yellow
+                    } else if line.contains("##[group]") || line.starts_with("Run ") {
+                        theme.blue
+                    } else {
+                        theme.fg
+                    };
+                    vec![(0..line.len(), Style::default().fg(color))]
+                }
+            };
+            let shifted: LogHighlight = ctx
+                .highlights
+                .get(i)
+                .into_iter()
+                .flatten()
+                .filter(|(range, _)| range.end > skip)
+                .map(|(range, color)| (range.start.max(skip) - skip..range.end - skip, *color))
+                .collect();
+            let is_match = ctx.matches.binary_search(&i).is_ok();
+            let hits = if is_match {
+                search_hits(line, ctx.search)
             } else {
-                FG
+                Vec::new()
+            };
+            let mut drawn = styled_log_line(theme, line, base, &shifted, &hits);
+            // ##[group] sections: ▸ folded / ▾ open, contents indented
+            let group = ctx.groups.get(i).copied().unwrap_or_default();
+            let indent = "  ".repeat(group.depth as usize);
+            let gutter = match (group.header, group.collapsed) {
+                (true, true) => format!("{}▸ ", indent),
+                (true, false) => format!("{}▾ ", indent),
+                _ if group.depth > 0 => indent,
+                _ => String::new(),
             };
-            Line::from(Span::styled(line.as_str(), Style::default().fg(color)))
+            if !gutter.is_empty() {
+                drawn
+                    .spans
+                    .insert(0, Span::styled(gutter, Style::default().fg(theme.gray)));
+            }
+            // The error `e` targeted; `/` matches, the current one stronger
+            if ctx.error_target == Some(i) {
+                drawn.style(Style::default().bg(theme.error_target_bg))
+            } else if current_match == Some(i) {
+                drawn.style(Style::default().bg(theme.current_match_bg))
+            } else if is_match {
+                drawn.style(Style::default().bg(theme.match_bg))
+            } else {
+                drawn
+            }
         })
         .collect();
 
-    let title = if let Some(job) = app.jobs.get(app.jobs_selected) {
-        format!(" Logs: {} ({} lines) ", job.name, app.log_content.len())
+    let mut title = if let Some(job) = ctx.job {
+        // "build › Run tests", or "build › Run tests (whole job)" after `x`
+        let name = match ctx.step {
+            Some(step) if step.only => format!("{} › {}", job.name, step.name),
+            Some(step) => format!("{} › {} (whole job)", job.name, step.name),
+            None => job.name.clone(),
+        };
+        format!(
+            " Logs: {} ({} lines){} · timestamps {}{} ",
+            name,
+            format_count(ctx.content.len() as u64),
+            if ctx.search.is_empty() {
+                String::new()
+            } else {
+                format!(" [{} matches]", format_count(ctx.matches.len() as u64))
+            },
+            if ctx.timestamps { "on" } else { "off" },
+            if ctx.streaming {
+                " · streaming…"
+            } else if ctx.follow {
+                " · following"
+            } else {
+                ""
+            }
+        )
     } else {
         " Logs ".to_string()
     };
+    if ctx.searching || !ctx.search.is_empty() {
+        title.push_str(&format!(
+            "· /{}{} ",
+            ctx.search,
+            if ctx.searching { "▏" } else { "" }
+        ));
+    }
 
     let p = Paragraph::new(lines)
-        .scroll(((app.log_scroll.min(u16::MAX as usize)) as u16, 0))
+        .scroll(((ctx.scroll.min(u16::MAX as usize)) as u16, 0))
         .wrap(Wrap { trim: false })
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(DIM))
+                .border_style(Style::default().fg(theme.dim))
                 .title(title)
-                .title_style(Style::default().fg(FG).add_modifier(Modifier::BOLD))
+                .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD))
                 .padding(Padding::horizontal(1))
-                .style(Style::default().bg(BG)),
+                .style(Style::default().bg(theme.bg)),
         );
 
     f.render_widget(p, area);
@@ -692,38 +1838,979 @@ fn draw_log_view(f: &mut Frame, app: &App, area: Rect) {
     let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
         .begin_symbol(Some("↑"))
         .end_symbol(Some("↓"))
-        .track_style(Style::default().fg(DIM))
-        .thumb_style(Style::default().fg(GRAY));
-    let total = app.log_content.len();
-    let mut scrollbar_state = ScrollbarState::new(total).position(app.log_scroll);
+        .track_style(Style::default().fg(theme.dim))
+        .thumb_style(Style::default().fg(theme.gray));
+    let total = visible.len();
+    let mut scrollbar_state = ScrollbarState::new(total).position(ctx.scroll);
     f.render_stateful_widget(scrollbar, area, &mut scrollbar_state);
 }
 
+/// Stands in for the log of a run older than the repo's retention
+fn draw_logs_expired(
+    f: &mut Frame,
+    theme: &Theme,
+    finished_at: Option<DateTime<Utc>>,
+    days: u32,
+    area: Rect,
+) {
+    let finished = finished_at
+        .map(|at| format_age((Utc::now() - at).num_seconds()))
+        .unwrap_or_default();
+    let lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            "  These logs have expired",
+            Style::default()
+                .fg(theme.yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(
+                "  The run finished {}; this repository keeps logs and artifacts for {} days.",
+                finished, days
+            ),
+            Style::default().fg(theme.fg),
+        )),
+        Line::from(Span::styled(
+            "  Re-run the workflow (R) to produce fresh logs. Admins can raise the limit under",
+            Style::default().fg(theme.gray),
+        )),
+        Line::from(Span::styled(
+            "  Settings → Actions → General → Artifact and log retention.",
+            Style::default().fg(theme.gray),
+        )),
+    ];
+    let p = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.dim))
+            .title(" Logs ")
+            .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD))
+            .style(Style::default().bg(theme.bg)),
+    );
+    f.render_widget(p, area);
+}
+
+/// Split a log line into spans, drawing user-pattern matches in bold over
+/// the style of the text they fall in
+fn styled_log_line<'a>(
+    theme: &Theme,
+    line: &'a str,
+    base: AnsiSegments,
+    highlights: &LogHighlight,
+    hits: &[Range<usize>],
+) -> Line<'a> {
+    let mut segments = base;
+    for (range, color) in highlights {
+        segments = restyle(segments, std::slice::from_ref(range), |style| {
+            style.fg(*color).add_modifier(Modifier::BOLD)
+        });
+    }
+    // `/` hits go on top, dark text on yellow
+    segments = restyle(segments, hits, |style| style.fg(theme.bg).bg(theme.yellow));
+    Line::from(
+        segments
+            .into_iter()
+            .map(|(range, style)| Span::styled(&line[range], style))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Split `segments` where they meet `ranges` (in order, not overlapping)
+/// and restyle the parts inside
+fn restyle(
+    segments: AnsiSegments,
+    ranges: &[Range<usize>],
+    f: impl Fn(Style) -> Style,
+) -> AnsiSegments {
+    let mut out = Vec::with_capacity(segments.len());
+    for (segment, style) in segments {
+        let mut pos = segment.start;
+        for range in ranges {
+            if range.end <= pos || range.start >= segment.end {
+                continue;
+            }
+            let (start, end) = (range.start.max(pos), range.end.min(segment.end));
+            if start > pos {
+                out.push((pos..start, style));
+            }
+            out.push((start..end, f(style)));
+            pos = end;
+        }
+        if pos < segment.end {
+            out.push((pos..segment.end, style));
+        }
+    }
+    out
+}
+
 // ── Status bar ─────────────────────────────────────────────────────
 
-fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
-    let loading_indicator = if app.loading { "⏳ " } else { "" };
+fn draw_status_bar(f: &mut Frame, theme: &Theme, app: &App, area: Rect) {
+    let loading_indicator = if app.loading {
+        format!("{} ", app.spinner())
+    } else {
+        String::new()
+    };
+    let message = match &app.connecting {
+        Some(connecting) => format!(
+            "connecting to GitHub… {:.1}s",
+            connecting.since.elapsed().as_secs_f32()
+        ),
+        None => app
+            .watch_readout()
+            .unwrap_or_else(|| app.status_message.clone()),
+    };
 
-    let status = Paragraph::new(Line::from(vec![
+    let mut spans = vec![
         Span::styled("  ", Style::default()),
-        Span::styled(loading_indicator, Style::default().fg(YELLOW)),
-        Span::styled(&app.status_message, Style::default().fg(FG)),
-    ]))
+        Span::styled(loading_indicator, Style::default().fg(theme.yellow)),
+        Span::styled(message, Style::default().fg(theme.fg)),
+    ];
+    if let Some(left) = app.next_auto_refresh() {
+        spans.push(Span::styled(
+            format!(" · next refresh in {} s", left.as_secs_f32().ceil()),
+            Style::default().fg(theme.gray),
+        ));
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.dim))
+        .style(Style::default().bg(theme.header_bg));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(Line::from(spans)), inner);
+
+    // API requests left this hour, on the right
+    if let Some(limit) = app.client.rate_limit() {
+        let color = match limit.remaining {
+            0..20 => theme.red,
+            20..100 => theme.yellow,
+            _ => theme.gray,
+        };
+        let readout = Paragraph::new(Span::styled(
+            format!("[{}/{}] ", limit.remaining, limit.limit),
+            Style::default().fg(color),
+        ))
+        .alignment(Alignment::Right);
+        f.render_widget(readout, inner);
+    }
+}
+
+// ── Toast ──────────────────────────────────────────────────────────
+
+/// A finished watch, in the top-right corner of the content until it
+/// times out
+fn draw_toast(f: &mut Frame, theme: &Theme, toast: &Toast, area: Rect) {
+    let color = if toast.success {
+        theme.green
+    } else {
+        theme.red
+    };
+    let width = (toast.message.chars().count() as u16 + 4).min(area.width);
+    let rect = Rect {
+        x: area.x + area.width - width,
+        y: area.y,
+        width,
+        height: 3.min(area.height),
+    };
+    let p = Paragraph::new(Span::styled(
+        toast.message.as_str(),
+        Style::default().fg(color).add_modifier(Modifier::BOLD),
+    ))
     .block(
         Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(DIM))
-            .style(Style::default().bg(HEADER_BG)),
+            .border_style(Style::default().fg(color))
+            .padding(Padding::horizontal(1))
+            .style(Style::default().bg(theme.header_bg)),
+    );
+    f.render_widget(Clear, rect);
+    f.render_widget(p, rect);
+}
+
+// ── Skeleton ───────────────────────────────────────────────────────
+
+/// Placeholder rows while the first fetch is in flight; a lighter band
+/// sweeps down them as the tick redraws
+fn draw_skeleton(f: &mut Frame, theme: &Theme, since: Instant, block: Block, area: Rect) {
+    const WIDTHS: [usize; 6] = [2, 10, 22, 14, 8, 12];
+    let rows = area.height.saturating_sub(2) as usize;
+    let band = (since.elapsed().as_millis() / 120) as usize % (rows + 6);
+
+    let lines: Vec<Line> = (0..rows)
+        .map(|i| {
+            let color = if i.abs_diff(band) <= 1 {
+                theme.skeleton_shine
+            } else {
+                theme.dim
+            };
+            let spans: Vec<Span> = WIDTHS
+                .iter()
+                .enumerate()
+                .map(|(col, width)| {
+                    // Ragged bar lengths read as text rather than a grid
+                    let len = width - (i * 7 + col * 3) % (width / 2 + 1);
+                    Span::styled(
+                        format!("{:<w$}  ", "▃".repeat(len), w = width),
+                        Style::default().fg(color),
+                    )
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect();
+
+    let p = Paragraph::new(lines)
+        .style(Style::default().bg(theme.bg))
+        .block(block.padding(Padding::horizontal(1)));
+    f.render_widget(p, area);
+}
+
+// ── Artifacts ──────────────────────────────────────────────────────
+
+fn draw_artifacts(f: &mut Frame, theme: &Theme, app: &App, area: Rect) {
+    let run_number = app.current_run.as_ref().map_or(0, |r| r.run_number);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.dim))
+        .title(format!(" Artifacts · #{} ", run_number))
+        .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD))
+        .padding(Padding::horizontal(1))
+        .style(Style::default().bg(theme.bg));
+
+    if app.artifacts.is_empty() {
+        let msg = if app.loading {
+            "  Loading artifacts..."
+        } else {
+            "  This run uploaded no artifacts."
+        };
+        let p = Paragraph::new(msg)
+            .style(Style::default().fg(theme.gray).bg(theme.bg))
+            .block(block);
+        f.render_widget(p, area);
+        return;
+    }
+
+    let header_cells = ["", "Name", "Size", "Created", ""].iter().map(|h| {
+        Cell::from(*h).style(
+            Style::default()
+                .fg(theme.gray)
+                .add_modifier(Modifier::BOLD)
+                .bg(theme.header_bg),
+        )
+    });
+    let header = Row::new(header_cells).height(1);
+
+    let now = chrono::Utc::now();
+    let rows = app.artifacts.iter().enumerate().map(|(i, artifact)| {
+        let selected = i == app.artifacts_selected;
+        let bg = if selected {
+            theme.selected_bg
+        } else {
+            theme.bg
+        };
+        let downloading = app.downloads.iter().any(|d| d.id == artifact.id);
+        let (note, note_color) = if artifact.expired {
+            ("expired", theme.gray)
+        } else if downloading {
+            ("downloading", theme.yellow)
+        } else {
+            ("", theme.gray)
+        };
+        let name_style = if artifact.expired {
+            Style::default()
+                .fg(theme.gray)
+                .bg(bg)
+                .add_modifier(Modifier::CROSSED_OUT)
+        } else {
+            Style::default().fg(theme.fg).bg(bg)
+        };
+        Row::new(vec![
+            Cell::from(if selected { "▸" } else { " " })
+                .style(Style::default().fg(theme.blue).bg(bg)),
+            Cell::from(artifact.name.clone()).style(name_style),
+            Cell::from(format_bytes(artifact.size_in_bytes))
+                .style(Style::default().fg(theme.gray).bg(bg)),
+            Cell::from(
+                artifact
+                    .created_at
+                    .map(|t| format_age((now - t).num_seconds()))
+                    .unwrap_or_default(),
+            )
+            .style(Style::default().fg(theme.gray).bg(bg)),
+            Cell::from(note).style(Style::default().fg(note_color).bg(bg)),
+        ])
+        .height(1)
+    });
+
+    let widths = [
+        Constraint::Length(2),  // selector
+        Constraint::Min(30),    // name
+        Constraint::Length(10), // size
+        Constraint::Length(10), // created
+        Constraint::Length(12), // note
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .row_highlight_style(Style::default().bg(theme.selected_bg));
+
+    let mut state = TableState::default();
+    state.select(Some(app.artifacts_selected));
+    f.render_stateful_widget(table, area, &mut state);
+    record_rows(&app.last_rendered_areas, ViewArea::Artifacts, area, true);
+}
+
+// ── Environments ───────────────────────────────────────────────────
+
+fn draw_environments(f: &mut Frame, theme: &Theme, app: &App, area: Rect) {
+    let run_number = app.current_run.as_ref().map_or(0, |r| r.run_number);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.dim))
+        .title(format!(" Environments · #{} ", run_number))
+        .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD))
+        .padding(Padding::horizontal(1))
+        .style(Style::default().bg(theme.bg));
+
+    if app.environments.is_empty() {
+        let msg = if app.loading {
+            "  Loading environments..."
+        } else {
+            "  This repository has no environments."
+        };
+        let p = Paragraph::new(msg)
+            .style(Style::default().fg(theme.gray).bg(theme.bg))
+            .block(block);
+        f.render_widget(p, area);
+        return;
+    }
+
+    let header_cells = [
+        "",
+        "Environment",
+        "Protection",
+        "Deployment",
+        "Deployer",
+        "When",
+    ]
+    .iter()
+    .map(|h| {
+        Cell::from(*h).style(
+            Style::default()
+                .fg(theme.gray)
+                .add_modifier(Modifier::BOLD)
+                .bg(theme.header_bg),
+        )
+    });
+    let header = Row::new(header_cells).height(1);
+
+    let now = chrono::Utc::now();
+    let rows = app.environments.iter().enumerate().map(|(i, env)| {
+        let selected = i == app.environments_selected;
+        let bg = if selected {
+            theme.selected_bg
+        } else {
+            theme.bg
+        };
+        let deployment = app.deployment_to(&env.name);
+        let (state, state_color) = deployment.map_or(("—".to_string(), theme.gray), |d| {
+            deployment_state(theme, d)
+        });
+        Row::new(vec![
+            Cell::from(if selected { "▸" } else { " " })
+                .style(Style::default().fg(theme.blue).bg(bg)),
+            Cell::from(env.name.clone()).style(Style::default().fg(theme.fg).bg(bg)),
+            Cell::from(env.protection_display()).style(Style::default().fg(theme.gray).bg(bg)),
+            Cell::from(state).style(Style::default().fg(state_color).bg(bg)),
+            Cell::from(
+                deployment
+                    .and_then(|d| d.creator.as_ref())
+                    .map(|c| c.login.clone())
+                    .unwrap_or_default(),
+            )
+            .style(Style::default().fg(theme.gray).bg(bg)),
+            Cell::from(
+                deployment
+                    .map(|d| format_age((now - d.created_at).num_seconds()))
+                    .unwrap_or_default(),
+            )
+            .style(Style::default().fg(theme.gray).bg(bg)),
+        ])
+        .height(1)
+    });
+
+    let widths = [
+        Constraint::Length(2),  // selector
+        Constraint::Min(16),    // name
+        Constraint::Min(24),    // protection
+        Constraint::Length(24), // deployment
+        Constraint::Length(18), // deployer
+        Constraint::Length(10), // when
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .row_highlight_style(Style::default().bg(theme.selected_bg));
+
+    let mut state = TableState::default();
+    state.select(Some(app.environments_selected));
+    f.render_stateful_widget(table, area, &mut state);
+    record_rows(&app.last_rendered_areas, ViewArea::Environments, area, true);
+}
+
+/// "⏸ waiting for approval", "✓ success", ... in the state's color
+fn deployment_state(theme: &Theme, deployment: &Deployment) -> (String, Color) {
+    let (icon, color) = match deployment.state() {
+        "waiting" => return ("⏸ waiting for approval".to_string(), theme.yellow),
+        "success" => ("✓", theme.green),
+        "failure" | "error" => ("✗", theme.red),
+        "in_progress" | "queued" | "pending" => ("●", theme.blue),
+        _ => ("○", theme.gray),
+    };
+    (
+        format!("{} {}", icon, deployment.state().replace('_', " ")),
+        color,
+    )
+}
+
+// ── Runners ────────────────────────────────────────────────────────
+
+fn draw_runners(f: &mut Frame, theme: &Theme, app: &App, area: Rect) {
+    let wanted = app.runner_labels_wanted();
+    let title = match app.jobs.get(app.jobs_selected) {
+        Some(job) if !wanted.is_empty() => {
+            format!(" Runners · {} runs on {} ", job.name, wanted.join(", "))
+        }
+        _ => " Runners ".to_string(),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.dim))
+        .title(title)
+        .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD))
+        .padding(Padding::horizontal(1))
+        .style(Style::default().bg(theme.bg));
+
+    if app.runners.is_empty() {
+        let msg = if app.loading {
+            "  Loading runners..."
+        } else {
+            "  No self-hosted runners. Jobs on GitHub-hosted runners don't need one."
+        };
+        let p = Paragraph::new(msg)
+            .style(Style::default().fg(theme.gray).bg(theme.bg))
+            .block(block);
+        f.render_widget(p, area);
+        return;
+    }
+
+    let header_cells = ["", "Runner", "Status", "OS", "Labels", "Fits job"]
+        .iter()
+        .map(|h| {
+            Cell::from(*h).style(
+                Style::default()
+                    .fg(theme.gray)
+                    .add_modifier(Modifier::BOLD)
+                    .bg(theme.header_bg),
+            )
+        });
+    let header = Row::new(header_cells).height(1);
+
+    let rows = app.runners.iter().enumerate().map(|(i, runner)| {
+        let selected = i == app.runners_selected;
+        let bg = if selected {
+            theme.selected_bg
+        } else {
+            theme.bg
+        };
+        let (icon, color) = match runner.state() {
+            "online" => ("●", theme.green),
+            "busy" => ("●", theme.orange),
+            _ => ("○", theme.red),
+        };
+        let fits = if wanted.is_empty() {
+            ""
+        } else if runner.fits(wanted) {
+            "✓"
+        } else {
+            "—"
+        };
+        Row::new(vec![
+            Cell::from(if selected { "▸" } else { " " })
+                .style(Style::default().fg(theme.blue).bg(bg)),
+            Cell::from(runner.name.clone()).style(Style::default().fg(theme.fg).bg(bg)),
+            Cell::from(format!("{} {}", icon, runner.state()))
+                .style(Style::default().fg(color).bg(bg)),
+            Cell::from(runner.os.clone()).style(Style::default().fg(theme.gray).bg(bg)),
+            Cell::from(runner.labels_display()).style(Style::default().fg(theme.gray).bg(bg)),
+            Cell::from(fits).style(Style::default().fg(theme.green).bg(bg)),
+        ])
+        .height(1)
+    });
+
+    let widths = [
+        Constraint::Length(2),  // selector
+        Constraint::Min(16),    // name
+        Constraint::Length(10), // status
+        Constraint::Length(8),  // os
+        Constraint::Min(24),    // labels
+        Constraint::Length(8),  // fits
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .row_highlight_style(Style::default().bg(theme.selected_bg));
+
+    let mut state = TableState::default();
+    state.select(Some(app.runners_selected));
+    f.render_stateful_widget(table, area, &mut state);
+    record_rows(&app.last_rendered_areas, ViewArea::Runners, area, true);
+}
+
+// ── Actions caches ─────────────────────────────────────────────────
+
+fn draw_caches(f: &mut Frame, theme: &Theme, app: &App, area: Rect) {
+    let caches = &app.actions_caches;
+    let total: u64 = caches.iter().map(|c| c.size_in_bytes).sum();
+    let title = if caches.is_empty() {
+        " Actions Caches ".to_string()
+    } else {
+        format!(
+            " Actions Caches ({} · {}) ",
+            format_count(caches.len() as u64),
+            format_bytes(total)
+        )
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.dim))
+        .title(title)
+        .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD))
+        .padding(Padding::horizontal(1))
+        .style(Style::default().bg(theme.bg));
+
+    if caches.is_empty() {
+        let msg = if app.loading {
+            "  Loading caches..."
+        } else {
+            "  No Actions caches in this repository."
+        };
+        let p = Paragraph::new(msg)
+            .style(Style::default().fg(theme.gray).bg(theme.bg))
+            .block(block);
+        f.render_widget(p, area);
+        return;
+    }
+
+    let header_cells = ["", "Key", "Ref", "Size", "Last used", "Created"]
+        .iter()
+        .map(|h| {
+            Cell::from(*h).style(
+                Style::default()
+                    .fg(theme.gray)
+                    .add_modifier(Modifier::BOLD)
+                    .bg(theme.header_bg),
+            )
+        });
+    let header = Row::new(header_cells).height(1);
+
+    let now = Utc::now();
+    let age = |at: DateTime<Utc>| format_age(now.signed_duration_since(at).num_seconds());
+    let rows = caches.iter().enumerate().map(|(i, cache)| {
+        let selected = i == app.actions_caches_selected;
+        let bg = if selected {
+            theme.selected_bg
+        } else {
+            theme.bg
+        };
+        let git_ref = cache
+            .ref_
+            .strip_prefix("refs/heads/")
+            .unwrap_or(&cache.ref_);
+        Row::new(vec![
+            Cell::from(if selected { "▸" } else { " " })
+                .style(Style::default().fg(theme.blue).bg(bg)),
+            Cell::from(cache.key.clone()).style(Style::default().fg(theme.fg).bg(bg)),
+            Cell::from(truncate_middle(git_ref, CACHE_REF_WIDTH))
+                .style(Style::default().fg(theme.purple).bg(bg)),
+            Cell::from(format_bytes(cache.size_in_bytes))
+                .style(Style::default().fg(theme.yellow).bg(bg)),
+            Cell::from(age(cache.last_accessed_at)).style(Style::default().fg(theme.gray).bg(bg)),
+            Cell::from(age(cache.created_at)).style(Style::default().fg(theme.gray).bg(bg)),
+        ])
+        .height(1)
+    });
+
+    let widths = [
+        Constraint::Length(2),                      // selector
+        Constraint::Min(24),                        // key
+        Constraint::Length(CACHE_REF_WIDTH as u16), // ref
+        Constraint::Length(10),                     // size
+        Constraint::Length(10),                     // last used
+        Constraint::Length(10),                     // created
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .row_highlight_style(Style::default().bg(theme.selected_bg));
+
+    let visible = table_rows(area);
+    let offset = keep_in_view(app.actions_caches_selected, 0, visible, caches.len());
+    let mut state = TableState::default()
+        .with_offset(offset)
+        .with_selected(Some(app.actions_caches_selected));
+    f.render_stateful_widget(table, area, &mut state);
+    record_rows(&app.last_rendered_areas, ViewArea::Caches, area, true);
+
+    draw_table_scrollbar(f, theme, caches.len(), offset, visible, area);
+}
+
+// ── Statistics ─────────────────────────────────────────────────────
+
+/// Build health of the loaded runs: a success-rate gauge, the figures
+/// beside it, a daily trend, and the workflows failing most
+fn draw_statistics(f: &mut Frame, theme: &Theme, app: &App, area: Rect) {
+    let stats = RepoStats::compute(&app.runs, Utc::now());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.dim))
+        .title(format!(
+            " Statistics · last {} days of {} runs loaded ",
+            stats::WINDOW_DAYS,
+            format_count(app.runs.len() as u64)
+        ))
+        .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD))
+        .padding(Padding::horizontal(1))
+        .style(Style::default().bg(theme.bg));
+
+    if stats.total == 0 {
+        let msg = if app.loading {
+            "  Loading runs..."
+        } else {
+            "  No runs in the last 30 days among those loaded."
+        };
+        let p = Paragraph::new(msg)
+            .style(Style::default().fg(theme.gray).bg(theme.bg))
+            .block(block);
+        f.render_widget(p, area);
+        return;
+    }
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // gap
+            Constraint::Length(1), // gauge
+            Constraint::Length(1), // gap
+            Constraint::Length(5), // figures
+            Constraint::Length(1), // gap
+            Constraint::Min(3),    // failing workflows
+        ])
+        .split(inner);
+
+    let rate = stats.success_rate.unwrap_or(0.0);
+    let rate_color = if rate >= 0.9 {
+        theme.green
+    } else if rate >= 0.7 {
+        theme.yellow
+    } else {
+        theme.red
+    };
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(rate_color).bg(theme.header_bg))
+        .ratio(rate)
+        .label(match stats.success_rate {
+            Some(rate) => format!("{:.0}% succeeded", rate * 100.0),
+            None => "no finished runs".to_string(),
+        });
+    f.render_widget(gauge, rows[1]);
+
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(theme.gray));
+    let value = |text: String| Span::styled(text, Style::default().fg(theme.fg));
+    let duration =
+        |secs: Option<u64>| secs.map_or("—".to_string(), |s| format_duration(s as i64));
+    let figures = vec![
+        Line::from(vec![
+            label("Runs          "),
+            value(format_count(stats.total as u64)),
+        ]),
+        Line::from(vec![
+            label("Avg duration  "),
+            value(duration(stats.avg_secs)),
+        ]),
+        Line::from(vec![
+            label("p95 duration  "),
+            value(duration(stats.p95_secs)),
+        ]),
+        Line::from(vec![
+            label("Top failure   "),
+            match &stats.top_failure {
+                Some((reason, count)) => Span::styled(
+                    format!("{} ×{}", reason.replace('_', " "), count),
+                    Style::default().fg(theme.red),
+                ),
+                None => value("none".to_string()),
+            },
+        ]),
+        Line::from(vec![
+            label("Last 14 days  "),
+            Span::styled(
+                stats::sparkline(&stats.daily),
+                Style::default().fg(rate_color),
+            ),
+            label("  success rate per day"),
+        ]),
+    ];
+    f.render_widget(
+        Paragraph::new(figures).style(Style::default().bg(theme.bg)),
+        rows[3],
     );
 
-    f.render_widget(status, area);
+    let header = Row::new(["Failing workflow", "Failures", "Runs", "Failed"].map(|h| {
+        Cell::from(h).style(
+            Style::default()
+                .fg(theme.gray)
+                .add_modifier(Modifier::BOLD)
+                .bg(theme.header_bg),
+        )
+    }));
+    let table_rows = stats.failing.iter().map(|w| {
+        Row::new(vec![
+            Cell::from(w.name.clone()).style(Style::default().fg(theme.fg)),
+            Cell::from(w.failures.to_string()).style(Style::default().fg(theme.red)),
+            Cell::from(w.runs.to_string()).style(Style::default().fg(theme.gray)),
+            Cell::from(format!("{:.0}%", w.failures as f64 / w.runs as f64 * 100.0))
+                .style(Style::default().fg(theme.gray)),
+        ])
+    });
+    let widths = [
+        Constraint::Min(24),    // workflow
+        Constraint::Length(10), // failures
+        Constraint::Length(8),  // runs
+        Constraint::Length(8),  // share failed
+    ];
+    if stats.failing.is_empty() {
+        let p = Paragraph::new("No failed runs — every workflow passed.")
+            .style(Style::default().fg(theme.green).bg(theme.bg));
+        f.render_widget(p, rows[5]);
+    } else {
+        let table = Table::new(table_rows, widths)
+            .header(header)
+            .style(Style::default().bg(theme.bg));
+        f.render_widget(table, rows[5]);
+    }
 }
 
+// ── Run timeline ───────────────────────────────────────────────────
+
+/// Widest job or step name the timeline shows before cutting it short
+const TIMELINE_NAME_WIDTH: usize = 28;
+/// Columns of the duration at the end of each bar
+const TIMELINE_DURATION_WIDTH: u16 = 9;
+
+/// The run's jobs as bars on one time axis, the selected job's steps under
+/// them on the same scale
+fn draw_run_timeline(f: &mut Frame, theme: &Theme, app: &App, area: Rect) {
+    let timeline = Timeline::compute(
+        app.current_run.as_ref(),
+        &app.jobs,
+        app.jobs_selected,
+        Utc::now(),
+    );
+    let run_number = app.current_run.as_ref().map_or(0, |run| run.run_number);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.dim))
+        .title(format!(
+            " Timeline · run #{} · {} wall clock · {} of jobs ",
+            run_number,
+            format_duration(timeline.total_secs as i64),
+            format_duration(timeline.job_secs() as i64)
+        ))
+        .title_style(Style::default().fg(theme.fg).add_modifier(Modifier::BOLD))
+        .padding(Padding::horizontal(1))
+        .style(Style::default().bg(theme.bg));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let name_width = timeline
+        .jobs
+        .iter()
+        .chain(&timeline.steps)
+        .map(|bar| bar.name.chars().count() + 2)
+        .max()
+        .unwrap_or(0)
+        .clamp(8, TIMELINE_NAME_WIDTH);
+    let track = inner
+        .width
+        .saturating_sub(name_width as u16 + 2 + TIMELINE_DURATION_WIDTH);
+
+    let bar_line = |bar: &Bar, name: String, name_style: Style| {
+        let color = match bar.conclusion.as_deref() {
+            Some("success") => theme.green,
+            Some("failure" | "timed_out") => theme.red,
+            Some("cancelled") => theme.yellow,
+            Some(_) => theme.gray,
+            None => theme.orange,
+        };
+        let mut spans = vec![Span::styled(
+            format!("{:<w$} ", name, w = name_width),
+            name_style,
+        )];
+        let (bar_text, duration) = match timeline.span(bar, track) {
+            Some((from, to)) => {
+                spans.push(Span::raw(" ".repeat(from as usize)));
+                spans.push(Span::styled(
+                    "█".repeat((to - from) as usize),
+                    Style::default().fg(color),
+                ));
+                (
+                    " ".repeat(track.saturating_sub(to) as usize),
+                    format_duration(bar.secs as i64),
+                )
+            }
+            None => (
+                format!("{:<w$}", "waiting", w = track as usize),
+                "—".to_string(),
+            ),
+        };
+        spans.push(Span::styled(bar_text, Style::default().fg(theme.dim)));
+        spans.push(Span::styled(
+            format!(" {:>w$}", duration, w = TIMELINE_DURATION_WIDTH as usize),
+            Style::default().fg(theme.gray),
+        ));
+        Line::from(spans)
+    };
+    let clip = |name: &str, width: usize| name.chars().take(width).collect::<String>();
+
+    let jobs: Vec<Line> = timeline
+        .jobs
+        .iter()
+        .enumerate()
+        .map(|(i, bar)| {
+            let selected = i == app.jobs_selected;
+            let (marker, style) = if selected {
+                (
+                    "▸ ",
+                    Style::default().fg(theme.blue).add_modifier(Modifier::BOLD),
+                )
+            } else {
+                ("  ", Style::default().fg(theme.fg))
+            };
+            let line = bar_line(
+                bar,
+                format!("{}{}", marker, clip(&bar.name, name_width - 2)),
+                style,
+            );
+            if selected {
+                line.style(Style::default().bg(theme.selected_bg))
+            } else {
+                line
+            }
+        })
+        .collect();
+
+    let job = app.jobs.get(app.jobs_selected);
+    let mut steps = vec![Line::from(Span::styled(
+        format!("Steps of {}", job.map_or("", |job| job.name.as_str())),
+        Style::default().fg(theme.gray).add_modifier(Modifier::BOLD),
+    ))];
+    if timeline.steps.is_empty() {
+        let loading = job.is_some_and(|job| app.steps_loading == Some(job.id));
+        steps.push(Line::from(Span::styled(
+            if loading {
+                "  Loading steps…"
+            } else {
+                "  No steps"
+            },
+            Style::default().fg(theme.gray),
+        )));
+    }
+    steps.extend(timeline.steps.iter().map(|bar| {
+        bar_line(
+            bar,
+            format!("  {}", clip(&bar.name, name_width - 2)),
+            Style::default().fg(theme.fg),
+        )
+    }));
+
+    // Axis labels, each where its time falls, dropped where they'd overlap
+    let mut axis = vec![' '; track as usize + TIMELINE_DURATION_WIDTH as usize + 1];
+    let mut free_from = 0;
+    for (column, label) in timeline.ticks(track) {
+        let column = column as usize;
+        if column < free_from || column + label.len() > axis.len() {
+            continue;
+        }
+        for (i, c) in label.chars().enumerate() {
+            axis[column + i] = c;
+        }
+        free_from = column + label.len() + 1;
+    }
+    let axis = Line::from(vec![
+        Span::raw(" ".repeat(name_width + 1)),
+        Span::styled(
+            axis.into_iter().collect::<String>(),
+            Style::default().fg(theme.gray),
+        ),
+    ]);
+
+    let steps_height = (steps.len() as u16).min(inner.height / 2);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // axis
+            Constraint::Min(1),    // jobs
+            Constraint::Length(1), // gap
+            Constraint::Length(steps_height),
+        ])
+        .split(inner);
+    f.render_widget(Paragraph::new(axis), rows[0]);
+
+    let visible = rows[1].height as usize;
+    let offset = keep_in_view(app.jobs_selected, 0, visible, jobs.len());
+    f.render_widget(Paragraph::new(jobs).scroll((offset as u16, 0)), rows[1]);
+    // Clicks pick jobs here as in run details
+    app.last_rendered_areas
+        .borrow_mut()
+        .insert(ViewArea::Jobs, rows[1]);
+
+    f.render_widget(Paragraph::new(steps), rows[3]);
+}
+
+/// Columns of the caches table's Ref cell
+const CACHE_REF_WIDTH: usize = 20;
+
 // ── Keybindings bar ────────────────────────────────────────────────
 
-fn draw_keybindings(f: &mut Frame, app: &App, area: Rect) {
-    let bindings = match app.view {
+fn draw_keybindings(f: &mut Frame, theme: &Theme, app: &App, area: Rect) {
+    let mut bindings = match app.view {
+        _ if app.connecting.is_some() => vec![("Esc", "cancel"), ("q", "quit")],
+        _ if app.workflow_picker => vec![
+            ("↑↓/jk", "navigate"),
+            ("Enter", "filter"),
+            ("Esc", "all workflows"),
+            ("w", "close"),
+        ],
         View::RepoList => {
             if app.searching {
                 vec![
@@ -734,44 +2821,216 @@ fn draw_keybindings(f: &mut Frame, app: &App, area: Rect) {
                     ("q", "quit"),
                 ]
             } else {
-                vec![
+                let mut bindings = vec![
                     ("↑↓/jk", "navigate"),
+                    ("gg/G", "top/bottom"),
                     ("Enter/l", "open"),
                     ("/", "search"),
+                    ("m", "bookmark"),
                     ("r", "refresh"),
                     ("o", "browser"),
                     ("q", "quit"),
-                ]
+                ];
+                if app.repos_pages().is_some() {
+                    bindings.insert(2, ("←→/np", "page"));
+                }
+                bindings
             }
         }
-        View::RunsList => vec![
+        View::WorkflowList => vec![
             ("↑↓/jk", "navigate"),
-            ("Enter/l", "open"),
+            ("gg/G", "top/bottom"),
+            ("Enter/l", "runs"),
+            ("Esc/h", "back"),
             ("r", "refresh"),
-            ("←→/np", "page"),
+            ("d", "dispatch"),
+            ("t", "enable/disable"),
+            ("o", "browser"),
+            ("q", "quit"),
+        ],
+        View::RunsList if app.branch_input.is_some() => vec![
+            ("type", "branch"),
+            ("Enter", "apply (empty clears)"),
+            ("Esc", "cancel"),
+        ],
+        View::RunsList => {
+            let mut bindings = vec![
+                ("↑↓/jk", "navigate"),
+                ("gg/G", "top/bottom"),
+                ("Enter/l", "open"),
+                ("r", "refresh"),
+                ("←→/np", "page"),
+                ("b", "branch"),
+                ("f", app.status_filter.unwrap_or("status")),
+                ("e", app.event_filter.as_deref().unwrap_or("event")),
+                ("w", "workflows"),
+                ("v", "split"),
+                (
+                    "P",
+                    if app.follow_selection {
+                        "unfollow"
+                    } else {
+                        "follow"
+                    },
+                ),
+                ("o", "browser"),
+                ("R", "rerun"),
+                ("F", "rerun-failed"),
+                ("C", "cancel"),
+                ("Space", "select"),
+                ("d", "dispatch"),
+                ("q", "quit"),
+            ];
+            if !app.selected_run_ids.is_empty() {
+                for (key, label) in bindings.iter_mut() {
+                    match *key {
+                        "R" => *label = "rerun selected",
+                        "C" => *label = "cancel selected",
+                        _ => {}
+                    }
+                }
+                bindings.insert(bindings.len() - 2, ("D", "delete selected"));
+            }
+            if app.split_view() {
+                bindings.insert(2, ("Tab", "focus"));
+            }
+            bindings
+        }
+        View::RunDetail if app.detail_focus == DetailFocus::Steps => vec![
+            ("↑↓/jk", "navigate"),
+            ("Enter", "step log"),
+            ("Tab", "next list"),
+            ("Esc/h", "back"),
+            ("o", "browser"),
+            ("q", "quit"),
+        ],
+        View::RunDetail if app.detail_focus == DetailFocus::Annotations => vec![
+            ("↑↓/jk", "navigate"),
+            ("Enter", "copy path:line"),
+            ("Tab", "jobs"),
+            ("Esc/h", "back"),
             ("o", "browser"),
-            ("R", "rerun"),
-            ("C", "cancel"),
             ("q", "quit"),
         ],
         View::RunDetail => vec![
             ("↑↓/jk", "navigate"),
+            ("gg/G", "top/bottom"),
             ("Enter/l", "logs"),
             ("Esc/h", "back"),
             ("r", "refresh"),
+            ("a", "artifacts"),
+            ("e", "environments"),
+            ("u", "runners"),
+            ("t", "timeline"),
+            (
+                "f",
+                if app.jobs_failed_first {
+                    "run order"
+                } else {
+                    "failed first"
+                },
+            ),
             ("o", "browser"),
-            ("R", "rerun"),
+            ("R", "rerun job"),
+            ("F", "rerun-failed"),
             ("C", "cancel"),
             ("q", "quit"),
         ],
-        View::Logs => vec![
-            ("↑↓/jk", "scroll"),
+        View::Artifacts => vec![
+            ("↑↓/jk", "navigate"),
+            ("gg/G", "top/bottom"),
+            ("Enter/l", "download"),
+            ("Esc/h", "back"),
+            ("r", "refresh"),
+            ("o", "browser"),
+            ("q", "quit"),
+        ],
+        View::Environments => vec![
+            ("↑↓/jk", "navigate"),
+            ("gg/G", "top/bottom"),
+            ("Esc/h", "back"),
+            ("r", "refresh"),
+            ("o", "browser"),
+            ("q", "quit"),
+        ],
+        View::Runners => vec![
+            ("↑↓/jk", "navigate"),
+            ("gg/G", "top/bottom"),
+            ("Esc/h", "back"),
+            ("r", "refresh"),
+            ("o", "runner settings"),
+            ("q", "quit"),
+        ],
+        View::RunTimeline => vec![
+            ("↑↓/jk", "job"),
+            ("gg/G", "top/bottom"),
+            ("Esc/h", "back"),
+            ("r", "refresh"),
+            ("o", "browser"),
+            ("q", "quit"),
+        ],
+        View::Statistics => vec![
+            ("Esc/h", "back"),
+            ("r", "refresh"),
+            ("o", "browser"),
+            ("q", "quit"),
+        ],
+        View::Caches => vec![
+            ("↑↓/jk", "navigate"),
+            ("gg/G", "top/bottom"),
+            ("d/Del", "delete"),
             ("Esc/h", "back"),
             ("r", "refresh"),
             ("o", "browser"),
             ("q", "quit"),
         ],
+        View::Logs if app.searching => {
+            vec![("type", "search"), ("Enter", "done"), ("Esc", "clear")]
+        }
+        View::Logs => {
+            let mut bindings = vec![
+                ("↑↓/jk", "scroll"),
+                ("PgUp/PgDn", "page"),
+                ("gg/G", "top/bottom"),
+                (
+                    "Esc/h",
+                    if app.log_search.is_empty() {
+                        "back"
+                    } else {
+                        "clear search"
+                    },
+                ),
+                ("/", "search"),
+                ("n/N", "match"),
+                ("e", "next error"),
+                ("Enter", "fold"),
+                (
+                    "t",
+                    if app.log_timestamps {
+                        "hide times"
+                    } else {
+                        "show times"
+                    },
+                ),
+                ("r", "refresh"),
+                ("F", if app.log_follow { "unfollow" } else { "follow" }),
+            ];
+            if let Some(step) = &app.log_step {
+                bindings.push(("x", if step.only { "whole job" } else { "step only" }));
+            }
+            bindings.extend([
+                ("s", "save"),
+                ("E", "exports"),
+                ("o", "browser"),
+                ("q", "quit"),
+            ]);
+            bindings
+        }
     };
+    // Wherever q quits, ? explains the rest
+    if app.connecting.is_none() && bindings.last() == Some(&("q", "quit")) {
+        bindings.insert(bindings.len() - 1, ("?", "help"));
+    }
 
     let spans: Vec<Span> = bindings
         .iter()
@@ -781,19 +3040,644 @@ fn draw_keybindings(f: &mut Frame, app: &App, area: Rect) {
                 Span::styled(
                     format!(" {} ", key),
                     Style::default()
-                        .fg(BG)
-                        .bg(GRAY)
+                        .fg(theme.bg)
+                        .bg(theme.gray)
                         .add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(format!(" {} ", desc), Style::default().fg(GRAY)),
+                Span::styled(format!(" {} ", desc), Style::default().fg(theme.gray)),
             ];
             if i < bindings.len() - 1 {
-                v.push(Span::styled("│", Style::default().fg(DIM)));
+                v.push(Span::styled("│", Style::default().fg(theme.dim)));
             }
             v
         })
         .collect();
 
-    let bar = Paragraph::new(Line::from(spans)).style(Style::default().bg(BG));
+    let bar = Paragraph::new(Line::from(spans)).style(Style::default().bg(theme.bg));
     f.render_widget(bar, area);
 }
+
+// ── Quit confirmation ──────────────────────────────────────────────
+
+/// "Cancel run #42? [y/N]" over whatever view it was asked from
+fn draw_confirmation(f: &mut Frame, theme: &Theme, pending: &PendingAction, area: Rect) {
+    let lines = vec![
+        Line::from(Span::styled(
+            format!("{} [y/N]", pending.prompt()),
+            Style::default()
+                .fg(theme.yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(
+                " y ",
+                Style::default()
+                    .fg(theme.bg)
+                    .bg(theme.red)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" yes   ", Style::default().fg(theme.gray)),
+            Span::styled(
+                " n ",
+                Style::default()
+                    .fg(theme.bg)
+                    .bg(theme.gray)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" no", Style::default().fg(theme.gray)),
+        ]),
+    ];
+
+    let popup = centered_rect(popup_width(pending, 40), lines.len() as u16 + 2, area);
+
+    let dialog = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.yellow))
+            .padding(Padding::horizontal(1))
+            .style(Style::default().bg(theme.header_bg)),
+    );
+    f.render_widget(Clear, popup);
+    f.render_widget(dialog, popup);
+}
+
+/// The confirmation for a protected workflow: the run number (or `yes`)
+/// typed out, in red
+fn draw_typed_confirmation(
+    f: &mut Frame,
+    theme: &Theme,
+    pending: &PendingAction,
+    typed: &str,
+    area: Rect,
+) {
+    let lines = vec![
+        Line::from(Span::styled(
+            pending.prompt(),
+            Style::default().fg(theme.red).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(
+                "Protected workflow · type {} and Enter",
+                pending.confirm_word()
+            ),
+            Style::default().fg(theme.gray),
+        )),
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(theme.red)),
+            Span::styled(format!("{}▏", typed), Style::default().fg(theme.fg)),
+        ]),
+    ];
+
+    let popup = centered_rect(popup_width(pending, 48), lines.len() as u16 + 2, area);
+
+    let dialog = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.red))
+            .padding(Padding::horizontal(1))
+            .style(Style::default().bg(theme.header_bg)),
+    );
+    f.render_widget(Clear, popup);
+    f.render_widget(dialog, popup);
+}
+
+/// Wide enough for the prompt and its `[y/N]`, or `min`
+fn popup_width(pending: &PendingAction, min: u16) -> u16 {
+    // Borders, padding and " [y/N]"
+    (pending.prompt().chars().count() as u16 + 10).max(min)
+}
+
+fn draw_quit_confirm(f: &mut Frame, theme: &Theme, app: &App, area: Rect) {
+    let mut lines = vec![Line::from(Span::styled(
+        "Download in progress — quit anyway?",
+        Style::default()
+            .fg(theme.yellow)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    lines.push(Line::from(""));
+    for download in &app.downloads {
+        lines.push(Line::from(vec![
+            Span::styled("  ⏳ ", Style::default().fg(theme.yellow)),
+            Span::styled(&download.label, Style::default().fg(theme.fg)),
+            Span::styled(
+                format!(" ({}s)", download.started.elapsed().as_secs()),
+                Style::default().fg(theme.gray),
+            ),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(
+            " y ",
+            Style::default()
+                .fg(theme.bg)
+                .bg(theme.red)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(" quit   ", Style::default().fg(theme.gray)),
+        Span::styled(
+            " n ",
+            Style::default()
+                .fg(theme.bg)
+                .bg(theme.gray)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(" keep going", Style::default().fg(theme.gray)),
+    ]));
+
+    let popup = centered_rect(50, lines.len() as u16 + 2, area);
+
+    let dialog = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.yellow))
+            .padding(Padding::horizontal(1))
+            .style(Style::default().bg(theme.header_bg)),
+    );
+    f.render_widget(Clear, popup);
+    f.render_widget(dialog, popup);
+}
+
+// ── Dispatch form ──────────────────────────────────────────────────
+
+fn draw_dispatch_form(f: &mut Frame, theme: &Theme, form: &DispatchForm, area: Rect) {
+    let mut lines = Vec::new();
+    for (i, field) in form.fields.iter().enumerate() {
+        let selected = i == form.selected;
+        let label = format!("{}{}", field.name, if field.required { " *" } else { "" });
+        lines.push(Line::from(vec![
+            Span::styled(
+                if selected { "▸ " } else { "  " },
+                Style::default().fg(theme.blue),
+            ),
+            Span::styled(
+                format!("{:<16} ", label),
+                Style::default().fg(if selected { theme.fg } else { theme.gray }),
+            ),
+            Span::styled(
+                field.value.as_str(),
+                Style::default().fg(theme.fg).bg(if selected {
+                    theme.selected_bg
+                } else {
+                    theme.header_bg
+                }),
+            ),
+            Span::styled(
+                if selected { "▏" } else { "" },
+                Style::default().fg(theme.yellow),
+            ),
+        ]));
+        if let Some(hint) = field.hint.as_deref().filter(|_| selected) {
+            lines.push(Line::from(Span::styled(
+                format!("                   {}", hint),
+                Style::default().fg(theme.gray),
+            )));
+        }
+    }
+    if form.loading_inputs {
+        lines.push(Line::from(Span::styled(
+            "  Loading workflow inputs...",
+            Style::default().fg(theme.gray),
+        )));
+    }
+    if let Some(error) = &form.error {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("  {}", error),
+            Style::default().fg(theme.red),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "  Tab next field · Enter run · Esc cancel",
+        Style::default().fg(theme.gray),
+    )));
+
+    let popup = centered_rect(70, lines.len() as u16 + 2, area);
+    let dialog = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(" Run workflow: {} ", form.workflow_name))
+            .title_style(Style::default().fg(theme.blue).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.blue))
+            .style(Style::default().bg(theme.header_bg)),
+    );
+    f.render_widget(Clear, popup);
+    f.render_widget(dialog, popup);
+}
+
+// ── Log exports ────────────────────────────────────────────────────
+
+fn draw_export_prompt(f: &mut Frame, theme: &Theme, annotation: &str, area: Rect) {
+    let lines = vec![
+        Line::from(vec![
+            Span::styled("Note: ", Style::default().fg(theme.gray)),
+            Span::styled(
+                annotation,
+                Style::default().fg(theme.fg).bg(theme.selected_bg),
+            ),
+            Span::styled("▏", Style::default().fg(theme.yellow)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Added to the file name · Enter save · Esc cancel",
+            Style::default().fg(theme.gray),
+        )),
+    ];
+
+    let popup = centered_rect(60, lines.len() as u16 + 2, area);
+    let dialog = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Save logs ")
+            .title_style(Style::default().fg(theme.blue).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.blue))
+            .padding(Padding::horizontal(1))
+            .style(Style::default().bg(theme.header_bg)),
+    );
+    f.render_widget(Clear, popup);
+    f.render_widget(dialog, popup);
+}
+
+/// Recent exports; files that were deleted are struck through
+fn draw_exports_overlay(f: &mut Frame, theme: &Theme, overlay: &ExportsOverlay, area: Rect) {
+    let now = chrono::Utc::now().timestamp();
+    let mut lines = Vec::new();
+    if overlay.entries.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No exports yet — press s in a job log to save one",
+            Style::default().fg(theme.gray),
+        )));
+    }
+    for (i, (record, exists)) in overlay.entries.iter().enumerate() {
+        let selected = i == overlay.selected;
+        let mut style = Style::default().fg(if *exists { theme.fg } else { theme.gray });
+        if !exists {
+            style = style.add_modifier(Modifier::CROSSED_OUT);
+        }
+        if selected {
+            style = style.bg(theme.selected_bg);
+        }
+        let what = match (record.run_number, &record.job) {
+            (Some(run), Some(job)) => format!("{} #{} {}", record.repo, run, job),
+            (Some(run), None) => format!("{} #{}", record.repo, run),
+            _ => record.repo.clone(),
+        };
+        let mut spans = vec![
+            Span::styled(
+                if selected { "▸ " } else { "  " },
+                Style::default().fg(theme.blue),
+            ),
+            Span::styled(
+                format!("{:<10} ", format_age(now - record.saved_at)),
+                Style::default().fg(theme.gray),
+            ),
+            Span::styled(what, style),
+        ];
+        if let Some(annotation) = &record.annotation {
+            spans.push(Span::styled(
+                format!("  {}", annotation),
+                Style::default().fg(theme.purple),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    if let Some((record, _)) = overlay.selected() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("  {}", record.path.display()),
+            Style::default().fg(theme.gray),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "  Enter/p pager · y copy path · x prune missing · Esc close",
+        Style::default().fg(theme.gray),
+    )));
+
+    let popup = centered_rect(90, lines.len() as u16 + 2, area);
+    // Keep the selected entry in view when the list is taller than the popup
+    let visible = popup.height.saturating_sub(2) as usize;
+    let scroll = (overlay.selected + 4).saturating_sub(visible);
+    let dialog = Paragraph::new(lines).scroll((scroll as u16, 0)).block(
+        Block::default()
+            .title(" Recent exports ")
+            .title_style(Style::default().fg(theme.blue).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.blue))
+            .style(Style::default().bg(theme.header_bg)),
+    );
+    f.render_widget(Clear, popup);
+    f.render_widget(dialog, popup);
+}
+
+// ── Workflow picker ────────────────────────────────────────────────
+
+/// "All workflows" and the repo's workflows over the runs list, the
+/// filtered one marked
+fn draw_workflow_picker(f: &mut Frame, theme: &Theme, app: &App, area: Rect) {
+    let filtered = app.workflow_filter.as_ref().map(|w| w.id);
+    let mut lines = Vec::with_capacity(app.workflows.len() + 1);
+    let names = std::iter::once((None, "All workflows", true)).chain(
+        app.workflows
+            .iter()
+            .map(|w| (Some(w.id), w.name.as_str(), w.state == "active")),
+    );
+    for (i, (id, name, active)) in names.enumerate() {
+        let selected = i == app.workflows_selected;
+        let mut style = Style::default().fg(if active { theme.fg } else { theme.dim });
+        if selected {
+            style = style.bg(theme.selected_bg);
+        }
+        lines.push(Line::from(vec![
+            Span::styled(
+                if selected { "▸ " } else { "  " },
+                Style::default().fg(theme.blue),
+            ),
+            Span::styled(name, style),
+            Span::styled(
+                if id == filtered { "  ✓" } else { "" },
+                Style::default().fg(theme.green),
+            ),
+        ]));
+    }
+    if app.workflows.is_empty() && app.loading {
+        lines.push(Line::from(Span::styled(
+            "  Loading workflows...",
+            Style::default().fg(theme.gray),
+        )));
+    }
+
+    let popup = centered_rect(50, lines.len() as u16 + 2, area);
+    let visible = popup.height.saturating_sub(2) as usize;
+    let scroll = keep_in_view(app.workflows_selected, 0, visible, lines.len());
+    let dialog = Paragraph::new(lines).scroll((scroll as u16, 0)).block(
+        Block::default()
+            .title(" Filter by workflow ")
+            .title_style(Style::default().fg(theme.blue).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.blue))
+            .style(Style::default().bg(theme.header_bg)),
+    );
+    f.render_widget(Clear, popup);
+    f.render_widget(dialog, popup);
+}
+
+// ── Help ───────────────────────────────────────────────────────────
+
+/// Every key grouped by view, scrolled by `scroll` lines when it doesn't
+/// fit
+fn draw_help(f: &mut Frame, theme: &Theme, scroll: usize, area: Rect) {
+    let mut lines = Vec::with_capacity(help_len());
+    for (i, (view, keys)) in HELP.iter().enumerate() {
+        if i > 0 {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(Span::styled(
+            *view,
+            Style::default().fg(theme.blue).add_modifier(Modifier::BOLD),
+        )));
+        for (key, desc) in keys.iter() {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<16}", key), Style::default().fg(theme.yellow)),
+                Span::styled(*desc, Style::default().fg(theme.fg)),
+            ]));
+        }
+    }
+
+    let popup = centered_rect(64, lines.len() as u16 + 2, area);
+    let visible = popup.height.saturating_sub(2) as usize;
+    let scroll = scroll.min(lines.len().saturating_sub(visible));
+    let title = if lines.len() > visible {
+        " Keys · ↑↓ scroll · ? close "
+    } else {
+        " Keys · ? close "
+    };
+    let dialog = Paragraph::new(lines).scroll((scroll as u16, 0)).block(
+        Block::default()
+            .title(title)
+            .title_style(Style::default().fg(theme.blue).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.blue))
+            .padding(Padding::horizontal(1))
+            .style(Style::default().bg(theme.header_bg)),
+    );
+    f.render_widget(Clear, popup);
+    f.render_widget(dialog, popup);
+}
+
+/// A `width` × `height` box in the middle of `area`, shrunk to fit
+fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+    use std::cell::RefCell;
+
+    fn test_run(id: u64) -> WorkflowRun {
+        serde_json::from_value(serde_json::json!({
+            "id": id, "name": "CI", "head_sha": "abc", "run_number": id, "event": "push",
+            "created_at": "2025-01-12T10:00:00Z", "updated_at": "2025-01-12T10:00:00Z",
+            "html_url": ""
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_runs_list_renders_without_an_app() {
+        let runs: Vec<WorkflowRun> = (1..=8).map(test_run).collect();
+        let offset = Default::default();
+        let areas = RefCell::default();
+        let ctx = RunsListCtx {
+            runs: runs
+                .iter()
+                .map(|run| RunRow {
+                    run,
+                    stuck: (run.id == 8).then_some(Stuck {
+                        elapsed: 600,
+                        usual: 60,
+                    }),
+                    logs_expired: false,
+                    marked: run.id == 8,
+                })
+                .collect(),
+            selected: 7,
+            offset: &offset,
+            total: 40,
+            workflow: Some("CI"),
+            status: Some("failure"),
+            event: Some("push"),
+            loading: false,
+            spinner: "⠋",
+            connecting: None,
+            focus: PaneFocus::Runs,
+            preview: None,
+            preview_selected: 0,
+            caches: &HashMap::new(),
+            areas: &areas,
+        };
+        let mut terminal = Terminal::new(TestBackend::new(120, 8)).unwrap();
+        terminal
+            .draw(|f| draw_runs_list(f, &Theme::default(), &ctx, true, f.area()))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let text: Vec<String> = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect()
+            })
+            .collect();
+        assert!(text[0].contains(
+            "Workflow Runs · CI (40 total) — status: failure — event: push · 1 selected"
+        ));
+        // Five rows fit, so the table scrolled to keep run 8 on screen
+        assert_eq!(offset.get(), 3);
+        assert!(text[6].contains("▸✓") && text[6].contains("running 10m"));
+        assert_eq!(areas.borrow()[&ViewArea::Runs], Rect::new(1, 2, 118, 5));
+    }
+
+    #[test]
+    fn test_truncate_middle_keeps_the_tail() {
+        let width = |s: &str| Span::raw(s).width();
+        assert_eq!(truncate_middle("main", 14), "main");
+        assert_eq!(truncate_middle("exactly-14-col", 14), "exactly-14-col");
+        let cut = truncate_middle("main/pr-123-abcdef0123", 14);
+        assert_eq!(cut, "main…bcdef0123");
+        assert_eq!(width(&cut), 14);
+        assert_eq!(truncate_middle("feature", 1), "…");
+        assert_eq!(truncate_middle("feature", 0), "");
+    }
+
+    #[test]
+    fn test_truncate_middle_counts_wide_characters() {
+        let width = |s: &str| Span::raw(s).width();
+        // Each of these takes two columns
+        let cut = truncate_middle("機能/ログイン画面の修正", 14);
+        assert_eq!(cut, "機能/…面の修正");
+        assert_eq!(width(&cut), 14);
+        // A wide character that would straddle the limit is left out
+        let odd = truncate_middle("ブランチ名", 6);
+        assert_eq!(odd, "…チ名");
+        assert_eq!(width(&odd), 5);
+        assert_eq!(truncate_middle("ab日本語cd", 20), "ab日本語cd");
+    }
+
+    #[test]
+    fn test_truncate_end() {
+        assert_eq!(truncate_end("Fix the flaky test", 18), "Fix the flaky test");
+        assert_eq!(truncate_end("Fix the flaky test", 10), "Fix the f…");
+        // A wide character that would straddle the limit is left out
+        assert_eq!(truncate_end("修正する", 4), "修…");
+        assert_eq!(truncate_end("Fix", 0), "");
+    }
+
+    #[test]
+    fn test_run_summary_shows_the_commit() {
+        let run = test_run(7);
+        let commit: Commit = serde_json::from_value(serde_json::json!({
+            "sha": "abc",
+            "commit": {
+                "message": "Fix the flaky test in the server's startup path\n\nIt raced.",
+                "author": { "name": "Mona", "date": "2025-01-12T09:00:00Z" }
+            },
+            "stats": { "additions": 12, "deletions": 3 }
+        }))
+        .unwrap();
+        let offset = Default::default();
+        let areas = RefCell::default();
+        let ctx = RunDetailCtx {
+            run: Some(&run),
+            stuck: None,
+            counts: RunCounts::default(),
+            commit: Some(&commit),
+            commit_loading: false,
+            jobs: &[],
+            selected: 0,
+            steps_loading: None,
+            caches: &HashMap::new(),
+            cache_summary: None,
+            problem: None,
+            loading: true,
+            annotations: &[],
+            annotations_selected: 0,
+            focus: DetailFocus::Jobs,
+            steps_selected: 0,
+            steps_offset: &offset,
+            areas: &areas,
+        };
+        let mut terminal = Terminal::new(TestBackend::new(40, 16)).unwrap();
+        terminal
+            .draw(|f| draw_run_detail(f, &Theme::default(), &ctx, f.area()))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String { (0..40).map(|x| buffer[(x, y)].symbol()).collect() };
+        assert_eq!(row(3), "│  Fix the flaky test in the server's …│");
+        assert!(row(4).starts_with("│  Mona · ") && row(4).contains("d ago · +12 −3"));
+        assert!(row(6).starts_with("╰"));
+    }
+
+    #[test]
+    fn test_runs_list_shows_the_selected_ref() {
+        let mut run = test_run(1);
+        run.head_branch = Some("gh-readonly-queue/main/pr-4821-9f3c2ab7e1d0".into());
+        run.event = "merge_group".into();
+        let offset = Default::default();
+        let areas = RefCell::default();
+        let ctx = RunsListCtx {
+            runs: vec![RunRow {
+                run: &run,
+                stuck: None,
+                logs_expired: false,
+                marked: false,
+            }],
+            selected: 0,
+            offset: &offset,
+            total: 1,
+            workflow: None,
+            status: None,
+            event: None,
+            loading: false,
+            spinner: "⠋",
+            connecting: None,
+            focus: PaneFocus::Runs,
+            preview: None,
+            preview_selected: 0,
+            caches: &HashMap::new(),
+            areas: &areas,
+        };
+        let mut terminal = Terminal::new(TestBackend::new(140, 5)).unwrap();
+        terminal
+            .draw(|f| draw_runs_list(f, &Theme::default(), &ctx, true, f.area()))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String { (0..140).map(|x| buffer[(x, y)].symbol()).collect() };
+        assert!(row(2).contains("⇶ main…c2ab7e1d0"), "{}", row(2));
+        assert!(row(4).contains("⇶ merge queue gh-readonly-queue/main/pr-4821-9f3c2ab7e1d0"));
+    }
+}
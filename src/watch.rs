@@ -0,0 +1,398 @@
+use anyhow::Result;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::github::GitHubClient;
+use crate::lookup;
+use crate::models::{format_duration, Job, WorkflowRun};
+
+// ── Run watch ──────────────────────────────────────────────────────
+
+/// Which run `atlas watch` follows
+#[derive(Debug, Clone, PartialEq)]
+pub enum Target {
+    Run(u64),
+    /// The latest run, on `branch` when given
+    Latest {
+        branch: Option<String>,
+    },
+}
+
+/// How the watched run ended
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    Succeeded,
+    Failed,
+    Cancelled,
+    TimedOut,
+}
+
+impl Outcome {
+    /// 0 succeeded, 1 failed, 2 cancelled, 3 gave up waiting
+    pub fn exit_code(self) -> u8 {
+        match self {
+            Self::Succeeded => 0,
+            Self::Failed => 1,
+            Self::Cancelled => 2,
+            Self::TimedOut => 3,
+        }
+    }
+
+    fn of(run: &WorkflowRun) -> Self {
+        match run.conclusion.as_deref() {
+            Some("success" | "neutral" | "skipped") => Self::Succeeded,
+            Some("cancelled") => Self::Cancelled,
+            _ => Self::Failed,
+        }
+    }
+}
+
+/// What changed in a job between two polls
+#[derive(Debug, Clone, PartialEq)]
+enum Event {
+    Started(String),
+    Finished {
+        id: u64,
+        name: String,
+        conclusion: String,
+        secs: Option<i64>,
+    },
+    StepFailed {
+        job: String,
+        step: String,
+    },
+}
+
+impl Event {
+    /// `build: failure (1m 5s)`
+    fn line(&self) -> String {
+        match self {
+            Self::Started(name) => format!("{}: started", name),
+            Self::Finished {
+                name,
+                conclusion,
+                secs,
+                ..
+            } => match secs {
+                Some(secs) => format!(
+                    "{}: {} ({})",
+                    name,
+                    conclusion.replace('_', " "),
+                    format_duration(*secs)
+                ),
+                None => format!("{}: {}", name, conclusion.replace('_', " ")),
+            },
+            Self::StepFailed { job, step } => format!("{}: step failed: {}", job, step),
+        }
+    }
+}
+
+/// The job states seen so far, to report only what changed
+#[derive(Debug, Default)]
+struct Tracker {
+    /// Job id to whether it had started and finished
+    seen: HashMap<u64, (bool, bool)>,
+}
+
+impl Tracker {
+    /// Jobs that started or finished since the last poll; a job that did
+    /// both in between gets both
+    fn update(&mut self, jobs: &[Job]) -> Vec<Event> {
+        let mut events = Vec::new();
+        for job in jobs {
+            let finished = job.status.as_deref() == Some("completed");
+            let started = finished || job.started_at.is_some();
+            let (was_started, was_finished) = self
+                .seen
+                .insert(job.id, (started, finished))
+                .unwrap_or_default();
+            // A job skipped by an `if:` finishes without ever starting
+            let skipped = job.conclusion.as_deref() == Some("skipped");
+            if started && !was_started && !skipped {
+                events.push(Event::Started(job.name.clone()));
+            }
+            if finished && !was_finished {
+                events.push(Event::Finished {
+                    id: job.id,
+                    name: job.name.clone(),
+                    conclusion: job.conclusion.clone().unwrap_or_else(|| "unknown".into()),
+                    secs: job
+                        .started_at
+                        .zip(job.completed_at)
+                        .map(|(from, to)| (to - from).num_seconds()),
+                });
+            }
+        }
+        events
+    }
+}
+
+/// Follow `target` every `interval` until it completes or `timeout` runs
+/// out, printing progress lines to stdout, or one JSON object per poll
+/// when `json`
+pub async fn run(
+    client: &GitHubClient,
+    target: &Target,
+    timeout: Option<Duration>,
+    interval: Duration,
+    json: bool,
+) -> Result<Outcome> {
+    let mut run = match target {
+        Target::Run(id) => client.get_workflow_run(*id).await?,
+        Target::Latest { branch } => {
+            lookup::latest_run(client, branch.as_deref(), None, None).await?
+        }
+    };
+    if !json {
+        println!("{}", header(&run));
+    }
+
+    let started = Instant::now();
+    let mut tracker = Tracker::default();
+    loop {
+        let jobs = client.get_jobs(run.id).await?.jobs;
+        let mut events = tracker.update(&jobs);
+        // The jobs list has no steps: read them for the jobs that just failed
+        let failed: Vec<&Job> = jobs
+            .iter()
+            .filter(|job| {
+                job.conclusion.as_deref() == Some("failure")
+                    && events
+                        .iter()
+                        .any(|event| matches!(event, Event::Finished { id, .. } if *id == job.id))
+            })
+            .collect();
+        for job in failed {
+            let detail = client.get_job(job.id).await?;
+            events.extend(
+                detail
+                    .steps
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|step| step.conclusion.as_deref() == Some("failure"))
+                    .map(|step| Event::StepFailed {
+                        job: job.name.clone(),
+                        step: step.name,
+                    }),
+            );
+        }
+
+        let waited = started.elapsed();
+        if json {
+            println!("{}", poll_json(&run, &jobs, &events, waited));
+        } else {
+            for event in &events {
+                println!(
+                    "[{}] {}",
+                    format_duration(waited.as_secs() as i64),
+                    event.line()
+                );
+            }
+        }
+        if run.status.as_deref() == Some("completed") {
+            if !json {
+                println!("{}", header(&run));
+            }
+            return Ok(Outcome::of(&run));
+        }
+        let left = timeout.map(|t| t.saturating_sub(started.elapsed()));
+        if left == Some(Duration::ZERO) {
+            eprintln!(
+                "Gave up on #{} after {}",
+                run.run_number,
+                format_duration(started.elapsed().as_secs() as i64)
+            );
+            return Ok(Outcome::TimedOut);
+        }
+        tokio::time::sleep(left.map_or(interval, |left| left.min(interval))).await;
+        run = client.get_workflow_run(run.id).await?;
+    }
+}
+
+/// `CI #42 on main: in progress`
+fn header(run: &WorkflowRun) -> String {
+    let state = match (run.status.as_deref(), run.conclusion.as_deref()) {
+        (Some("completed"), Some(conclusion)) => conclusion,
+        (status, _) => status.unwrap_or("unknown"),
+    };
+    format!(
+        "{} #{} on {}: {}",
+        run.name.as_deref().unwrap_or("Workflow"),
+        run.run_number,
+        run.head_branch.as_deref().unwrap_or("?"),
+        state.replace('_', " ")
+    )
+}
+
+/// One poll for `--json`: the run, its jobs, and what changed since the
+/// last poll
+fn poll_json(
+    run: &WorkflowRun,
+    jobs: &[Job],
+    events: &[Event],
+    waited: Duration,
+) -> serde_json::Value {
+    let jobs: Vec<_> = jobs
+        .iter()
+        .map(|job| {
+            serde_json::json!({
+                "id": job.id,
+                "name": job.name,
+                "status": job.status,
+                "conclusion": job.conclusion,
+            })
+        })
+        .collect();
+    let events: Vec<String> = events.iter().map(Event::line).collect();
+    serde_json::json!({
+        "elapsed_secs": waited.as_secs(),
+        "run": {
+            "id": run.id,
+            "run_number": run.run_number,
+            "workflow": run.name,
+            "branch": run.head_branch,
+            "status": run.status,
+            "conclusion": run.conclusion,
+            "url": run.html_url,
+        },
+        "jobs": jobs,
+        "events": events,
+    })
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{client, job, job_json, run_json};
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_tracker_reports_changes_once() {
+        let mut tracker = Tracker::default();
+        let events = tracker.update(&[job(1, "in_progress", None), job(2, "queued", None)]);
+        assert_eq!(events, [Event::Started("job-1".into())]);
+        assert!(tracker
+            .update(&[job(1, "in_progress", None), job(2, "queued", None)])
+            .is_empty());
+
+        // Job 2 started and finished between polls
+        let events = tracker.update(&[
+            job(1, "completed", Some("success")),
+            job(2, "completed", Some("failure")),
+        ]);
+        assert_eq!(
+            events.iter().map(Event::line).collect::<Vec<_>>(),
+            [
+                "job-1: success (1m 5s)",
+                "job-2: started",
+                "job-2: failure (1m 5s)",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_watches_the_latest_run_to_the_end() {
+        let api = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs"))
+            .and(query_param("branch", "main"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 1,
+                "workflow_runs": [run_json("in_progress", None)]
+            })))
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs/7"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(run_json("completed", Some("failure"))),
+            )
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs/7/jobs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 1,
+                "jobs": [job_json(1, "completed", Some("failure"))]
+            })))
+            .mount(&api)
+            .await;
+        let mut detail = job_json(1, "completed", Some("failure"));
+        detail["steps"] = serde_json::json!([{
+            "name": "Run tests", "status": "completed", "conclusion": "failure",
+            "number": 2, "started_at": null, "completed_at": null
+        }]);
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/jobs/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(detail))
+            .expect(1)
+            .mount(&api)
+            .await;
+
+        let target = Target::Latest {
+            branch: Some("main".into()),
+        };
+        let outcome = run(
+            &client(&api),
+            &target,
+            None,
+            Duration::from_millis(10),
+            true,
+        )
+        .await
+        .unwrap();
+        assert_eq!(outcome, Outcome::Failed);
+        assert_eq!(outcome.exit_code(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_and_timed_out() {
+        let api = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs/7"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(run_json("completed", Some("cancelled"))),
+            )
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs/7/jobs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 0, "jobs": []
+            })))
+            .mount(&api)
+            .await;
+        let outcome = run(&client(&api), &Target::Run(7), None, Duration::ZERO, false)
+            .await
+            .unwrap();
+        assert_eq!(outcome.exit_code(), 2);
+
+        let api = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs/7"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(run_json("queued", None)))
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/api/actions/runs/7/jobs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 0, "jobs": []
+            })))
+            .mount(&api)
+            .await;
+        let outcome = run(
+            &client(&api),
+            &Target::Run(7),
+            Some(Duration::from_millis(50)),
+            Duration::from_millis(10),
+            false,
+        )
+        .await
+        .unwrap();
+        assert_eq!(outcome, Outcome::TimedOut);
+        assert_eq!(outcome.exit_code(), 3);
+    }
+}